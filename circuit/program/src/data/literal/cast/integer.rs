@@ -41,6 +41,17 @@ impl<E: Environment, I: IntegerType> Cast<Boolean<E>> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Cast<Decimal<E>> for Integer<E, I> {
+    /// Casts an `Integer` to a `Decimal`, if the scaled integer fits in the decimal mantissa.
+    ///
+    /// To cast arbitrary integers to decimals, use `Integer::cast_lossy`.
+    #[inline]
+    fn cast(&self) -> Decimal<E> {
+        let integer: I64<E> = self.cast();
+        Decimal::from_mantissa(integer.mul_checked(&Decimal::scaling_factor()))
+    }
+}
+
 impl<E: Environment, I: IntegerType> Cast<Field<E>> for Integer<E, I> {
     /// Casts an `Integer` to a `Field`.
     #[inline]
@@ -210,6 +221,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i8_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 388, 393),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 388, 393),
+            );
+        }
+
         #[test]
         fn test_i8_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -350,6 +377,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i16_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 388, 393),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 388, 393),
+            );
+        }
+
         #[test]
         fn test_i16_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -490,6 +533,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i32_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 388, 393),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 388, 393),
+            );
+        }
+
         #[test]
         fn test_i32_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -630,6 +689,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i64_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 388, 393),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 388, 393),
+            );
+        }
+
         #[test]
         fn test_i64_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -770,6 +845,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i128_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 388, 457),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 388, 457),
+            );
+        }
+
         #[test]
         fn test_i128_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -906,6 +997,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u8_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 258, 262),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 258, 262),
+            );
+        }
+
         #[test]
         fn test_u8_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -1046,6 +1153,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u16_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 258, 262),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 258, 262),
+            );
+        }
+
         #[test]
         fn test_u16_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -1186,6 +1309,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u32_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 258, 262),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 258, 262),
+            );
+        }
+
         #[test]
         fn test_u32_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -1326,6 +1465,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u64_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 258, 263),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 258, 263),
+            );
+        }
+
         #[test]
         fn test_u64_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -1466,6 +1621,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u128_to_decimal() {
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(320, 0, 258, 263),
+            );
+            check_cast::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(320, 0, 258, 263),
+            );
+        }
+
         #[test]
         fn test_u128_to_field() {
             check_cast::<Field<Circuit>, console_root::types::Field<Testnet3>>(Mode::Constant, count_is!(0, 0, 0, 0));
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod boolean;
+mod decimal;
 mod field;
 mod integer;
 mod scalar;
@@ -26,6 +27,8 @@ use snarkvm_circuit_types::prelude::{
     Address,
     BitOr,
     Boolean,
+    Decimal,
+    DivWrapped,
     Environment,
     Field,
     FromBits,
@@ -33,6 +36,8 @@ use snarkvm_circuit_types::prelude::{
     FromGroup,
     Group,
     IntegerType,
+    MulChecked,
+    MulWrapped,
     One,
     Result,
     Scalar,
@@ -40,11 +45,12 @@ use snarkvm_circuit_types::prelude::{
     ToField,
     ToGroup,
     Zero,
+    I64,
     MSB,
 };
 
 #[cfg(test)]
-use snarkvm_circuit_types::prelude::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+use snarkvm_circuit_types::prelude::{I128, I16, I32, I8, U128, U16, U32, U64, U8};
 
 /// Unary operator for casting values of one type to another.
 pub trait Cast<T: Sized = Self> {
@@ -62,6 +68,7 @@ impl<A: Aleo> Literal<A> {
     ///
     /// The hierarchy of casting is as follows:
     ///  - (`Address`, `Group`) <-> `Field` <-> `Scalar` <-> `Integer` <-> `Boolean`
+    ///  - `Decimal` <-> `Integer`
     ///  - `Signature` (not supported)
     ///  - `String` (not supported)
     /// Note that casting to left along the hierarchy always preserves information.
@@ -84,6 +91,7 @@ impl<A: Aleo> Literal<A> {
             Self::Scalar(scalar) => cast_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
+            Self::Decimal(decimal) => cast_decimal_to_type(decimal, to_type),
         }
     }
 }
@@ -113,6 +121,9 @@ macro_rules! impl_cast_body {
             LiteralType::String => {
                 bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a string type."))
             }
+            LiteralType::Decimal => {
+                bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a decimal type."))
+            }
         }
     };
 }
@@ -138,7 +149,18 @@ fn cast_group_to_type<A: Aleo>(input: &Group<A>, to_type: LiteralType) -> Result
 
 /// Casts an integer literal to the given literal type.
 fn cast_integer_to_type<A: Aleo, I: IntegerType>(input: &Integer<A, I>, to_type: LiteralType) -> Result<Literal<A>> {
-    impl_cast_body!(integer, cast, input, to_type)
+    match to_type {
+        LiteralType::Decimal => Ok(Literal::Decimal(input.cast())),
+        _ => impl_cast_body!(integer, cast, input, to_type),
+    }
+}
+
+/// Casts a decimal literal to the given literal type.
+fn cast_decimal_to_type<A: Aleo>(input: &Decimal<A>, to_type: LiteralType) -> Result<Literal<A>> {
+    match to_type {
+        LiteralType::Decimal => Ok(Literal::Decimal(input.clone())),
+        _ => cast_integer_to_type(&Cast::<I64<A>>::cast(input), to_type),
+    }
 }
 
 /// Casts a scalar literal to the given literal type.
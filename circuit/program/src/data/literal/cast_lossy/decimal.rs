@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> CastLossy<I64<E>> for Decimal<E> {
+    /// Casts a `Decimal` to an `I64`, with lossy truncation.
+    /// This operation truncates the fractional part toward zero.
+    #[inline]
+    fn cast_lossy(&self) -> I64<E> {
+        self.mantissa().div_wrapped(&Decimal::scaling_factor())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::CastLossy as _;
+    use console_root::{
+        network::Testnet3,
+        prelude::{TestRng, Uniform},
+    };
+    use snarkvm_circuit_types::environment::{count_is, Circuit, Eject, Inject, Mode, UpdatableCount};
+
+    use std::fmt::Debug;
+
+    const ITERATIONS: usize = 100;
+
+    fn sample_values(
+        i: usize,
+        mode: Mode,
+        rng: &mut TestRng,
+    ) -> (console_root::types::Decimal<Testnet3>, Decimal<Circuit>) {
+        let console_value = match i {
+            0 => console_root::types::Decimal::<Testnet3>::from_mantissa(0),
+            1 => console_root::types::Decimal::<Testnet3>::from_integer(1),
+            2 => console_root::types::Decimal::<Testnet3>::from_integer(-7),
+            3 => console_root::types::Decimal::<Testnet3>::MAX,
+            4 => console_root::types::Decimal::<Testnet3>::MIN,
+            _ => Uniform::rand(rng),
+        };
+        let circuit_value = Decimal::<Circuit>::new(mode, console_value);
+        (console_value, circuit_value)
+    }
+
+    check_cast_lossy!(cast_lossy, Decimal<Circuit>, console_root::types::Decimal::<Testnet3>);
+
+    #[test]
+    fn test_decimal_to_i64() {
+        check_cast_lossy::<I64<Circuit>, console_root::types::I64<Testnet3>>(Mode::Constant, count_is!(128, 0, 0, 0));
+        check_cast_lossy::<I64<Circuit>, console_root::types::I64<Testnet3>>(Mode::Public, count_is!(384, 0, 451, 456));
+        check_cast_lossy::<I64<Circuit>, console_root::types::I64<Testnet3>>(
+            Mode::Private,
+            count_is!(384, 0, 451, 456),
+        );
+    }
+}
@@ -38,6 +38,16 @@ impl<E: Environment, I: IntegerType> CastLossy<Boolean<E>> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> CastLossy<Decimal<E>> for Integer<E, I> {
+    /// Casts an `Integer` to a `Decimal`, with lossy truncation.
+    /// This operation truncates the integer to an `I64`, and wraps the scaled mantissa on overflow.
+    #[inline]
+    fn cast_lossy(&self) -> Decimal<E> {
+        let integer: I64<E> = self.cast_lossy();
+        Decimal::from_mantissa(integer.mul_wrapped(&Decimal::scaling_factor()))
+    }
+}
+
 impl<E: Environment, I: IntegerType> CastLossy<Field<E>> for Integer<E, I> {
     /// Casts an `Integer` to a `Field`.
     /// This is safe because casting from an integer to a field is **always** lossless.
@@ -158,6 +168,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i8_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_i8_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -334,6 +360,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i16_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_i16_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -510,6 +552,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i32_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_i32_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -686,6 +744,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i64_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_i64_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -862,6 +936,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_i128_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_i128_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -1034,6 +1124,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u8_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_u8_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -1210,6 +1316,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u16_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_u16_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -1386,6 +1508,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u32_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_u32_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -1562,6 +1700,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u64_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_u64_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -1738,6 +1892,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_u128_to_decimal() {
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Constant,
+                count_is!(128, 0, 0, 0),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Public,
+                count_is!(64, 0, 97, 98),
+            );
+            check_cast_lossy::<Decimal<Circuit>, console_root::types::Decimal<Testnet3>>(
+                Mode::Private,
+                count_is!(64, 0, 97, 98),
+            );
+        }
+
         #[test]
         fn test_u128_to_field() {
             check_cast_lossy::<Field<Circuit>, console_root::types::Field<Testnet3>>(
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod boolean;
+mod decimal;
 mod field;
 mod integer;
 mod scalar;
@@ -29,6 +30,8 @@ use snarkvm_circuit_types::prelude::{
     witness_mode,
     Address,
     Boolean,
+    Decimal,
+    DivWrapped,
     Eject,
     Environment,
     Field,
@@ -39,6 +42,7 @@ use snarkvm_circuit_types::prelude::{
     Inject,
     IntegerType,
     Mode,
+    MulWrapped,
     One,
     Result,
     Scalar,
@@ -47,11 +51,12 @@ use snarkvm_circuit_types::prelude::{
     ToField,
     ToGroup,
     Zero,
+    I64,
     MSB,
 };
 
 #[cfg(test)]
-use snarkvm_circuit_types::prelude::{I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+use snarkvm_circuit_types::prelude::{I128, I16, I32, I8, U128, U16, U32, U64, U8};
 
 /// Unary operator for casting values of one type to another, with lossy truncation.
 pub trait CastLossy<T: Sized = Self> {
@@ -70,6 +75,7 @@ impl<A: Aleo> Literal<A> {
     ///
     /// The hierarchy of casting is as follows:
     ///  - (`Address`, `Group`) <-> `Field` <-> `Scalar` <-> `Integer` <-> `Boolean`
+    ///  - `Decimal` <-> `Integer`
     ///  - `Signature` (not supported)
     ///  - `String` (not supported)
     /// Note that casting to left along the hierarchy always preserves information.
@@ -92,6 +98,7 @@ impl<A: Aleo> Literal<A> {
             Self::Scalar(scalar) => cast_lossy_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
+            Self::Decimal(decimal) => cast_lossy_decimal_to_type(decimal, to_type),
         }
     }
 }
@@ -121,6 +128,9 @@ macro_rules! impl_cast_body {
             LiteralType::String => {
                 bail!(concat!("Cannot cast (lossy) a ", stringify!($type_name), " literal to a string type."))
             }
+            LiteralType::Decimal => {
+                bail!(concat!("Cannot cast (lossy) a ", stringify!($type_name), " literal to a decimal type."))
+            }
         }
     };
 }
@@ -149,7 +159,18 @@ fn cast_lossy_integer_to_type<A: Aleo, I: IntegerType>(
     input: &Integer<A, I>,
     to_type: LiteralType,
 ) -> Result<Literal<A>> {
-    impl_cast_body!(integer, cast_lossy, input, to_type)
+    match to_type {
+        LiteralType::Decimal => Ok(Literal::Decimal(input.cast_lossy())),
+        _ => impl_cast_body!(integer, cast_lossy, input, to_type),
+    }
+}
+
+/// Casts a decimal literal to the given literal type, with lossy truncation.
+fn cast_lossy_decimal_to_type<A: Aleo>(input: &Decimal<A>, to_type: LiteralType) -> Result<Literal<A>> {
+    match to_type {
+        LiteralType::Decimal => Ok(Literal::Decimal(input.clone())),
+        _ => cast_lossy_integer_to_type(&CastLossy::<I64<A>>::cast_lossy(input), to_type),
+    }
 }
 
 /// Casts a scalar literal to the given literal type, with lossy truncation.
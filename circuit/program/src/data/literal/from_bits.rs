@@ -36,7 +36,8 @@ impl<A: Aleo> Literal<A> {
             14 => Literal::Scalar(Scalar::from_bits_le(literal)),
             15 => Literal::Signature(Box::new(Signature::from_bits_le(literal))),
             16 => Literal::String(StringType::from_bits_le(literal)),
-            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
+            17 => Literal::Decimal(Decimal::from_bits_le(literal)),
+            18.. => A::halt(format!("Failed to initialize literal variant {} from bits (LE)", variant.eject_value())),
         }
     }
 
@@ -61,7 +62,8 @@ impl<A: Aleo> Literal<A> {
             14 => Literal::Scalar(Scalar::from_bits_be(literal)),
             15 => Literal::Signature(Box::new(Signature::from_bits_be(literal))),
             16 => Literal::String(StringType::from_bits_be(literal)),
-            17.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
+            17 => Literal::Decimal(Decimal::from_bits_be(literal)),
+            18.. => A::halt(format!("Failed to initialize literal variant {} from bits (BE))", variant.eject_value())),
         }
     }
 }
@@ -132,6 +134,8 @@ mod tests {
             // Sample a random string. Take 1/4th to ensure we fit for all code points.
             let string = rng.next_string(Circuit::MAX_STRING_BYTES / 4, false);
             check_serialization(Literal::<Circuit>::String(StringType::new(mode, console::StringType::new(&string))));
+            // Decimal
+            check_serialization(Literal::<Circuit>::Decimal(Decimal::new(mode, Uniform::rand(rng))));
         }
     }
 
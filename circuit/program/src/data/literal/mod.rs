@@ -69,6 +69,8 @@ pub enum Literal<A: Aleo> {
     Signature(Box<Signature<A>>),
     /// The string type.
     String(StringType<A>),
+    /// The fixed-point decimal type.
+    Decimal(Decimal<A>),
 }
 
 #[cfg(console)]
@@ -95,6 +97,7 @@ impl<A: Aleo> Inject for Literal<A> {
             Self::Primitive::Scalar(scalar) => Self::Scalar(Scalar::new(mode, scalar)),
             Self::Primitive::Signature(signature) => Self::Signature(Box::new(Signature::new(mode, *signature))),
             Self::Primitive::String(string) => Self::String(StringType::new(mode, string)),
+            Self::Primitive::Decimal(decimal) => Self::Decimal(Decimal::new(mode, decimal)),
         }
     }
 }
@@ -123,6 +126,7 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::Scalar(literal) => literal.eject_mode(),
             Self::Signature(literal) => literal.eject_mode(),
             Self::String(literal) => literal.eject_mode(),
+            Self::Decimal(literal) => literal.eject_mode(),
         }
     }
 
@@ -146,6 +150,7 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::Scalar(literal) => Self::Primitive::Scalar(literal.eject_value()),
             Self::Signature(literal) => Self::Primitive::Signature(Box::new(literal.eject_value())),
             Self::String(literal) => Self::Primitive::String(literal.eject_value()),
+            Self::Decimal(literal) => Self::Primitive::Decimal(literal.eject_value()),
         }
     }
 }
@@ -173,6 +178,7 @@ impl<A: Aleo> Parser for Literal<A> {
             map(Scalar::parse, |literal| Self::Scalar(literal)),
             map(Signature::parse, |literal| Self::Signature(Box::new(literal))),
             map(StringType::parse, |literal| Self::String(literal)),
+            map(Decimal::parse, |literal| Self::Decimal(literal)),
         ))(string)
     }
 }
@@ -218,6 +224,7 @@ impl<A: Aleo> Literal<A> {
             Self::Scalar(..) => Scalar::<A>::type_name(),
             Self::Signature(..) => Signature::<A>::type_name(),
             Self::String(..) => StringType::<A>::type_name(),
+            Self::Decimal(..) => Decimal::<A>::type_name(),
         }
     }
 }
@@ -250,6 +257,7 @@ impl<A: Aleo> Display for Literal<A> {
             Self::Scalar(literal) => Display::fmt(literal, f),
             Self::Signature(literal) => Display::fmt(literal, f),
             Self::String(literal) => Display::fmt(literal, f),
+            Self::Decimal(literal) => Display::fmt(literal, f),
         }
     }
 }
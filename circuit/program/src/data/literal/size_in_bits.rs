@@ -35,6 +35,7 @@ impl<A: Aleo> Literal<A> {
             Self::Scalar(..) => console::Scalar::<A::Network>::size_in_bits() as u16,
             Self::Signature(..) => console::Signature::<A::Network>::size_in_bits() as u16,
             Self::String(string) => string.to_bits_le().len() as u16,
+            Self::Decimal(..) => console::Decimal::<A::Network>::size_in_bits() as u16,
         }))
     }
 }
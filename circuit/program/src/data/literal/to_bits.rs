@@ -51,6 +51,7 @@ impl<A: Aleo> ToBits for &Literal<A> {
             Literal::Scalar(literal) => literal.write_bits_le(vec),
             Literal::Signature(literal) => literal.write_bits_le(vec),
             Literal::String(literal) => literal.write_bits_le(vec),
+            Literal::Decimal(literal) => literal.write_bits_le(vec),
         }
     }
 
@@ -74,6 +75,7 @@ impl<A: Aleo> ToBits for &Literal<A> {
             Literal::Scalar(literal) => literal.write_bits_be(vec),
             Literal::Signature(literal) => literal.write_bits_be(vec),
             Literal::String(literal) => literal.write_bits_be(vec),
+            Literal::Decimal(literal) => literal.write_bits_be(vec),
         }
     }
 }
@@ -0,0 +1,21 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod serial_number_proof;
+pub use serial_number_proof::SerialNumberProof;
+
+use crate::{Plaintext, Record};
+use snarkvm_circuit_account::ComputeKey;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{Boolean, Field, Group, Scalar, environment::prelude::*};
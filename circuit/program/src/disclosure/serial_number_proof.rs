@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+pub struct SerialNumberProof<A: Aleo> {
+    /// The record commitment.
+    commitment: Field<A>,
+    /// The serial number of the record.
+    serial_number: Field<A>,
+    /// The `gamma` of the record, as `sk_sig * H`.
+    gamma: Group<A>,
+    /// The verifier challenge.
+    challenge: Scalar<A>,
+    /// The prover response.
+    response: Scalar<A>,
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for SerialNumberProof<A> {
+    type Primitive = console::SerialNumberProof<A::Network>;
+
+    /// Initializes a serial number proof from the given mode and console serial number proof.
+    fn new(mode: Mode, proof: Self::Primitive) -> Self {
+        Self {
+            commitment: Field::new(mode, proof.commitment()),
+            serial_number: Field::new(mode, proof.serial_number()),
+            gamma: Group::new(mode, proof.gamma()),
+            challenge: Scalar::new(mode, proof.challenge()),
+            response: Scalar::new(mode, proof.response()),
+        }
+    }
+}
+
+impl<A: Aleo> SerialNumberProof<A> {
+    /// Returns `true` if the proof is valid for the given compute key.
+    ///
+    /// Verifies (challenge == challenge') && (serial_number == serial_number') where:
+    ///     challenge' := HashToScalar(pk_sig, gamma, (response * G) + (challenge * pk_sig),
+    ///                                (response * H) + (challenge * gamma), commitment, serial_number)
+    pub fn verify(&self, compute_key: &ComputeKey<A>) -> Boolean<A> {
+        // Compute the generator `H` as `HashToGroup(commitment)`.
+        let h = A::hash_to_group_psd2(&[A::serial_number_domain(), self.commitment.clone()]);
+        // Compute the candidate serial number from `gamma`.
+        let serial_number = Record::<A, Plaintext<A>>::serial_number_from_gamma(&self.gamma, self.commitment.clone());
+
+        // Compute `k * G` as `(response * G) + (challenge * pk_sig)`.
+        let pk_sig = compute_key.pk_sig();
+        let g_k = A::g_scalar_multiply(&self.response) + (pk_sig * &self.challenge);
+        // Compute `k * H` as `(response * H) + (challenge * gamma)`.
+        let h_k = (&h * &self.response) + (&self.gamma * &self.challenge);
+
+        // Compute the candidate verifier challenge.
+        let mut preimage = Vec::with_capacity(7);
+        preimage.push(A::serial_number_domain());
+        preimage.extend([pk_sig, &self.gamma, &g_k, &h_k].map(|point| point.to_x_coordinate()));
+        preimage.extend([self.commitment.clone(), self.serial_number.clone()]);
+        let challenge = A::hash_to_scalar_psd8(&preimage);
+
+        // Ensure the challenge and serial number match.
+        challenge.is_equal(&self.challenge) & serial_number.is_equal(&self.serial_number)
+    }
+
+    /// Returns the record commitment.
+    pub const fn commitment(&self) -> &Field<A> {
+        &self.commitment
+    }
+
+    /// Returns the serial number of the record.
+    pub const fn serial_number(&self) -> &Field<A> {
+        &self.serial_number
+    }
+
+    /// Returns the `gamma` of the record.
+    pub const fn gamma(&self) -> &Group<A> {
+        &self.gamma
+    }
+
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> &Scalar<A> {
+        &self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> &Scalar<A> {
+        &self.response
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for SerialNumberProof<A> {
+    type Primitive = console::SerialNumberProof<A::Network>;
+
+    /// Ejects the mode of the serial number proof.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(self.commitment.eject_mode(), [
+            self.serial_number.eject_mode(),
+            self.gamma.eject_mode(),
+            self.challenge.eject_mode(),
+            self.response.eject_mode(),
+        ])
+    }
+
+    /// Ejects the serial number proof.
+    fn eject_value(&self) -> Self::Primitive {
+        Self::Primitive::from((
+            self.commitment.eject_value(),
+            self.serial_number.eject_value(),
+            self.gamma.eject_value(),
+            self.challenge.eject_value(),
+            self.response.eject_value(),
+        ))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an account and a serial number proof.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let compute_key = snarkvm_console_account::ComputeKey::try_from(&private_key)?;
+            let proof = console::SerialNumberProof::prove(&private_key, console::Field::rand(rng), rng)?;
+            assert!(proof.verify(&compute_key));
+
+            Circuit::scope(format!("SerialNumberProof {i}"), || {
+                let compute_key = ComputeKey::<Circuit>::new(Mode::Public, compute_key);
+                let candidate = SerialNumberProof::<Circuit>::new(Mode::Private, proof);
+                assert_eq!(proof, candidate.eject_value());
+                assert!(candidate.verify(&compute_key).eject_value());
+                assert!(Circuit::is_satisfied());
+            });
+            Circuit::reset();
+
+            // Check the proof is invalid for another account.
+            let other_key = snarkvm_console_account::ComputeKey::try_from(&PrivateKey::new(rng)?)?;
+            Circuit::scope(format!("SerialNumberProof {i} (invalid)"), || {
+                let compute_key = ComputeKey::<Circuit>::new(Mode::Public, other_key);
+                let candidate = SerialNumberProof::<Circuit>::new(Mode::Private, proof);
+                assert!(!candidate.verify(&compute_key).eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
@@ -136,8 +136,20 @@ pub struct Request<A: Aleo> {
     tvk: Field<A>,
     /// The transition commitment.
     tcm: Field<A>,
+    /// The transition public key base and expiration height.
+    /// Note: These are not injected, as the transition public key is checked to commit to them outside the circuit.
+    #[cfg(console)]
+    expiration: (console::Group<A::Network>, Option<u32>),
+    /// The nonce and ciphertext of the memo, if any.
+    /// Note: This is not injected, as the transition public key is checked to commit to it outside the circuit.
+    #[cfg(console)]
+    memo: Option<MemoCiphertext<A::Network>>,
 }
 
+/// The nonce and ciphertext of a request memo.
+#[cfg(console)]
+type MemoCiphertext<N> = (console::Group<N>, console::Ciphertext<N>);
+
 #[cfg(console)]
 impl<A: Aleo> Inject for Request<A> {
     type Primitive = console::Request<A::Network>;
@@ -218,6 +230,8 @@ impl<A: Aleo> Inject for Request<A> {
             sk_tag: Field::new(mode, *request.sk_tag()),
             tvk: Field::new(mode, *request.tvk()),
             tcm,
+            expiration: (*request.tpk_base(), request.expiration_height()),
+            memo: request.memo().cloned(),
         }
     }
 }
@@ -306,6 +320,9 @@ impl<A: Aleo> Eject for Request<A> {
             self.sk_tag.eject_value(),
             self.tvk.eject_value(),
             self.tcm.eject_value(),
+            self.expiration.0,
+            self.expiration.1,
+            self.memo.clone(),
         ))
     }
 }
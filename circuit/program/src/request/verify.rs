@@ -104,6 +104,39 @@ impl<A: Aleo> Request<A> {
         tvk: &Field<A>,
         tcm: &Field<A>,
         signature: Option<&Signature<A>>,
+    ) -> (Boolean<A>, Option<Vec<Field<A>>>) {
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id =
+            A::hash_bhp1024(&(network_id, program_id.name(), program_id.network(), function_name).to_bits_le());
+
+        Self::check_input_ids_with_function_id::<CREATE_MESSAGE>(
+            &function_id,
+            program_id,
+            input_ids,
+            inputs,
+            input_types,
+            signer,
+            sk_tag,
+            tvk,
+            tcm,
+            signature,
+        )
+    }
+
+    /// Returns `true` if the inputs match their input IDs, given a precomputed function ID.
+    /// This is used for dynamic calls, where the function ID is a public input of the caller.
+    /// Note: This method does **not** perform signature checks.
+    pub fn check_input_ids_with_function_id<const CREATE_MESSAGE: bool>(
+        function_id: &Field<A>,
+        program_id: &ProgramID<A>,
+        input_ids: &[InputID<A>],
+        inputs: &[Value<A>],
+        input_types: &[console::ValueType<A::Network>],
+        signer: &Address<A>,
+        sk_tag: &Field<A>,
+        tvk: &Field<A>,
+        tcm: &Field<A>,
+        signature: Option<&Signature<A>>,
     ) -> (Boolean<A>, Option<Vec<Field<A>>>) {
         // Ensure the signature response matches the `CREATE_MESSAGE` flag.
         match CREATE_MESSAGE {
@@ -111,10 +144,6 @@ impl<A: Aleo> Request<A> {
             false => assert!(signature.is_none()),
         }
 
-        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id =
-            A::hash_bhp1024(&(network_id, program_id.name(), program_id.network(), function_name).to_bits_le());
-
         // Initialize a vector for a message.
         let mut message = Vec::new();
 
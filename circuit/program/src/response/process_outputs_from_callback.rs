@@ -30,6 +30,29 @@ impl<A: Aleo> Response<A> {
         let function_id =
             A::hash_bhp1024(&(network_id, program_id.name(), program_id.network(), function_name).to_bits_le());
 
+        Self::process_outputs_from_callback_with_function_id(
+            &function_id,
+            program_id,
+            num_inputs,
+            tvk,
+            tcm,
+            outputs,
+            output_types,
+        )
+    }
+
+    /// Returns the injected circuit outputs, given a precomputed function ID, the number of inputs, tvk, tcm,
+    /// outputs, and output types.
+    /// This is used for dynamic calls, where the function ID is a public input of the caller.
+    pub fn process_outputs_from_callback_with_function_id(
+        function_id: &Field<A>,
+        program_id: &ProgramID<A>,
+        num_inputs: usize,
+        tvk: &Field<A>,
+        tcm: &Field<A>,
+        outputs: Vec<console::Value<A::Network>>,        // Note: Console type
+        output_types: &[console::ValueType<A::Network>], // Note: Console type
+    ) -> Vec<Value<A>> {
         match outputs
             .iter()
             .zip_eq(output_types)
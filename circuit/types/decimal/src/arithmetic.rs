@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Decimal<E> {
+    /// Returns the scaling factor of the mantissa, i.e. `10^SCALE`, as a constant `I128`.
+    fn wide_scaling_factor() -> I128<E> {
+        I128::constant(console::Integer::new(i128::from(console::Decimal::<E::Network>::SCALING_FACTOR)))
+    }
+
+    /// Sign-extends the mantissa into an `I128`.
+    fn widen(&self) -> I128<E> {
+        let mut bits_le = self.mantissa.to_bits_le();
+        let msb = bits_le[bits_le.len() - 1].clone();
+        bits_le.resize(128, msb);
+        I128::from_bits_le(&bits_le)
+    }
+
+    /// Narrows the given `I128` into a decimal mantissa, enforcing that the value fits in an `I64`.
+    fn narrow(value: &I128<E>) -> Self {
+        let bits_le = value.to_bits_le();
+        // Ensure the upper bits are a sign-extension of the `I64` sign bit.
+        let msb = &bits_le[63];
+        for bit in &bits_le[64..] {
+            E::assert_eq(bit, msb);
+        }
+        Self { mantissa: I64::from_bits_le(&bits_le[..64]) }
+    }
+}
+
+impl<E: Environment> Neg for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `negation` of `self`.
+    fn neg(self) -> Self::Output {
+        Self { mantissa: -self.mantissa }
+    }
+}
+
+impl<E: Environment> AbsChecked for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `absolute value` of `self`.
+    fn abs_checked(self) -> Self::Output {
+        Self { mantissa: self.mantissa.abs_checked() }
+    }
+}
+
+impl<E: Environment> Add<&Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    fn add(self, other: &Decimal<E>) -> Self::Output {
+        Self { mantissa: self.mantissa.add_checked(&other.mantissa) }
+    }
+}
+
+impl<E: Environment> Sub<&Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `difference` of `self` and `other`.
+    fn sub(self, other: &Decimal<E>) -> Self::Output {
+        Self { mantissa: self.mantissa.sub_checked(&other.mantissa) }
+    }
+}
+
+impl<E: Environment> Mul<&Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `product` of `self` and `other`, truncated toward zero to `SCALE` fractional digits.
+    fn mul(self, other: &Decimal<E>) -> Self::Output {
+        match self.is_constant() && other.is_constant() {
+            true => Self::constant(self.eject_value() * other.eject_value()),
+            false => {
+                // Note: The product of two `I64` values never overflows an `I128`.
+                let product = self.widen().mul_wrapped(&other.widen());
+                // Note: The divisor is a non-zero constant, so the division cannot fail.
+                Self::narrow(&product.div_wrapped(&Self::wide_scaling_factor()))
+            }
+        }
+    }
+}
+
+impl<E: Environment> Div<&Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `quotient` of `self` and `other`, truncated toward zero to `SCALE` fractional digits.
+    fn div(self, other: &Decimal<E>) -> Self::Output {
+        match self.is_constant() && other.is_constant() {
+            true => Self::constant(self.eject_value() / other.eject_value()),
+            false => {
+                // Note: The scaled dividend never overflows an `I128`.
+                let dividend = self.widen().mul_wrapped(&Self::wide_scaling_factor());
+                // Note: This call to `div_checked` halts or is unsatisfied if `other` is zero.
+                Self::narrow(&dividend.div_checked(&other.widen()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    type ConsoleDecimal = console::Decimal<<Circuit as Environment>::Network>;
+
+    const ITERATIONS: u64 = 32;
+
+    /// Checks the circuit operation against the console operation on the given operands.
+    fn check_operation(
+        first: ConsoleDecimal,
+        second: ConsoleDecimal,
+        console_op: fn(&ConsoleDecimal, &ConsoleDecimal) -> Option<ConsoleDecimal>,
+        circuit_op: fn(Decimal<Circuit>, &Decimal<Circuit>) -> Decimal<Circuit>,
+    ) {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = Decimal::<Circuit>::new(mode_a, first);
+                let b = Decimal::<Circuit>::new(mode_b, second);
+                match console_op(&first, &second) {
+                    Some(expected) => Circuit::scope("check_operation", || {
+                        let candidate = circuit_op(a, &b);
+                        assert_eq!(expected, candidate.eject_value());
+                        assert!(Circuit::is_satisfied());
+                    }),
+                    None => match (mode_a.is_constant() && mode_b.is_constant())
+                        || (mode_b.is_constant() && second.is_zero())
+                    {
+                        true => assert!(std::panic::catch_unwind(move || circuit_op(a, &b)).is_err()),
+                        false => Circuit::scope("check_operation", || {
+                            let _candidate = circuit_op(a, &b);
+                            assert!(!Circuit::is_satisfied());
+                        }),
+                    },
+                }
+                Circuit::reset();
+            }
+        }
+    }
+
+    /// Samples operand pairs, covering small values, boundary values, and random mantissas.
+    fn sample_operands(rng: &mut TestRng) -> Vec<(ConsoleDecimal, ConsoleDecimal)> {
+        let small = |rng: &mut TestRng| ConsoleDecimal::from_mantissa(i64::from(i32::rand(rng)));
+        let mut operands = vec![
+            (ConsoleDecimal::from_mantissa(-3), ConsoleDecimal::from_str("0.5decimal").unwrap()),
+            (ConsoleDecimal::MAX, ConsoleDecimal::from_str("2decimal").unwrap()),
+            (ConsoleDecimal::MIN, ConsoleDecimal::from_str("-1decimal").unwrap()),
+            (ConsoleDecimal::from_str("1decimal").unwrap(), ConsoleDecimal::zero()),
+        ];
+        for _ in 0..ITERATIONS {
+            operands.push((small(rng), small(rng)));
+            operands.push((ConsoleDecimal::rand(rng), small(rng)));
+        }
+        operands
+    }
+
+    #[test]
+    fn test_mul() {
+        let mut rng = TestRng::default();
+        for (first, second) in sample_operands(&mut rng) {
+            check_operation(first, second, |a, b| a.checked_mul(b), |a, b| a * b);
+        }
+    }
+
+    #[test]
+    fn test_div() {
+        let mut rng = TestRng::default();
+        for (first, second) in sample_operands(&mut rng) {
+            check_operation(first, second, |a, b| a.checked_div(b), |a, b| a / b);
+        }
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let mut rng = TestRng::default();
+        for (first, second) in sample_operands(&mut rng) {
+            check_operation(first, second, |a, b| a.checked_add(b), |a, b| a + b);
+            check_operation(first, second, |a, b| a.checked_sub(b), |a, b| a - b);
+        }
+    }
+}
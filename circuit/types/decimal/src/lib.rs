@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![forbid(unsafe_code)]
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+
+mod arithmetic;
+mod compare;
+mod equal;
+mod helpers;
+mod ternary;
+
+#[cfg(test)]
+use console::{TestRng, Uniform};
+
+use snarkvm_circuit_environment::prelude::*;
+use snarkvm_circuit_types_boolean::Boolean;
+use snarkvm_circuit_types_field::Field;
+use snarkvm_circuit_types_integers::{I128, I64};
+
+/// A signed fixed-point decimal, represented as an `I64` mantissa scaled by `10^SCALE`.
+#[derive(Clone)]
+pub struct Decimal<E: Environment> {
+    /// The underlying mantissa, i.e. the decimal value multiplied by `10^SCALE`.
+    mantissa: I64<E>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for Decimal<E> {
+    type Primitive = console::Decimal<E::Network>;
+
+    /// Initializes a new decimal.
+    fn new(mode: Mode, decimal: Self::Primitive) -> Self {
+        Self { mantissa: I64::new(mode, console::Integer::new(*decimal)) }
+    }
+}
+
+impl<E: Environment> Decimal<E> {
+    /// Initializes a new decimal from its scaled mantissa.
+    pub const fn from_mantissa(mantissa: I64<E>) -> Self {
+        Self { mantissa }
+    }
+
+    /// Returns the scaled mantissa of the decimal.
+    pub const fn mantissa(&self) -> &I64<E> {
+        &self.mantissa
+    }
+
+    /// Returns the scaling factor of the mantissa, i.e. `10^SCALE`, as a constant `I64`.
+    pub fn scaling_factor() -> I64<E> {
+        I64::constant(console::Integer::new(console::Decimal::<E::Network>::SCALING_FACTOR))
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Eject for Decimal<E> {
+    type Primitive = console::Decimal<E::Network>;
+
+    /// Ejects the mode of the decimal.
+    fn eject_mode(&self) -> Mode {
+        self.mantissa.eject_mode()
+    }
+
+    /// Ejects the decimal circuit as a console decimal value.
+    fn eject_value(&self) -> Self::Primitive {
+        console::Decimal::from_mantissa(*self.mantissa.eject_value())
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Parser for Decimal<E> {
+    /// Parses a string into a decimal circuit.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the decimal from the string.
+        let (string, decimal) = console::Decimal::parse(string)?;
+        // Parse the mode from the string.
+        let (string, mode) = opt(pair(tag("."), Mode::parse))(string)?;
+
+        match mode {
+            Some((_, mode)) => Ok((string, Decimal::new(mode, decimal))),
+            None => Ok((string, Decimal::new(Mode::Constant, decimal))),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> FromStr for Decimal<E> {
+    type Err = Error;
+
+    /// Parses a string into a decimal circuit.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> TypeName for Decimal<E> {
+    /// Returns the type name of the circuit as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        console::Decimal::<E::Network>::type_name()
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Debug for Decimal<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Display for Decimal<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.eject_value(), self.eject_mode())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_new_eject() {
+        let mut rng = TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let expected = console::Decimal::<<Circuit as Environment>::Network>::rand(&mut rng);
+                let candidate = Decimal::<Circuit>::new(mode, expected);
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(mode, candidate.eject_mode());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let candidate = Decimal::<Circuit>::from_str("-1.25decimal.private").unwrap();
+        assert_eq!(-1_250_000, *candidate.eject_value());
+        assert_eq!(Mode::Private, candidate.eject_mode());
+        assert_eq!("-1.250000decimal.private", candidate.to_string());
+    }
+}
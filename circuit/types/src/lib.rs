@@ -25,6 +25,9 @@ pub mod modules {
     pub use snarkvm_circuit_types_boolean as boolean;
     pub use snarkvm_circuit_types_boolean::Boolean;
 
+    pub use snarkvm_circuit_types_decimal as decimal;
+    pub use snarkvm_circuit_types_decimal::Decimal;
+
     pub use snarkvm_circuit_types_field as field;
     pub use snarkvm_circuit_types_field::Field;
 
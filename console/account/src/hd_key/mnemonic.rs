@@ -0,0 +1,277 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use sha2::{Digest, Sha256, Sha512};
+use unicode_normalization::UnicodeNormalization;
+
+/// The BIP39 English wordlist.
+static ENGLISH_WORDLIST: &str = include_str!("english.txt");
+
+/// The number of PBKDF2 iterations used to derive the seed.
+const PBKDF2_ITERATIONS: u32 = 2048;
+
+/// Returns the BIP39 English wordlist, in sorted order.
+fn wordlist() -> Vec<&'static str> {
+    ENGLISH_WORDLIST.lines().collect()
+}
+
+/// A BIP39 mnemonic, encoding between 128 and 256 bits of entropy as 12 to 24 English words.
+#[derive(Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct Mnemonic {
+    /// The entropy of the mnemonic.
+    entropy: Vec<u8>,
+}
+
+impl Mnemonic {
+    /// Initializes a new mnemonic from the given entropy, which must be 16, 20, 24, 28, or 32 bytes.
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self> {
+        ensure!(
+            matches!(entropy.len(), 16 | 20 | 24 | 28 | 32),
+            "Invalid mnemonic entropy length: found {}, expected 16, 20, 24, 28, or 32 bytes",
+            entropy.len()
+        );
+        Ok(Self { entropy: entropy.to_vec() })
+    }
+
+    /// Samples a new mnemonic with the given number of words, which must be 12, 15, 18, 21, or 24.
+    pub fn new<R: Rng + CryptoRng>(num_words: usize, rng: &mut R) -> Result<Self> {
+        ensure!(
+            matches!(num_words, 12 | 15 | 18 | 21 | 24),
+            "Invalid number of mnemonic words: found {num_words}, expected 12, 15, 18, 21, or 24"
+        );
+        // Sample the entropy, which is 4 bytes for every 3 words.
+        let mut entropy = (0..num_words / 3 * 4).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let mnemonic = Self::from_entropy(&entropy);
+        entropy.zeroize();
+        mnemonic
+    }
+
+    /// Returns the entropy of the mnemonic.
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy
+    }
+
+    /// Returns the number of words in the mnemonic.
+    pub fn num_words(&self) -> usize {
+        self.entropy.len() / 4 * 3
+    }
+
+    /// Returns the 64-byte seed of the mnemonic, for the given (possibly empty) passphrase.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        // Normalize the mnemonic and passphrase into NFKD form.
+        let mut password = self.to_string().nfkd().collect::<String>();
+        let mut salt = format!("mnemonic{passphrase}").nfkd().collect::<String>();
+        // Derive the seed.
+        let mut seed = [0u8; 64];
+        pbkdf2::pbkdf2_hmac::<Sha512>(password.as_bytes(), salt.as_bytes(), PBKDF2_ITERATIONS, &mut seed);
+        password.zeroize();
+        salt.zeroize();
+        seed
+    }
+
+    /// Returns the checksum bits of the given entropy, in the high bits of a byte.
+    fn checksum(entropy: &[u8]) -> u8 {
+        // The checksum is the first `entropy.len() / 4` bits of the SHA-256 hash of the entropy.
+        let num_bits = entropy.len() / 4;
+        Sha256::digest(entropy)[0] >> (8 - num_bits) << (8 - num_bits)
+    }
+
+    /// Returns the 11-bit word indices of the mnemonic.
+    fn word_indices(&self) -> Vec<usize> {
+        // Concatenate the entropy and its checksum.
+        let mut bytes = self.entropy.clone();
+        bytes.push(Self::checksum(&self.entropy));
+        // Split the bits into groups of 11 bits.
+        let indices = (0..self.num_words())
+            .map(|word| {
+                (0..11).fold(0usize, |index, bit| {
+                    let position = word * 11 + bit;
+                    (index << 1) | ((bytes[position / 8] >> (7 - position % 8)) & 1) as usize
+                })
+            })
+            .collect();
+        bytes.zeroize();
+        indices
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    /// Parses a mnemonic from a phrase of 12 to 24 English words, separated by whitespace.
+    fn from_str(phrase: &str) -> Result<Self> {
+        let wordlist = wordlist();
+        // Normalize the phrase into NFKD form, and split it into words.
+        let phrase = phrase.nfkd().collect::<String>();
+        let words = phrase.split_whitespace().collect::<Vec<_>>();
+        ensure!(
+            matches!(words.len(), 12 | 15 | 18 | 21 | 24),
+            "Invalid number of mnemonic words: found {}, expected 12, 15, 18, 21, or 24",
+            words.len()
+        );
+        // Concatenate the 11-bit word indices.
+        let mut bytes = vec![0u8; (words.len() * 11 + 7) / 8];
+        for (word_position, word) in words.iter().enumerate() {
+            let Ok(index) = wordlist.binary_search(word) else { bail!("Invalid mnemonic word '{word}'") };
+            for bit in 0..11 {
+                if (index >> (10 - bit)) & 1 == 1 {
+                    let position = word_position * 11 + bit;
+                    bytes[position / 8] |= 1 << (7 - position % 8);
+                }
+            }
+        }
+        // Split the bytes into the entropy and the checksum.
+        let entropy_length = words.len() / 3 * 4;
+        let mnemonic = Self::from_entropy(&bytes[..entropy_length])?;
+        let checksum = bytes[entropy_length];
+        bytes.zeroize();
+        // Ensure the checksum is valid.
+        ensure!(checksum == Self::checksum(mnemonic.entropy()), "Invalid mnemonic checksum");
+        Ok(mnemonic)
+    }
+}
+
+impl Debug for Mnemonic {
+    /// Writes the mnemonic without revealing its words.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Mnemonic({} words)", self.num_words())
+    }
+}
+
+impl Display for Mnemonic {
+    /// Writes the mnemonic as a phrase of English words, separated by single spaces.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let wordlist = wordlist();
+        for (position, index) in self.word_indices().into_iter().enumerate() {
+            if position > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", wordlist[index])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 100;
+
+    /// Decodes the given hex string.
+    fn hex(string: &str) -> Vec<u8> {
+        (0..string.len()).step_by(2).map(|i| u8::from_str_radix(&string[i..i + 2], 16).unwrap()).collect()
+    }
+
+    /// The BIP39 test vectors from the reference implementation, with the passphrase "TREZOR".
+    const TEST_VECTORS: [(&str, &str, &str); 6] = [
+        (
+            "00000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+        ),
+        (
+            "80808080808080808080808080808080",
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+            "d71de856f81a8acc65e6fc851a38d4d7ec216fd0796d0a6827a3ad6ed5511a30fa280f12eb2e47ed2ac03b5c462a0358d18d69fe4f985ec81778c1b370b652a8",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffff",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+            "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+        ),
+        (
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+            "bda85446c68413707090a52022edd26a1c9462295029f2e60cd7c4f2bbd3097170af7a4d73245cafa9c3cca8d561a7c3de6f5d4a10be8ed2a5e608d68f92fcc8",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+            "dd48c104698c30cfe2b6142103248622fb7bb0ff692eebb00089b32d22484e1613912f0a5b694407be899ffd31ed3992c456cdf60f5d4564b8ba3f05a69890ad",
+        ),
+    ];
+
+    #[test]
+    fn test_wordlist() {
+        let wordlist = wordlist();
+        assert_eq!(wordlist.len(), 2048);
+        // Ensure the wordlist is sorted, so that it can be binary searched.
+        assert!(wordlist.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_vectors() -> Result<()> {
+        for (entropy, phrase, seed) in TEST_VECTORS {
+            // Check the mnemonic from the entropy.
+            let mnemonic = Mnemonic::from_entropy(&hex(entropy))?;
+            assert_eq!(mnemonic.to_string(), phrase);
+            // Check the mnemonic from the phrase.
+            let candidate = Mnemonic::from_str(phrase)?;
+            assert_eq!(candidate.entropy(), hex(entropy));
+            // Check the seed.
+            assert_eq!(mnemonic.to_seed("TREZOR").to_vec(), hex(seed));
+        }
+
+        // Check the seed without a passphrase.
+        let mnemonic = Mnemonic::from_str(TEST_VECTORS[0].1)?;
+        assert_eq!(
+            mnemonic.to_seed("").to_vec(),
+            hex(
+                "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4"
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Ensure an invalid checksum is rejected.
+        assert!(Mnemonic::from_str(&"abandon ".repeat(12)).is_err());
+        // Ensure an unknown word is rejected.
+        assert!(Mnemonic::from_str(&format!("{}aleo", "abandon ".repeat(11))).is_err());
+        // Ensure an invalid number of words is rejected.
+        assert!(Mnemonic::from_str(&format!("{}about", "abandon ".repeat(10))).is_err());
+        // Ensure an invalid entropy length is rejected.
+        assert!(Mnemonic::from_entropy(&[0u8; 15]).is_err());
+        assert!(Mnemonic::from_entropy(&[0u8; 33]).is_err());
+        // Ensure an invalid number of words is rejected.
+        assert!(Mnemonic::new(13, &mut TestRng::default()).is_err());
+    }
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            for num_words in [12, 15, 18, 21, 24] {
+                // Sample a new mnemonic.
+                let expected = Mnemonic::new(num_words, rng)?;
+                assert_eq!(expected.num_words(), num_words);
+                // Check the phrase round trips.
+                let phrase = expected.to_string();
+                assert_eq!(phrase.split(' ').count(), num_words);
+                assert_eq!(expected, Mnemonic::from_str(&phrase)?);
+            }
+        }
+        Ok(())
+    }
+}
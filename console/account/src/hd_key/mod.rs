@@ -0,0 +1,236 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod mnemonic;
+pub use mnemonic::*;
+
+mod path;
+pub use path::*;
+
+use crate::{ComputeKey, PrivateKey, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use core::marker::PhantomData;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// HMAC-SHA512, as defined in RFC 2104.
+type HmacSha512 = Hmac<Sha512>;
+
+/// The HMAC key used to derive the master key from a seed.
+static MASTER_KEY_DOMAIN: &[u8] = b"Aleo seed";
+
+/// The offset of hardened child indices.
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+/// The maximum depth of an extended private key.
+pub const MAX_DEPTH: u8 = u8::MAX;
+/// The BIP44 purpose.
+pub const PURPOSE: u32 = 44;
+/// The SLIP-44 coin type of Aleo.
+pub const ALEO_COIN_TYPE: u32 = 683;
+
+/// An extended private key, for SLIP-10 style hardened derivation of account keys from a master seed.
+///
+/// Each node holds 32 bytes of key material and a 32-byte chain code. The account private key
+/// of a node is derived from its key material as the account seed, so the leaf keys keep the
+/// existing `sk_sig` and `r_sig` derivation of `PrivateKey`.
+#[derive(Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct ExtendedPrivateKey<N: Network> {
+    /// The depth of the key, where the master key has depth 0.
+    depth: u8,
+    /// The child index of the key, without the hardened offset.
+    child_index: u32,
+    /// The key material.
+    key: [u8; 32],
+    /// The chain code.
+    chain_code: [u8; 32],
+    /// PhantomData.
+    #[zeroize(skip)]
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> ExtendedPrivateKey<N> {
+    /// Returns the master key for the given seed, which must be between 16 and 64 bytes.
+    pub fn new_master(seed: &[u8]) -> Result<Self> {
+        ensure!(
+            (16..=64).contains(&seed.len()),
+            "Invalid seed length: found {}, expected between 16 and 64 bytes",
+            seed.len()
+        );
+        // Compute I := HMAC-SHA512(key = "Aleo seed", data = seed).
+        let mut hmac = Self::new_hmac(MASTER_KEY_DOMAIN)?;
+        hmac.update(seed);
+        Ok(Self::from_hmac(0, 0, hmac))
+    }
+
+    /// Returns the master key for the given mnemonic and (possibly empty) passphrase.
+    pub fn from_mnemonic(mnemonic: &Mnemonic, passphrase: &str) -> Result<Self> {
+        let mut seed = mnemonic.to_seed(passphrase);
+        let master = Self::new_master(&seed);
+        seed.zeroize();
+        master
+    }
+
+    /// Returns the hardened child key at the given index, without the hardened offset.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        ensure!(index < HARDENED_OFFSET, "Derivation index '{index}' exceeds the maximum of {}", HARDENED_OFFSET - 1);
+        ensure!(self.depth < MAX_DEPTH, "Cannot derive a child key beyond the maximum depth of {MAX_DEPTH}");
+        // Compute I := HMAC-SHA512(key = chain code, data = 0x00 || key || ser32(index + 2^31)).
+        let mut hmac = Self::new_hmac(&self.chain_code)?;
+        hmac.update(&[0u8]);
+        hmac.update(&self.key);
+        hmac.update(&(index + HARDENED_OFFSET).to_be_bytes());
+        Ok(Self::from_hmac(self.depth + 1, index, hmac))
+    }
+
+    /// Returns the key at the given derivation path, relative to this key.
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self> {
+        path.indices().iter().try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Returns the depth of the key, where the master key has depth 0.
+    pub const fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the child index of the key, without the hardened offset.
+    pub const fn child_index(&self) -> u32 {
+        self.child_index
+    }
+
+    /// Returns the chain code of the key.
+    pub const fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Returns the account private key of this node.
+    pub fn private_key(&self) -> Result<PrivateKey<N>> {
+        // Derive the account seed from the key material.
+        PrivateKey::try_from(Field::new(N::Field::from_bytes_le_mod_order(&self.key)))
+    }
+
+    /// Returns the account private key, view key, and compute key of this node.
+    pub fn to_account_keys(&self) -> Result<(PrivateKey<N>, ViewKey<N>, ComputeKey<N>)> {
+        let private_key = self.private_key()?;
+        let compute_key = ComputeKey::try_from(&private_key)?;
+        let view_key = ViewKey::try_from((&private_key, &compute_key))?;
+        Ok((private_key, view_key, compute_key))
+    }
+
+    /// Initializes a new HMAC-SHA512 instance with the given key.
+    fn new_hmac(key: &[u8]) -> Result<HmacSha512> {
+        HmacSha512::new_from_slice(key).map_err(|e| anyhow!("Failed to initialize HMAC-SHA512: {e}"))
+    }
+
+    /// Initializes a key from the output of HMAC-SHA512, splitting it into the key material and chain code.
+    fn from_hmac(depth: u8, child_index: u32, hmac: HmacSha512) -> Self {
+        let mut output = hmac.finalize().into_bytes();
+        let mut key = [0u8; 32];
+        let mut chain_code = [0u8; 32];
+        key.copy_from_slice(&output[..32]);
+        chain_code.copy_from_slice(&output[32..]);
+        output.as_mut_slice().zeroize();
+        Self { depth, child_index, key, chain_code, _phantom: PhantomData }
+    }
+}
+
+impl<N: Network> Debug for ExtendedPrivateKey<N> {
+    /// Writes the extended private key without revealing its key material.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey")
+            .field("depth", &self.depth)
+            .field("child_index", &self.child_index)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 25;
+
+    const MNEMONIC: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const ACCOUNT_0_PRIVATE_KEY: &str = "APrivateKey1zkpC4gBKhpTomsVLH6ZU8SA7xS4R4MvhTBVeRPqHRkzbzUb";
+
+    #[test]
+    fn test_derive_path() -> Result<()> {
+        let master = ExtendedPrivateKey::<CurrentNetwork>::from_mnemonic(&Mnemonic::from_str(MNEMONIC)?, "")?;
+        assert_eq!(master.depth(), 0);
+
+        // Ensure deriving a path matches deriving each child in turn.
+        let path = DerivationPath::for_account(1)?;
+        let expected = master.derive_child(PURPOSE)?.derive_child(ALEO_COIN_TYPE)?.derive_child(1)?.derive_child(0)?;
+        let candidate = master.derive_path(&path)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(candidate.depth(), 4);
+        assert_eq!(candidate.child_index(), 0);
+
+        // Ensure the first account matches the expected private key.
+        let private_key = master.derive_path(&DerivationPath::for_account(0)?)?.private_key()?;
+        assert_eq!(private_key.to_string(), ACCOUNT_0_PRIVATE_KEY);
+
+        // Ensure the empty path returns the key itself.
+        assert_eq!(master.derive_path(&DerivationPath::default())?, master);
+
+        // Ensure the derivation is deterministic.
+        let master2 = ExtendedPrivateKey::<CurrentNetwork>::from_mnemonic(&Mnemonic::from_str(MNEMONIC)?, "")?;
+        assert_eq!(master2.derive_path(&path)?.private_key()?, candidate.private_key()?);
+
+        // Ensure the passphrase changes the keys.
+        let master3 = ExtendedPrivateKey::<CurrentNetwork>::from_mnemonic(&Mnemonic::from_str(MNEMONIC)?, "TREZOR")?;
+        assert_ne!(master3.derive_path(&path)?.private_key()?, candidate.private_key()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_account_keys() -> Result<()> {
+        let master = ExtendedPrivateKey::<CurrentNetwork>::from_mnemonic(&Mnemonic::from_str(MNEMONIC)?, "")?;
+
+        let mut addresses = std::collections::HashSet::new();
+        for account in 0..ITERATIONS {
+            let key = master.derive_path(&DerivationPath::for_account(account)?)?;
+            let (private_key, view_key, compute_key) = key.to_account_keys()?;
+
+            // Ensure the leaf keys use the existing account derivation.
+            assert_eq!(private_key, PrivateKey::try_from(private_key.seed())?);
+            assert_eq!(view_key, ViewKey::try_from(&private_key)?);
+            assert_eq!(compute_key, ComputeKey::try_from(&private_key)?);
+
+            // Ensure every account is distinct.
+            assert!(addresses.insert(Address::try_from(&private_key)?));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid() -> Result<()> {
+        // Ensure invalid seed lengths are rejected.
+        assert!(ExtendedPrivateKey::<CurrentNetwork>::new_master(&[0u8; 15]).is_err());
+        assert!(ExtendedPrivateKey::<CurrentNetwork>::new_master(&[0u8; 65]).is_err());
+
+        // Ensure indices with the hardened offset are rejected.
+        let master = ExtendedPrivateKey::<CurrentNetwork>::new_master(&[0u8; 32])?;
+        assert!(master.derive_child(HARDENED_OFFSET).is_err());
+        assert!(master.derive_child(HARDENED_OFFSET - 1).is_ok());
+        Ok(())
+    }
+}
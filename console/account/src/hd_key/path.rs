@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A hardened derivation path, i.e. `m/44'/683'/0'/0'`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    /// The child indices, without the hardened offset.
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// Initializes a new derivation path from the given child indices, without the hardened offset.
+    pub fn new(indices: Vec<u32>) -> Result<Self> {
+        // Ensure the indices are within the hardened range.
+        for index in &indices {
+            ensure!(
+                *index < HARDENED_OFFSET,
+                "Derivation index '{index}' exceeds the maximum of {}",
+                HARDENED_OFFSET - 1
+            );
+        }
+        // Ensure the path does not exceed the maximum depth.
+        ensure!(indices.len() <= MAX_DEPTH as usize, "Derivation path exceeds the maximum depth of {MAX_DEPTH}");
+        Ok(Self { indices })
+    }
+
+    /// Returns the derivation path for the given account index, i.e. `m/44'/683'/{account}'/0'`.
+    pub fn for_account(account: u32) -> Result<Self> {
+        Self::new(vec![PURPOSE, ALEO_COIN_TYPE, account, 0])
+    }
+
+    /// Returns the child indices, without the hardened offset.
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    /// Parses a derivation path, i.e. `m/44'/683'/0'/0'`.
+    fn from_str(path: &str) -> Result<Self> {
+        let mut components = path.split('/');
+        // Ensure the path starts at the master key.
+        ensure!(components.next() == Some("m"), "Derivation path '{path}' must start with 'm'");
+        // Parse the child indices.
+        let indices = components
+            .map(|component| {
+                // Ensure the child index is hardened.
+                let Some(index) = component.strip_suffix('\'').or_else(|| component.strip_suffix('h')) else {
+                    bail!("Derivation path '{path}' contains a non-hardened index '{component}'")
+                };
+                // Ensure the child index is a number, without a sign or leading zeros.
+                ensure!(
+                    !index.is_empty() && index.bytes().all(|byte| byte.is_ascii_digit()),
+                    "Derivation path '{path}' contains an invalid index '{component}'"
+                );
+                ensure!(index == "0" || !index.starts_with('0'), "Derivation index '{component}' has leading zeros");
+                index.parse::<u32>().map_err(|e| anyhow!("Invalid derivation index '{component}': {e}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(indices)
+    }
+}
+
+impl Display for DerivationPath {
+    /// Writes the derivation path, i.e. `m/44'/683'/0'/0'`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "m")?;
+        for index in &self.indices {
+            write!(f, "/{index}'")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(DerivationPath::from_str("m")?.indices(), &[] as &[u32]);
+        assert_eq!(DerivationPath::from_str("m/44'/683'/0'/0'")?, DerivationPath::for_account(0)?);
+        assert_eq!(DerivationPath::from_str("m/44h/683h/7h/0h")?, DerivationPath::for_account(7)?);
+        assert_eq!(DerivationPath::from_str("m/2147483647'")?.indices(), &[HARDENED_OFFSET - 1]);
+
+        // Ensure the path must start with the master key.
+        assert!(DerivationPath::from_str("").is_err());
+        assert!(DerivationPath::from_str("44'/683'").is_err());
+        // Ensure non-hardened indices are rejected.
+        assert!(DerivationPath::from_str("m/44'/683'/0").is_err());
+        // Ensure malformed indices are rejected.
+        assert!(DerivationPath::from_str("m/'").is_err());
+        assert!(DerivationPath::from_str("m/+1'").is_err());
+        assert!(DerivationPath::from_str("m/01'").is_err());
+        assert!(DerivationPath::from_str("m/2147483648'").is_err());
+        assert!(DerivationPath::from_str("m/44'/").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        for path in ["m", "m/0'", "m/44'/683'/0'/0'", "m/2147483647'/1'"] {
+            assert_eq!(DerivationPath::from_str(path)?.to_string(), path);
+        }
+        Ok(())
+    }
+}
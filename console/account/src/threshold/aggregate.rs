@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> ThresholdAccount<N> {
+    /// Returns the signature for the given signing package, by aggregating the signature shares of its signers.
+    pub fn aggregate(&self, package: &SigningPackage<N>, shares: &[SignatureShare<N>]) -> Result<Signature<N>> {
+        // Compute the verifier challenge.
+        let challenge = package.challenge(&self.compute_key)?;
+        // Aggregate with the challenge.
+        self.aggregate_with_challenge(package, shares, challenge)
+    }
+
+    /// Returns the signature for the given signing package and challenge, by aggregating the signature shares.
+    /// Each share is verified, so that a misbehaving signer is identified.
+    pub fn aggregate_with_challenge(
+        &self,
+        package: &SigningPackage<N>,
+        shares: &[SignatureShare<N>],
+        challenge: Scalar<N>,
+    ) -> Result<Signature<N>> {
+        // Ensure there are enough signers.
+        ensure!(
+            package.commitments().len() >= self.threshold as usize,
+            "Found {} signers, but the threshold is {}",
+            package.commitments().len(),
+            self.threshold
+        );
+        // Ensure there is exactly one share for each signer in the package.
+        ensure!(
+            shares.len() == package.commitments().len(),
+            "Found {} signature shares for {} signers",
+            shares.len(),
+            package.commitments().len()
+        );
+
+        let mut response = Scalar::zero();
+        for (commitments, share) in package.commitments().iter().zip_eq(shares) {
+            // Ensure the shares are in the order of the package.
+            ensure!(
+                commitments.identifier() == share.identifier(),
+                "Expected a signature share from signer {}, found signer {}",
+                commitments.identifier(),
+                share.identifier()
+            );
+            // Verify the signature share.
+            self.verify_share(package, share, challenge)?;
+            // Accumulate the response.
+            response += share.response();
+        }
+
+        // Return the signature.
+        Ok(Signature::from((challenge, response, self.compute_key)))
+    }
+
+    /// Verifies the signature share of a signer for the given signing package and challenge, where:
+    ///     response * G == D_i + (rho_i * E_i) - (challenge * lambda_i * Y_i)
+    pub fn verify_share(
+        &self,
+        package: &SigningPackage<N>,
+        share: &SignatureShare<N>,
+        challenge: Scalar<N>,
+    ) -> Result<()> {
+        let identifier = share.identifier();
+        // Retrieve the verifying share of the signer.
+        let Some(verifying_share) = self.verifying_share(identifier) else {
+            bail!("Signer {identifier} is not a signer of this account")
+        };
+        // Retrieve the commitments of the signer.
+        let Some(commitments) = package.commitments().iter().find(|c| c.identifier() == identifier) else {
+            bail!("Signer {identifier} is not in the signing package")
+        };
+
+        // Compute the binding factor.
+        let binding_factor = package.binding_factor(&self.compute_key, identifier)?;
+        // Compute the Lagrange coefficient.
+        let lambda = lagrange_coefficient::<N>(identifier, package.identifiers())?;
+
+        // Compute the expected commitment of the signer.
+        let expected =
+            commitments.hiding() + (commitments.binding() * binding_factor) - (verifying_share * (challenge * lambda));
+        ensure!(
+            N::g_scalar_multiply(&share.response()) == expected,
+            "Invalid signature share from signer {identifier}"
+        );
+        Ok(())
+    }
+}
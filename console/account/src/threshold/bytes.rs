@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> FromBytes for ThresholdAccount<N> {
+    /// Reads the threshold account from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid threshold account version"));
+        }
+        // Read the compute key.
+        let compute_key = ComputeKey::read_le(&mut reader)?;
+        // Read the threshold.
+        let threshold = u16::read_le(&mut reader)?;
+        // Read the verifying shares.
+        let num_signers = u16::read_le(&mut reader)?;
+        let verifying_shares = (0..num_signers).map(|_| Group::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Ensure the threshold is valid.
+        if threshold == 0 || threshold > num_signers {
+            return Err(error(format!("Invalid threshold ({threshold}) for {num_signers} signers")));
+        }
+        Ok(Self { compute_key, threshold, verifying_shares })
+    }
+}
+
+impl<N: Network> ToBytes for ThresholdAccount<N> {
+    /// Writes the threshold account to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the compute key.
+        self.compute_key.write_le(&mut writer)?;
+        // Write the threshold.
+        self.threshold.write_le(&mut writer)?;
+        // Write the verifying shares.
+        self.num_signers().write_le(&mut writer)?;
+        self.verifying_shares.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for KeyShare<N> {
+    /// Reads the key share from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid key share version"));
+        }
+        // Read the identifier.
+        let identifier = u16::read_le(&mut reader)?;
+        if identifier == 0 {
+            return Err(error("Invalid key share identifier"));
+        }
+        // Read the secret share.
+        let secret_share = Scalar::read_le(&mut reader)?;
+        // Read the compute key.
+        let compute_key = ComputeKey::read_le(&mut reader)?;
+        Ok(Self { identifier, secret_share, compute_key })
+    }
+}
+
+impl<N: Network> ToBytes for KeyShare<N> {
+    /// Writes the key share to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the identifier.
+        self.identifier.write_le(&mut writer)?;
+        // Write the secret share.
+        self.secret_share.write_le(&mut writer)?;
+        // Write the compute key.
+        self.compute_key.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for SigningCommitments<N> {
+    /// Reads the signing commitments from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let identifier = u16::read_le(&mut reader)?;
+        let hiding = Group::read_le(&mut reader)?;
+        let binding = Group::read_le(&mut reader)?;
+        Ok(Self { identifier, hiding, binding })
+    }
+}
+
+impl<N: Network> ToBytes for SigningCommitments<N> {
+    /// Writes the signing commitments to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.identifier.write_le(&mut writer)?;
+        self.hiding.write_le(&mut writer)?;
+        self.binding.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for SigningPackage<N> {
+    /// Reads the signing package from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid signing package version"));
+        }
+        // Read the commitments.
+        let num_commitments = u16::read_le(&mut reader)?;
+        let commitments =
+            (0..num_commitments).map(|_| SigningCommitments::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the message.
+        let message_length = u16::read_le(&mut reader)?;
+        let message = (0..message_length).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the signing package.
+        Self::new(commitments, message).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for SigningPackage<N> {
+    /// Writes the signing package to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the commitments.
+        u16::try_from(self.commitments().len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        self.commitments().write_le(&mut writer)?;
+        // Write the message.
+        u16::try_from(self.message().len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        self.message().write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for SignatureShare<N> {
+    /// Reads the signature share from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let identifier = u16::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { identifier, response })
+    }
+}
+
+impl<N: Network> ToBytes for SignatureShare<N> {
+    /// Writes the signature share to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.identifier.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a threshold account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let (account, key_shares) = ThresholdAccount::split(&private_key, 2, 3, rng)?;
+
+        // Check the threshold account.
+        let expected_bytes = account.to_bytes_le()?;
+        assert_eq!(account, ThresholdAccount::read_le(&expected_bytes[..])?);
+        assert!(ThresholdAccount::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the key shares.
+        for key_share in &key_shares {
+            let expected_bytes = key_share.to_bytes_le()?;
+            assert_eq!(*key_share, KeyShare::read_le(&expected_bytes[..])?);
+            assert!(KeyShare::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+
+        // Check the signing package and signature share.
+        let (nonces, commitments): (Vec<_>, Vec<_>) = key_shares[1..].iter().map(|share| share.commit(rng)).unzip();
+        let package = SigningPackage::new(commitments, vec![Uniform::rand(rng)])?;
+        let expected_bytes = package.to_bytes_le()?;
+        assert_eq!(package, SigningPackage::read_le(&expected_bytes[..])?);
+        assert!(SigningPackage::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        let share = key_shares[1].sign(nonces.into_iter().next().unwrap(), &package)?;
+        let expected_bytes = share.to_bytes_le()?;
+        assert_eq!(share, SignatureShare::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
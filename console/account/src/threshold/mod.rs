@@ -0,0 +1,282 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod aggregate;
+mod bytes;
+mod package;
+pub use package::*;
+mod sign;
+mod split;
+
+use crate::{Address, ComputeKey, PrivateKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The public description of a threshold account, whose `sk_sig` is shared among `num_signers` signers,
+/// any `threshold` of whom can jointly produce a standard `Signature` for the account.
+///
+/// Signing follows the two-round FROST protocol over the account signature scheme:
+///  1. Each participating signer samples nonces with `KeyShare::commit`, and sends its commitments to a coordinator.
+///  2. The coordinator sends the `SigningPackage` to the participants, who each return a `SignatureShare`.
+///
+/// The coordinator then aggregates the shares into a `Signature`, which `Signature::verify` accepts unchanged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdAccount<N: Network> {
+    /// The compute key of the account.
+    compute_key: ComputeKey<N>,
+    /// The minimum number of signers required to sign.
+    threshold: u16,
+    /// The verifying share `s_i * G` of each signer, where signer `i` is at index `i - 1`.
+    verifying_shares: Vec<Group<N>>,
+}
+
+impl<N: Network> ThresholdAccount<N> {
+    /// Returns the compute key of the account.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the address of the account.
+    pub fn address(&self) -> Address<N> {
+        self.compute_key.to_address()
+    }
+
+    /// Returns the minimum number of signers required to sign.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the total number of signers.
+    pub fn num_signers(&self) -> u16 {
+        // Note: The number of verifying shares is bounded by `u16::MAX` on construction.
+        u16::try_from(self.verifying_shares.len()).or_halt_with::<N>("The number of signers exceeds u16")
+    }
+
+    /// Returns the verifying share of the given signer, if it exists.
+    pub fn verifying_share(&self, identifier: u16) -> Option<Group<N>> {
+        match identifier {
+            0 => None,
+            _ => self.verifying_shares.get(identifier as usize - 1).copied(),
+        }
+    }
+}
+
+/// The share of `sk_sig` held by one signer of a threshold account.
+#[derive(Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct KeyShare<N: Network> {
+    /// The identifier of the signer, which is nonzero.
+    identifier: u16,
+    /// The secret share `s_i` of `sk_sig`.
+    secret_share: Scalar<N>,
+    /// The compute key of the account.
+    #[zeroize(skip)]
+    compute_key: ComputeKey<N>,
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the identifier of the signer.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the verifying share `s_i * G` of the signer.
+    pub fn verifying_share(&self) -> Group<N> {
+        N::g_scalar_multiply(&self.secret_share)
+    }
+
+    /// Returns the compute key of the account.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+}
+
+impl<N: Network> Debug for KeyShare<N> {
+    /// Writes the key share without revealing the secret share.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("KeyShare").field("identifier", &self.identifier).finish()
+    }
+}
+
+/// The secret nonces `(d_i, e_i)` of a signer for a single signing session.
+/// Note: The nonces are consumed on signing, and must never be reused.
+#[derive(PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct SigningNonces<N: Network> {
+    /// The hiding nonce `d_i`.
+    hiding: Scalar<N>,
+    /// The binding nonce `e_i`.
+    binding: Scalar<N>,
+}
+
+/// The public commitments `(d_i * G, e_i * G)` to the nonces of a signer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SigningCommitments<N: Network> {
+    /// The identifier of the signer.
+    identifier: u16,
+    /// The hiding commitment `d_i * G`.
+    hiding: Group<N>,
+    /// The binding commitment `e_i * G`.
+    binding: Group<N>,
+}
+
+impl<N: Network> SigningCommitments<N> {
+    /// Returns the identifier of the signer.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the hiding commitment.
+    pub const fn hiding(&self) -> Group<N> {
+        self.hiding
+    }
+
+    /// Returns the binding commitment.
+    pub const fn binding(&self) -> Group<N> {
+        self.binding
+    }
+}
+
+/// The response `z_i` of a signer for a signing package.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignatureShare<N: Network> {
+    /// The identifier of the signer.
+    identifier: u16,
+    /// The response `z_i`.
+    response: Scalar<N>,
+}
+
+impl<N: Network> SignatureShare<N> {
+    /// Returns the identifier of the signer.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+}
+
+/// Returns the scalar for the given signer identifier.
+fn to_scalar<N: Network>(identifier: u16) -> Scalar<N> {
+    Scalar::new(N::Scalar::from(identifier as u128))
+}
+
+/// Returns the Lagrange coefficient at zero of the given signer, over the given set of signers.
+fn lagrange_coefficient<N: Network>(identifier: u16, identifiers: impl Iterator<Item = u16>) -> Result<Scalar<N>> {
+    let x_i = to_scalar::<N>(identifier);
+    let mut numerator = Scalar::<N>::one();
+    let mut denominator = Scalar::<N>::one();
+    for x_j in identifiers.filter(|j| *j != identifier).map(to_scalar::<N>) {
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+    Ok(numerator * denominator.inverse()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 25;
+
+    /// Runs a signing session with the given signers, and returns the aggregated signature.
+    fn sign(
+        account: &ThresholdAccount<CurrentNetwork>,
+        signers: &[&KeyShare<CurrentNetwork>],
+        message: &[Field<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Result<Signature<CurrentNetwork>> {
+        // Round 1: Each signer commits to its nonces.
+        let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|signer| signer.commit(rng)).unzip();
+        let package = SigningPackage::new(commitments, message.to_vec())?;
+        // Round 2: Each signer signs the package.
+        let shares = signers
+            .iter()
+            .zip_eq(nonces)
+            .map(|(signer, nonces)| signer.sign(nonces, &package))
+            .collect::<Result<Vec<_>>>()?;
+        // Aggregate the signature shares.
+        account.aggregate(&package, &shares)
+    }
+
+    #[test]
+    fn test_sign_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Split a new private key into 3-of-5 key shares.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let (account, key_shares) = ThresholdAccount::split(&private_key, 3, 5, rng)?;
+            assert_eq!(account.address(), Address::try_from(&private_key)?);
+
+            // Check that any 3 signers produce a valid signature.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            for signers in key_shares.iter().combinations(3).take(4) {
+                let signature = sign(&account, &signers, &message, rng)?;
+                assert!(signature.verify(&account.address(), &message));
+                assert_eq!(signature.compute_key(), ComputeKey::try_from(&private_key)?);
+            }
+
+            // Check that all 5 signers produce a valid signature.
+            let signature = sign(&account, &key_shares.iter().collect::<Vec<_>>(), &message, rng)?;
+            assert!(signature.verify(&account.address(), &message));
+
+            // Check that 2 signers cannot produce a signature.
+            assert!(sign(&account, &key_shares.iter().take(2).collect::<Vec<_>>(), &message, rng).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_share() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let (account, key_shares) = ThresholdAccount::split(&private_key, 2, 3, rng)?;
+        let message = vec![Uniform::rand(rng)];
+
+        // Sign with the first two signers.
+        let (nonces, commitments): (Vec<_>, Vec<_>) = key_shares[..2].iter().map(|signer| signer.commit(rng)).unzip();
+        let package = SigningPackage::new(commitments, message.clone())?;
+        let mut shares = key_shares[..2]
+            .iter()
+            .zip_eq(nonces)
+            .map(|(signer, nonces)| signer.sign(nonces, &package))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Check that a tampered share is rejected.
+        shares[1].response += Scalar::one();
+        assert!(account.aggregate(&package, &shares).is_err());
+
+        // Check that a signer rejects nonces that are not in the package.
+        let (nonces, _) = key_shares[2].commit(rng);
+        assert!(key_shares[2].sign(nonces, &package).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_invalid() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        assert!(ThresholdAccount::split(&private_key, 0, 3, rng).is_err());
+        assert!(ThresholdAccount::split(&private_key, 4, 3, rng).is_err());
+        assert!(ThresholdAccount::split(&private_key, 1, 1, rng).is_ok());
+        Ok(())
+    }
+}
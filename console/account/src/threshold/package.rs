@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// The domain separator of the binding factors.
+const BINDING_FACTOR_DOMAIN: &str = "AleoThresholdBinding0";
+
+/// The signing package that a coordinator sends to the participating signers in the second round of signing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningPackage<N: Network> {
+    /// The commitments of the participating signers, sorted by identifier.
+    commitments: Vec<SigningCommitments<N>>,
+    /// The message to sign.
+    message: Vec<Field<N>>,
+}
+
+impl<N: Network> SigningPackage<N> {
+    /// Initializes a new signing package for the given commitments and message.
+    pub fn new(mut commitments: Vec<SigningCommitments<N>>, message: Vec<Field<N>>) -> Result<Self> {
+        // Ensure there is at least one signer.
+        ensure!(!commitments.is_empty(), "A signing package requires at least one signer");
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Sort the commitments by identifier.
+        commitments.sort_by_key(|commitments| commitments.identifier());
+        // Ensure the identifiers are nonzero and unique.
+        ensure!(commitments[0].identifier() != 0, "Found a signer with identifier 0");
+        ensure!(
+            commitments.windows(2).all(|pair| pair[0].identifier() != pair[1].identifier()),
+            "Found a duplicate signer in the signing package"
+        );
+        Ok(Self { commitments, message })
+    }
+
+    /// Returns the commitments of the participating signers, sorted by identifier.
+    pub fn commitments(&self) -> &[SigningCommitments<N>] {
+        &self.commitments
+    }
+
+    /// Returns the message to sign.
+    pub fn message(&self) -> &[Field<N>] {
+        &self.message
+    }
+
+    /// Returns the identifiers of the participating signers.
+    pub fn identifiers(&self) -> impl '_ + Iterator<Item = u16> {
+        self.commitments.iter().map(SigningCommitments::identifier)
+    }
+
+    /// Returns the binding factor of the given signer, where:
+    ///     rho_i := HashToScalar(domain, pk_sig, pr_sig, i, message, commitments)
+    pub fn binding_factor(&self, compute_key: &ComputeKey<N>, identifier: u16) -> Result<Scalar<N>> {
+        // Construct the preimage, which binds the signer to the message and to the commitments of all signers.
+        let mut preimage = Vec::with_capacity(5 + self.message.len() + 3 * self.commitments.len());
+        preimage.push(Field::new_domain_separator(BINDING_FACTOR_DOMAIN));
+        preimage.extend([compute_key.pk_sig(), compute_key.pr_sig()].map(|point| point.to_x_coordinate()));
+        preimage.push(Field::from_u16(identifier));
+        preimage.push(Field::from_u32(u32::try_from(self.message.len())?));
+        preimage.extend(&self.message);
+        for commitments in &self.commitments {
+            preimage.push(Field::from_u16(commitments.identifier()));
+            preimage.extend([commitments.hiding(), commitments.binding()].map(|point| point.to_x_coordinate()));
+        }
+        N::hash_to_scalar_psd8(&preimage)
+    }
+
+    /// Returns the group commitment `R`, where:
+    ///     R := Sum(D_i + (rho_i * E_i))
+    pub fn group_commitment(&self, compute_key: &ComputeKey<N>) -> Result<Group<N>> {
+        self.commitments.iter().try_fold(Group::zero(), |acc, commitments| {
+            let binding_factor = self.binding_factor(compute_key, commitments.identifier())?;
+            Ok(acc + commitments.hiding() + (commitments.binding() * binding_factor))
+        })
+    }
+
+    /// Returns the verifier challenge of the package, where:
+    ///     challenge := HashToScalar(R, pk_sig, pr_sig, address, message)
+    pub fn challenge(&self, compute_key: &ComputeKey<N>) -> Result<Scalar<N>> {
+        // Compute the group commitment.
+        let g_r = self.group_commitment(compute_key)?;
+        // Derive the address from the compute key.
+        let address = compute_key.to_address();
+
+        // Construct the hash input as (R, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + self.message.len());
+        preimage
+            .extend([g_r, compute_key.pk_sig(), compute_key.pr_sig(), *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(&self.message);
+
+        // Compute the verifier challenge.
+        N::hash_to_scalar_psd8(&preimage)
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> KeyShare<N> {
+    /// Returns fresh signing nonces and their commitments, for the first round of signing.
+    /// The commitments are sent to the coordinator, and the nonces are kept secret until signing.
+    pub fn commit<R: Rng + CryptoRng>(&self, rng: &mut R) -> (SigningNonces<N>, SigningCommitments<N>) {
+        // Sample the hiding and binding nonces.
+        let nonces = SigningNonces { hiding: Scalar::rand(rng), binding: Scalar::rand(rng) };
+        // Compute the commitments.
+        let commitments = nonces.to_commitments(self.identifier);
+        (nonces, commitments)
+    }
+
+    /// Returns the signature share of this signer for the given signing package, where:
+    ///     challenge := HashToScalar(R, pk_sig, pr_sig, address, message)
+    ///     response := d_i + (e_i * rho_i) - (challenge * lambda_i * s_i)
+    pub fn sign(&self, nonces: SigningNonces<N>, package: &SigningPackage<N>) -> Result<SignatureShare<N>> {
+        // Compute the verifier challenge.
+        let challenge = package.challenge(&self.compute_key)?;
+        // Sign with the challenge.
+        self.sign_with_challenge(nonces, package, challenge)
+    }
+
+    /// Returns the signature share of this signer for the given signing package and challenge.
+    ///
+    /// This is for signature schemes that derive the challenge from the group commitment of the package,
+    /// such as requests. The signer must compute the challenge itself, and never use one that it has not verified.
+    pub fn sign_with_challenge(
+        &self,
+        nonces: SigningNonces<N>,
+        package: &SigningPackage<N>,
+        challenge: Scalar<N>,
+    ) -> Result<SignatureShare<N>> {
+        // Ensure the package contains the commitments of this signer.
+        let commitments = nonces.to_commitments(self.identifier);
+        ensure!(
+            package.commitments().contains(&commitments),
+            "The signing package does not contain the commitments of signer {}",
+            self.identifier
+        );
+
+        // Compute the binding factor.
+        let binding_factor = package.binding_factor(&self.compute_key, self.identifier)?;
+        // Compute the Lagrange coefficient.
+        let lambda = lagrange_coefficient::<N>(self.identifier, package.identifiers())?;
+
+        // Compute the response.
+        let response = nonces.hiding + (nonces.binding * binding_factor) - (challenge * lambda * self.secret_share);
+        Ok(SignatureShare { identifier: self.identifier, response })
+    }
+}
+
+impl<N: Network> SigningNonces<N> {
+    /// Returns the commitments to the nonces, for the given signer.
+    fn to_commitments(&self, identifier: u16) -> SigningCommitments<N> {
+        SigningCommitments {
+            identifier,
+            hiding: N::g_scalar_multiply(&self.hiding),
+            binding: N::g_scalar_multiply(&self.binding),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> ThresholdAccount<N> {
+    /// Splits the `sk_sig` of the given private key into `num_signers` key shares, any `threshold` of which can sign.
+    ///
+    /// The shares are computed by a trusted dealer as `s_i := f(i)`, for a random polynomial `f` of degree
+    /// `threshold - 1` where `f(0) := sk_sig`. The dealer must distribute each key share privately,
+    /// and then erase the private key.
+    pub fn split<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        threshold: u16,
+        num_signers: u16,
+        rng: &mut R,
+    ) -> Result<(Self, Vec<KeyShare<N>>)> {
+        // Ensure the threshold is valid.
+        ensure!(threshold > 0, "The threshold must be at least 1");
+        ensure!(threshold <= num_signers, "The threshold ({threshold}) exceeds the number of signers ({num_signers})");
+
+        // Derive the compute key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+
+        // Sample the coefficients of the polynomial `f(x) := sk_sig + a_1 * x + ... + a_{t-1} * x^{t-1}`.
+        let mut coefficients = Vec::with_capacity(threshold as usize);
+        coefficients.push(private_key.sk_sig());
+        coefficients.extend((1..threshold).map(|_| Scalar::rand(rng)));
+
+        // Evaluate the polynomial at each signer identifier.
+        let key_shares = (1..=num_signers)
+            .map(|identifier| {
+                let x = to_scalar::<N>(identifier);
+                // Evaluate `f(x)` using Horner's method.
+                let secret_share =
+                    coefficients.iter().rev().fold(Scalar::zero(), |acc, coefficient| acc * x + coefficient);
+                KeyShare { identifier, secret_share, compute_key }
+            })
+            .collect::<Vec<_>>();
+
+        // Erase the coefficients.
+        coefficients.zeroize();
+
+        // Compute the verifying shares.
+        let verifying_shares = key_shares.iter().map(KeyShare::verifying_share).collect();
+
+        Ok((Self { compute_key, threshold, verifying_shares }, key_shares))
+    }
+}
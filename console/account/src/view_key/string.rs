@@ -22,7 +22,7 @@ impl<N: Network> FromStr for ViewKey<N> {
     /// Reads in an account view key from a base58 string.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // Encode the string into base58.
-        let data = bs58::decode(s).into_vec().map_err(|err| anyhow!("{:?}", err))?;
+        let data = bs58::decode(s).into_vec().map_err(|err| anyhow!("Failed to decode the account view key: {err}"))?;
         if data.len() != 39 {
             bail!("Invalid account view key length: found {}, expected 39", data.len())
         } else if data[0..7] != VIEW_KEY_PREFIX {
@@ -113,6 +113,10 @@ pub trait Network:
     const MAX_SOLUTIONS: usize = 1 << 8; // 256 solutions
     /// The number of blocks per epoch.
     const NUM_BLOCKS_PER_EPOCH: u32 = 3600 / Self::BLOCK_TIME as u32; // 360 blocks == ~1 hour
+    /// The block height from which the entries of a mapping can be read by position, with `get.at`.
+    /// At this height, the entries of every mapping are repositioned in the order of their key bytes,
+    /// so that every node agrees on the positions, regardless of how it stored the mappings before.
+    const MAPPING_POSITIONS_HEIGHT: u32 = 1_000_000;
 
     /// The maximum number of entries in data.
     const MAX_DATA_ENTRIES: usize = 32;
@@ -122,6 +126,8 @@ pub trait Network:
     /// The maximum number of fields in data (must not exceed u16::MAX).
     #[allow(clippy::cast_possible_truncation)]
     const MAX_DATA_SIZE_IN_FIELDS: u32 = ((128 * 1024 * 8) / Field::<Self>::SIZE_IN_DATA_BITS) as u32;
+    /// The maximum number of fields in a transition memo.
+    const MAX_MEMO_SIZE_IN_FIELDS: usize = 16;
 
     /// The minimum number of entries in a struct.
     const MIN_STRUCT_ENTRIES: usize = 1; // This ensures the struct is not empty.
@@ -142,6 +148,8 @@ pub trait Network:
     const MAX_MAPPINGS: usize = 31;
     /// The maximum number of functions in a program.
     const MAX_FUNCTIONS: usize = 31;
+    /// The maximum number of interfaces a program implements.
+    const MAX_INTERFACES: usize = 16;
     /// The maximum number of operands in an instruction.
     const MAX_OPERANDS: usize = Self::MAX_INPUTS;
     /// The maximum number of instructions in a closure or function.
@@ -150,6 +158,9 @@ pub trait Network:
     const MAX_COMMANDS: usize = u16::MAX as usize;
     /// The maximum number of write commands in finalize.
     const MAX_WRITES: u16 = 16;
+    /// The maximum number of events emitted in the finalize of a transaction.
+    /// Note: This bounds the events of a full block by the capacity of the events tree.
+    const MAX_EVENTS: usize = 16;
 
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 16;
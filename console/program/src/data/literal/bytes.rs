@@ -36,7 +36,8 @@ impl<N: Network> FromBytes for Literal<N> {
             14 => Self::Scalar(Scalar::read_le(&mut reader)?),
             15 => Self::Signature(Box::new(Signature::read_le(&mut reader)?)),
             16 => Self::String(StringType::read_le(&mut reader)?),
-            17.. => return Err(error(format!("Failed to decode literal variant {index}"))),
+            17 => Self::Decimal(Decimal::read_le(&mut reader)?),
+            18.. => return Err(error(format!("Failed to decode literal variant {index}"))),
         };
         Ok(literal)
     }
@@ -115,6 +116,10 @@ impl<N: Network> ToBytes for Literal<N> {
                 (16 as Size).write_le(&mut writer)?;
                 primitive.write_le(&mut writer)
             }
+            Self::Decimal(primitive) => {
+                (17 as Size).write_le(&mut writer)?;
+                primitive.write_le(&mut writer)
+            }
         }
     }
 }
@@ -176,6 +181,8 @@ mod tests {
             check_bytes(Literal::sample(LiteralType::Signature, rng))?;
             // String
             check_bytes(Literal::<CurrentNetwork>::String(StringType::rand(rng)))?;
+            // Decimal
+            check_bytes(Literal::<CurrentNetwork>::Decimal(Decimal::rand(rng)))?;
         }
         Ok(())
     }
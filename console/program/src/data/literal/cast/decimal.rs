@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType + TryFrom<i64>> Cast<Integer<E, I>> for Decimal<E> {
+    /// Casts a `Decimal` to an `Integer`, if the decimal has no fractional part
+    /// and its whole part fits in the integer type.
+    ///
+    /// To truncate the fractional part, use `Decimal::cast_lossy`.
+    #[inline]
+    fn cast(&self) -> Result<Integer<E, I>> {
+        ensure!(self.fract() == 0, "Failed to convert 'decimal' into '{}': found a fractional part", I::type_name());
+        match I::try_from(self.trunc()) {
+            Ok(value) => Ok(Integer::new(value)),
+            Err(_) => bail!("Failed to convert 'decimal' into '{}'", I::type_name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_decimal_to_integer() -> Result<()> {
+        let decimal = |string: &str| Literal::<CurrentNetwork>::from_str(string).unwrap();
+
+        assert_eq!(decimal("-3decimal").cast(LiteralType::I8)?, Literal::from_str("-3i8")?);
+        assert_eq!(decimal("300decimal").cast(LiteralType::U16)?, Literal::from_str("300u16")?);
+        assert_eq!(decimal("1decimal").cast(LiteralType::Boolean)?, Literal::from_str("true")?);
+        assert_eq!(decimal("7decimal").cast(LiteralType::Field)?, Literal::from_str("7field")?);
+        assert_eq!(decimal("1.5decimal").cast(LiteralType::Decimal)?, decimal("1.5decimal"));
+        // Ensure a fractional part or an out-of-range whole part fails.
+        assert!(decimal("1.5decimal").cast(LiteralType::I64).is_err());
+        assert!(decimal("300decimal").cast(LiteralType::U8).is_err());
+        assert!(decimal("-1decimal").cast(LiteralType::U64).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_integer_to_decimal() -> Result<()> {
+        let literal = |string: &str| Literal::<CurrentNetwork>::from_str(string).unwrap();
+
+        assert_eq!(literal("-3i8").cast(LiteralType::Decimal)?, literal("-3decimal"));
+        assert_eq!(literal("9223372036854i64").cast(LiteralType::Decimal)?, literal("9223372036854decimal"));
+        // Ensure values outside of the decimal range fail.
+        assert!(literal("9223372036855i64").cast(LiteralType::Decimal).is_err());
+        assert!(literal("340282366920938463463374607431768211455u128").cast(LiteralType::Decimal).is_err());
+        // Ensure non-integer types cannot be cast to a decimal.
+        assert!(literal("1field").cast(LiteralType::Decimal).is_err());
+        assert!(literal("true").cast(LiteralType::Decimal).is_err());
+        Ok(())
+    }
+}
@@ -45,6 +45,19 @@ impl<E: Environment, I: IntegerType> Cast<Boolean<E>> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType> Cast<Decimal<E>> for Integer<E, I> {
+    /// Casts an `Integer` to a `Decimal`, if the integer is within the range of the decimal.
+    ///
+    /// To cast arbitrary integers to decimals, use `Integer::cast_lossy`.
+    #[inline]
+    fn cast(&self) -> Result<Decimal<E>> {
+        match (**self).to_i64().and_then(|integer| integer.checked_mul(Decimal::<E>::SCALING_FACTOR)) {
+            Some(mantissa) => Ok(Decimal::from_mantissa(mantissa)),
+            None => bail!("Failed to convert '{}' into 'decimal'", I::type_name()),
+        }
+    }
+}
+
 impl<E: Environment, I: IntegerType> Cast<Field<E>> for Integer<E, I> {
     /// Casts an `Integer` to a `Field`.
     #[inline]
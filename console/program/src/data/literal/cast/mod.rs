@@ -13,13 +13,14 @@
 // limitations under the License.
 
 mod boolean;
+mod decimal;
 mod field;
 mod integer;
 mod scalar;
 
 use crate::{Literal, LiteralType};
 use snarkvm_console_network::Network;
-use snarkvm_console_types::{integers::Integer, prelude::*, Boolean};
+use snarkvm_console_types::{integers::Integer, prelude::*, Boolean, Decimal, I64};
 
 /// Unary operator for casting values of one type to another.
 pub trait Cast<T: Sized = Self> {
@@ -38,6 +39,7 @@ impl<N: Network> Literal<N> {
     ///
     /// The hierarchy of casting is as follows:
     ///  - (`Address`, `Group`) <-> `Field` <-> `Scalar` <-> `Integer` <-> `Boolean`
+    ///  - `Decimal` <-> `Integer`
     ///  - `Signature` (not supported)
     ///  - `String` (not supported)
     /// Note that casting to left along the hierarchy always preserves information.
@@ -60,6 +62,7 @@ impl<N: Network> Literal<N> {
             Self::Scalar(scalar) => cast_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
+            Self::Decimal(decimal) => cast_decimal_to_type(decimal, to_type),
         }
     }
 }
@@ -89,6 +92,9 @@ macro_rules! impl_cast_body {
             LiteralType::String => {
                 bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a string type."))
             }
+            LiteralType::Decimal => {
+                bail!(concat!("Cannot cast a ", stringify!($type_name), " literal to a decimal type."))
+            }
         }
     };
 }
@@ -129,7 +135,18 @@ where
     u64: TryFrom<I>,
     u128: TryFrom<I>,
 {
-    impl_cast_body!(integer, cast, input, to_type)
+    match to_type {
+        LiteralType::Decimal => Ok(Literal::Decimal(input.cast()?)),
+        _ => impl_cast_body!(integer, cast, input, to_type),
+    }
+}
+
+/// Casts a decimal literal to the given literal type.
+fn cast_decimal_to_type<N: Network>(input: &Decimal<N>, to_type: LiteralType) -> Result<Literal<N>> {
+    match to_type {
+        LiteralType::Decimal => Ok(Literal::Decimal(*input)),
+        _ => cast_integer_to_type(&Cast::<I64<N>>::cast(input)?, to_type),
+    }
 }
 
 /// Casts a scalar literal to the given literal type.
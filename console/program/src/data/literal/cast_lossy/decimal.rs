@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, I: IntegerType> CastLossy<Integer<E, I>> for Decimal<E>
+where
+    i64: AsPrimitive<I>,
+{
+    /// Casts a `Decimal` to an `Integer`, truncating the fractional part toward zero,
+    /// and then truncating the whole part to the bit-width of the integer type.
+    #[inline]
+    fn cast_lossy(&self) -> Integer<E, I> {
+        Integer::new(self.trunc().as_())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_decimal_to_integer_lossy() -> Result<()> {
+        let literal = |string: &str| Literal::<CurrentNetwork>::from_str(string).unwrap();
+
+        assert_eq!(literal("1.999999decimal").cast_lossy(LiteralType::I64)?, literal("1i64"));
+        assert_eq!(literal("-1.999999decimal").cast_lossy(LiteralType::I64)?, literal("-1i64"));
+        assert_eq!(literal("257.5decimal").cast_lossy(LiteralType::U8)?, literal("1u8"));
+        assert_eq!(literal("2.5decimal").cast_lossy(LiteralType::Boolean)?, literal("false"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_integer_to_decimal_lossy() -> Result<()> {
+        let literal = |string: &str| Literal::<CurrentNetwork>::from_str(string).unwrap();
+
+        assert_eq!(literal("-3i8").cast_lossy(LiteralType::Decimal)?, literal("-3decimal"));
+        // Ensure the scaled value wraps around the decimal range.
+        let expected = Decimal::from_mantissa(9_223_372_036_855i64.wrapping_mul(1_000_000));
+        assert_eq!(literal("9223372036855i64").cast_lossy(LiteralType::Decimal)?, Literal::Decimal(expected));
+        assert!(literal("1field").cast_lossy(LiteralType::Decimal).is_err());
+        Ok(())
+    }
+}
@@ -38,6 +38,18 @@ impl<E: Environment, I: IntegerType> CastLossy<Boolean<E>> for Integer<E, I> {
     }
 }
 
+impl<E: Environment, I: IntegerType + AsPrimitive<i64>> CastLossy<Decimal<E>> for Integer<E, I> {
+    /// Casts an `Integer` to a `Decimal`, with lossy truncation.
+    ///
+    /// This operation truncates the integer to an `i64`, and then scales it by the decimal
+    /// scaling factor, wrapping around at the boundary of the decimal range.
+    #[inline]
+    fn cast_lossy(&self) -> Decimal<E> {
+        let integer: i64 = (**self).as_();
+        Decimal::from_mantissa(integer.wrapping_mul(Decimal::<E>::SCALING_FACTOR))
+    }
+}
+
 impl<E: Environment, I: IntegerType> CastLossy<Field<E>> for Integer<E, I> {
     /// Casts an `Integer` to a `Field`.
     /// This is safe because casting from an integer to a field is **always** lossless.
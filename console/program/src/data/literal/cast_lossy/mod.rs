@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod boolean;
+mod decimal;
 mod field;
 mod integer;
 mod scalar;
@@ -20,7 +21,7 @@ mod scalar;
 use crate::{Literal, LiteralType};
 use snarkvm_console_algorithms::Elligator2;
 use snarkvm_console_network::Network;
-use snarkvm_console_types::{integers::Integer, prelude::*, Boolean};
+use snarkvm_console_types::{integers::Integer, prelude::*, Boolean, Decimal, I64};
 
 /// Unary operator for casting values of one type to another, with lossy truncation.
 pub trait CastLossy<T: Sized = Self> {
@@ -39,6 +40,7 @@ impl<N: Network> Literal<N> {
     ///
     /// The hierarchy of casting is as follows:
     ///  - (`Address`, `Group`) <-> `Field` <-> `Scalar` <-> `Integer` <-> `Boolean`
+    ///  - `Decimal` <-> `Integer`
     ///  - `Signature` (not supported)
     ///  - `String` (not supported)
     /// Note that casting to left along the hierarchy always preserves information.
@@ -61,6 +63,7 @@ impl<N: Network> Literal<N> {
             Self::Scalar(scalar) => cast_lossy_scalar_to_type(scalar, to_type),
             Self::Signature(..) => bail!("Cannot cast a signature literal to another type."),
             Self::String(..) => bail!("Cannot cast a string literal to another type."),
+            Self::Decimal(decimal) => cast_lossy_decimal_to_type(decimal, to_type),
         }
     }
 }
@@ -90,6 +93,9 @@ macro_rules! impl_cast_lossy_body {
             LiteralType::String => {
                 bail!(concat!("Cannot cast (lossy) a ", stringify!($type_name), " literal to a string type."))
             }
+            LiteralType::Decimal => {
+                bail!(concat!("Cannot cast (lossy) a ", stringify!($type_name), " literal to a decimal type."))
+            }
         }
     };
 }
@@ -130,7 +136,18 @@ where
         + AsPrimitive<i64>
         + AsPrimitive<i128>,
 {
-    impl_cast_lossy_body!(integer, cast_lossy, input, to_type)
+    match to_type {
+        LiteralType::Decimal => Ok(Literal::Decimal(input.cast_lossy())),
+        _ => impl_cast_lossy_body!(integer, cast_lossy, input, to_type),
+    }
+}
+
+/// Casts a decimal literal to the given literal type, with lossy truncation.
+fn cast_lossy_decimal_to_type<N: Network>(input: &Decimal<N>, to_type: LiteralType) -> Result<Literal<N>> {
+    match to_type {
+        LiteralType::Decimal => Ok(Literal::Decimal(*input)),
+        _ => cast_lossy_integer_to_type(&CastLossy::<I64<N>>::cast_lossy(input), to_type),
+    }
 }
 
 /// Casts a scalar literal to the given literal type, with lossy truncation.
@@ -43,6 +43,7 @@ impl<N: Network> core::hash::Hash for Literal<N> {
             Self::Scalar(a) => a.hash(state),
             Self::Signature(a) => a.hash(state),
             Self::String(a) => a.hash(state),
+            Self::Decimal(a) => a.hash(state),
         }
     }
 }
@@ -70,6 +71,7 @@ impl<N: Network> Equal for Literal<N> {
             (Self::Scalar(a), Self::Scalar(b)) => a.is_equal(b),
             (Self::Signature(a), Self::Signature(b)) => a.is_equal(b),
             (Self::String(a), Self::String(b)) => a.is_equal(b),
+            (Self::Decimal(a), Self::Decimal(b)) => a.is_equal(b),
             _ => Boolean::new(false),
         }
     }
@@ -94,6 +96,7 @@ impl<N: Network> Equal for Literal<N> {
             (Self::Scalar(a), Self::Scalar(b)) => a.is_not_equal(b),
             (Self::Signature(a), Self::Signature(b)) => a.is_not_equal(b),
             (Self::String(a), Self::String(b)) => a.is_not_equal(b),
+            (Self::Decimal(a), Self::Decimal(b)) => a.is_not_equal(b),
             _ => Boolean::new(true),
         }
     }
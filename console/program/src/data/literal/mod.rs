@@ -70,4 +70,6 @@ pub enum Literal<N: Network> {
     Signature(Box<Signature<N>>),
     /// The string type.
     String(StringType<N>),
+    /// The fixed-point decimal type.
+    Decimal(Decimal<N>),
 }
@@ -40,6 +40,7 @@ impl<N: Network> Literal<N> {
                     .expect("ComputeKey::try_from failed."),
             )))),
             LiteralType::String => Literal::String(StringType::rand(rng)),
+            LiteralType::Decimal => Literal::Decimal(Decimal::rand(rng)),
         }
     }
 }
@@ -47,6 +47,7 @@ impl<N: Network> ToBits for &Literal<N> {
             Literal::Scalar(literal) => literal.write_bits_le(vec),
             Literal::Signature(literal) => literal.write_bits_le(vec),
             Literal::String(literal) => literal.as_bytes().write_bits_le(vec),
+            Literal::Decimal(literal) => literal.write_bits_le(vec),
         }
     }
 
@@ -70,6 +71,7 @@ impl<N: Network> ToBits for &Literal<N> {
             Literal::Scalar(literal) => literal.write_bits_be(vec),
             Literal::Signature(literal) => literal.write_bits_be(vec),
             Literal::String(literal) => literal.as_bytes().write_bits_be(vec),
+            Literal::Decimal(literal) => literal.write_bits_be(vec),
         }
     }
 }
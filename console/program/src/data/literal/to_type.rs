@@ -35,6 +35,7 @@ impl<N: Network> Literal<N> {
             Self::Scalar(..) => LiteralType::Scalar,
             Self::Signature(..) => LiteralType::Signature,
             Self::String(..) => LiteralType::String,
+            Self::Decimal(..) => LiteralType::Decimal,
         }
     }
 }
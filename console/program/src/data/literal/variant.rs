@@ -35,6 +35,7 @@ impl<N: Network> Literal<N> {
             Self::Scalar(..) => 14,
             Self::Signature(..) => 15,
             Self::String(..) => 16,
+            Self::Decimal(..) => 17,
         }
     }
 }
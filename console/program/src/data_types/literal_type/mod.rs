@@ -58,6 +58,8 @@ pub enum LiteralType {
     Signature,
     /// The string type.
     String,
+    /// The fixed-point decimal type, with a fixed scale of 6 fractional digits.
+    Decimal,
 }
 
 impl LiteralType {
@@ -81,6 +83,7 @@ impl LiteralType {
             Self::Scalar => "scalar",
             Self::Signature => "signature",
             Self::String => "string",
+            Self::Decimal => "decimal",
         }
     }
 
@@ -37,6 +37,7 @@ impl Parser for LiteralType {
             map(tag("scalar"), |_| Self::Scalar),
             map(tag("signature"), |_| Self::Signature),
             map(tag("string"), |_| Self::String),
+            map(tag("decimal"), |_| Self::Decimal),
         ))(string)
     }
 }
@@ -57,6 +57,7 @@ mod tests {
         "scalar",
         "signature",
         "string",
+        "decimal",
     ];
 
     fn check_serde_json<
@@ -60,6 +60,7 @@ mod tests {
         "u128",
         "scalar",
         "string",
+        "decimal",
         // Struct
         "signature",
         "message",
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> FromBytes for SerialNumberProof<N> {
+    /// Reads the serial number proof from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let commitment = Field::read_le(&mut reader)?;
+        let serial_number = Field::read_le(&mut reader)?;
+        let gamma = Group::read_le(&mut reader)?;
+        let challenge = Scalar::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self::from((commitment, serial_number, gamma, challenge, response)))
+    }
+}
+
+impl<N: Network> ToBytes for SerialNumberProof<N> {
+    /// Writes the serial number proof to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.commitment().write_le(&mut writer)?;
+        self.serial_number().write_le(&mut writer)?;
+        self.gamma().write_le(&mut writer)?;
+        self.challenge().write_le(&mut writer)?;
+        self.response().write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a serial number proof.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let expected = SerialNumberProof::prove(&private_key, Field::rand(rng), rng)?;
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, SerialNumberProof::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
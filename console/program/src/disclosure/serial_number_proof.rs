@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// A proof that the serial number of a record belongs to an account, without revealing `sk_sig`.
+///
+/// The proof shows that `gamma := sk_sig * H` for `H := HashToGroup(commitment)`, with the same `sk_sig`
+/// as `pk_sig := sk_sig * G`, using a Chaum-Pedersen proof of discrete log equality, where:
+///     challenge := HashToScalar(pk_sig, gamma, k * G, k * H, commitment, serial_number)
+///     response := k - challenge * sk_sig
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SerialNumberProof<N: Network> {
+    /// The record commitment.
+    commitment: Field<N>,
+    /// The serial number of the record.
+    serial_number: Field<N>,
+    /// The `gamma` of the record, as `sk_sig * H`.
+    gamma: Group<N>,
+    /// The verifier challenge.
+    challenge: Scalar<N>,
+    /// The prover response.
+    response: Scalar<N>,
+}
+
+impl<N: Network> SerialNumberProof<N> {
+    /// Returns a serial number proof for the given private key and record commitment.
+    pub fn prove<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, commitment: Field<N>, rng: &mut R) -> Result<Self> {
+        // Compute the generator `H` as `HashToGroup(commitment)`.
+        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
+        // Compute `gamma` as `sk_sig * H`.
+        let gamma = h * private_key.sk_sig();
+        // Compute the serial number from `gamma`.
+        let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
+
+        // Sample a random nonce.
+        let nonce = Scalar::rand(rng);
+        // Compute the commitments `k * G` and `k * H`.
+        let g_k = N::g_scalar_multiply(&nonce);
+        let h_k = h * nonce;
+
+        // Compute the verifier challenge.
+        let pk_sig = N::g_scalar_multiply(&private_key.sk_sig());
+        let challenge = Self::compute_challenge(pk_sig, gamma, g_k, h_k, commitment, serial_number)?;
+        // Compute the prover response.
+        let response = nonce - (challenge * private_key.sk_sig());
+
+        Ok(Self { commitment, serial_number, gamma, challenge, response })
+    }
+
+    /// Returns `true` if the proof is valid for the given compute key.
+    pub fn verify(&self, compute_key: &ComputeKey<N>) -> bool {
+        // Compute the generator `H` as `HashToGroup(commitment)`.
+        let h = match N::hash_to_group_psd2(&[N::serial_number_domain(), self.commitment]) {
+            Ok(h) => h,
+            Err(_) => return false,
+        };
+        // Ensure the serial number is derived from `gamma`.
+        match Record::<N, Plaintext<N>>::serial_number_from_gamma(&self.gamma, self.commitment) {
+            Ok(serial_number) if serial_number == self.serial_number => (),
+            _ => return false,
+        }
+
+        // Compute `k * G` as `(response * G) + (challenge * pk_sig)`.
+        let pk_sig = compute_key.pk_sig();
+        let g_k = N::g_scalar_multiply(&self.response) + (pk_sig * self.challenge);
+        // Compute `k * H` as `(response * H) + (challenge * gamma)`.
+        let h_k = (h * self.response) + (self.gamma * self.challenge);
+
+        // Ensure the challenge is correct.
+        match Self::compute_challenge(pk_sig, self.gamma, g_k, h_k, self.commitment, self.serial_number) {
+            Ok(candidate_challenge) => candidate_challenge == self.challenge,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the record commitment.
+    pub const fn commitment(&self) -> Field<N> {
+        self.commitment
+    }
+
+    /// Returns the serial number of the record.
+    pub const fn serial_number(&self) -> Field<N> {
+        self.serial_number
+    }
+
+    /// Returns the `gamma` of the record.
+    pub const fn gamma(&self) -> Group<N> {
+        self.gamma
+    }
+
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the prover response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+
+    /// Returns the verifier challenge as `HashToScalar(pk_sig, gamma, k * G, k * H, commitment, serial_number)`.
+    fn compute_challenge(
+        pk_sig: Group<N>,
+        gamma: Group<N>,
+        g_k: Group<N>,
+        h_k: Group<N>,
+        commitment: Field<N>,
+        serial_number: Field<N>,
+    ) -> Result<Scalar<N>> {
+        let mut preimage = Vec::with_capacity(7);
+        preimage.push(N::serial_number_domain());
+        preimage.extend([pk_sig, gamma, g_k, h_k].map(|point| point.to_x_coordinate()));
+        preimage.extend([commitment, serial_number]);
+        N::hash_to_scalar_psd8(&preimage)
+    }
+}
+
+impl<N: Network> From<(Field<N>, Field<N>, Group<N>, Scalar<N>, Scalar<N>)> for SerialNumberProof<N> {
+    /// Initializes a serial number proof from `(commitment, serial_number, gamma, challenge, response)`.
+    fn from(
+        (commitment, serial_number, gamma, challenge, response): (Field<N>, Field<N>, Group<N>, Scalar<N>, Scalar<N>),
+    ) -> Self {
+        Self { commitment, serial_number, gamma, challenge, response }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_prove_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample an account and a record commitment.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let commitment = Field::rand(rng);
+
+            // Check the proof is valid, and has the serial number of the record.
+            let proof = SerialNumberProof::prove(&private_key, commitment, rng)?;
+            assert!(proof.verify(&compute_key));
+            assert_eq!(
+                proof.serial_number(),
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, commitment)?
+            );
+
+            // Check the proof is invalid for another account.
+            let other_key = ComputeKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!proof.verify(&other_key));
+
+            // Check the proof is invalid for another serial number.
+            let candidate = SerialNumberProof::from((
+                commitment,
+                Field::rand(rng),
+                proof.gamma(),
+                proof.challenge(),
+                proof.response(),
+            ));
+            assert!(!candidate.verify(&compute_key));
+
+            // Check the proof is invalid for another commitment.
+            let candidate = SerialNumberProof::from((
+                Field::rand(rng),
+                proof.serial_number(),
+                proof.gamma(),
+                proof.challenge(),
+                proof.response(),
+            ));
+            assert!(!candidate.verify(&compute_key));
+        }
+        Ok(())
+    }
+}
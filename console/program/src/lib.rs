@@ -30,6 +30,9 @@ pub use data::*;
 mod data_types;
 pub use data_types::*;
 
+mod disclosure;
+pub use disclosure::*;
+
 mod id;
 pub use id::*;
 
@@ -39,6 +42,9 @@ pub use locator::*;
 mod owner;
 pub use owner::*;
 
+mod payment_uri;
+pub use payment_uri::*;
+
 mod request;
 pub use request::*;
 
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod string;
+
+use crate::{Identifier, ProgramID};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Address;
+
+/// A payment URI, of the form `aleo:<address>?amount=<microcredits>&program=<program ID>&function=<function name>`.
+///
+/// The query parameters are optional, and a function is only given together with its program.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentURI<N: Network> {
+    /// The address of the recipient.
+    address: Address<N>,
+    /// The amount to pay, in microcredits, if any.
+    amount: Option<u64>,
+    /// The program ID to call, if any.
+    program: Option<ProgramID<N>>,
+    /// The function name to call, if any.
+    function: Option<Identifier<N>>,
+}
+
+impl<N: Network> PaymentURI<N> {
+    /// Initializes a new payment URI for the given recipient.
+    pub const fn new(address: Address<N>) -> Self {
+        Self { address, amount: None, program: None, function: None }
+    }
+
+    /// Returns the payment URI with the given amount, in microcredits.
+    pub fn with_amount(mut self, amount: u64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Returns the payment URI with the given program ID.
+    pub fn with_program(mut self, program: ProgramID<N>) -> Self {
+        self.program = Some(program);
+        self
+    }
+
+    /// Returns the payment URI with the given function name.
+    /// Note: The program ID must be set before the function name.
+    pub fn with_function(mut self, function: Identifier<N>) -> Result<Self> {
+        // Ensure the program ID is set.
+        ensure!(self.program.is_some(), "A payment URI with a function must also have a program");
+        self.function = Some(function);
+        Ok(self)
+    }
+}
+
+impl<N: Network> PaymentURI<N> {
+    /// Returns the address of the recipient.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns the amount to pay, in microcredits, if any.
+    pub const fn amount(&self) -> Option<u64> {
+        self.amount
+    }
+
+    /// Returns the program ID to call, if any.
+    pub const fn program(&self) -> Option<&ProgramID<N>> {
+        self.program.as_ref()
+    }
+
+    /// Returns the function name to call, if any.
+    pub const fn function(&self) -> Option<&Identifier<N>> {
+        self.function.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_builder() -> Result<()> {
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::rand(&mut rng);
+        let program = ProgramID::from_str("credits.aleo")?;
+        let function = Identifier::from_str("transfer_public")?;
+
+        // Check the builder sets each field.
+        let uri = PaymentURI::new(address).with_amount(5).with_program(program).with_function(function)?;
+        assert_eq!(uri.address(), &address);
+        assert_eq!(uri.amount(), Some(5));
+        assert_eq!(uri.program(), Some(&program));
+        assert_eq!(uri.function(), Some(&function));
+
+        // Ensure a function requires a program.
+        assert!(PaymentURI::new(address).with_function(function).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static PAYMENT_URI_SCHEME: &str = "aleo:";
+
+impl<N: Network> FromStr for PaymentURI<N> {
+    type Err = Error;
+
+    /// Reads in a payment URI string.
+    ///
+    /// Note: The scheme is case-insensitive. A payment URI without lowercase characters is read as its lowercase
+    /// form, so that an uppercase payment URI can be used in a QR code. As program IDs and function names are
+    /// case-sensitive, a payment URI with uppercase characters in its program ID or function name must be mixed-case.
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        // Read an uppercase payment URI as its lowercase form.
+        if !uri.bytes().any(|byte| byte.is_ascii_lowercase()) {
+            return Self::parse_uri(&uri.to_ascii_lowercase());
+        }
+        Self::parse_uri(uri)
+    }
+}
+
+impl<N: Network> PaymentURI<N> {
+    /// Reads in a payment URI string, where only the scheme is case-insensitive.
+    fn parse_uri(uri: &str) -> Result<Self> {
+        // Ensure the URI starts with the scheme.
+        let Some(scheme) = uri.get(..PAYMENT_URI_SCHEME.len()) else {
+            bail!("Invalid payment URI: expected the '{PAYMENT_URI_SCHEME}' scheme")
+        };
+        ensure!(scheme.eq_ignore_ascii_case(PAYMENT_URI_SCHEME), "Invalid payment URI scheme '{scheme}'");
+        // Split the address from the query parameters.
+        let (address, query) = match uri[PAYMENT_URI_SCHEME.len()..].split_once('?') {
+            Some((address, query)) => (address, Some(query)),
+            None => (&uri[PAYMENT_URI_SCHEME.len()..], None),
+        };
+        // Parse the address.
+        let mut payment_uri = Self::new(Address::from_str(address)?);
+
+        // Parse the query parameters.
+        let mut function = None;
+        for parameter in query.into_iter().flat_map(|query| query.split('&')) {
+            let Some((key, value)) = parameter.split_once('=') else {
+                bail!("Invalid payment URI parameter '{parameter}': expected 'key=value'")
+            };
+            match key {
+                "amount" => {
+                    ensure!(payment_uri.amount.is_none(), "Duplicate payment URI parameter '{key}'");
+                    let amount = value.parse().map_err(|_| anyhow!("Invalid payment URI amount '{value}'"))?;
+                    payment_uri = payment_uri.with_amount(amount);
+                }
+                "program" => {
+                    ensure!(payment_uri.program.is_none(), "Duplicate payment URI parameter '{key}'");
+                    let program = ProgramID::from_str(value)
+                        .map_err(|_| anyhow!("Invalid program ID '{value}' in payment URI"))?;
+                    payment_uri = payment_uri.with_program(program);
+                }
+                "function" => {
+                    let name = Identifier::from_str(value)
+                        .map_err(|_| anyhow!("Invalid function name '{value}' in payment URI"))?;
+                    ensure!(function.replace(name).is_none(), "Duplicate payment URI parameter '{key}'");
+                }
+                _ => bail!("Unknown payment URI parameter '{key}'"),
+            }
+        }
+        // Set the function name, once the program ID is known.
+        if let Some(function) = function {
+            payment_uri = payment_uri.with_function(function)?;
+        }
+        Ok(payment_uri)
+    }
+}
+
+impl<N: Network> Display for PaymentURI<N> {
+    /// Writes the payment URI as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the scheme and the address.
+        write!(f, "{PAYMENT_URI_SCHEME}{}", self.address)?;
+        // Write the query parameters.
+        let amount = self.amount.map(|amount| format!("amount={amount}"));
+        let program = self.program.as_ref().map(|program| format!("program={program}"));
+        let function = self.function.as_ref().map(|function| format!("function={function}"));
+        for (index, parameter) in [amount, program, function].into_iter().flatten().enumerate() {
+            match index {
+                0 => write!(f, "?{parameter}")?,
+                _ => write!(f, "&{parameter}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::rand(&mut rng);
+
+        let program = ProgramID::from_str("credits.aleo")?;
+
+        for expected in [
+            PaymentURI::new(address),
+            PaymentURI::new(address).with_amount(1_500_000),
+            PaymentURI::new(address).with_program(program),
+            PaymentURI::new(address)
+                .with_amount(0)
+                .with_program(program)
+                .with_function(Identifier::from_str("transfer_private")?)?,
+        ] {
+            // Check the string representation.
+            let candidate = expected.to_string();
+            assert_eq!(expected, PaymentURI::from_str(&candidate)?);
+        }
+
+        // Check the parameters are written in a fixed order.
+        let uri = PaymentURI::new(address)
+            .with_amount(5)
+            .with_program(program)
+            .with_function(Identifier::from_str("transfer_public")?)?;
+        assert_eq!(uri.to_string(), format!("aleo:{address}?amount=5&program=credits.aleo&function=transfer_public"));
+        // Check the parameters are parsed in any order.
+        let candidate = format!("aleo:{address}?function=transfer_public&amount=5&program=credits.aleo");
+        assert_eq!(uri, PaymentURI::from_str(&candidate)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_uppercase_string() -> Result<()> {
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::rand(&mut rng);
+
+        // Check an uppercase scheme is accepted.
+        let candidate = format!("ALEO:{address}?amount=7");
+        assert_eq!(PaymentURI::new(address).with_amount(7), PaymentURI::from_str(&candidate)?);
+
+        // Check a fully uppercase payment URI is read as its lowercase form, as used in QR codes.
+        let expected = PaymentURI::new(address)
+            .with_amount(7)
+            .with_program(ProgramID::from_str("credits.aleo")?)
+            .with_function(Identifier::from_str("transfer_public")?)?;
+        let candidate = expected.to_string().to_ascii_uppercase();
+        assert_eq!(
+            candidate,
+            format!("ALEO:{}?AMOUNT=7&PROGRAM=CREDITS.ALEO&FUNCTION=TRANSFER_PUBLIC", address.to_uppercase_string())
+        );
+        assert_eq!(expected, PaymentURI::from_str(&candidate)?);
+
+        // Check a mixed-case payment URI keeps the case of its program ID and function name.
+        let candidate = format!("aleo:{address}?program=token.aleo&function=transferPublic");
+        let uri = PaymentURI::<CurrentNetwork>::from_str(&candidate)?;
+        assert_eq!(uri.function(), Some(&Identifier::from_str("transferPublic")?));
+        // Ensure a mixed-case payment URI with uppercase keys or network is rejected.
+        for candidate in [format!("aleo:{address}?AMOUNT=7"), format!("aleo:{address}?program=token.ALEO")] {
+            assert!(PaymentURI::<CurrentNetwork>::from_str(&candidate).is_err(), "{candidate}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_string() {
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::rand(&mut rng);
+
+        for candidate in [
+            String::new(),
+            "aleo".to_string(),
+            format!("{address}"),
+            format!("bitcoin:{address}"),
+            format!("aleo:{address}?"),
+            format!("aleo:{address}?amount"),
+            format!("aleo:{address}?amount=-1"),
+            format!("aleo:{address}?amount=1.5"),
+            format!("aleo:{address}?amount=1&amount=2"),
+            format!("aleo:{address}?label=coffee"),
+            format!("aleo:{address}?function=transfer_public"),
+            format!("aleo:{address}?program=credits"),
+            format!("aleo:{address}?program=credits.aleo&program=token.aleo"),
+            format!("aleo:{address}?program=credits.eth"),
+            format!("aleo:{address}?program=1credits.aleo"),
+            format!("aleo:{address}?program=credits.aleo&function=transfer-public"),
+            format!("aleo:{address}?program=credits.aleo&function=transfer_public&function=transfer_private"),
+        ] {
+            assert!(PaymentURI::<CurrentNetwork>::from_str(&candidate).is_err(), "{candidate}");
+        }
+    }
+}
@@ -20,7 +20,7 @@ impl<N: Network> FromBytes for Request<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if !(1..=3).contains(&version) {
             return Err(error("Invalid request version"));
         }
 
@@ -48,8 +48,49 @@ impl<N: Network> FromBytes for Request<N> {
         let tvk = FromBytes::read_le(&mut reader)?;
         // Read the transition commitment.
         let tcm = FromBytes::read_le(&mut reader)?;
-
-        Ok(Self::from((signer, network_id, program_id, function_name, input_ids, inputs, signature, sk_tag, tvk, tcm)))
+        // Note: The expiration height is only written in versions 2 and 3, and the memo only in version 3.
+        // A request without either has `tpk == R_0`.
+        let (tpk_base, expiration_height, memo) = match version {
+            1 => (Self::tpk_from_signature(&signature), None, None),
+            2 => {
+                // Read the transition public key base.
+                let tpk_base = FromBytes::read_le(&mut reader)?;
+                // Read the expiration height.
+                let expiration_height = u32::read_le(&mut reader)?;
+                (tpk_base, Some(expiration_height), None)
+            }
+            _ => {
+                // Read the transition public key base.
+                let tpk_base = FromBytes::read_le(&mut reader)?;
+                // Read the expiration height.
+                let expiration_height = match bool::read_le(&mut reader)? {
+                    true => Some(u32::read_le(&mut reader)?),
+                    false => None,
+                };
+                // Read the memo nonce and ciphertext.
+                let nonce = FromBytes::read_le(&mut reader)?;
+                let memo = FromBytes::read_le(&mut reader)?;
+                // Ensure the memo does not exceed the maximum size.
+                Self::check_memo_size(&memo).map_err(|e| error(e.to_string()))?;
+                (tpk_base, expiration_height, Some((nonce, memo)))
+            }
+        };
+
+        Ok(Self::from((
+            signer,
+            network_id,
+            program_id,
+            function_name,
+            input_ids,
+            inputs,
+            signature,
+            sk_tag,
+            tvk,
+            tcm,
+            tpk_base,
+            expiration_height,
+            memo,
+        )))
     }
 }
 
@@ -57,7 +98,13 @@ impl<N: Network> ToBytes for Request<N> {
     /// Writes the request to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: A request without an expiration height or memo is written as version 1, to preserve its encoding.
+        let version = match (self.expiration_height, &self.memo) {
+            (_, Some(_)) => 3u8,
+            (Some(_), None) => 2u8,
+            (None, None) => 1u8,
+        };
+        version.write_le(&mut writer)?;
 
         // Write the signer.
         self.signer.write_le(&mut writer)?;
@@ -93,7 +140,27 @@ impl<N: Network> ToBytes for Request<N> {
         // Write the transition view key.
         self.tvk.write_le(&mut writer)?;
         // Write the transition commitment.
-        self.tcm.write_le(&mut writer)
+        self.tcm.write_le(&mut writer)?;
+        // Write the transition public key base, expiration height, and memo.
+        match (self.expiration_height, &self.memo) {
+            (expiration_height, Some((nonce, memo))) => {
+                self.tpk_base.write_le(&mut writer)?;
+                match expiration_height {
+                    Some(expiration_height) => {
+                        true.write_le(&mut writer)?;
+                        expiration_height.write_le(&mut writer)?;
+                    }
+                    None => false.write_le(&mut writer)?,
+                }
+                nonce.write_le(&mut writer)?;
+                memo.write_le(&mut writer)
+            }
+            (Some(expiration_height), None) => {
+                self.tpk_base.write_le(&mut writer)?;
+                expiration_height.write_le(&mut writer)
+            }
+            (None, None) => Ok(()),
+        }
     }
 }
 
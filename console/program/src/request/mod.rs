@@ -19,10 +19,21 @@ mod bytes;
 mod serialize;
 mod sign;
 mod string;
+mod threshold;
 mod verify;
 
-use crate::{Identifier, Plaintext, ProgramID, Record, Value, ValueType};
-use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey};
+use crate::{Ciphertext, Identifier, Plaintext, ProgramID, Record, Value, ValueType};
+use snarkvm_console_account::{
+    Address,
+    ComputeKey,
+    GraphKey,
+    PrivateKey,
+    Signature,
+    SignatureShare,
+    SigningPackage,
+    ThresholdAccount,
+    ViewKey,
+};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -48,6 +59,12 @@ pub struct Request<N: Network> {
     tvk: Field<N>,
     /// The transition commitment.
     tcm: Field<N>,
+    /// The transition public key base `R_0`, where `tpk := R_0 + (Hash(R_0, expiration height) + Hash(R_0, memo)) * G`.
+    tpk_base: Group<N>,
+    /// The block height after which the request expires, if any.
+    expiration_height: Option<u32>,
+    /// The nonce and ciphertext of the memo for the recipient, if any.
+    memo: Option<(Group<N>, Ciphertext<N>)>,
 }
 
 impl<N: Network>
@@ -62,11 +79,28 @@ impl<N: Network>
         Field<N>,
         Field<N>,
         Field<N>,
+        Group<N>,
+        Option<u32>,
+        Option<(Group<N>, Ciphertext<N>)>,
     )> for Request<N>
 {
     /// Note: See `Request::sign` to create the request. This method is used to eject from a circuit.
     fn from(
-        (signer, network_id, program_id, function_name, input_ids, inputs, signature, sk_tag, tvk, tcm): (
+        (
+            signer,
+            network_id,
+            program_id,
+            function_name,
+            input_ids,
+            inputs,
+            signature,
+            sk_tag,
+            tvk,
+            tcm,
+            tpk_base,
+            expiration_height,
+            memo,
+        ): (
             Address<N>,
             U16<N>,
             ProgramID<N>,
@@ -77,13 +111,30 @@ impl<N: Network>
             Field<N>,
             Field<N>,
             Field<N>,
+            Group<N>,
+            Option<u32>,
+            Option<(Group<N>, Ciphertext<N>)>,
         ),
     ) -> Self {
         // Ensure the network ID is correct.
         if *network_id != N::ID {
             N::halt(format!("Invalid network ID. Expected {}, found {}", N::ID, *network_id))
         } else {
-            Self { signer, network_id, program_id, function_name, input_ids, inputs, signature, sk_tag, tvk, tcm }
+            Self {
+                signer,
+                network_id,
+                program_id,
+                function_name,
+                input_ids,
+                inputs,
+                signature,
+                sk_tag,
+                tvk,
+                tcm,
+                tpk_base,
+                expiration_height,
+                memo,
+            }
         }
     }
 }
@@ -136,12 +187,17 @@ impl<N: Network> Request<N> {
 
     /// Returns the transition public key `tpk`.
     pub fn to_tpk(&self) -> Group<N> {
+        Self::tpk_from_signature(&self.signature)
+    }
+
+    /// Returns the transition public key `tpk` for the given request signature.
+    fn tpk_from_signature(signature: &Signature<N>) -> Group<N> {
         // Retrieve the challenge from the signature.
-        let challenge = self.signature.challenge();
+        let challenge = signature.challenge();
         // Retrieve the response from the signature.
-        let response = self.signature.response();
+        let response = signature.response();
         // Retrieve `pk_sig` from the signature.
-        let pk_sig = self.signature.compute_key().pk_sig();
+        let pk_sig = signature.compute_key().pk_sig();
         // Compute `tpk` as `(challenge * pk_sig) + (response * G)`, equivalent to `r * G`.
         (pk_sig * challenge) + N::g_scalar_multiply(&response)
     }
@@ -150,6 +206,107 @@ impl<N: Network> Request<N> {
     pub const fn tcm(&self) -> &Field<N> {
         &self.tcm
     }
+
+    /// Returns the transition public key base `R_0`.
+    pub const fn tpk_base(&self) -> &Group<N> {
+        &self.tpk_base
+    }
+
+    /// Returns the block height after which the request expires, if any.
+    pub const fn expiration_height(&self) -> Option<u32> {
+        self.expiration_height
+    }
+
+    /// Returns `true` if the request has expired, given the latest block height.
+    pub fn is_expired(&self, latest_height: u32) -> bool {
+        self.expiration_height.map_or(false, |expiration_height| latest_height >= expiration_height)
+    }
+
+    /// Returns the nonce and ciphertext of the memo for the recipient, if any.
+    pub const fn memo(&self) -> Option<&(Group<N>, Ciphertext<N>)> {
+        self.memo.as_ref()
+    }
+
+    /// Returns the hash of the memo, if any.
+    pub fn to_memo_hash(&self) -> Result<Option<Field<N>>> {
+        self.memo.as_ref().map(|(nonce, memo)| Self::memo_hash(nonce, memo)).transpose()
+    }
+
+    /// Returns the offset of the transition public key from its base, as `Hash(R_0, expiration height)`.
+    ///
+    /// The transition public key is `tpk := R_0 + offset * G`. As `tpk` is the nonce of the request signature,
+    /// the expiration height is bound into the signature, and can not be changed without invalidating it.
+    /// A request without an expiration height has a zero offset, so that `tpk == R_0`.
+    pub fn expiration_offset(tpk_base: &Group<N>, expiration_height: Option<u32>) -> Result<Scalar<N>> {
+        match expiration_height {
+            // Compute the offset as `HashToScalar(domain || R_0 || expiration height)`.
+            Some(expiration_height) => N::hash_to_scalar_psd4(&[
+                Field::<N>::new_domain_separator("AleoTransitionExpiration0"),
+                tpk_base.to_x_coordinate(),
+                Field::from_u32(expiration_height),
+            ]),
+            None => Ok(Scalar::zero()),
+        }
+    }
+
+    /// Returns the offset of the transition public key from its base, for the given expiration height and memo hash.
+    ///
+    /// The offset is `Hash(R_0, expiration height) + Hash(R_0, memo hash)`, where each term is zero if absent.
+    /// As with the expiration height, this binds the memo into the request signature, so it can not be stripped.
+    pub fn tpk_offset(
+        tpk_base: &Group<N>,
+        expiration_height: Option<u32>,
+        memo_hash: Option<Field<N>>,
+    ) -> Result<Scalar<N>> {
+        // Compute the offset for the expiration height.
+        let offset = Self::expiration_offset(tpk_base, expiration_height)?;
+        match memo_hash {
+            // Add the offset as `HashToScalar(domain || R_0 || memo hash)`.
+            Some(memo_hash) => Ok(offset
+                + N::hash_to_scalar_psd4(&[
+                    Field::<N>::new_domain_separator("AleoTransitionMemo0"),
+                    tpk_base.to_x_coordinate(),
+                    memo_hash,
+                ])?),
+            None => Ok(offset),
+        }
+    }
+
+    /// Returns the nonce and ciphertext of the given memo, encrypted to the given recipient.
+    pub fn encrypt_memo<R: Rng + CryptoRng>(
+        memo: &Plaintext<N>,
+        recipient: &Address<N>,
+        rng: &mut R,
+    ) -> Result<(Group<N>, Ciphertext<N>)> {
+        // Sample a randomizer, and compute the nonce.
+        let randomizer = Scalar::rand(rng);
+        let nonce = N::g_scalar_multiply(&randomizer);
+        // Encrypt the memo to the recipient.
+        let ciphertext = memo.encrypt(recipient, randomizer)?;
+        // Ensure the memo does not exceed the maximum size.
+        Self::check_memo_size(&ciphertext)?;
+        Ok((nonce, ciphertext))
+    }
+
+    /// Returns the memo hash as `Hash(nonce || memo)`.
+    pub fn memo_hash(nonce: &Group<N>, memo: &Ciphertext<N>) -> Result<Field<N>> {
+        // Construct the preimage as `(nonce || memo)`.
+        let mut preimage = vec![nonce.to_x_coordinate()];
+        preimage.extend(memo.to_fields()?);
+        // Hash the preimage.
+        N::hash_psd8(&preimage)
+    }
+
+    /// Ensures the given memo ciphertext does not exceed the maximum size.
+    pub fn check_memo_size(memo: &Ciphertext<N>) -> Result<()> {
+        ensure!(
+            memo.len() <= N::MAX_MEMO_SIZE_IN_FIELDS,
+            "Memo exceeds the maximum size ({} > {} field elements)",
+            memo.len(),
+            N::MAX_MEMO_SIZE_IN_FIELDS
+        );
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -193,9 +350,27 @@ mod test_helpers {
                     ValueType::from_str("token.aleo/token.record").unwrap(),
                 ];
 
+                // Sample an expiration height for every other request.
+                let expiration_height = (i % 2 == 1).then_some(u32::try_from(i).unwrap());
+
+                // Sample a memo for every third request.
+                let memo = (i % 3 == 0).then(|| {
+                    let plaintext = Plaintext::from_str(&format!("{i}field")).unwrap();
+                    Request::encrypt_memo(&plaintext, &address, rng).unwrap()
+                });
+
                 // Compute the signed request.
-                let request =
-                    Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap();
+                let request = Request::sign_with_memo(
+                    &private_key,
+                    program_id,
+                    function_name,
+                    inputs.into_iter(),
+                    &input_types,
+                    expiration_height,
+                    memo,
+                    rng,
+                )
+                .unwrap();
                 assert!(request.verify(&input_types));
                 request
             })
@@ -21,7 +21,11 @@ impl<N: Network> Serialize for Request<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transition = serializer.serialize_struct("Request", 10)?;
+                let num_fields = 10
+                    + (self.expiration_height.is_some() || self.memo.is_some()) as usize
+                    + self.expiration_height.is_some() as usize
+                    + 2 * self.memo.is_some() as usize;
+                let mut transition = serializer.serialize_struct("Request", num_fields)?;
                 transition.serialize_field("signer", &self.signer)?;
                 transition.serialize_field("network", &self.network_id)?;
                 transition.serialize_field("program", &self.program_id)?;
@@ -32,6 +36,17 @@ impl<N: Network> Serialize for Request<N> {
                 transition.serialize_field("sk_tag", &self.sk_tag)?;
                 transition.serialize_field("tvk", &self.tvk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
+                // Note: The transition public key base is only included if the request expires or has a memo.
+                if self.expiration_height.is_some() || self.memo.is_some() {
+                    transition.serialize_field("tpk_base", &self.tpk_base)?;
+                }
+                if let Some(expiration_height) = &self.expiration_height {
+                    transition.serialize_field("expiration_height", expiration_height)?;
+                }
+                if let Some((nonce, memo)) = &self.memo {
+                    transition.serialize_field("memo_nonce", nonce)?;
+                    transition.serialize_field("memo", memo)?;
+                }
                 transition.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -46,6 +61,28 @@ impl<'de, N: Network> Deserialize<'de> for Request<N> {
             true => {
                 // Parse the request from a string into a value.
                 let mut request = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the signature.
+                let signature = DeserializeExt::take_from_value::<D>(&mut request, "signature")?;
+                // Retrieve the `tpk_base`, where a request without an expiration height or memo has `tpk == R_0`.
+                let tpk_base = match request.get("tpk_base") {
+                    Some(_) => DeserializeExt::take_from_value::<D>(&mut request, "tpk_base")?,
+                    None => Self::tpk_from_signature(&signature),
+                };
+                // Retrieve the expiration height, if any.
+                let expiration_height = match request.get("expiration_height") {
+                    Some(_) => Some(DeserializeExt::take_from_value::<D>(&mut request, "expiration_height")?),
+                    None => None,
+                };
+                // Retrieve the memo, if any.
+                let memo = match request.get("memo") {
+                    Some(_) => {
+                        let nonce = DeserializeExt::take_from_value::<D>(&mut request, "memo_nonce")?;
+                        let memo = DeserializeExt::take_from_value::<D>(&mut request, "memo")?;
+                        Self::check_memo_size(&memo).map_err(de::Error::custom)?;
+                        Some((nonce, memo))
+                    }
+                    None => None,
+                };
                 // Recover the request.
                 Ok(Self::from((
                     // Retrieve the signer.
@@ -60,14 +97,16 @@ impl<'de, N: Network> Deserialize<'de> for Request<N> {
                     DeserializeExt::take_from_value::<D>(&mut request, "input_ids")?,
                     // Retrieve the inputs.
                     DeserializeExt::take_from_value::<D>(&mut request, "inputs")?,
-                    // Retrieve the signature.
-                    DeserializeExt::take_from_value::<D>(&mut request, "signature")?,
+                    signature,
                     // Retrieve the `sk_tag`.
                     DeserializeExt::take_from_value::<D>(&mut request, "sk_tag")?,
                     // Retrieve the `tvk`.
                     DeserializeExt::take_from_value::<D>(&mut request, "tvk")?,
                     // Retrieve the `tcm`.
                     DeserializeExt::take_from_value::<D>(&mut request, "tcm")?,
+                    tpk_base,
+                    expiration_height,
+                    memo,
                 )))
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "request"),
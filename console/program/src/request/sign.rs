@@ -15,15 +15,48 @@
 use super::*;
 
 impl<N: Network> Request<N> {
-    /// Returns the request for a given private key, program ID, function name, inputs, input types, and RNG, where:
+    /// Returns the request for a given private key, program ID, function name, inputs, input types, and RNG.
+    /// The request does not expire. See `Request::sign_with_expiration` for details.
+    pub fn sign<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign_with_expiration(private_key, program_id, function_name, inputs, input_types, None, rng)
+    }
+
+    /// Returns the request for a given private key, program ID, function name, inputs, input types,
+    /// expiration height, and RNG. The request has no memo. See `Request::sign_with_memo` for details.
+    pub fn sign_with_expiration<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        expiration_height: Option<u32>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign_with_memo(private_key, program_id, function_name, inputs, input_types, expiration_height, None, rng)
+    }
+
+    /// Returns the request for a given private key, program ID, function name, inputs, input types,
+    /// expiration height, memo (as a nonce and ciphertext, see `Request::encrypt_memo`), and RNG, where:
+    ///     r := r_0 + HashToScalar(r_0 * G, expiration height) + HashToScalar(r_0 * G, memo hash)
     ///     challenge := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
     ///     response := r - challenge * sk_sig
-    pub fn sign<R: Rng + CryptoRng>(
+    /// Each term of the offset of `r` is omitted if the request does not expire, or has no memo.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign_with_memo<R: Rng + CryptoRng>(
         private_key: &PrivateKey<N>,
         program_id: ProgramID<N>,
         function_name: Identifier<N>,
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         input_types: &[ValueType<N>],
+        expiration_height: Option<u32>,
+        memo: Option<(Group<N>, Ciphertext<N>)>,
         rng: &mut R,
     ) -> Result<Self> {
         // Ensure the number of inputs matches the number of input types.
@@ -52,8 +85,21 @@ impl<N: Network> Request<N> {
 
         // Sample a random nonce.
         let nonce = Field::<N>::rand(rng);
-        // Compute a `r` as `HashToScalar(sk_sig || nonce)`. Note: This is the transition secret key `tsk`.
-        let r = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
+        // Compute `r_0` as `HashToScalar(sk_sig || nonce)`.
+        let r_0 = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
+        // Compute the transition public key base `R_0` as `r_0 * G`.
+        let tpk_base = N::g_scalar_multiply(&r_0);
+        // Compute the memo hash, after ensuring the memo does not exceed the maximum size.
+        let memo_hash = match &memo {
+            Some((nonce, memo)) => {
+                Self::check_memo_size(memo)?;
+                Some(Self::memo_hash(nonce, memo)?)
+            }
+            None => None,
+        };
+        // Compute `r` as `r_0 + Hash(R_0, expiration height) + Hash(R_0, memo hash)`.
+        // Note: This is the transition secret key `tsk`.
+        let r = r_0 + Self::tpk_offset(&tpk_base, expiration_height, memo_hash)?;
         // Compute `g_r` as `r * G`. Note: This is the transition public key `tpk`.
         let g_r = N::g_scalar_multiply(&r);
 
@@ -74,6 +120,61 @@ impl<N: Network> Request<N> {
         message.extend([g_r, pk_sig, pr_sig, *signer].map(|point| point.to_x_coordinate()));
         message.extend([tvk, tcm, function_id]);
 
+        // Prepare the inputs, and add the input IDs to the message.
+        let (prepared_inputs, input_ids) = Self::prepare_inputs(
+            &program_id,
+            &function_name,
+            function_id,
+            inputs,
+            input_types,
+            signer,
+            sk_tag,
+            tvk,
+            tcm,
+            Some((sk_sig, r)),
+            &mut message,
+        )?;
+
+        // Compute `challenge` as `HashToScalar(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
+        let challenge = N::hash_to_scalar_psd8(&message)?;
+        // Compute `response` as `r - challenge * sk_sig`.
+        let response = r - challenge * sk_sig;
+
+        Ok(Self {
+            signer,
+            network_id: U16::new(N::ID),
+            program_id,
+            function_name,
+            input_ids,
+            inputs: prepared_inputs,
+            signature: Signature::from((challenge, response, compute_key)),
+            sk_tag,
+            tvk,
+            tcm,
+            tpk_base,
+            expiration_height,
+            memo,
+        })
+    }
+
+    /// Prepares the given inputs, and appends their input IDs to the signature message.
+    /// Returns the prepared inputs and their input IDs.
+    ///
+    /// Record inputs require `sk_sig` and the transition secret key `r`, as `record_secrets`.
+    #[allow(clippy::type_complexity)]
+    pub(super) fn prepare_inputs(
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        function_id: Field<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        signer: Address<N>,
+        sk_tag: Field<N>,
+        tvk: Field<N>,
+        tcm: Field<N>,
+        record_secrets: Option<(Scalar<N>, Scalar<N>)>,
+        message: &mut Vec<Field<N>>,
+    ) -> Result<(Vec<Value<N>>, Vec<InputID<N>>)> {
         // Initialize a vector to store the prepared inputs.
         let mut prepared_inputs = Vec::with_capacity(inputs.len());
         // Initialize a vector to store the input IDs.
@@ -164,11 +265,15 @@ impl<N: Network> Request<N> {
                         Value::Plaintext(..) => bail!("Expected a record input, found a plaintext input"),
                         Value::Future(..) => bail!("Expected a record input, found a future input"),
                     };
+                    // Retrieve `sk_sig` and the transition secret key `r`.
+                    let Some((sk_sig, r)) = record_secrets else {
+                        bail!("Record inputs for '{program_id}/{function_name}' require the private key of the signer")
+                    };
                     // Ensure the record belongs to the signer.
                     ensure!(**record.owner() == signer, "Input record for '{program_id}' must belong to the signer");
 
                     // Compute the record commitment.
-                    let commitment = record.to_commitment(&program_id, record_name)?;
+                    let commitment = record.to_commitment(program_id, record_name)?;
 
                     // Compute the generator `H` as `HashToGroup(commitment)`.
                     let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
@@ -215,22 +320,6 @@ impl<N: Network> Request<N> {
             }
         }
 
-        // Compute `challenge` as `HashToScalar(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
-        let challenge = N::hash_to_scalar_psd8(&message)?;
-        // Compute `response` as `r - challenge * sk_sig`.
-        let response = r - challenge * sk_sig;
-
-        Ok(Self {
-            signer,
-            network_id: U16::new(N::ID),
-            program_id,
-            function_name,
-            input_ids,
-            inputs: prepared_inputs,
-            signature: Signature::from((challenge, response, compute_key)),
-            sk_tag,
-            tvk,
-            tcm,
-        })
+        Ok((prepared_inputs, input_ids))
     }
 }
@@ -0,0 +1,290 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Request<N> {
+    /// Returns the message that the signers of a threshold request commit to, as `[function ID, input hashes]`.
+    pub fn threshold_message(
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<Vec<Field<N>>> {
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(U16::<N>::new(N::ID), program_id.name(), program_id.network(), function_name).to_bits_le(),
+        )?;
+        // Construct the message as `[function ID, Hash(input_0), ..., Hash(input_n)]`.
+        let mut message = Vec::with_capacity(1 + inputs.len());
+        message.push(function_id);
+        for input in inputs {
+            message.push(N::hash_psd8(&input.to_fields()?)?);
+        }
+        Ok(message)
+    }
+
+    /// Returns the verifier challenge of a threshold request for the given signing package.
+    ///
+    /// Each signer must compute the challenge with this method, before signing it with `KeyShare::sign_with_challenge`.
+    /// The signing package must be for the message from `Request::threshold_message`.
+    pub fn threshold_challenge(
+        view_key: &ViewKey<N>,
+        compute_key: &ComputeKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+        package: &SigningPackage<N>,
+    ) -> Result<Scalar<N>> {
+        let request =
+            Self::prepare_threshold(view_key, compute_key, program_id, function_name, inputs, input_types, package)?;
+        Ok(request.signature.challenge())
+    }
+
+    /// Returns the request for a threshold account, by aggregating the signature shares of the signers, where:
+    ///     challenge := HashToScalar(R + offset * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
+    ///     response := Sum(z_i) + offset
+    ///
+    /// The transition secret key is the combined nonce of the signers, so record inputs are not supported.
+    #[allow(clippy::too_many_arguments)]
+    pub fn sign_threshold(
+        view_key: &ViewKey<N>,
+        account: &ThresholdAccount<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+        package: &SigningPackage<N>,
+        shares: &[SignatureShare<N>],
+    ) -> Result<Self> {
+        // Prepare the request.
+        let mut request = Self::prepare_threshold(
+            view_key,
+            account.compute_key(),
+            program_id,
+            function_name,
+            inputs,
+            input_types,
+            package,
+        )?;
+        // Aggregate the signature shares.
+        let signature = account.aggregate_with_challenge(package, shares, request.signature.challenge())?;
+        // Compute the offset of the transition public key from the group commitment `R`.
+        let offset = Self::expiration_offset(&request.tpk_base, request.expiration_height)?;
+        // Compute the response as `Sum(z_i) + offset`, as the transition public key is `R + offset * G`.
+        request.signature =
+            Signature::from((signature.challenge(), signature.response() + offset, *account.compute_key()));
+        Ok(request)
+    }
+
+    /// Returns the threshold request for the given signing package, with the challenge but without the response.
+    fn prepare_threshold(
+        view_key: &ViewKey<N>,
+        compute_key: &ComputeKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        input_types: &[ValueType<N>],
+        package: &SigningPackage<N>,
+    ) -> Result<Self> {
+        // Derive the signer from the compute key.
+        let signer = compute_key.to_address();
+        // Ensure the view key belongs to the signer.
+        ensure!(view_key.to_address() == signer, "The view key does not belong to the threshold account");
+        // Ensure the signing package is for this request.
+        ensure!(
+            package.message() == Self::threshold_message(&program_id, &function_name, inputs)?,
+            "The signing package is not for a request to '{program_id}/{function_name}'"
+        );
+
+        // Retrieve `pk_sig`.
+        let pk_sig = compute_key.pk_sig();
+        // Retrieve `pr_sig`.
+        let pr_sig = compute_key.pr_sig();
+        // Derive `sk_tag` from the graph key.
+        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+
+        // Compute the group commitment `R`. Note: This is the transition public key base `R_0`.
+        let tpk_base = package.group_commitment(compute_key)?;
+        // Compute `g_r` as `R + Hash(R, expiration height) * G`. Note: This is the transition public key `tpk`.
+        let g_r = tpk_base + N::g_scalar_multiply(&Self::expiration_offset(&tpk_base, None)?);
+        // Compute the transition view key `tvk` as `view_key * g_r`, which equals `r * signer`.
+        let tvk = (g_r * **view_key).to_x_coordinate();
+        // Compute the transition commitment `tcm` as `Hash(tvk)`.
+        let tcm = N::hash_psd2(&[tvk])?;
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(U16::<N>::new(N::ID), program_id.name(), program_id.network(), function_name).to_bits_le(),
+        )?;
+
+        // Construct the hash input as `(g_r, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
+        let mut message = Vec::with_capacity(9 + 2 * inputs.len());
+        message.extend([g_r, pk_sig, pr_sig, *signer].map(|point| point.to_x_coordinate()));
+        message.extend([tvk, tcm, function_id]);
+
+        // Prepare the inputs, and add the input IDs to the message.
+        let (prepared_inputs, input_ids) = Self::prepare_inputs(
+            &program_id,
+            &function_name,
+            function_id,
+            inputs.iter().cloned(),
+            input_types,
+            signer,
+            sk_tag,
+            tvk,
+            tcm,
+            None,
+            &mut message,
+        )?;
+
+        // Compute `challenge` as `HashToScalar(g_r, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
+        let challenge = N::hash_to_scalar_psd8(&message)?;
+
+        Ok(Self {
+            signer,
+            network_id: U16::new(N::ID),
+            program_id,
+            function_name,
+            input_ids,
+            inputs: prepared_inputs,
+            // Note: The response is set when the signature shares are aggregated.
+            signature: Signature::from((challenge, Scalar::zero(), *compute_key)),
+            sk_tag,
+            tvk,
+            tcm,
+            tpk_base,
+            // Note: Threshold requests do not expire, and have no memo.
+            expiration_height: None,
+            memo: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_sign_threshold_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Split a random private key into 2-of-3 key shares.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let (account, key_shares) = ThresholdAccount::split(&private_key, 2, 3, rng)?;
+
+            // Construct a public transfer.
+            let program_id = ProgramID::from_str("credits.aleo")?;
+            let function_name = Identifier::from_str("transfer_public")?;
+            let inputs = vec![
+                Value::from_str(&Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?.to_string())?,
+                Value::from_str("100u64")?,
+            ];
+            let input_types = vec![ValueType::from_str("address.public")?, ValueType::from_str("u64.public")?];
+
+            // Round 1: The first and third signers commit to their nonces.
+            let signers = [&key_shares[0], &key_shares[2]];
+            let (nonces, commitments): (Vec<_>, Vec<_>) = signers.iter().map(|signer| signer.commit(rng)).unzip();
+            let message = Request::threshold_message(&program_id, &function_name, &inputs)?;
+            let package = SigningPackage::new(commitments, message)?;
+
+            // Round 2: Each signer computes the challenge, and signs it.
+            let shares = signers
+                .iter()
+                .zip_eq(nonces)
+                .map(|(signer, nonces)| {
+                    let challenge = Request::threshold_challenge(
+                        &view_key,
+                        signer.compute_key(),
+                        program_id,
+                        function_name,
+                        &inputs,
+                        &input_types,
+                        &package,
+                    )?;
+                    signer.sign_with_challenge(nonces, &package, challenge)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            // Aggregate the signature shares into a request.
+            let request = Request::sign_threshold(
+                &view_key,
+                &account,
+                program_id,
+                function_name,
+                &inputs,
+                &input_types,
+                &package,
+                &shares,
+            )?;
+            assert!(request.verify(&input_types));
+            assert_eq!(request.signer(), &account.address());
+
+            // Check that the signing package cannot be used for different inputs.
+            let other_inputs = vec![inputs[0].clone(), Value::from_str("200u64")?];
+            assert!(Request::sign_threshold(
+                &view_key,
+                &account,
+                program_id,
+                function_name,
+                &other_inputs,
+                &input_types,
+                &package,
+                &shares,
+            )
+            .is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_threshold_rejects_records() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+        let (account, key_shares) = ThresholdAccount::split(&private_key, 1, 1, rng)?;
+
+        // Prepare a record belonging to the address.
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let function_name = Identifier::from_str("transfer")?;
+        let inputs = vec![Value::from_str(&format!(
+            "{{ owner: {address}.private, token_amount: 100u64.private, _nonce: 2293253577170800572742339369209137467208538700597121244293392265726446806023group.public }}"
+        ))?];
+        let input_types = vec![ValueType::from_str("token.record")?];
+
+        // Check that a record input is rejected.
+        let (_nonces, commitments) = key_shares[0].commit(rng);
+        let message = Request::threshold_message(&program_id, &function_name, &inputs)?;
+        let package = SigningPackage::new(vec![commitments], message)?;
+        assert!(Request::threshold_challenge(
+            &view_key,
+            account.compute_key(),
+            program_id,
+            function_name,
+            &inputs,
+            &input_types,
+            &package
+        )
+        .is_err());
+        Ok(())
+    }
+}
@@ -17,8 +17,9 @@ use super::*;
 impl<N: Network> Request<N> {
     /// Returns `true` if the request is valid, and `false` otherwise.
     ///
-    /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') where:
+    /// Verifies (challenge == challenge') && (address == address') && (serial_numbers == serial_numbers') && (tpk == tpk') where:
     ///     challenge' := HashToScalar(r * G, pk_sig, pr_sig, signer, \[tvk, tcm, function ID, input IDs\])
+    ///     tpk' := R_0 + (HashToScalar(R_0, expiration height) + HashToScalar(R_0, memo hash)) * G
     pub fn verify(&self, input_types: &[ValueType<N>]) -> bool {
         // Verify the transition public key, transition view key, and transition commitment are well-formed.
         {
@@ -38,6 +39,32 @@ impl<N: Network> Request<N> {
             }
         }
 
+        // Verify the memo does not exceed the maximum size.
+        if let Some((_, memo)) = &self.memo {
+            if let Err(error) = Self::check_memo_size(memo) {
+                eprintln!("Invalid memo in request: {error}");
+                return false;
+            }
+        }
+
+        // Verify the transition public key commits to the expiration height and memo.
+        match self.to_memo_hash().and_then(|memo_hash| Self::tpk_offset(&self.tpk_base, self.expiration_height, memo_hash))
+        {
+            Ok(offset) => {
+                // Ensure the transition public key is `R_0 + offset * G`.
+                if self.to_tpk() != self.tpk_base + N::g_scalar_multiply(&offset) {
+                    eprintln!(
+                        "Invalid transition public key in request: it does not match the expiration height and memo."
+                    );
+                    return false;
+                }
+            }
+            Err(error) => {
+                eprintln!("Failed to compute the transition public key offset in request verification: {error}");
+                return false;
+            }
+        }
+
         // Retrieve the challenge from the signature.
         let challenge = self.signature.challenge();
         // Retrieve the response from the signature.
@@ -257,4 +284,96 @@ mod tests {
             assert!(request.verify(&input_types));
         }
     }
+
+    #[test]
+    fn test_sign_with_expiration_and_verify() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+
+        // Construct the inputs and input types.
+        let inputs = [Value::from_str("{ token_amount: 9876543210u128 }").unwrap()];
+        let input_types = vec![ValueType::from_str("amount.public").unwrap()];
+
+        // Compute the signed request.
+        let request = Request::sign_with_expiration(
+            &private_key,
+            program_id,
+            function_name,
+            inputs.into_iter(),
+            &input_types,
+            Some(100),
+            rng,
+        )
+        .unwrap();
+        assert!(request.verify(&input_types));
+        assert_eq!(request.expiration_height(), Some(100));
+        assert!(!request.is_expired(99));
+        assert!(request.is_expired(100));
+
+        // Ensure the expiration height can not be changed or stripped.
+        for expiration_height in [Some(101), Some(u32::MAX), None] {
+            let mut candidate = request.clone();
+            candidate.expiration_height = expiration_height;
+            assert!(!candidate.verify(&input_types));
+        }
+    }
+
+    #[test]
+    fn test_sign_with_memo_and_verify() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key, and a recipient.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let recipient_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Construct a program ID and function name.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+
+        // Construct the inputs and input types.
+        let inputs = [Value::from_str("{ token_amount: 9876543210u128 }").unwrap()];
+        let input_types = vec![ValueType::from_str("amount.public").unwrap()];
+
+        // Encrypt a memo to the recipient.
+        let expected = Plaintext::from_str("123field").unwrap();
+        let memo = Request::encrypt_memo(&expected, &recipient_view_key.to_address(), rng).unwrap();
+
+        // Compute the signed request.
+        let request = Request::sign_with_memo(
+            &private_key,
+            program_id,
+            function_name,
+            inputs.into_iter(),
+            &input_types,
+            Some(100),
+            Some(memo.clone()),
+            rng,
+        )
+        .unwrap();
+        assert!(request.verify(&input_types));
+        assert_eq!(request.memo(), Some(&memo));
+        assert_eq!(memo.1.decrypt(recipient_view_key, memo.0).unwrap(), expected);
+
+        // Ensure the memo can not be changed or stripped.
+        let other_memo = Request::encrypt_memo(&expected, &recipient_view_key.to_address(), rng).unwrap();
+        for memo in [Some(other_memo), None] {
+            let mut candidate = request.clone();
+            candidate.memo = memo;
+            assert!(!candidate.verify(&input_types));
+        }
+
+        // Ensure a memo that exceeds the maximum size is rejected.
+        let oversized = Plaintext::from_str(&format!(
+            "[{}]",
+            (0..=CurrentNetwork::MAX_MEMO_SIZE_IN_FIELDS).map(|i| format!("{i}field")).collect::<Vec<_>>().join(", ")
+        ))
+        .unwrap();
+        assert!(Request::encrypt_memo(&oversized, &recipient_view_key.to_address(), rng).is_err());
+    }
 }
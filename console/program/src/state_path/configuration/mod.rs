@@ -19,6 +19,8 @@ use snarkvm_console_network::BHPMerkleTree;
 pub const BLOCKS_DEPTH: u8 = 32;
 /// The depth of the Merkle tree for the block header.
 pub const HEADER_DEPTH: u8 = 3;
+/// The depth of the Merkle tree for the events in a block.
+pub const EVENTS_DEPTH: u8 = 20;
 /// The depth of the Merkle tree for finalize operations in a block.
 pub const FINALIZE_OPERATIONS_DEPTH: u8 = 20;
 /// The depth of the Merkle tree for the ratifications in a block.
@@ -42,6 +44,11 @@ pub type HeaderTree<N> = BHPMerkleTree<N, HEADER_DEPTH>;
 /// The Merkle path for the block header.
 pub type HeaderPath<N> = MerklePath<N, HEADER_DEPTH>;
 
+/// The Merkle tree for the events in a block.
+pub type EventsTree<N> = BHPMerkleTree<N, EVENTS_DEPTH>;
+/// The Merkle path for an event in a block.
+pub type EventsPath<N> = MerklePath<N, EVENTS_DEPTH>;
+
 /// The Merkle tree for ratifications in a block.
 pub type RatificationsTree<N> = BHPMerkleTree<N, RATIFICATIONS_DEPTH>;
 /// The Merkle path for a ratification in a block.
@@ -15,16 +15,14 @@
 use super::*;
 
 static ADDRESS_PREFIX: &str = "aleo";
+static ADDRESS_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
 
 impl<E: Environment> Parser for Address<E> {
     /// Parses a string into an address.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Prepare a parser for the Aleo address.
-        let parse_address = recognize(pair(
-            tag("aleo1"),
-            many1(terminated(one_of("qpzry9x8gf2tvdw0s3jn54khce6mua7l"), many0(char('_')))),
-        ));
+        let parse_address = recognize(pair(tag("aleo1"), many1(terminated(one_of(ADDRESS_CHARSET), many0(char('_'))))));
 
         // Parse the address from the string.
         map_res(parse_address, |address: &str| -> Result<_, Error> { Self::from_str(&address.replace('_', "")) })(
@@ -43,7 +41,21 @@ impl<E: Environment> FromStr for Address<E> {
             bail!("Invalid account address length: found {}, expected 63", address.len())
         }
         // Decode the address string from bech32m.
-        let (hrp, data, variant) = bech32::decode(address)?;
+        let (hrp, data, variant) = match bech32::decode(address) {
+            Ok(decoded) => decoded,
+            Err(bech32::Error::InvalidChar(character)) => match address.find(character) {
+                Some(index) => bail!("Failed to decode address: invalid character '{character}' at index {index}"),
+                None => bail!("Failed to decode address: invalid character '{character}'"),
+            },
+            Err(bech32::Error::InvalidChecksum) => match locate_corrupted_character(address) {
+                Some(index) => bail!(
+                    "Failed to decode address: invalid checksum, the character '{}' at index {index} is likely corrupted",
+                    &address[index..index + 1]
+                ),
+                None => bail!("Failed to decode address: invalid checksum"),
+            },
+            Err(error) => bail!("Failed to decode address: {error}"),
+        };
         if hrp != ADDRESS_PREFIX {
             bail!("Failed to decode address: '{hrp}' is an invalid prefix")
         } else if data.is_empty() {
@@ -56,6 +68,42 @@ impl<E: Environment> FromStr for Address<E> {
     }
 }
 
+impl<E: Environment> Address<E> {
+    /// Returns the address as an uppercase bech32m string.
+    ///
+    /// Note: An uppercase address fits the alphanumeric mode of a QR code, which is more compact than
+    /// the byte mode needed for a lowercase address. Parsing the string with `from_str` returns the address.
+    pub fn to_uppercase_string(&self) -> String {
+        self.to_string().to_uppercase()
+    }
+}
+
+/// Returns the index of the character in the given address string, that if corrected,
+/// makes the bech32m checksum valid, or `None` if no single correction does so.
+///
+/// Note: The bech32m checksum detects any error in up to 4 characters of an address,
+/// so at most one single-character correction can yield a valid checksum.
+fn locate_corrupted_character(address: &str) -> Option<usize> {
+    // Ensure the address is ASCII, so that every character is one byte.
+    if !address.is_ascii() {
+        return None;
+    }
+    let address = address.to_lowercase();
+    // Check the prefix, as a corrupted prefix also invalidates the checksum.
+    let prefix = format!("{ADDRESS_PREFIX}1");
+    if let Some(index) = address.bytes().zip(prefix.bytes()).position(|(a, b)| a != b) {
+        return Some(index);
+    }
+    // Check each character of the data, for a correction that yields a valid checksum.
+    (prefix.len()..address.len()).find(|&index| {
+        ADDRESS_CHARSET.chars().any(|candidate| {
+            let mut corrected = address.clone();
+            corrected.replace_range(index..index + 1, candidate.encode_utf8(&mut [0u8; 4]));
+            corrected != address && matches!(bech32::decode(&corrected), Ok((_, _, bech32::Variant::Bech32m)))
+        })
+    })
+}
+
 impl<E: Environment> Debug for Address<E> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         Display::fmt(self, f)
@@ -121,6 +169,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_uppercase_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new address.
+            let expected = Address::<CurrentEnvironment>::rand(&mut rng);
+
+            // Check the uppercase string representation.
+            let candidate = expected.to_uppercase_string();
+            assert_eq!(expected.to_string().to_uppercase(), candidate);
+            assert_eq!(expected, Address::from_str(&candidate)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_corrupted_character() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..100 {
+            // Sample a new address.
+            let address = Address::<CurrentEnvironment>::rand(&mut rng).to_string();
+
+            // Corrupt a random character of the address, other than the separator.
+            let index = match rng.gen_range(0..address.len() - 1) {
+                index if index < ADDRESS_PREFIX.len() => index,
+                index => index + 1,
+            };
+            let original = address.as_bytes()[index] as char;
+            let corrupted = ADDRESS_CHARSET.chars().find(|c| *c != original && *c != '1').unwrap();
+            let mut candidate = address.clone();
+            candidate.replace_range(index..index + 1, &corrupted.to_string());
+
+            // Ensure the error points to the corrupted character.
+            let error = Address::<CurrentEnvironment>::from_str(&candidate).unwrap_err().to_string();
+            assert!(error.contains(&format!("at index {index}")), "{error}");
+        }
+
+        // Ensure an invalid character is reported at its index.
+        let mut candidate = Address::<CurrentEnvironment>::rand(&mut rng).to_string();
+        candidate.replace_range(10..11, "b");
+        let error = Address::<CurrentEnvironment>::from_str(&candidate).unwrap_err().to_string();
+        assert!(error.contains("invalid character 'b' at index 10"), "{error}");
+
+        // Ensure a mixed-case address is rejected.
+        let mut candidate = Address::<CurrentEnvironment>::rand(&mut rng).to_string();
+        candidate.replace_range(0..4, "ALEO");
+        assert!(Address::<CurrentEnvironment>::from_str(&candidate).is_err());
+    }
+
     #[test]
     fn test_display() -> Result<()> {
         let mut rng = TestRng::default();
@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Decimal<E> {
+    /// Returns the `sum` of `self` and `other`, or `None` on overflow.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.mantissa.checked_add(other.mantissa).map(Self::from_mantissa)
+    }
+
+    /// Returns the `difference` of `self` and `other`, or `None` on overflow.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.mantissa.checked_sub(other.mantissa).map(Self::from_mantissa)
+    }
+
+    /// Returns the `product` of `self` and `other`, or `None` on overflow.
+    ///
+    /// The product is computed over `i128` and truncated toward zero to `SCALE` fractional digits.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        // Note: The product of two `i64` values always fits in an `i128`.
+        let product = i128::from(self.mantissa) * i128::from(other.mantissa);
+        i64::try_from(product / i128::from(Self::SCALING_FACTOR)).ok().map(Self::from_mantissa)
+    }
+
+    /// Returns the `quotient` of `self` and `other`, or `None` on overflow or division by zero.
+    ///
+    /// The quotient is computed over `i128` and truncated toward zero to `SCALE` fractional digits.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        // Note: The scaled dividend always fits in an `i128`.
+        let dividend = i128::from(self.mantissa) * i128::from(Self::SCALING_FACTOR);
+        let quotient = dividend.checked_div(i128::from(other.mantissa))?;
+        i64::try_from(quotient).ok().map(Self::from_mantissa)
+    }
+}
+
+impl<E: Environment> Neg for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `negation` of `self`.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        match self.mantissa.checked_neg() {
+            Some(mantissa) => Decimal::from_mantissa(mantissa),
+            None => E::halt(format!("Decimal negation failed on: {self}")),
+        }
+    }
+}
+
+impl<E: Environment> AbsChecked for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `absolute value` of `self`.
+    #[inline]
+    fn abs_checked(self) -> Self::Output {
+        match self.mantissa.checked_abs() {
+            Some(mantissa) => Decimal::from_mantissa(mantissa),
+            None => E::halt(format!("Decimal absolute value failed on: {self}")),
+        }
+    }
+}
+
+impl<E: Environment> Add<Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: Decimal<E>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment> Add<&Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `sum` of `self` and `other`.
+    #[inline]
+    fn add(self, other: &Decimal<E>) -> Self::Output {
+        match self.checked_add(other) {
+            Some(decimal) => decimal,
+            None => E::halt(format!("Decimal addition failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Sub<Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub(self, other: Decimal<E>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment> Sub<&Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `difference` of `self` and `other`.
+    #[inline]
+    fn sub(self, other: &Decimal<E>) -> Self::Output {
+        match self.checked_sub(other) {
+            Some(decimal) => decimal,
+            None => E::halt(format!("Decimal subtraction failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Mul<Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `product` of `self` and `other`.
+    #[inline]
+    fn mul(self, other: Decimal<E>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment> Mul<&Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `product` of `self` and `other`.
+    #[inline]
+    fn mul(self, other: &Decimal<E>) -> Self::Output {
+        match self.checked_mul(other) {
+            Some(decimal) => decimal,
+            None => E::halt(format!("Decimal multiplication failed on: {self} and {other}")),
+        }
+    }
+}
+
+impl<E: Environment> Div<Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `quotient` of `self` and `other`.
+    #[inline]
+    fn div(self, other: Decimal<E>) -> Self::Output {
+        self / &other
+    }
+}
+
+impl<E: Environment> Div<&Decimal<E>> for Decimal<E> {
+    type Output = Decimal<E>;
+
+    /// Returns the `quotient` of `self` and `other`.
+    #[inline]
+    fn div(self, other: &Decimal<E>) -> Self::Output {
+        match other.is_zero() {
+            true => E::halt(format!("Decimal division by zero: {self} / {other}")),
+            false => match self.checked_div(other) {
+                Some(decimal) => decimal,
+                None => E::halt(format!("Decimal division failed on: {self} and {other}")),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    fn decimal(string: &str) -> Decimal<CurrentEnvironment> {
+        Decimal::from_str(string).unwrap()
+    }
+
+    #[test]
+    fn test_add_sub() {
+        assert_eq!(decimal("1.25decimal") + decimal("2.5decimal"), decimal("3.75decimal"));
+        assert_eq!(decimal("1.25decimal") - decimal("2.5decimal"), decimal("-1.25decimal"));
+        assert!(Decimal::<CurrentEnvironment>::MAX.checked_add(&decimal("0.000001decimal")).is_none());
+        assert!(Decimal::<CurrentEnvironment>::MIN.checked_sub(&decimal("0.000001decimal")).is_none());
+    }
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(decimal("1.5decimal") * decimal("2.5decimal"), decimal("3.75decimal"));
+        assert_eq!(decimal("-1.5decimal") * decimal("2decimal"), decimal("-3decimal"));
+        // Ensure the product truncates toward zero.
+        assert_eq!(decimal("0.000001decimal") * decimal("0.5decimal"), decimal("0decimal"));
+        assert_eq!(decimal("-0.000003decimal") * decimal("0.5decimal"), decimal("-0.000001decimal"));
+        // Ensure overflows are detected.
+        assert!(Decimal::<CurrentEnvironment>::MAX.checked_mul(&decimal("2decimal")).is_none());
+        assert!(Decimal::<CurrentEnvironment>::MAX.checked_mul(&decimal("0.5decimal")).is_some());
+    }
+
+    #[test]
+    fn test_div() {
+        assert_eq!(decimal("3.75decimal") / decimal("2.5decimal"), decimal("1.5decimal"));
+        assert_eq!(decimal("1decimal") / decimal("-3decimal"), decimal("-0.333333decimal"));
+        assert_eq!(decimal("2decimal") / decimal("3decimal"), decimal("0.666666decimal"));
+        // Ensure division by zero and overflows are detected.
+        assert!(decimal("1decimal").checked_div(&decimal("0decimal")).is_none());
+        assert!(Decimal::<CurrentEnvironment>::MAX.checked_div(&decimal("0.5decimal")).is_none());
+        assert!(Decimal::<CurrentEnvironment>::MIN.checked_div(&decimal("-1decimal")).is_none());
+    }
+
+    #[test]
+    fn test_neg_abs() {
+        assert_eq!(-decimal("1.5decimal"), decimal("-1.5decimal"));
+        assert_eq!(decimal("-1.5decimal").abs_checked(), decimal("1.5decimal"));
+        assert!(std::panic::catch_unwind(|| -Decimal::<CurrentEnvironment>::MIN).is_err());
+    }
+}
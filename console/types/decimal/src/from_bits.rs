@@ -0,0 +1,27 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+use super::*;
+
+impl<E: Environment> FromBits for Decimal<E> {
+    /// Initializes a new decimal from a list of **little-endian** bits.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        Ok(Self::from_mantissa(i64::from_bits_le(bits_le)?))
+    }
+
+    /// Initializes a new decimal from a list of **big-endian** bits.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        Ok(Self::from_mantissa(i64::from_bits_be(bits_be)?))
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+#![warn(clippy::cast_possible_truncation)]
+
+mod arithmetic;
+mod bitwise;
+mod bytes;
+mod compare;
+mod from_bits;
+mod parse;
+mod random;
+mod serialize;
+mod size_in_bits;
+mod size_in_bytes;
+mod to_bits;
+mod zero;
+
+pub use snarkvm_console_network_environment::prelude::*;
+pub use snarkvm_console_types_boolean::Boolean;
+pub use snarkvm_console_types_integers::Integer;
+
+use core::marker::PhantomData;
+
+/// A signed fixed-point decimal, represented as an `i64` mantissa scaled by `10^SCALE`.
+/// Note: Every `decimal` has the same scale, as a program does not declare the scale of the type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Decimal<E: Environment> {
+    /// The underlying mantissa, i.e. the decimal value multiplied by `10^SCALE`.
+    mantissa: i64,
+    /// PhantomData.
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment> Decimal<E> {
+    pub const MAX: Self = Self::from_mantissa(i64::MAX);
+    pub const MIN: Self = Self::from_mantissa(i64::MIN);
+    /// The number of fractional digits in a decimal.
+    pub const SCALE: u32 = 6;
+    /// The scaling factor of the mantissa, i.e. `10^SCALE`.
+    pub const SCALING_FACTOR: i64 = 10i64.pow(Self::SCALE);
+
+    /// Initializes a new decimal from its scaled mantissa.
+    pub const fn from_mantissa(mantissa: i64) -> Self {
+        Self { mantissa, _phantom: PhantomData }
+    }
+
+    /// Initializes a new decimal from a whole number, halting if it is out of range.
+    pub fn from_integer(integer: i64) -> Self {
+        match integer.checked_mul(Self::SCALING_FACTOR) {
+            Some(mantissa) => Self::from_mantissa(mantissa),
+            None => E::halt(format!("Decimal conversion failed on: {integer}")),
+        }
+    }
+
+    /// Returns the whole part of the decimal, truncated toward zero.
+    pub const fn trunc(&self) -> i64 {
+        self.mantissa / Self::SCALING_FACTOR
+    }
+
+    /// Returns the fractional part of the decimal as a scaled mantissa, carrying the sign of `self`.
+    pub const fn fract(&self) -> i64 {
+        self.mantissa % Self::SCALING_FACTOR
+    }
+}
+
+impl<E: Environment> TypeName for Decimal<E> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "decimal"
+    }
+}
+
+impl<E: Environment> Deref for Decimal<E> {
+    type Target = i64;
+
+    /// Returns the scaled mantissa.
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.mantissa
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+use super::*;
+
+impl<E: Environment> Parser for Decimal<E> {
+    /// Parses a string into a decimal.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the negative sign '-' from the string.
+        let (string, negation) = map(opt(tag("-")), |neg: Option<&str>| neg.is_some())(string)?;
+        // Parse the whole digits from the string.
+        let (string, whole) = recognize(many1(terminated(one_of("0123456789"), many0(char('_')))))(string)?;
+        // Parse the fractional digits from the string.
+        let (string, fraction) = opt(pair(char('.'), recognize(many1(one_of("0123456789")))))(string)?;
+        // Parse the value from the string.
+        let (string, value) = map_res(tag(Self::type_name()), |_| {
+            Self::from_digits(negation, whole, fraction.map(|(_, digits)| digits).unwrap_or_default())
+        })(string)?;
+
+        Ok((string, value))
+    }
+}
+
+impl<E: Environment> Decimal<E> {
+    /// Initializes a decimal from its sign, whole digits, and (at most `SCALE`) fractional digits.
+    fn from_digits(is_negative: bool, whole: &str, fraction: &str) -> Result<Self> {
+        // Ensure the fractional part does not exceed the scale.
+        ensure!(
+            fraction.len() <= Self::SCALE as usize,
+            "A decimal must have at most {} fractional digits, found {}",
+            Self::SCALE,
+            fraction.len()
+        );
+        // Parse the whole and fractional parts, padding the fractional part to the scale.
+        let whole: i128 = whole.replace('_', "").parse()?;
+        let fraction: i128 = format!("{fraction:0<width$}", width = Self::SCALE as usize).parse()?;
+        // Compute the magnitude of the mantissa.
+        let magnitude = match whole.checked_mul(i128::from(Self::SCALING_FACTOR)) {
+            Some(scaled) => scaled + fraction,
+            None => bail!("Decimal literal is out of range"),
+        };
+        // Apply the sign and ensure the mantissa is in range.
+        let mantissa = match is_negative {
+            true => -magnitude,
+            false => magnitude,
+        };
+        match i64::try_from(mantissa) {
+            Ok(mantissa) => Ok(Self::from_mantissa(mantissa)),
+            Err(_) => bail!("Decimal literal is out of range"),
+        }
+    }
+}
+
+impl<E: Environment> FromStr for Decimal<E> {
+    type Err = Error;
+
+    /// Parses a string into a decimal.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<E: Environment> Debug for Decimal<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<E: Environment> Display for Decimal<E> {
+    /// Prints the decimal with exactly `SCALE` fractional digits.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let magnitude = self.mantissa.unsigned_abs();
+        let scaling_factor = Self::SCALING_FACTOR.unsigned_abs();
+        write!(
+            f,
+            "{sign}{}.{:0width$}{}",
+            magnitude / scaling_factor,
+            magnitude % scaling_factor,
+            Self::type_name(),
+            width = Self::SCALE as usize
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        // Ensure type and empty value fails.
+        assert!(Decimal::<CurrentEnvironment>::parse(Decimal::<CurrentEnvironment>::type_name()).is_err());
+        assert!(Decimal::<CurrentEnvironment>::parse("").is_err());
+        // Ensure a missing or malformed fractional part fails.
+        assert!(Decimal::<CurrentEnvironment>::from_str("1.decimal").is_err());
+        assert!(Decimal::<CurrentEnvironment>::from_str(".5decimal").is_err());
+        // Ensure more fractional digits than the scale fails.
+        assert!(Decimal::<CurrentEnvironment>::from_str("1.0000001decimal").is_err());
+        // Ensure out-of-range values fail.
+        assert!(Decimal::<CurrentEnvironment>::from_str("9223372036854.775808decimal").is_err());
+        assert!(Decimal::<CurrentEnvironment>::from_str("-9223372036854.775809decimal").is_err());
+
+        // Ensure short fractional parts are padded.
+        assert_eq!(1_500_000, *Decimal::<CurrentEnvironment>::from_str("1.5decimal")?);
+        assert_eq!(-2_000_000, *Decimal::<CurrentEnvironment>::from_str("-2decimal")?);
+        assert_eq!(1_000_000_000, *Decimal::<CurrentEnvironment>::from_str("1_000decimal")?);
+        assert_eq!(Decimal::<CurrentEnvironment>::MAX, Decimal::from_str("9223372036854.775807decimal")?);
+        assert_eq!(Decimal::<CurrentEnvironment>::MIN, Decimal::from_str("-9223372036854.775808decimal")?);
+
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected = Decimal::<CurrentEnvironment>::rand(&mut rng);
+            let expected_string = expected.to_string();
+            let (remainder, candidate) = Decimal::<CurrentEnvironment>::parse(&expected_string).unwrap();
+            assert_eq!(expected, candidate);
+            assert_eq!("", remainder);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() {
+        /// Attempts to construct a decimal from the given mantissa, format it in display mode, and recover it.
+        fn check_display(mantissa: i64, expected: &str) {
+            let decimal = Decimal::<CurrentEnvironment>::from_mantissa(mantissa);
+            assert_eq!(expected, format!("{decimal}"));
+            assert_eq!(decimal, Decimal::from_str(expected).unwrap());
+        }
+
+        check_display(0, "0.000000decimal");
+        check_display(1, "0.000001decimal");
+        check_display(-1, "-0.000001decimal");
+        check_display(1_500_000, "1.500000decimal");
+        check_display(-12_345_678, "-12.345678decimal");
+    }
+}
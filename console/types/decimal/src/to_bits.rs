@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg_attr(test, allow(clippy::assertions_on_result_states))]
+use super::*;
+
+impl<E: Environment> ToBits for Decimal<E> {
+    /// Outputs the little-endian bit representation of the mantissa.
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        self.mantissa.write_bits_le(vec);
+    }
+
+    /// Outputs the big-endian bit representation of the mantissa.
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        self.mantissa.write_bits_be(vec);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_to_bits_round_trip() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random decimal.
+            let expected = Decimal::<CurrentEnvironment>::rand(&mut rng);
+
+            let bits_le = expected.to_bits_le();
+            assert_eq!(Decimal::<CurrentEnvironment>::size_in_bits(), bits_le.len());
+            assert_eq!(expected, Decimal::from_bits_le(&bits_le)?);
+
+            let bits_be = expected.to_bits_be();
+            assert_eq!(expected, Decimal::from_bits_be(&bits_be)?);
+        }
+        Ok(())
+    }
+}
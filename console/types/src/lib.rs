@@ -36,6 +36,11 @@ pub mod modules {
     #[cfg(feature = "boolean")]
     pub use snarkvm_console_types_boolean::Boolean;
 
+    #[cfg(feature = "decimal")]
+    pub use snarkvm_console_types_decimal as decimal;
+    #[cfg(feature = "decimal")]
+    pub use snarkvm_console_types_decimal::Decimal;
+
     #[cfg(feature = "field")]
     pub use snarkvm_console_types_field as field;
     #[cfg(feature = "field")]
@@ -21,7 +21,7 @@ impl<N: Network> FromBytes for Header<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid header version"));
         }
 
@@ -32,6 +32,15 @@ impl<N: Network> FromBytes for Header<N> {
         let ratifications_root = Field::<N>::read_le(&mut reader)?;
         let solutions_root = Field::<N>::read_le(&mut reader)?;
         let subdag_root = Field::<N>::read_le(&mut reader)?;
+        // Note: The events root is only written in version 2, and is otherwise zero.
+        let events_root = match version {
+            1 => Field::zero(),
+            _ => match Field::<N>::read_le(&mut reader)? {
+                // Ensure the encoding is canonical, as a header without events is written as version 1.
+                events_root if events_root == Field::zero() => return Err(error("Invalid header events root")),
+                events_root => events_root,
+            },
+        };
         let metadata = Metadata::read_le(&mut reader)?;
 
         // Construct the block header.
@@ -42,6 +51,7 @@ impl<N: Network> FromBytes for Header<N> {
             ratifications_root,
             solutions_root,
             subdag_root,
+            events_root,
             metadata,
         )
         .map_err(|e| error(e.to_string()))
@@ -53,7 +63,12 @@ impl<N: Network> ToBytes for Header<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: A header without events is written as version 1, to preserve its encoding.
+        let version = match self.events_root == Field::zero() {
+            true => 1u8,
+            false => 2u8,
+        };
+        version.write_le(&mut writer)?;
 
         // Write to the buffer.
         self.previous_state_root.write_le(&mut writer)?;
@@ -62,6 +77,9 @@ impl<N: Network> ToBytes for Header<N> {
         self.ratifications_root.write_le(&mut writer)?;
         self.solutions_root.write_le(&mut writer)?;
         self.subdag_root.write_le(&mut writer)?;
+        if version == 2 {
+            self.events_root.write_le(&mut writer)?;
+        }
         self.metadata.write_le(&mut writer)
     }
 }
@@ -77,12 +95,27 @@ mod tests {
     fn test_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
 
-        for expected in [crate::header::test_helpers::sample_block_header(rng)].into_iter() {
+        for expected in [
+            crate::header::test_helpers::sample_block_header(rng),
+            crate::header::test_helpers::sample_block_header_with_events(rng),
+        ]
+        .into_iter()
+        {
             // Check the byte representation.
             let expected_bytes = expected.to_bytes_le()?;
             assert_eq!(expected, Header::read_le(&expected_bytes[..])?);
             assert!(Header::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         }
+
+        // Ensure a version 2 header with a zero events root is rejected, as it is not canonical.
+        let header = crate::header::test_helpers::sample_block_header(rng);
+        let mut bytes = header.to_bytes_le()?;
+        assert_eq!(bytes[0], 1);
+        bytes[0] = 2;
+        let field_size = Field::<CurrentNetwork>::zero().to_bytes_le()?.len();
+        let offset = 1 + 6 * field_size;
+        bytes.splice(offset..offset, Field::<CurrentNetwork>::zero().to_bytes_le()?);
+        assert!(Header::<CurrentNetwork>::read_le(&bytes[..]).is_err());
         Ok(())
     }
 }
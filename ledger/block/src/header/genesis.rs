@@ -35,6 +35,7 @@ impl<N: Network> Header<N> {
         let ratifications_root = ratifications.to_ratifications_root()?;
         let solutions_root = Field::zero();
         let subdag_root = Field::zero();
+        let events_root = transactions.to_events_root()?;
         let metadata = Metadata::genesis()?;
 
         // Return the genesis block header.
@@ -45,6 +46,7 @@ impl<N: Network> Header<N> {
             ratifications_root,
             solutions_root,
             subdag_root,
+            events_root,
             metadata,
         )
     }
@@ -52,6 +52,10 @@ impl<N: Network> Header<N> {
         else if id == &self.subdag_root {
             Ok(HeaderLeaf::<N>::new(5, self.subdag_root))
         }
+        // If the ID is the events root, return the 6th leaf.
+        else if id == &self.events_root {
+            Ok(HeaderLeaf::<N>::new(6, self.events_root))
+        }
         // If the ID is the metadata hash, then return the 7th leaf.
         else if id == &self.metadata.to_hash()? {
             Ok(HeaderLeaf::<N>::new(7, *id))
@@ -75,7 +79,7 @@ impl<N: Network> Header<N> {
         leaves.push(HeaderLeaf::<N>::new(3, self.ratifications_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(4, self.solutions_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(5, self.subdag_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(6, Field::zero()).to_bits_le());
+        leaves.push(HeaderLeaf::<N>::new(6, self.events_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(7, self.metadata.to_hash()?).to_bits_le());
 
         // Ensure the correct number of leaves are allocated.
@@ -121,6 +125,7 @@ mod tests {
                 Field::rand(rng),
                 Field::rand(rng),
                 Field::rand(rng),
+                Field::rand(rng),
                 Metadata::new(
                     CurrentNetwork::ID,
                     u64::rand(rng),
@@ -168,6 +173,11 @@ mod tests {
             assert_eq!(leaf.index(), 5);
             check_path(header.to_path(&leaf)?, root, &leaf)?;
 
+            // Check the 6th leaf.
+            let leaf = header.to_leaf(&header.events_root())?;
+            assert_eq!(leaf.index(), 6);
+            check_path(header.to_path(&leaf)?, root, &leaf)?;
+
             // Check the 7th leaf.
             let leaf = header.to_leaf(&CurrentNetwork::hash_bhp1024(&header.metadata().to_bits_le())?)?;
             assert_eq!(leaf.index(), 7);
@@ -45,6 +45,8 @@ pub struct Header<N: Network> {
     solutions_root: Field<N>,
     /// The subdag root of the authority.
     subdag_root: Field<N>,
+    /// The Merkle root representing the events emitted in the block.
+    events_root: Field<N>,
     /// The metadata of the block.
     metadata: Metadata<N>,
 }
@@ -58,6 +60,7 @@ impl<N: Network> Header<N> {
         ratifications_root: Field<N>,
         solutions_root: Field<N>,
         subdag_root: Field<N>,
+        events_root: Field<N>,
         metadata: Metadata<N>,
     ) -> Result<Self> {
         // Construct a new block header.
@@ -68,6 +71,7 @@ impl<N: Network> Header<N> {
             ratifications_root,
             solutions_root,
             subdag_root,
+            events_root,
             metadata,
         };
         // Ensure the header is valid.
@@ -126,6 +130,11 @@ impl<N: Network> Header<N> {
         self.subdag_root
     }
 
+    /// Returns the events root in the block header.
+    pub const fn events_root(&self) -> Field<N> {
+        self.events_root
+    }
+
     /// Returns the metadata in the block header.
     pub const fn metadata(&self) -> &Metadata<N> {
         &self.metadata
@@ -192,4 +201,20 @@ pub mod test_helpers {
     pub(crate) fn sample_block_header(rng: &mut TestRng) -> Header<CurrentNetwork> {
         *crate::test_helpers::sample_genesis_block(rng).header()
     }
+
+    /// Samples a block header with an events root.
+    pub(crate) fn sample_block_header_with_events(rng: &mut TestRng) -> Header<CurrentNetwork> {
+        let header = sample_block_header(rng);
+        Header::from(
+            header.previous_state_root,
+            header.transactions_root,
+            header.finalize_root,
+            header.ratifications_root,
+            header.solutions_root,
+            header.subdag_root,
+            Field::rand(rng),
+            header.metadata,
+        )
+        .unwrap()
+    }
 }
@@ -19,13 +19,18 @@ impl<N: Network> Serialize for Header<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut header = serializer.serialize_struct("Header", 7)?;
+                let mut header =
+                    serializer.serialize_struct("Header", 7 + (self.events_root != Field::zero()) as usize)?;
                 header.serialize_field("previous_state_root", &self.previous_state_root)?;
                 header.serialize_field("transactions_root", &self.transactions_root)?;
                 header.serialize_field("finalize_root", &self.finalize_root)?;
                 header.serialize_field("ratifications_root", &self.ratifications_root)?;
                 header.serialize_field("solutions_root", &self.solutions_root)?;
                 header.serialize_field("subdag_root", &self.subdag_root)?;
+                // Note: The events root is only included if there are events.
+                if self.events_root != Field::zero() {
+                    header.serialize_field("events_root", &self.events_root)?;
+                }
                 header.serialize_field("metadata", &self.metadata)?;
                 header.end()
             }
@@ -40,6 +45,11 @@ impl<'de, N: Network> Deserialize<'de> for Header<N> {
         match deserializer.is_human_readable() {
             true => {
                 let mut header = serde_json::Value::deserialize(deserializer)?;
+                // Parse the events root, which is zero if there are no events.
+                let events_root = match header.get("events_root") {
+                    Some(_) => DeserializeExt::take_from_value::<D>(&mut header, "events_root")?,
+                    None => Field::zero(),
+                };
                 Ok(Self::from(
                     DeserializeExt::take_from_value::<D>(&mut header, "previous_state_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "transactions_root")?,
@@ -47,6 +57,7 @@ impl<'de, N: Network> Deserialize<'de> for Header<N> {
                     DeserializeExt::take_from_value::<D>(&mut header, "ratifications_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "solutions_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "subdag_root")?,
+                    events_root,
                     DeserializeExt::take_from_value::<D>(&mut header, "metadata")?,
                 )
                 .map_err(de::Error::custom)?)
@@ -64,7 +75,12 @@ mod tests {
     fn test_serde_json() -> Result<()> {
         let rng = &mut TestRng::default();
 
-        for expected in [crate::header::test_helpers::sample_block_header(rng)].into_iter() {
+        for expected in [
+            crate::header::test_helpers::sample_block_header(rng),
+            crate::header::test_helpers::sample_block_header_with_events(rng),
+        ]
+        .into_iter()
+        {
             // Serialize
             let expected_string = &expected.to_string();
             let candidate_string = serde_json::to_string(&expected)?;
@@ -80,7 +96,12 @@ mod tests {
     fn test_bincode() -> Result<()> {
         let rng = &mut TestRng::default();
 
-        for expected in [crate::header::test_helpers::sample_block_header(rng)].into_iter() {
+        for expected in [
+            crate::header::test_helpers::sample_block_header(rng),
+            crate::header::test_helpers::sample_block_header_with_events(rng),
+        ]
+        .into_iter()
+        {
             // Serialize
             let expected_bytes = expected.to_bytes_le()?;
             let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
@@ -26,6 +26,7 @@ impl<N: Network> Header<N> {
         expected_ratifications_root: Field<N>,
         expected_solutions_root: Field<N>,
         expected_subdag_root: Field<N>,
+        expected_events_root: Field<N>,
         expected_round: u64,
         expected_height: u32,
         expected_cumulative_weight: u128,
@@ -81,6 +82,13 @@ impl<N: Network> Header<N> {
             self.subdag_root,
             expected_subdag_root
         );
+        // Ensure the events root is correct.
+        ensure!(
+            self.events_root == expected_events_root,
+            "Events root is incorrect in block {expected_height} (found '{}', expected '{}')",
+            self.events_root,
+            expected_events_root
+        );
         // Ensure the block metadata is correct.
         self.metadata.verify(
             expected_round,
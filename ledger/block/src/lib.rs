@@ -179,6 +179,11 @@ impl<N: Network> Block<N> {
             bail!("The subdag root in the block does not correspond to the authority");
         }
 
+        // Ensure that the events root matches the transactions.
+        if header.events_root() != transactions.to_events_root()? {
+            bail!("The events root in the block does not correspond to the transactions");
+        }
+
         // Return the block.
         Self::from_unchecked(
             block_hash.into(),
@@ -634,7 +639,7 @@ pub mod test_helpers {
         // Construct the transaction.
         let transaction = Transaction::from_execution(execution, None).unwrap();
         // Prepare the confirmed transaction.
-        let confirmed = ConfirmedTransaction::accepted_execute(0, transaction.clone(), vec![]).unwrap();
+        let confirmed = ConfirmedTransaction::accepted_execute(0, transaction.clone(), vec![], vec![]).unwrap();
         // Prepare the transactions.
         let transactions = Transactions::from_iter([confirmed].into_iter());
 
@@ -43,7 +43,7 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 let finalize =
                     (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
                 // Return the confirmed transaction.
-                Self::accepted_execute(index, transaction, finalize).map_err(error)
+                Self::accepted_execute(index, transaction, finalize, vec![]).map_err(error)
             }
             2 => {
                 // Read the index.
@@ -75,7 +75,28 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 // Return the confirmed transaction.
                 Self::rejected_execute(index, transaction, rejected, finalize).map_err(error)
             }
-            4.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
+            4 => {
+                // Read the index.
+                let index = u32::read_le(&mut reader)?;
+                // Read the transaction.
+                let transaction = Transaction::<N>::read_le(&mut reader)?;
+                // Read the number of finalize operations.
+                let num_finalize = NumFinalizeSize::read_le(&mut reader)?;
+                // Read the finalize operations.
+                let finalize =
+                    (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Read the number of events.
+                let num_events = NumEventsSize::read_le(&mut reader)?;
+                // Ensure the encoding is canonical, as an execution without events is written as variant 1.
+                if num_events == 0 {
+                    return Err(error("Confirmed transaction variant 4 must contain events"));
+                }
+                // Read the events.
+                let events = (0..num_events).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Return the confirmed transaction.
+                Self::accepted_execute(index, transaction, finalize, events).map_err(error)
+            }
+            5.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
         }
     }
 }
@@ -96,9 +117,13 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the finalize operations.
                 finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
             }
-            Self::AcceptedExecute(index, transaction, finalize) => {
+            Self::AcceptedExecute(index, transaction, finalize, events) => {
                 // Write the variant.
-                1u8.write_le(&mut writer)?;
+                // Note: An execution without events is written as variant 1, to preserve its encoding.
+                match events.is_empty() {
+                    true => 1u8.write_le(&mut writer)?,
+                    false => 4u8.write_le(&mut writer)?,
+                }
                 // Write the index.
                 index.write_le(&mut writer)?;
                 // Write the transaction.
@@ -106,7 +131,15 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the number of finalize operations.
                 NumFinalizeSize::try_from(finalize.len()).map_err(error)?.write_le(&mut writer)?;
                 // Write the finalize operations.
-                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
+                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))?;
+                // Write the events, if there are any.
+                if !events.is_empty() {
+                    // Write the number of events.
+                    NumEventsSize::try_from(events.len()).map_err(error)?.write_le(&mut writer)?;
+                    // Write the events.
+                    events.iter().try_for_each(|event| event.write_le(&mut writer))?;
+                }
+                Ok(())
             }
             Self::RejectedDeploy(index, transaction, rejected, finalize) => {
                 // Write the variant.
@@ -143,6 +176,9 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
 
     #[test]
     fn test_bytes() {
@@ -151,5 +187,14 @@ mod tests {
             let expected_bytes = expected.to_bytes_le().unwrap();
             assert_eq!(expected, ConfirmedTransaction::read_le(&expected_bytes[..]).unwrap());
         }
+
+        // Ensure variant 4 without events is rejected, as it is not canonical.
+        let rng = &mut TestRng::default();
+        let expected = crate::transactions::confirmed::test_helpers::sample_accepted_execute(0, true, rng);
+        let mut bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(bytes[0], 1);
+        bytes[0] = 4;
+        bytes.extend(0u16.to_bytes_le().unwrap());
+        assert!(ConfirmedTransaction::<CurrentNetwork>::read_le(&bytes[..]).is_err());
     }
 }
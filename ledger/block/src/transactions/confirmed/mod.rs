@@ -18,17 +18,18 @@ mod string;
 
 use crate::{rejected::Rejected, Transaction};
 use console::{network::prelude::*, types::Field};
-use synthesizer_program::FinalizeOperation;
+use synthesizer_program::{Event, FinalizeOperation};
 
 pub type NumFinalizeSize = u16;
+pub type NumEventsSize = u16;
 
 /// The confirmed transaction.
 #[derive(Clone, PartialEq, Eq)]
 pub enum ConfirmedTransaction<N: Network> {
     /// The accepted deploy transaction is composed of `(index, deploy_transaction, finalize_operations)`.
     AcceptedDeploy(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
-    /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations)`.
-    AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
+    /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations, events)`.
+    AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>, Vec<Event<N>>),
     /// The rejected deploy transaction is composed of `(index, fee_transaction, rejected_deployment, finalize_operations)`.
     RejectedDeploy(u32, Transaction<N>, Rejected<N>, Vec<FinalizeOperation<N>>),
     /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution, finalize_operations)`.
@@ -97,6 +98,7 @@ impl<N: Network> ConfirmedTransaction<N> {
         index: u32,
         transaction: Transaction<N>,
         finalize_operations: Vec<FinalizeOperation<N>>,
+        events: Vec<Event<N>>,
     ) -> Result<Self> {
         // Ensure the finalize operations contain the correct types.
         for operation in finalize_operations.iter() {
@@ -113,10 +115,30 @@ impl<N: Network> ConfirmedTransaction<N> {
             }
         }
         // Ensure the transaction is an execute transaction.
-        match transaction.is_execute() {
-            true => Ok(Self::AcceptedExecute(index, transaction, finalize_operations)),
-            false => bail!("Transaction '{}' is not an execute transaction", transaction.id()),
+        let execution = match &transaction {
+            Transaction::Execute(_, execution, _) => execution,
+            Transaction::Deploy(..) | Transaction::Fee(..) => {
+                bail!("Transaction '{}' is not an execute transaction", transaction.id())
+            }
+        };
+        // Ensure the number of events is within the allowed range.
+        ensure!(
+            events.len() <= N::MAX_EVENTS,
+            "Transaction '{}' (execute) exceeds the maximum number of events ({})",
+            transaction.id(),
+            N::MAX_EVENTS
+        );
+        // Ensure the events are emitted by the programs in the execution.
+        for event in events.iter() {
+            if !execution.transitions().any(|transition| transition.program_id() == event.program_id()) {
+                bail!(
+                    "Transaction '{}' (execute) contains an event from '{}', which is not in the execution",
+                    transaction.id(),
+                    event.program_id()
+                )
+            }
         }
+        Ok(Self::AcceptedExecute(index, transaction, finalize_operations, events))
     }
 
     /// Returns a new instance of a rejected deploy transaction.
@@ -225,7 +247,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub const fn transaction(&self) -> &Transaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
-            Self::AcceptedExecute(_, transaction, _) => transaction,
+            Self::AcceptedExecute(_, transaction, ..) => transaction,
             Self::RejectedDeploy(_, transaction, _, _) => transaction,
             Self::RejectedExecute(_, transaction, _, _) => transaction,
         }
@@ -235,7 +257,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn into_transaction(self) -> Transaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
-            Self::AcceptedExecute(_, transaction, _) => transaction,
+            Self::AcceptedExecute(_, transaction, ..) => transaction,
             Self::RejectedDeploy(_, transaction, _, _) => transaction,
             Self::RejectedExecute(_, transaction, _, _) => transaction,
         }
@@ -245,7 +267,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn num_finalize(&self) -> usize {
         match self {
             Self::AcceptedDeploy(_, _, finalize) => finalize.len(),
-            Self::AcceptedExecute(_, _, finalize) => finalize.len(),
+            Self::AcceptedExecute(_, _, finalize, _) => finalize.len(),
             Self::RejectedDeploy(_, _, _, finalize) => finalize.len(),
             Self::RejectedExecute(_, _, _, finalize) => finalize.len(),
         }
@@ -255,12 +277,20 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub const fn finalize_operations(&self) -> &Vec<FinalizeOperation<N>> {
         match self {
             Self::AcceptedDeploy(_, _, finalize) => finalize,
-            Self::AcceptedExecute(_, _, finalize) => finalize,
+            Self::AcceptedExecute(_, _, finalize, _) => finalize,
             Self::RejectedDeploy(_, _, _, finalize) => finalize,
             Self::RejectedExecute(_, _, _, finalize) => finalize,
         }
     }
 
+    /// Returns the events emitted by the confirmed transaction.
+    pub fn events(&self) -> &[Event<N>] {
+        match self {
+            Self::AcceptedExecute(_, _, _, events) => events,
+            Self::AcceptedDeploy(..) | Self::RejectedDeploy(..) | Self::RejectedExecute(..) => &[],
+        }
+    }
+
     /// Returns the rejected ID, if the confirmed transaction is rejected.
     pub fn to_rejected_id(&self) -> Result<Option<Field<N>>> {
         match self {
@@ -285,7 +315,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn to_unconfirmed_transaction_id(&self) -> Result<N::TransactionID> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => Ok(transaction.id()),
-            Self::AcceptedExecute(_, transaction, _) => Ok(transaction.id()),
+            Self::AcceptedExecute(_, transaction, ..) => Ok(transaction.id()),
             Self::RejectedDeploy(_, fee_transaction, rejected, _)
             | Self::RejectedExecute(_, fee_transaction, rejected, _) => {
                 Ok(rejected.to_unconfirmed_id(&fee_transaction.fee_transition())?.into())
@@ -299,7 +329,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn to_unconfirmed_transaction(&self) -> Result<Transaction<N>> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => Ok(transaction.clone()),
-            Self::AcceptedExecute(_, transaction, _) => Ok(transaction.clone()),
+            Self::AcceptedExecute(_, transaction, ..) => Ok(transaction.clone()),
             Self::RejectedDeploy(_, fee_transaction, rejected, _) => Transaction::from_deployment(
                 rejected
                     .program_owner()
@@ -328,7 +358,10 @@ impl<N: Network> Deref for ConfirmedTransaction<N> {
 #[cfg(test)]
 pub mod test_helpers {
     use super::*;
-    use console::network::Testnet3;
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Plaintext},
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -368,7 +401,28 @@ pub mod test_helpers {
         // Sample an execute transaction.
         let tx = crate::transaction::test_helpers::sample_execution_transaction_with_fee(is_fee_private, rng);
         // Return the confirmed transaction.
-        ConfirmedTransaction::accepted_execute(index, tx, vec![]).unwrap()
+        ConfirmedTransaction::accepted_execute(index, tx, vec![], vec![]).unwrap()
+    }
+
+    /// Samples an accepted execute transaction with events at the given index.
+    pub(crate) fn sample_accepted_execute_with_events(
+        index: u32,
+        rng: &mut TestRng,
+    ) -> ConfirmedTransaction<CurrentNetwork> {
+        // Sample an execute transaction.
+        let tx = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        // Sample the events, as emitted by the program of the root transition.
+        let program_id = *tx.execution().unwrap().peek().unwrap().program_id();
+        let events = vec![
+            Event::new(program_id, Identifier::from_str("transfer").unwrap(), Plaintext::from_str("5u64").unwrap()),
+            Event::new(
+                program_id,
+                Identifier::from_str("memo").unwrap(),
+                Plaintext::from_str("{ a: 1field }").unwrap(),
+            ),
+        ];
+        // Return the confirmed transaction.
+        ConfirmedTransaction::accepted_execute(index, tx, vec![], events).unwrap()
     }
 
     /// Samples a rejected deploy transaction at the given index.
@@ -418,6 +472,7 @@ pub mod test_helpers {
             sample_accepted_deploy(0, false, rng),
             sample_accepted_execute(1, true, rng),
             sample_accepted_execute(1, false, rng),
+            sample_accepted_execute_with_events(1, rng),
             sample_rejected_deploy(2, true, rng),
             sample_rejected_deploy(2, false, rng),
             sample_rejected_execute(3, true, rng),
@@ -438,6 +493,7 @@ pub mod test_helpers {
 mod test {
     use super::*;
     use crate::transactions::confirmed::test_helpers;
+    use console::program::{Identifier, Plaintext, ProgramID};
 
     type CurrentNetwork = console::network::Testnet3;
 
@@ -459,20 +515,34 @@ mod test {
             ),
             FinalizeOperation::RemoveKeyValue(Uniform::rand(rng), Uniform::rand(rng)),
         ];
-        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations.clone()).unwrap();
+        // Create an `Event` emitted by the program of the root transition.
+        let program_id = *tx.execution().unwrap().peek().unwrap().program_id();
+        let events =
+            vec![Event::new(program_id, Identifier::from_str("event").unwrap(), Plaintext::from_str("1u8").unwrap())];
+        let confirmed =
+            ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations.clone(), events.clone())
+                .unwrap();
 
         assert_eq!(confirmed.index(), index);
         assert_eq!(confirmed.transaction(), &tx);
         assert_eq!(confirmed.num_finalize(), finalize_operations.len());
         assert_eq!(confirmed.finalize_operations(), &finalize_operations);
+        assert_eq!(confirmed.events(), &events);
 
         // Attempt to create an `AcceptedExecution` with invalid `FinalizeOperation`s.
         let finalize_operations = vec![FinalizeOperation::InitializeMapping(Uniform::rand(rng))];
-        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations);
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations, vec![]);
         assert!(confirmed.is_err());
 
         let finalize_operations = vec![FinalizeOperation::RemoveMapping(Uniform::rand(rng))];
-        let confirmed = ConfirmedTransaction::accepted_execute(index, tx, finalize_operations);
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx.clone(), finalize_operations, vec![]);
+        assert!(confirmed.is_err());
+
+        // Attempt to create an `AcceptedExecution` with an `Event` from a program that is not in the execution.
+        let program_id = ProgramID::from_str("unknown.aleo").unwrap();
+        let events =
+            vec![Event::new(program_id, Identifier::from_str("event").unwrap(), Plaintext::from_str("1u8").unwrap())];
+        let confirmed = ConfirmedTransaction::accepted_execute(index, tx, vec![], events);
         assert!(confirmed.is_err());
     }
 
@@ -27,6 +27,59 @@ impl<N: Network> Transactions<N> {
     }
 }
 
+impl<N: Network> Transactions<N> {
+    /// Returns the events root of the transactions.
+    /// Note: If there are no events, the events root is zero.
+    pub fn to_events_root(&self) -> Result<Field<N>> {
+        match self.events().next() {
+            Some(_) => Ok(*self.to_events_tree()?.root()),
+            None => Ok(Field::zero()),
+        }
+    }
+
+    /// Returns the Merkle path for the event at the given index, in the events of the given transaction.
+    pub fn to_events_path(&self, transaction_id: &N::TransactionID, index: u16) -> Result<EventsPath<N>> {
+        // Initialize the position of the first event of the current transaction in the events tree.
+        let mut offset = 0;
+        for transaction in self.iter() {
+            if transaction.id() == *transaction_id {
+                // Retrieve the event.
+                let Some(event) = transaction.events().get(index as usize) else {
+                    bail!("The transaction '{transaction_id}' does not contain an event at index {index}")
+                };
+                // Compute the Merkle path.
+                let leaf = Self::to_event_leaf(transaction_id, index, event);
+                return self.to_events_tree()?.prove(offset + index as usize, &leaf);
+            }
+            offset += transaction.events().len();
+        }
+        bail!("The transaction '{transaction_id}' is not in the block transactions")
+    }
+
+    /// The Merkle tree of events for the block.
+    pub fn to_events_tree(&self) -> Result<EventsTree<N>> {
+        // Prepare the leaves.
+        let mut leaves = Vec::new();
+        for transaction in self.iter() {
+            for (index, event) in transaction.events().iter().enumerate() {
+                leaves.push(Self::to_event_leaf(&transaction.id(), u16::try_from(index)?, event));
+            }
+        }
+        // Compute the events tree.
+        // Note: This call will check the number of events is within the size of the Merkle tree.
+        N::merkle_tree_bhp::<EVENTS_DEPTH>(&leaves)
+    }
+
+    /// Returns the leaf of the given event in the events tree,
+    /// which binds the event to the transaction that emitted it, and to its index in the events of the transaction.
+    pub fn to_event_leaf(transaction_id: &N::TransactionID, index: u16, event: &Event<N>) -> Vec<bool> {
+        let mut leaf = transaction_id.to_bits_le();
+        index.write_bits_le(&mut leaf);
+        event.write_bits_le(&mut leaf);
+        leaf
+    }
+}
+
 impl<N: Network> Transactions<N> {
     /// Returns the transactions root, by computing the root for a Merkle tree of the transaction IDs.
     pub fn to_transactions_root(&self) -> Result<Field<N>> {
@@ -76,4 +129,33 @@ mod tests {
         // Ensure the log2 relationship between depth and the maximum number of transactions.
         assert_eq!(2usize.pow(TRANSACTIONS_DEPTH as u32), Transactions::<CurrentNetwork>::MAX_TRANSACTIONS);
     }
+
+    #[test]
+    fn test_events_path() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the events root is zero if there are no events.
+        let transactions = Transactions::<CurrentNetwork>::from(&[
+            crate::transactions::confirmed::test_helpers::sample_accepted_execute(0, true, rng),
+        ]);
+        assert_eq!(transactions.to_events_root().unwrap(), Field::zero());
+
+        // Ensure each event has a valid Merkle path to the events root.
+        let transactions = Transactions::<CurrentNetwork>::from(&[
+            crate::transactions::confirmed::test_helpers::sample_accepted_execute(0, true, rng),
+            crate::transactions::confirmed::test_helpers::sample_accepted_execute_with_events(1, rng),
+        ]);
+        let events_root = transactions.to_events_root().unwrap();
+        assert_ne!(events_root, Field::zero());
+        for transaction in transactions.iter() {
+            for (index, event) in transaction.events().iter().enumerate() {
+                let index = u16::try_from(index).unwrap();
+                let path = transactions.to_events_path(&transaction.id(), index).unwrap();
+                let leaf = Transactions::to_event_leaf(&transaction.id(), index, event);
+                assert!(CurrentNetwork::verify_merkle_path_bhp(&path, &events_root, &leaf));
+                // Ensure the path does not verify for the bare event.
+                assert!(!CurrentNetwork::verify_merkle_path_bhp(&path, &events_root, &event.to_bits_le()));
+            }
+        }
+    }
 }
@@ -28,16 +28,19 @@ use console::{
     network::prelude::*,
     program::{
         Ciphertext,
+        EventsPath,
+        EventsTree,
         ProgramOwner,
         Record,
         TransactionsPath,
         TransactionsTree,
+        EVENTS_DEPTH,
         FINALIZE_OPERATIONS_DEPTH,
         TRANSACTIONS_DEPTH,
     },
     types::{Field, Group, U64},
 };
-use synthesizer_program::FinalizeOperation;
+use synthesizer_program::{Event, FinalizeOperation};
 
 use indexmap::IndexMap;
 
@@ -259,6 +262,11 @@ impl<N: Network> Transactions<N> {
     pub fn finalize_operations(&self) -> impl '_ + Iterator<Item = &FinalizeOperation<N>> {
         self.iter().flat_map(|tx| tx.finalize_operations())
     }
+
+    /// Returns an iterator over the events, for all transactions.
+    pub fn events(&self) -> impl '_ + Iterator<Item = &Event<N>> {
+        self.iter().flat_map(|tx| tx.events())
+    }
 }
 
 impl<N: Network> IntoIterator for Transactions<N> {
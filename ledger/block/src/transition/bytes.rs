@@ -20,7 +20,7 @@ impl<N: Network> FromBytes for Transition<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if !(1..=3).contains(&version) {
             return Err(error("Invalid transition version"));
         }
 
@@ -53,10 +53,36 @@ impl<N: Network> FromBytes for Transition<N> {
         let tpk = FromBytes::read_le(&mut reader)?;
         // Read the transition commitment.
         let tcm = FromBytes::read_le(&mut reader)?;
+        // Note: The expiration height is only written in version 2, and a transition without one has `tpk == R_0`.
+        // Version 3 is written for a transition with a memo, where the expiration height is optional.
+        let (tpk_base, expiration_height) = match version {
+            1 => (tpk, None),
+            2 => {
+                // Read the transition public key base.
+                let tpk_base = FromBytes::read_le(&mut reader)?;
+                // Read the expiration height.
+                let expiration_height = u32::read_le(&mut reader)?;
+                (tpk_base, Some(expiration_height))
+            }
+            _ => {
+                // Ensure the transition has a memo.
+                if !matches!(outputs.last(), Some(Output::Memo(..))) {
+                    return Err(error("Transition version 3 requires a memo"));
+                }
+                // Read the transition public key base.
+                let tpk_base = FromBytes::read_le(&mut reader)?;
+                // Read the expiration height.
+                let expiration_height = match bool::read_le(&mut reader)? {
+                    true => Some(u32::read_le(&mut reader)?),
+                    false => None,
+                };
+                (tpk_base, expiration_height)
+            }
+        };
 
         // Construct the candidate transition.
-        let transition =
-            Self::new(program_id, function_name, inputs, outputs, tpk, tcm).map_err(|e| error(e.to_string()))?;
+        let transition = Self::new(program_id, function_name, inputs, outputs, tpk, tcm, tpk_base, expiration_height)
+            .map_err(|e| error(e.to_string()))?;
         // Ensure the transition ID matches the expected ID.
         match transition_id == *transition.id() {
             true => Ok(transition),
@@ -69,7 +95,13 @@ impl<N: Network> ToBytes for Transition<N> {
     /// Writes the literal to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: A transition without an expiration height or memo is written as version 1, to preserve its encoding.
+        let version = match (self.memo(), self.expiration_height) {
+            (Some(_), _) => 3u8,
+            (None, Some(_)) => 2u8,
+            (None, None) => 1u8,
+        };
+        version.write_le(&mut writer)?;
 
         // Write the transition ID.
         self.id.write_le(&mut writer)?;
@@ -91,7 +123,23 @@ impl<N: Network> ToBytes for Transition<N> {
         // Write the transition public key.
         self.tpk.write_le(&mut writer)?;
         // Write the transition commitment.
-        self.tcm.write_le(&mut writer)
+        self.tcm.write_le(&mut writer)?;
+        // Write the transition public key base and expiration height.
+        match version {
+            1 => (),
+            2 => {
+                self.tpk_base.write_le(&mut writer)?;
+                self.expiration_height.unwrap_or_default().write_le(&mut writer)?;
+            }
+            _ => {
+                self.tpk_base.write_le(&mut writer)?;
+                self.expiration_height.is_some().write_le(&mut writer)?;
+                if let Some(expiration_height) = self.expiration_height {
+                    expiration_height.write_le(&mut writer)?;
+                }
+            }
+        }
+        Ok(())
     }
 }
 
@@ -103,12 +151,16 @@ mod tests {
     fn test_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
 
-        // Sample the transition.
-        let expected = crate::transition::test_helpers::sample_transition(rng);
-
-        // Check the byte representation.
-        let expected_bytes = expected.to_bytes_le()?;
-        assert_eq!(expected, Transition::read_le(&expected_bytes[..])?);
+        for expected in [
+            crate::transition::test_helpers::sample_transition(rng),
+            crate::transition::test_helpers::sample_transition_with_expiration(100, rng),
+            crate::transition::test_helpers::sample_transition_with_memo(None, rng),
+            crate::transition::test_helpers::sample_transition_with_memo(Some(100), rng),
+        ] {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Transition::read_le(&expected_bytes[..])?);
+        }
 
         Ok(())
     }
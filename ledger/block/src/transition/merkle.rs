@@ -78,6 +78,11 @@ impl<N: Network> Transition<N> {
             N::MAX_OUTPUTS,
             outputs.len()
         );
+        // Ensure there is at most one memo, and that it is the last output.
+        ensure!(
+            outputs.iter().rev().skip(1).all(|output| !output.is_memo()),
+            "Transition memo must be the last output, and there can be at most one"
+        );
 
         // Prepare the input leaves.
         let input_leaves = inputs
@@ -61,6 +61,10 @@ pub struct Transition<N: Network> {
     tpk: Group<N>,
     /// The transition commitment.
     tcm: Field<N>,
+    /// The transition public key base.
+    tpk_base: Group<N>,
+    /// The block height after which the transition expires, if any.
+    expiration_height: Option<u32>,
 }
 
 impl<N: Network> Transition<N> {
@@ -73,12 +77,31 @@ impl<N: Network> Transition<N> {
         outputs: Vec<Output<N>>,
         tpk: Group<N>,
         tcm: Field<N>,
+        tpk_base: Group<N>,
+        expiration_height: Option<u32>,
     ) -> Result<Self> {
+        // Retrieve the memo hash, if the transition has a memo.
+        let memo_hash = match outputs.last() {
+            Some(Output::Memo(memo_hash, _, memo)) => {
+                // Ensure the memo does not exceed the maximum size.
+                if let Some(memo) = memo {
+                    Request::check_memo_size(memo)?;
+                }
+                Some(*memo_hash)
+            }
+            _ => None,
+        };
+        // Ensure the transition public key commits to the expiration height and memo.
+        let offset = Request::tpk_offset(&tpk_base, expiration_height, memo_hash)?;
+        ensure!(
+            tpk == tpk_base + N::g_scalar_multiply(&offset),
+            "The transition public key does not match the expiration height and memo"
+        );
         // Compute the transition ID.
         let function_tree = Self::function_tree(&inputs, &outputs)?;
         let id = N::hash_bhp512(&(*function_tree.root(), tcm).to_bits_le())?;
         // Return the transition.
-        Ok(Self { id: id.into(), program_id, function_name, inputs, outputs, tpk, tcm })
+        Ok(Self { id: id.into(), program_id, function_name, inputs, outputs, tpk, tcm, tpk_base, expiration_height })
     }
 
     /// Initializes a new transition from a request and response.
@@ -146,7 +169,7 @@ impl<N: Network> Transition<N> {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let outputs = response
+        let mut outputs = response
             .output_ids()
             .iter()
             .zip_eq(response.outputs())
@@ -251,12 +274,26 @@ impl<N: Network> Transition<N> {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        // Append the memo as the last output, if the request has one.
+        if let Some((nonce, memo)) = request.memo() {
+            outputs.push(Output::from_memo(*nonce, memo.clone())?);
+        }
+
         // Retrieve the `tpk`.
         let tpk = request.to_tpk();
         // Retrieve the `tcm`.
         let tcm = *request.tcm();
         // Return the transition.
-        Self::new(program_id, function_name, inputs, outputs, tpk, tcm)
+        Self::new(
+            program_id,
+            function_name,
+            inputs,
+            outputs,
+            tpk,
+            tcm,
+            *request.tpk_base(),
+            request.expiration_height(),
+        )
     }
 }
 
@@ -286,6 +323,11 @@ impl<N: Network> Transition<N> {
         &self.outputs
     }
 
+    /// Returns the memo, if the transition has one.
+    pub fn memo(&self) -> Option<&Output<N>> {
+        self.outputs.last().filter(|output| output.is_memo())
+    }
+
     /// Returns the transition public key.
     pub const fn tpk(&self) -> &Group<N> {
         &self.tpk
@@ -295,6 +337,21 @@ impl<N: Network> Transition<N> {
     pub const fn tcm(&self) -> &Field<N> {
         &self.tcm
     }
+
+    /// Returns the transition public key base.
+    pub const fn tpk_base(&self) -> &Group<N> {
+        &self.tpk_base
+    }
+
+    /// Returns the block height after which the transition expires, if any.
+    pub const fn expiration_height(&self) -> Option<u32> {
+        self.expiration_height
+    }
+
+    /// Returns `true` if the transition has expired, given the latest block height.
+    pub fn is_expired(&self, latest_height: u32) -> bool {
+        self.expiration_height.map_or(false, |expiration_height| latest_height >= expiration_height)
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -359,6 +416,7 @@ impl<N: Network> Transition<N> {
             Output::Record(output_cm, _, _) => output_cm == commitment,
             Output::ExternalRecord(_) => false,
             Output::Future(_, _) => false,
+            Output::Memo(_, _, _) => false,
         })
     }
 }
@@ -374,6 +432,7 @@ impl<N: Network> Transition<N> {
             Output::Record(_, _, _) => None,
             Output::ExternalRecord(_) => None,
             Output::Future(_, _) => None,
+            Output::Memo(_, _, _) => None,
         })
     }
 }
@@ -464,6 +523,10 @@ impl<N: Network> Transition<N> {
 pub mod test_helpers {
     use super::*;
     use crate::Transaction;
+    use console::{
+        account::{Address, PrivateKey},
+        program::{Literal, Plaintext},
+    };
 
     type CurrentNetwork = console::network::Testnet3;
 
@@ -477,4 +540,115 @@ pub mod test_helpers {
             unreachable!()
         }
     }
+
+    /// Samples a random transition that expires after the given block height.
+    pub(crate) fn sample_transition_with_expiration(
+        expiration_height: u32,
+        rng: &mut TestRng,
+    ) -> Transition<CurrentNetwork> {
+        let transition = sample_transition(rng);
+        // Sample a transition public key base, and offset it by the expiration height.
+        let tpk_base = Group::rand(rng);
+        let offset = Request::expiration_offset(&tpk_base, Some(expiration_height)).unwrap();
+        let tpk = tpk_base + CurrentNetwork::g_scalar_multiply(&offset);
+        // Return the transition.
+        Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            tpk,
+            *transition.tcm(),
+            tpk_base,
+            Some(expiration_height),
+        )
+        .unwrap()
+    }
+
+    /// Samples a random transition with a memo, that expires after the given block height, if any.
+    pub(crate) fn sample_transition_with_memo(
+        expiration_height: Option<u32>,
+        rng: &mut TestRng,
+    ) -> Transition<CurrentNetwork> {
+        let transition = sample_transition(rng);
+        // Sample a memo to a random recipient.
+        let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let memo = Output::new_memo(&Plaintext::from(Literal::Field(Uniform::rand(rng))), &recipient, rng).unwrap();
+        // Sample a transition public key base, and offset it by the expiration height and memo.
+        let tpk_base = Group::rand(rng);
+        let offset = Request::tpk_offset(&tpk_base, expiration_height, Some(*memo.id())).unwrap();
+        let tpk = tpk_base + CurrentNetwork::g_scalar_multiply(&offset);
+        // Return the transition.
+        Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            [transition.outputs(), &[memo]].concat(),
+            tpk,
+            *transition.tcm(),
+            tpk_base,
+            expiration_height,
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey, ViewKey},
+        network::Testnet3,
+        program::{Literal, Plaintext},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_memo_is_bound_to_tpk() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition and a recipient.
+        let transition = test_helpers::sample_transition(rng);
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Sample a memo, and offset the transition public key by its hash.
+        let expected = Plaintext::from(Literal::Field(Uniform::rand(rng)));
+        let memo = Output::new_memo(&expected, &address, rng)?;
+        let tpk_base = Group::rand(rng);
+        let offset = Request::tpk_offset(&tpk_base, None, Some(*memo.id()))?;
+        let tpk = tpk_base + CurrentNetwork::g_scalar_multiply(&offset);
+
+        // Constructs a transition with the given outputs.
+        let new = |outputs: Vec<Output<CurrentNetwork>>| {
+            Transition::new(
+                *transition.program_id(),
+                *transition.function_name(),
+                transition.inputs().to_vec(),
+                outputs,
+                tpk,
+                *transition.tcm(),
+                tpk_base,
+                None,
+            )
+        };
+
+        // Check the memo is included in the transition ID.
+        let candidate = new([transition.outputs(), &[memo.clone()]].concat())?;
+        assert_ne!(transition.id(), candidate.id());
+        assert_eq!(candidate.memo(), Some(&memo));
+
+        // Check the memo decrypts with the recipient's view key.
+        assert_eq!(candidate.memo().unwrap().decrypt_memo(&view_key)?, expected);
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert_ne!(memo.decrypt_memo(&other_view_key).ok(), Some(expected.clone()));
+
+        // Check the memo cannot be stripped or replaced.
+        assert!(new(transition.outputs().to_vec()).is_err());
+        let other_memo = Output::new_memo(&expected, &address, rng)?;
+        assert!(new([transition.outputs(), &[other_memo]].concat()).is_err());
+        Ok(())
+    }
 }
@@ -71,7 +71,22 @@ impl<N: Network> FromBytes for Output<N> {
                 };
                 Self::Future(future_hash, future)
             }
-            6.. => return Err(error(format!("Failed to decode output variant {index}"))),
+            6 => {
+                let memo_hash: Field<N> = FromBytes::read_le(&mut reader)?;
+                let nonce: Group<N> = FromBytes::read_le(&mut reader)?;
+                let memo_exists: bool = FromBytes::read_le(&mut reader)?;
+                let memo = match memo_exists {
+                    true => {
+                        let memo: Ciphertext<N> = FromBytes::read_le(&mut reader)?;
+                        // Ensure the memo does not exceed the maximum size.
+                        Request::check_memo_size(&memo).map_err(|e| error(e.to_string()))?;
+                        Some(memo)
+                    }
+                    false => None,
+                };
+                Self::Memo(memo_hash, nonce, memo)
+            }
+            7.. => return Err(error(format!("Failed to decode output variant {index}"))),
         };
         Ok(literal)
     }
@@ -141,6 +156,18 @@ impl<N: Network> ToBytes for Output<N> {
                     None => false.write_le(&mut writer),
                 }
             }
+            Self::Memo(memo_hash, nonce, memo) => {
+                (6 as Variant).write_le(&mut writer)?;
+                memo_hash.write_le(&mut writer)?;
+                nonce.write_le(&mut writer)?;
+                match memo {
+                    Some(memo) => {
+                        true.write_le(&mut writer)?;
+                        memo.write_le(&mut writer)
+                    }
+                    None => false.write_le(&mut writer),
+                }
+            }
         }
     }
 }
@@ -17,8 +17,9 @@ mod serialize;
 mod string;
 
 use console::{
+    account::{Address, ViewKey},
     network::prelude::*,
-    program::{Ciphertext, Future, Plaintext, Record, TransitionLeaf},
+    program::{Ciphertext, Future, Plaintext, Record, Request, TransitionLeaf},
     types::{Field, Group},
 };
 
@@ -39,6 +40,8 @@ pub enum Output<N: Network> {
     ExternalRecord(Field<N>),
     /// The future hash and (optional) future.
     Future(Field<N>, Option<Future<N>>),
+    /// The memo hash, nonce, and (optional) memo ciphertext.
+    Memo(Field<N>, Group<N>, Option<Ciphertext<N>>),
 }
 
 impl<N: Network> Output<N> {
@@ -51,6 +54,7 @@ impl<N: Network> Output<N> {
             Output::Record(_, _, _) => 3,
             Output::ExternalRecord(_) => 4,
             Output::Future(_, _) => 5,
+            Output::Memo(_, _, _) => 6,
         }
     }
 
@@ -63,6 +67,7 @@ impl<N: Network> Output<N> {
             Output::Record(commitment, ..) => commitment,
             Output::ExternalRecord(id) => id,
             Output::Future(id, ..) => id,
+            Output::Memo(id, ..) => id,
         }
     }
 
@@ -145,10 +150,24 @@ impl<N: Network> Output<N> {
         }
     }
 
+    /// Returns `true` if the output is a memo.
+    pub const fn is_memo(&self) -> bool {
+        matches!(self, Output::Memo(..))
+    }
+
+    /// Returns the nonce and memo ciphertext, if the output is a memo.
+    pub const fn memo(&self) -> Option<(&Group<N>, &Ciphertext<N>)> {
+        match self {
+            Output::Memo(_, nonce, Some(memo)) => Some((nonce, memo)),
+            _ => None,
+        }
+    }
+
     /// Returns the public verifier inputs for the proof.
+    /// Note: Memos are bound to the transition through its public key, not the proof, so they have no verifier inputs.
     pub fn verifier_inputs(&self) -> impl '_ + Iterator<Item = N::Field> {
-        // Append the output ID.
-        [**self.id()].into_iter()
+        // Append the output ID, if the output is not a memo.
+        [(!self.is_memo()).then(|| **self.id())].into_iter().flatten()
             // Append the checksum if it exists.
             .chain([self.checksum().map(|sum| **sum)].into_iter().flatten())
     }
@@ -232,11 +251,18 @@ impl<N: Network> Output<N> {
                     Err(error) => Err(error),
                 }
             }
+            Output::Memo(hash, nonce, Some(memo)) => {
+                match Request::check_memo_size(memo).and_then(|_| Request::memo_hash(nonce, memo)) {
+                    Ok(candidate_hash) => Ok(hash == &candidate_hash),
+                    Err(error) => Err(error),
+                }
+            }
             Output::Constant(_, None)
             | Output::Public(_, None)
             | Output::Private(_, None)
             | Output::Record(_, _, None)
-            | Output::Future(_, None) => {
+            | Output::Future(_, None)
+            | Output::Memo(_, _, None) => {
                 // This enforces that the transition *must* contain the value for this transition output.
                 // A similar rule is enforced for the transition input.
                 bail!("A transition output value is missing")
@@ -254,6 +280,36 @@ impl<N: Network> Output<N> {
     }
 }
 
+impl<N: Network> Output<N> {
+    /// Returns a memo output, with the given plaintext encrypted to the given recipient.
+    /// Note: To attach a memo to a transition, sign the request with it. See `Request::sign_with_memo`.
+    pub fn new_memo<R: Rng + CryptoRng>(memo: &Plaintext<N>, recipient: &Address<N>, rng: &mut R) -> Result<Self> {
+        // Encrypt the memo to the recipient.
+        let (nonce, ciphertext) = Request::encrypt_memo(memo, recipient, rng)?;
+        // Return the memo output.
+        Self::from_memo(nonce, ciphertext)
+    }
+
+    /// Returns a memo output for the given nonce and memo ciphertext.
+    pub fn from_memo(nonce: Group<N>, memo: Ciphertext<N>) -> Result<Self> {
+        // Ensure the memo does not exceed the maximum size.
+        Request::check_memo_size(&memo)?;
+        // Compute the memo hash.
+        let hash = Request::memo_hash(&nonce, &memo)?;
+        // Return the memo output.
+        Ok(Output::Memo(hash, nonce, Some(memo)))
+    }
+
+    /// Returns the decrypted memo, if the output is a memo for the given view key.
+    pub fn decrypt_memo(&self, view_key: &ViewKey<N>) -> Result<Plaintext<N>> {
+        match self {
+            Output::Memo(_, nonce, Some(memo)) => memo.decrypt(*view_key, *nonce),
+            Output::Memo(_, _, None) => bail!("The memo ciphertext is missing"),
+            _ => bail!("The output is not a memo"),
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
@@ -288,6 +344,8 @@ pub(crate) mod test_helpers {
         ).unwrap();
         let record_ciphertext = record.encrypt(randomizer).unwrap();
         let record_checksum = CurrentNetwork::hash_bhp1024(&record_ciphertext.to_bits_le()).unwrap();
+        // Sample a random memo.
+        let memo = Output::new_memo(&plaintext, &Address::rand(rng), rng).unwrap();
 
         vec![
             (transition_id, input),
@@ -300,6 +358,53 @@ pub(crate) mod test_helpers {
             (Uniform::rand(rng), Output::Record(Uniform::rand(rng), Uniform::rand(rng), None)),
             (Uniform::rand(rng), Output::Record(Uniform::rand(rng), record_checksum, Some(record_ciphertext))),
             (Uniform::rand(rng), Output::ExternalRecord(Uniform::rand(rng))),
+            (Uniform::rand(rng), Output::Memo(Uniform::rand(rng), Uniform::rand(rng), None)),
+            (Uniform::rand(rng), memo),
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3, program::Literal};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_memo() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a recipient.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Encrypt a memo to the recipient.
+        let expected = Plaintext::from(Literal::Field(Uniform::rand(rng)));
+        let memo = Output::new_memo(&expected, &address, rng)?;
+        assert!(memo.is_memo());
+        assert_eq!(memo.variant(), 6);
+        assert_eq!(memo.verifier_inputs().count(), 0);
+
+        // Check the memo is well-formed, and decrypts with the recipient's view key.
+        assert!(memo.verify(Uniform::rand(rng), &Uniform::rand(rng), 0));
+        assert_eq!(memo.decrypt_memo(&view_key)?, expected);
+
+        // Check a memo with a different hash is rejected.
+        let (nonce, ciphertext) = memo.memo().unwrap();
+        assert!(!Output::Memo(Uniform::rand(rng), *nonce, Some(ciphertext.clone())).verify(
+            Uniform::rand(rng),
+            &Uniform::rand(rng),
+            0
+        ));
+
+        // Check a memo that exceeds the maximum size is rejected.
+        let oversized = Plaintext::from_str(&format!(
+            "[{}]",
+            (0..=CurrentNetwork::MAX_MEMO_SIZE_IN_FIELDS).map(|i| format!("{i}field")).collect::<Vec<_>>().join(", ")
+        ))?;
+        assert!(Output::new_memo(&oversized, &address, rng).is_err());
+        Ok(())
+    }
+}
@@ -71,6 +71,16 @@ impl<N: Network> Serialize for Output<N> {
                     }
                     output.end()
                 }
+                Self::Memo(id, nonce, value) => {
+                    let mut output = serializer.serialize_struct("Output", 3 + value.is_some() as usize)?;
+                    output.serialize_field("type", "memo")?;
+                    output.serialize_field("id", &id)?;
+                    output.serialize_field("nonce", &nonce)?;
+                    if let Some(value) = value {
+                        output.serialize_field("value", &value)?;
+                    }
+                    output.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -117,6 +127,15 @@ impl<'de, N: Network> Deserialize<'de> for Output<N> {
                         Some(value) => Some(Future::<N>::from_str(value).map_err(de::Error::custom)?),
                         None => None,
                     }),
+                    Some("memo") => {
+                        // Retrieve the nonce.
+                        let nonce: Group<N> = DeserializeExt::take_from_value::<D>(&mut output, "nonce")?;
+                        // Return the memo.
+                        Output::Memo(id, nonce, match output.get("value").and_then(|v| v.as_str()) {
+                            Some(value) => Some(Ciphertext::<N>::from_str(value).map_err(de::Error::custom)?),
+                            None => None,
+                        })
+                    }
                     _ => return Err(de::Error::custom("Invalid output type")),
                 };
 
@@ -19,7 +19,11 @@ impl<N: Network> Serialize for Transition<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transition = serializer.serialize_struct("Transition", 7)?;
+                let has_tpk_base = self.expiration_height.is_some() || self.memo().is_some();
+                let mut transition = serializer.serialize_struct(
+                    "Transition",
+                    7 + has_tpk_base as usize + self.expiration_height.is_some() as usize,
+                )?;
                 transition.serialize_field("id", &self.id)?;
                 transition.serialize_field("program", &self.program_id)?;
                 transition.serialize_field("function", &self.function_name)?;
@@ -27,6 +31,13 @@ impl<N: Network> Serialize for Transition<N> {
                 transition.serialize_field("outputs", &self.outputs)?;
                 transition.serialize_field("tpk", &self.tpk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
+                // Note: The transition public key base is only included if the transition expires or has a memo.
+                if has_tpk_base {
+                    transition.serialize_field("tpk_base", &self.tpk_base)?;
+                }
+                if let Some(expiration_height) = &self.expiration_height {
+                    transition.serialize_field("expiration_height", expiration_height)?;
+                }
                 transition.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -43,6 +54,18 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                 let mut transition = serde_json::Value::deserialize(deserializer)?;
                 // Retrieve the ID.
                 let id: N::TransitionID = DeserializeExt::take_from_value::<D>(&mut transition, "id")?;
+                // Retrieve the `tpk`.
+                let tpk = DeserializeExt::take_from_value::<D>(&mut transition, "tpk")?;
+                // Retrieve the `tpk_base`, where a transition without an expiration height or memo has `tpk == R_0`.
+                let tpk_base = match transition.get("tpk_base") {
+                    Some(_) => DeserializeExt::take_from_value::<D>(&mut transition, "tpk_base")?,
+                    None => tpk,
+                };
+                // Retrieve the expiration height, if any.
+                let expiration_height = match transition.get("expiration_height") {
+                    Some(_) => Some(DeserializeExt::take_from_value::<D>(&mut transition, "expiration_height")?),
+                    None => None,
+                };
 
                 // Recover the transition.
                 let transition = Self::new(
@@ -54,10 +77,11 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                     DeserializeExt::take_from_value::<D>(&mut transition, "inputs")?,
                     // Retrieve the outputs.
                     DeserializeExt::take_from_value::<D>(&mut transition, "outputs")?,
-                    // Retrieve the `tpk`.
-                    DeserializeExt::take_from_value::<D>(&mut transition, "tpk")?,
+                    tpk,
                     // Retrieve the `tcm`.
                     DeserializeExt::take_from_value::<D>(&mut transition, "tcm")?,
+                    tpk_base,
+                    expiration_height,
                 )
                 .map_err(de::Error::custom)?;
 
@@ -80,17 +104,20 @@ mod tests {
     fn test_serde_json() -> Result<()> {
         let rng = &mut TestRng::default();
 
-        // Sample the transition.
-        let expected = crate::transition::test_helpers::sample_transition(rng);
-
-        // Serialize
-        let expected_string = &expected.to_string();
-        let candidate_string = serde_json::to_string(&expected)?;
-        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
-
-        // Deserialize
-        assert_eq!(expected, Transition::from_str(expected_string)?);
-        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        for expected in [
+            crate::transition::test_helpers::sample_transition(rng),
+            crate::transition::test_helpers::sample_transition_with_expiration(100, rng),
+            crate::transition::test_helpers::sample_transition_with_memo(None, rng),
+        ] {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+            // Deserialize
+            assert_eq!(expected, Transition::from_str(expected_string)?);
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        }
 
         Ok(())
     }
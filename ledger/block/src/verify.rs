@@ -73,6 +73,8 @@ impl<N: Network> Block<N> {
         let expected_solutions_root = self.compute_solutions_root()?;
         // Compute the expected subdag root.
         let expected_subdag_root = self.compute_subdag_root()?;
+        // Compute the expected events root.
+        let expected_events_root = self.compute_events_root()?;
 
         // Ensure the block header is correct.
         self.header.verify(
@@ -82,6 +84,7 @@ impl<N: Network> Block<N> {
             expected_ratifications_root,
             expected_solutions_root,
             expected_subdag_root,
+            expected_events_root,
             expected_round,
             expected_height,
             expected_cumulative_weight,
@@ -501,6 +504,14 @@ impl<N: Network> Block<N> {
         }
     }
 
+    /// Computes the events root for the block.
+    fn compute_events_root(&self) -> Result<Field<N>> {
+        match self.transactions.to_events_root() {
+            Ok(events_root) => Ok(events_root),
+            Err(error) => bail!("Failed to compute the events root for block {} - {error}", self.height()),
+        }
+    }
+
     /// Checks that the transmission IDs in the given subdag matches the solutions and transactions in the block.
     pub(super) fn check_subdag_transmissions(
         subdag: &Subdag<N>,
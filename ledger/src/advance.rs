@@ -227,9 +227,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         )?;
 
         // Construct the finalize state.
-        let state = FinalizeGlobalState::new::<N>(
+        let state = FinalizeGlobalState::new(
             next_round,
             next_height,
+            next_timestamp,
             next_cumulative_weight,
             next_cumulative_proof_target,
             previous_block.hash(),
@@ -274,6 +275,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             ratifications_root,
             solutions_root,
             subdag_root,
+            transactions.to_events_root()?,
             metadata,
         )?;
 
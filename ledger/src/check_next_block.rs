@@ -73,9 +73,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
 
         // Construct the finalize state.
-        let state = FinalizeGlobalState::new::<N>(
+        let state = FinalizeGlobalState::new(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
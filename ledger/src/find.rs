@@ -141,4 +141,48 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             })
         })
     }
+
+    /// Scans the blocks from the cursor of the given scanner up to the latest block,
+    /// and returns the records that belong to its view keys, along with their spent status.
+    /// On success, the cursor of the scanner is advanced past the latest block.
+    pub fn scan_records(&self, scanner: &mut RecordScanner<N>) -> Result<Vec<ScannedRecord<N>>> {
+        // Ensure the last scanned block is still in the ledger, i.e. it was not rolled back or reorganized.
+        if let Some((height, hash)) = scanner.cursor().last_block() {
+            ensure!(
+                self.get_hash(height).ok() == Some(hash),
+                "The scanned block at height {height} is no longer in the ledger - rescan from an earlier cursor"
+            );
+        }
+
+        // Retrieve the height of the block after the latest block.
+        let end_height = self.latest_height().saturating_add(1);
+
+        // Initialize a list for the records.
+        let mut records = Vec::new();
+        // Initialize the cursor for the last scanned block.
+        let mut cursor = scanner.cursor();
+        // Scan the blocks in batches.
+        let mut start_height = cursor.next_height();
+        while start_height < end_height {
+            // Retrieve the next batch of blocks.
+            let batch_end_height =
+                start_height.saturating_add(RecordScanner::<N>::MAX_BLOCKS_PER_BATCH).min(end_height);
+            let blocks = self.get_blocks(start_height..batch_end_height)?;
+            // Scan the blocks.
+            for block in &blocks {
+                records.extend(scanner.scan_block(block)?);
+                cursor = ScanCursor::new(block.height(), block.hash());
+            }
+            start_height = batch_end_height;
+        }
+
+        // Determine if the records are spent.
+        for record in records.iter_mut() {
+            record.set_spent(self.contains_tag(record.tag())?);
+        }
+
+        // Advance the cursor of the scanner.
+        scanner.set_cursor(cursor);
+        Ok(records)
+    }
 }
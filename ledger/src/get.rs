@@ -143,6 +143,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Retrieve the block transaction.
         match self.vm.block_store().get_block_transactions(&block_hash)? {
             Some(transactions) => Ok(transactions),
+            None if self.vm.block_store().is_block_pruned(&block_hash)? => {
+                bail!("Block {height} has been pruned from storage")
+            }
             None => bail!("Missing block transactions for block {height}"),
         }
     }
@@ -160,6 +163,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Retrieve the aborted transaction IDs.
         match self.vm.block_store().get_block_aborted_transaction_ids(&block_hash)? {
             Some(aborted_transaction_ids) => Ok(aborted_transaction_ids),
+            None if self.vm.block_store().is_block_pruned(&block_hash)? => {
+                bail!("Block {height} has been pruned from storage")
+            }
             None => bail!("Missing aborted transaction IDs for block {height}"),
         }
     }
@@ -210,6 +216,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             Some(block_hash) => block_hash,
             None => bail!("Block {height} does not exist in storage"),
         };
+        // Ensure the block has not been pruned.
+        if self.vm.block_store().is_block_pruned(&block_hash)? {
+            bail!("Block {height} has been pruned from storage")
+        }
         // Retrieve the block solutions.
         self.vm.block_store().get_block_solutions(&block_hash)
     }
@@ -233,6 +243,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Retrieve the block authority.
         match self.vm.block_store().get_block_authority(&block_hash)? {
             Some(authority) => Ok(authority),
+            None if self.vm.block_store().is_block_pruned(&block_hash)? => {
+                bail!("Block {height} has been pruned from storage")
+            }
             None => bail!("Missing authority for block {height}"),
         }
     }
@@ -15,5 +15,8 @@
 mod bft;
 pub use bft::*;
 
+mod scanner;
+pub use scanner::*;
+
 mod supply;
 pub use supply::*;
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// The position of a record scanner in the ledger.
+/// The cursor can be persisted, so that a scan may resume from where it left off.
+/// It records the hash of the last scanned block, so that a rollback or reorganization can be detected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ScanCursor<N: Network> {
+    /// The height and hash of the last scanned block, if any.
+    last_block: Option<(u32, N::BlockHash)>,
+}
+
+impl<N: Network> ScanCursor<N> {
+    /// Initializes a new scan cursor, resuming after the block with the given height and hash.
+    pub const fn new(last_height: u32, last_hash: N::BlockHash) -> Self {
+        Self { last_block: Some((last_height, last_hash)) }
+    }
+
+    /// Returns the height and hash of the last scanned block, if any.
+    pub const fn last_block(&self) -> Option<(u32, N::BlockHash)> {
+        self.last_block
+    }
+
+    /// Returns the height of the next block to be scanned.
+    pub fn next_height(&self) -> u32 {
+        self.last_block.map_or(0, |(height, _)| height.saturating_add(1))
+    }
+}
+
+impl<N: Network> Default for ScanCursor<N> {
+    /// Initializes a scan cursor that starts at the genesis block.
+    fn default() -> Self {
+        Self { last_block: None }
+    }
+}
+
+impl<N: Network> FromBytes for ScanCursor<N> {
+    /// Reads the scan cursor from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid scan cursor version"));
+        }
+        // Read the last scanned block.
+        let last_block = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some((u32::read_le(&mut reader)?, N::BlockHash::read_le(&mut reader)?)),
+            variant => return Err(error(format!("Invalid scan cursor variant ({variant})"))),
+        };
+        // Return the scan cursor.
+        Ok(Self { last_block })
+    }
+}
+
+impl<N: Network> ToBytes for ScanCursor<N> {
+    /// Writes the scan cursor to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the last scanned block.
+        match self.last_block {
+            None => 0u8.write_le(&mut writer),
+            Some((height, hash)) => {
+                1u8.write_le(&mut writer)?;
+                height.write_le(&mut writer)?;
+                hash.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            ScanCursor::<CurrentNetwork>::default(),
+            ScanCursor::new(1, Uniform::rand(rng)),
+            ScanCursor::new(u32::MAX, Uniform::rand(rng)),
+        ] {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, ScanCursor::read_le(&expected_bytes[..]).unwrap());
+            assert!(ScanCursor::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+    }
+}
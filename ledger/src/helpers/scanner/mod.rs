@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod cursor;
+pub use cursor::*;
+
+use console::{
+    account::{Address, GraphKey, ViewKey},
+    network::prelude::*,
+    program::{Ciphertext, Owner, Plaintext, Record},
+    types::Field,
+};
+use ledger_block::Block;
+
+use anyhow::Result;
+use std::collections::HashSet;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// An account that is tracked by the record scanner.
+#[derive(Clone)]
+struct ScanAccount<N: Network> {
+    /// The view key of the account.
+    view_key: ViewKey<N>,
+    /// The address of the account.
+    address: Address<N>,
+    /// The x-coordinate of the address, used to check record ownership.
+    address_x_coordinate: Field<N>,
+    /// The `sk_tag` of the account, used to determine if a record is spent.
+    sk_tag: Field<N>,
+}
+
+/// A record that was found by the record scanner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedRecord<N: Network> {
+    /// The address of the account that owns the record.
+    owner: Address<N>,
+    /// The height of the block that contains the record.
+    block_height: u32,
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The decrypted record.
+    record: Record<N, Plaintext<N>>,
+    /// The tag of the record.
+    tag: Field<N>,
+    /// Whether the record was spent, i.e. its serial number is in the ledger.
+    is_spent: bool,
+}
+
+impl<N: Network> ScannedRecord<N> {
+    /// Returns the address of the account that owns the record.
+    pub const fn owner(&self) -> &Address<N> {
+        &self.owner
+    }
+
+    /// Returns the height of the block that contains the record.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the decrypted record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns the tag of the record.
+    pub const fn tag(&self) -> &Field<N> {
+        &self.tag
+    }
+
+    /// Returns `true` if the record was spent at the time it was scanned.
+    pub const fn is_spent(&self) -> bool {
+        self.is_spent
+    }
+
+    /// Sets the spent status of the record.
+    pub(crate) fn set_spent(&mut self, is_spent: bool) {
+        self.is_spent = is_spent;
+    }
+}
+
+/// A scanner that finds the records belonging to many view keys at once.
+///
+/// The scanner walks the ledger in block order, checking every output record against
+/// all of its view keys in parallel, and remembers the next block height to scan in its cursor.
+#[derive(Clone)]
+pub struct RecordScanner<N: Network> {
+    /// The accounts to scan for.
+    accounts: Vec<ScanAccount<N>>,
+    /// The position of the scanner in the ledger.
+    cursor: ScanCursor<N>,
+}
+
+impl<N: Network> RecordScanner<N> {
+    /// The maximum number of blocks that are loaded from the ledger at once.
+    pub const MAX_BLOCKS_PER_BATCH: u32 = 64;
+
+    /// Initializes a new record scanner for the given view keys, resuming from the given cursor.
+    pub fn new(view_keys: impl IntoIterator<Item = ViewKey<N>>, cursor: ScanCursor<N>) -> Result<Self> {
+        let accounts = view_keys
+            .into_iter()
+            .map(|view_key| {
+                // Derive the address of the view key.
+                let address = view_key.to_address();
+                // Derive the `sk_tag` from the graph key.
+                let sk_tag = match GraphKey::try_from(view_key) {
+                    Ok(graph_key) => graph_key.sk_tag(),
+                    Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+                };
+                Ok(ScanAccount { view_key, address, address_x_coordinate: address.to_x_coordinate(), sk_tag })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure the view keys are unique.
+        let num_unique = accounts.iter().map(|account| account.address).collect::<HashSet<_>>().len();
+        ensure!(num_unique == accounts.len(), "Found a duplicate view key in the record scanner");
+        // Return the record scanner.
+        Ok(Self { accounts, cursor })
+    }
+
+    /// Returns the addresses of the accounts being scanned.
+    pub fn addresses(&self) -> impl '_ + Iterator<Item = &Address<N>> {
+        self.accounts.iter().map(|account| &account.address)
+    }
+
+    /// Returns the cursor of the scanner.
+    pub const fn cursor(&self) -> ScanCursor<N> {
+        self.cursor
+    }
+
+    /// Sets the cursor of the scanner.
+    pub fn set_cursor(&mut self, cursor: ScanCursor<N>) {
+        self.cursor = cursor;
+    }
+
+    /// Returns the records in the given block that belong to the scanned accounts, along with their tags.
+    /// Note: The returned records are not marked as spent, as this requires the ledger.
+    pub fn scan_block(&self, block: &Block<N>) -> Result<Vec<ScannedRecord<N>>> {
+        // Collect the output records in the block.
+        let records = block.transactions().records().collect::<Vec<_>>();
+        // Check every record against every account in parallel.
+        cfg_iter!(records)
+            .filter_map(|(commitment, record)| {
+                // Find the account that owns the record.
+                let account = self.find_owner(record)?;
+                // Decrypt the record, and compute its tag.
+                let scanned = record.decrypt(&account.view_key).and_then(|record| {
+                    let tag = Record::<N, Plaintext<N>>::tag(account.sk_tag, **commitment)?;
+                    Ok(ScannedRecord {
+                        owner: account.address,
+                        block_height: block.height(),
+                        commitment: **commitment,
+                        record,
+                        tag,
+                        is_spent: false,
+                    })
+                });
+                Some(scanned)
+            })
+            .collect()
+    }
+
+    /// Returns the account that owns the given record, if any.
+    fn find_owner(&self, record: &Record<N, Ciphertext<N>>) -> Option<&ScanAccount<N>> {
+        match record.owner() {
+            // If the owner is public, compare it against the addresses directly.
+            Owner::Public(owner) => self.accounts.iter().find(|account| account.address == *owner),
+            // If the owner is private, derive the candidate record view keys of all accounts at once.
+            Owner::Private(ciphertext) => {
+                // Compute `nonce * view_key` for every account.
+                let products = self.accounts.iter().map(|account| **record.nonce() * **account.view_key).collect();
+                // Note: This converts all of the products to affine with a single batch inversion,
+                // instead of one inversion per account.
+                let record_view_keys = N::Projective::batch_normalization_into_affine(products);
+                // Find the account whose address matches the decrypted owner.
+                self.accounts.iter().zip(record_view_keys).find_map(|(account, record_view_key)| {
+                    // Compute the randomizer of the owner, using the x-coordinate of the record view key.
+                    let record_view_key = Field::new(record_view_key.to_x_coordinate());
+                    let randomizer = N::hash_many_psd8(&[N::encryption_domain(), record_view_key], 1);
+                    // Decrypt the x-coordinate of the owner, and compare it to the address.
+                    match ciphertext.first().zip(randomizer.first()) {
+                        Some((owner, randomizer)) if *owner - *randomizer == account.address_x_coordinate => {
+                            Some(account)
+                        }
+                        _ => None,
+                    }
+                })
+            }
+        }
+    }
+}
@@ -57,7 +57,7 @@ use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSo
 use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_query::Query;
-use ledger_store::{ConsensusStorage, ConsensusStore};
+use ledger_store::{ConsensusSnapshot, ConsensusStorage, ConsensusStore};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
     vm::VM,
@@ -112,31 +112,31 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn load(genesis_block: Block<N>, dev: Option<u16>) -> Result<Self> {
         let timer = timer!("Ledger::load");
 
-        // Retrieve the genesis hash.
-        let genesis_hash = genesis_block.hash();
         // Initialize the ledger.
         let ledger = Self::load_unchecked(genesis_block, dev)?;
+        lap!(timer, "Load ledger");
 
-        // Ensure the ledger contains the correct genesis block.
-        if !ledger.contains_block_hash(&genesis_hash)? {
-            bail!("Incorrect genesis block (run 'snarkos clean' and try again)")
-        }
+        // Check the integrity of the ledger.
+        ledger.spot_check()?;
 
-        // Spot check the integrity of `NUM_BLOCKS` random blocks upon bootup.
-        const NUM_BLOCKS: usize = 10;
-        // Retrieve the latest height.
-        let latest_height = ledger.current_block.read().height();
-        debug_assert_eq!(latest_height, *ledger.vm.block_store().heights().max().unwrap(), "Mismatch in latest height");
-        // Sample random block heights.
-        let block_heights: Vec<u32> =
-            (0..=latest_height).choose_multiple(&mut OsRng, (latest_height as usize).min(NUM_BLOCKS));
-        cfg_into_iter!(block_heights).try_for_each(|height| {
-            ledger.get_block(height)?;
-            Ok::<_, Error>(())
-        })?;
-        lap!(timer, "Check existence of {NUM_BLOCKS} random blocks");
+        finish!(timer, "Check the integrity of the ledger");
+        Ok(ledger)
+    }
+
+    /// Loads the ledger from the given consensus store.
+    /// This allows the storage to be opened with custom options, such as `ConsensusDB::open_with_pruning`.
+    pub fn load_from_store(genesis_block: Block<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        let timer = timer!("Ledger::load_from_store");
+
+        info!("Loading the ledger from storage...");
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store(genesis_block, store)?;
+        lap!(timer, "Initialize ledger");
+
+        // Check the integrity of the ledger.
+        ledger.spot_check()?;
 
-        finish!(timer);
+        finish!(timer, "Check the integrity of the ledger");
         Ok(ledger)
     }
 
@@ -152,9 +152,62 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
         lap!(timer, "Load consensus store");
 
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store(genesis_block, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Loads the ledger from the given snapshot, which is inserted into empty storage.
+    /// The snapshot checksum must match the given checksum, which should be obtained from a trusted source,
+    /// and the finalize mappings must match the given finalize checksum, which is the confirmed finalize checksum
+    /// of a trusted node at the snapshot height.
+    /// The blocks after the snapshot height must then be added with `Ledger::advance_to_next_block`.
+    pub fn load_from_snapshot(
+        genesis_block: Block<N>,
+        snapshot: &ConsensusSnapshot<N>,
+        checksum: Field<N>,
+        finalize_checksum: Field<N>,
+        dev: Option<u16>,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::load_from_snapshot");
+
+        // Ensure the snapshot checksum matches.
+        if snapshot.to_checksum()? != checksum {
+            bail!("Incorrect checksum for the snapshot at height {}", snapshot.height())
+        }
+        // Ensure the snapshot extends the genesis block.
+        let snapshot_genesis_hash = match snapshot.block_hashes().first() {
+            Some(block_hash) => *block_hash,
+            None => snapshot.block().hash(),
+        };
+        if snapshot_genesis_hash != genesis_block.hash() {
+            bail!("Incorrect genesis block for the snapshot at height {}", snapshot.height())
+        }
+        lap!(timer, "Check the snapshot");
+
+        info!("Loading the ledger from a snapshot at height {}...", snapshot.height());
+        // Initialize the consensus store.
+        let store = match ConsensusStore::<N, C>::open(dev) {
+            Ok(store) => store,
+            Err(e) => bail!("Failed to load ledger (run 'snarkos clean' and try again)\n\n{e}\n"),
+        };
+        // Insert the snapshot into the consensus store.
+        store.insert_snapshot(snapshot, finalize_checksum)?;
+        lap!(timer, "Insert the snapshot");
+
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store(genesis_block, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Initializes the ledger from the given consensus store.
+    fn from_store(genesis_block: Block<N>, store: ConsensusStore<N, C>) -> Result<Self> {
         // Initialize a new VM.
         let vm = VM::from(store)?;
-        lap!(timer, "Initialize a new VM");
 
         // Retrieve the current committee.
         let current_committee = vm.finalize_store().committee_store().current_committee().ok();
@@ -174,7 +227,6 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             // Add the genesis block.
             ledger.advance_to_next_block(&genesis_block)?;
         }
-        lap!(timer, "Initialize genesis");
 
         // Retrieve the latest height.
         let latest_height =
@@ -191,10 +243,35 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Set the current epoch challenge.
         ledger.current_epoch_challenge = Arc::new(RwLock::new(Some(ledger.get_epoch_challenge(latest_height)?)));
 
-        finish!(timer, "Initialize ledger");
         Ok(ledger)
     }
 
+    /// Checks the ledger contains the genesis block, and spot checks the integrity of random blocks.
+    fn spot_check(&self) -> Result<()> {
+        // Ensure the ledger contains the correct genesis block.
+        if !self.contains_block_hash(&self.genesis_block.hash())? {
+            bail!("Incorrect genesis block (run 'snarkos clean' and try again)")
+        }
+
+        // Spot check the integrity of `NUM_BLOCKS` random blocks upon bootup.
+        const NUM_BLOCKS: usize = 10;
+        // Retrieve the latest height.
+        let latest_height = self.current_block.read().height();
+        debug_assert_eq!(latest_height, *self.vm.block_store().heights().max().unwrap(), "Mismatch in latest height");
+        // Retrieve the oldest height, as a ledger loaded from a snapshot does not store the earlier blocks.
+        let oldest_height = self.vm.block_store().oldest_block_height()?.unwrap_or_default();
+        // Sample random block heights.
+        let block_heights: Vec<u32> = (oldest_height..=latest_height)
+            .choose_multiple(&mut OsRng, ((latest_height - oldest_height) as usize).min(NUM_BLOCKS));
+        cfg_into_iter!(block_heights).try_for_each(|height| {
+            // Skip the blocks that have been pruned from storage.
+            if !self.vm.block_store().is_block_pruned(&self.get_hash(height)?)? {
+                self.get_block(height)?;
+            }
+            Ok::<_, Error>(())
+        })
+    }
+
     /// Returns the VM.
     pub const fn vm(&self) -> &VM<N, C> {
         &self.vm
@@ -401,6 +478,11 @@ pub(crate) mod test_helpers {
     #[cfg(feature = "rocks")]
     pub(crate) type CurrentLedger = Ledger<CurrentNetwork, ledger_store::helpers::rocksdb::ConsensusDB<CurrentNetwork>>;
 
+    #[cfg(not(feature = "rocks"))]
+    pub(crate) type CurrentConsensusStorage = ledger_store::helpers::memory::ConsensusMemory<CurrentNetwork>;
+    #[cfg(feature = "rocks")]
+    pub(crate) type CurrentConsensusStorage = ledger_store::helpers::rocksdb::ConsensusDB<CurrentNetwork>;
+
     #[cfg(not(feature = "rocks"))]
     pub(crate) type CurrentConsensusStore =
         ConsensusStore<CurrentNetwork, ledger_store::helpers::memory::ConsensusMemory<CurrentNetwork>>;
@@ -13,13 +13,17 @@
 // limitations under the License.
 
 use crate::{
-    test_helpers::{CurrentLedger, CurrentNetwork},
+    test_helpers::{CurrentConsensusStorage, CurrentConsensusStore, CurrentLedger, CurrentNetwork},
+    Ledger,
+    RecordScanner,
     RecordsFilter,
+    ScanCursor,
 };
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, ViewKey},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
@@ -568,6 +572,79 @@ fn test_bond_and_unbond_validator() {
     assert!(!committee.is_committee_member(new_member_address));
 }
 
+#[test]
+fn test_scan_records() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Sample a recipient account.
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+    let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+    // Ensure the scanner rejects duplicate view keys.
+    assert!(RecordScanner::new([view_key, view_key], ScanCursor::default()).is_err());
+
+    // Scan the ledger for both accounts.
+    let mut scanner = RecordScanner::new([view_key, recipient_view_key], ScanCursor::default()).unwrap();
+    let records = ledger.scan_records(&mut scanner).unwrap();
+    assert_eq!(scanner.cursor(), ScanCursor::new(ledger.latest_height(), ledger.latest_hash()));
+
+    // Ensure the scanner finds the same records as `find_records`.
+    let expected = ledger
+        .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+        .unwrap()
+        .collect::<indexmap::IndexMap<_, _>>();
+    assert!(!records.is_empty());
+    assert_eq!(records.len(), expected.len());
+    for scanned in &records {
+        assert_eq!(scanned.owner(), &address);
+        assert_eq!(scanned.block_height(), 0);
+        assert_eq!(expected.get(scanned.commitment()), Some(scanned.record()));
+        assert!(!scanned.is_spent());
+    }
+
+    // Ensure scanning again from the cursor does not find any records.
+    assert!(ledger.scan_records(&mut scanner).unwrap().is_empty());
+
+    // Ensure a cursor for a block that is not in the ledger is rejected.
+    let mut unknown = RecordScanner::new([view_key], ScanCursor::new(0, Uniform::rand(rng))).unwrap();
+    assert!(ledger.scan_records(&mut unknown).is_err());
+
+    // Spend one of the records.
+    let spent = records[0].clone();
+    let inputs = [
+        Value::Record(spent.record().clone()),
+        Value::from_str(&format!("{recipient_address}")).unwrap(),
+        Value::from_str("100u64").unwrap(),
+    ];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure resuming the scan only finds the new records, for both accounts.
+    let records = ledger.scan_records(&mut scanner).unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(records.iter().all(|scanned| scanned.block_height() == block.height() && !scanned.is_spent()));
+    assert!(records.iter().any(|scanned| scanned.owner() == &address));
+    assert!(records.iter().any(|scanned| scanned.owner() == &recipient_address));
+
+    // Ensure a scan from the genesis block reports the spent record.
+    let mut scanner = RecordScanner::new([view_key], ScanCursor::default()).unwrap();
+    let records = ledger.scan_records(&mut scanner).unwrap();
+    let scanned = records.iter().find(|scanned| scanned.commitment() == spent.commitment()).unwrap();
+    assert!(scanned.is_spent());
+    assert_eq!(records.iter().filter(|scanned| scanned.is_spent()).count(), 1);
+}
+
 #[test]
 fn test_aborted_transaction_indexing() {
     let rng = &mut TestRng::default();
@@ -828,3 +905,145 @@ finalize foo2:
     assert!(ledger.vm.transaction_store().contains_transaction_id(&deployment_1_id).unwrap());
     assert!(ledger.vm.block_store().contains_rejected_or_aborted_transaction_id(&deployment_2_id).unwrap());
 }
+
+#[test]
+fn test_load_from_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Prepare a block with a public transfer.
+    let prepare_block = |ledger: &CurrentLedger, rng: &mut TestRng| {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+        let transaction = ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap()
+    };
+
+    // Add a block to the ledger.
+    let block = prepare_block(&ledger, rng);
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Snapshot the ledger, and check the byte serialization.
+    let snapshot = ledger.vm.to_snapshot().unwrap();
+    assert_eq!(snapshot.height(), 1);
+    let snapshot = ledger_store::ConsensusSnapshot::from_bytes_le(&snapshot.to_bytes_le().unwrap()).unwrap();
+    let checksum = snapshot.to_checksum().unwrap();
+    let finalize_checksum = ledger.vm.finalize_store().get_checksum_confirmed().unwrap();
+
+    // Ensure the snapshot is not loaded with an incorrect checksum.
+    let result = Ledger::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::load_from_snapshot(
+        ledger.genesis_block.clone(),
+        &snapshot,
+        checksum + Field::one(),
+        finalize_checksum,
+        None,
+    );
+    assert!(result.is_err());
+
+    // Ensure the snapshot is not loaded with an incorrect finalize checksum.
+    let result = Ledger::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::load_from_snapshot(
+        ledger.genesis_block.clone(),
+        &snapshot,
+        checksum,
+        finalize_checksum + Field::one(),
+        None,
+    );
+    assert!(result.is_err());
+
+    // Load a new ledger from the snapshot.
+    let candidate = Ledger::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::load_from_snapshot(
+        ledger.genesis_block.clone(),
+        &snapshot,
+        checksum,
+        finalize_checksum,
+        None,
+    )
+    .unwrap();
+    assert_eq!(candidate.latest_block(), ledger.latest_block());
+    assert_eq!(candidate.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(candidate.latest_committee().unwrap(), ledger.latest_committee().unwrap());
+    assert_eq!(
+        candidate.vm.finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm.finalize_store().get_checksum_confirmed().unwrap()
+    );
+    assert!(candidate.vm.block_store().get_block(&block.hash()).unwrap().is_some());
+    assert_eq!(candidate.vm.block_store().oldest_block_height().unwrap(), Some(1));
+
+    // Ensure the transaction in the snapshot cannot be replayed.
+    let transaction_id = block.transactions().transaction_ids().next().unwrap();
+    assert!(candidate.vm.block_store().contains_transaction_id(transaction_id).unwrap());
+
+    // Add the next block to both ledgers.
+    let block = prepare_block(&ledger, rng);
+    candidate.check_next_block(&block, rng).unwrap();
+    candidate.advance_to_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(candidate.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(
+        candidate.vm.finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm.finalize_store().get_checksum_confirmed().unwrap()
+    );
+}
+
+#[test]
+fn test_pruned_blocks() {
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+
+    // Initialize a ledger that prunes the blocks older than the given depth.
+    let depth = 2;
+    let store = CurrentConsensusStore::from(CurrentConsensusStorage::open_with_pruning(None, depth).unwrap());
+    let genesis = VM::from(store.clone()).unwrap().genesis_beacon(&private_key, rng).unwrap();
+    let ledger = CurrentLedger::load_from_store(genesis, store).unwrap();
+
+    // Add blocks with a public transfer, and ensure each block only prunes the block beyond the depth.
+    let mut transaction_ids = vec![];
+    for height in 1..=4u32 {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+        let transaction = ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        transaction_ids.push(transaction.id());
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.check_next_block(&block, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+
+        // Ensure the blocks beyond the depth are pruned.
+        let oldest_height = height.saturating_sub(depth);
+        assert_eq!(ledger.vm.block_store().oldest_block_height().unwrap(), Some(oldest_height));
+        for candidate in 0..=height {
+            let block_hash = ledger.get_hash(candidate).unwrap();
+            assert_eq!(ledger.vm.block_store().is_block_pruned(&block_hash).unwrap(), candidate < oldest_height);
+        }
+    }
+
+    // Ensure the pruned blocks report that they have been pruned.
+    let is_pruned_error = |error: anyhow::Error| error.to_string().contains("has been pruned");
+    let height = 1;
+    assert!(is_pruned_error(ledger.get_block(height).unwrap_err()));
+    assert!(is_pruned_error(ledger.get_transactions(height).unwrap_err()));
+    assert!(is_pruned_error(ledger.get_aborted_transaction_ids(height).unwrap_err()));
+    assert!(is_pruned_error(ledger.get_solutions(height).unwrap_err()));
+    assert!(is_pruned_error(ledger.get_authority(height).unwrap_err()));
+    let transaction_id = transaction_ids[0];
+    assert!(is_pruned_error(ledger.get_transaction(transaction_id).unwrap_err()));
+    assert!(is_pruned_error(ledger.get_confirmed_transaction(transaction_id).unwrap_err()));
+    // Ensure the block hash, header, and state root are kept.
+    assert!(ledger.get_header(height).is_ok());
+    assert!(ledger.get_state_root(height).is_ok());
+    // Ensure the blocks within the depth are retrieved.
+    for height in 2..=4 {
+        assert_eq!(ledger.get_block(height).unwrap().height(), height);
+        let transaction_id = transaction_ids[height as usize - 1];
+        assert_eq!(ledger.get_transaction(transaction_id).unwrap().id(), transaction_id);
+    }
+}
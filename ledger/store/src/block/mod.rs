@@ -40,7 +40,7 @@ use ledger_block::{
 };
 use ledger_coinbase::{CoinbaseSolution, ProverSolution, PuzzleCommitment};
 use ledger_narwhal_batch_certificate::BatchCertificate;
-use synthesizer_program::Program;
+use synthesizer_program::{Event, Program};
 
 use anyhow::Result;
 use parking_lot::RwLock;
@@ -49,6 +49,9 @@ use std::{borrow::Cow, io::Cursor, sync::Arc};
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+/// The key of the height of the oldest block stored in full, in the oldest height map.
+const OLDEST_HEIGHT_KEY: u8 = 0;
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ConfirmedTxType {
     /// A deploy transaction that was accepted.
@@ -73,7 +76,8 @@ fn to_confirmed_tuple<N: Network>(
             // Return the confirmed tuple.
             Ok((ConfirmedTxType::AcceptedDeploy(index), tx, (num_finalize, finalize).to_bytes_le()?, None))
         }
-        ConfirmedTransaction::AcceptedExecute(index, tx, finalize) => {
+        ConfirmedTransaction::AcceptedExecute(index, tx, finalize, _) => {
+            // Note: The events are stored separately, in the events map.
             // Retrieve the number of finalize operations.
             let num_finalize = NumFinalizeSize::try_from(finalize.len())?;
             // Return the confirmed tuple.
@@ -118,6 +122,7 @@ fn to_confirmed_transaction<N: Network>(
     confirmed_type: ConfirmedTxType,
     transaction: Transaction<N>,
     blob: Vec<u8>,
+    events: Vec<Event<N>>,
 ) -> Result<ConfirmedTransaction<N>> {
     match confirmed_type {
         ConfirmedTxType::AcceptedDeploy(index) => {
@@ -138,7 +143,7 @@ fn to_confirmed_transaction<N: Network>(
             // Read the finalize operations.
             let finalize = (0..num_finalize).map(|_| FromBytes::read_le(&mut cursor)).collect::<Result<Vec<_>, _>>()?;
             // Return the confirmed transaction.
-            ConfirmedTransaction::accepted_execute(index, transaction, finalize)
+            ConfirmedTransaction::accepted_execute(index, transaction, finalize, events)
         }
         ConfirmedTxType::RejectedDeploy(index) => {
             // Initialize a cursor.
@@ -201,6 +206,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of `transaction ID` to `[event]`.
+    type EventsMap: for<'a> Map<'a, N::TransactionID, Vec<Event<N>>>;
+    /// The mapping of `OLDEST_HEIGHT_KEY` to the height of the oldest block stored in full.
+    type OldestHeightMap: for<'a> Map<'a, u8, u32>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -239,6 +248,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap;
+    /// Returns the oldest height map.
+    fn oldest_height_map(&self) -> &Self::OldestHeightMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -252,6 +265,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.transition_store().dev()
     }
 
+    /// Returns the number of recent blocks to keep in full, or `None` if the blocks are never pruned.
+    fn pruning_depth(&self) -> Option<u32> {
+        None
+    }
+
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.state_root_map().start_atomic();
@@ -269,6 +287,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.events_map().start_atomic();
+        self.oldest_height_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -289,6 +309,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.events_map().is_atomic_in_progress()
+            || self.oldest_height_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -309,6 +331,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.events_map().atomic_checkpoint();
+        self.oldest_height_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -329,6 +353,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.events_map().clear_latest_checkpoint();
+        self.oldest_height_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -349,6 +375,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.events_map().atomic_rewind();
+        self.oldest_height_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -369,6 +397,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.events_map().abort_atomic();
+        self.oldest_height_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -389,6 +419,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.events_map().finish_atomic()?;
+        self.oldest_height_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -418,6 +450,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             .map(|tx| tx.to_unconfirmed_transaction_id())
             .collect::<Result<Vec<_>>>()?;
 
+        // Prepare the events emitted by the accepted executions.
+        let events: Vec<_> = block
+            .transactions()
+            .iter()
+            .filter(|tx| !tx.events().is_empty())
+            .map(|tx| (tx.id(), tx.events().to_vec()))
+            .collect();
+
         atomic_batch_scope!(self, {
             // Store the (block height, state root) pair.
             self.state_root_map().insert(block.height(), state_root)?;
@@ -478,6 +518,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().insert(&transaction)?;
             }
 
+            // Store the events.
+            for (transaction_id, events) in events {
+                self.events_map().insert(transaction_id, events)?;
+            }
+
             Ok(())
         })
     }
@@ -591,6 +636,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             for transaction_id in transaction_ids.iter() {
                 // Remove the reverse transaction ID.
                 self.confirmed_transactions_map().remove(transaction_id)?;
+                // Remove the events.
+                self.events_map().remove(transaction_id)?;
                 // Remove the transaction.
                 self.transaction_store().remove(transaction_id)?;
             }
@@ -599,6 +646,47 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Prunes the block for the given `block hash`, by removing the block body and its transaction bodies.
+    /// Note: The block hash, state root, and header are kept, along with the transaction IDs, transition IDs,
+    /// serial numbers, commitments, and other IDs that are required to verify the next blocks. The block hash
+    /// of each transaction, and the transaction ID of each transition, are also kept to locate spent records.
+    fn prune(&self, block_hash: &N::BlockHash) -> Result<()> {
+        // Retrieve the transaction IDs.
+        let transaction_ids = match self.transactions_map().get_confirmed(block_hash)? {
+            Some(transaction_ids) => cow_to_cloned!(transaction_ids),
+            // The block has already been pruned.
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the block authority.
+            self.authority_map().remove(block_hash)?;
+            // Remove the block ratifications.
+            self.ratifications_map().remove(block_hash)?;
+            // Remove the block solutions.
+            self.solutions_map().remove(block_hash)?;
+            // Remove the transaction IDs.
+            self.transactions_map().remove(block_hash)?;
+            // Remove the aborted transaction IDs.
+            self.aborted_transaction_ids_map().remove(block_hash)?;
+
+            // Prune the block transactions.
+            for transaction_id in transaction_ids.iter() {
+                // Remove the confirmed blob, keeping the block hash and confirmed type.
+                if let Some(confirmed) = self.confirmed_transactions_map().get_confirmed(transaction_id)? {
+                    let (block_hash, confirmed_type, _) = cow_to_cloned!(confirmed);
+                    self.confirmed_transactions_map().insert(*transaction_id, (block_hash, confirmed_type, Vec::new()))?;
+                }
+                // Remove the events.
+                self.events_map().remove(transaction_id)?;
+                // Prune the transaction.
+                self.transaction_store().prune(transaction_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns `true` if the given transaction ID exists.
     fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
         Ok(self.transaction_store().contains_transaction_id(transaction_id)?
@@ -615,6 +703,20 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_deployment_or_execution_map().contains_key_confirmed(rejected_id)
     }
 
+    /// Returns `true` if the block for the given `block hash` exists, but is no longer stored in full.
+    fn is_block_pruned(&self, block_hash: &N::BlockHash) -> Result<bool> {
+        Ok(self.reverse_id_map().contains_key_confirmed(block_hash)?
+            && !self.transactions_map().contains_key_confirmed(block_hash)?)
+    }
+
+    /// Returns `true` if the transaction for the given `transaction ID` exists, but is no longer stored in full.
+    fn is_transaction_pruned(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        match self.find_block_hash(transaction_id)? {
+            Some(block_hash) => self.is_block_pruned(&block_hash),
+            None => Ok(false),
+        }
+    }
+
     /// Returns the block height that contains the given `state root`.
     fn find_block_height_from_state_root(&self, state_root: N::StateRoot) -> Result<Option<u32>> {
         match self.reverse_state_root_map().get_confirmed(&state_root)? {
@@ -841,6 +943,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         let Some(block_hash) = self.get_block_hash(block_height)? else {
             bail!("The block hash for block '{block_height}' is missing in block storage")
         };
+        // Ensure the block has not been pruned.
+        if self.is_block_pruned(&block_hash)? {
+            bail!("Block {block_height} ('{block_hash}') has been pruned from storage")
+        }
         // Retrieve the solutions.
         let Some(solutions) = self.solutions_map().get_confirmed(&block_hash)? else {
             bail!("The solutions for block '{block_height}' are missing in block storage")
@@ -882,6 +988,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns the transaction for the given `transaction ID`.
     fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        // Ensure the transaction is stored in full.
+        if self.is_transaction_pruned(transaction_id)? {
+            bail!("Transaction '{transaction_id}' has been pruned from storage");
+        }
         // Check if the transaction was rejected or aborted.
         // Note: We can only retrieve accepted or rejected transactions. We cannot retrieve aborted transactions.
         match self.rejected_or_aborted_transaction_id_map().get_confirmed(transaction_id)? {
@@ -919,12 +1029,41 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(confirmed_attributes) => cow_to_cloned!(confirmed_attributes),
             None => bail!("Missing confirmed transaction '{transaction_id}' in block storage"),
         };
+        // Retrieve the events.
+        let events = self.get_transaction_events(&transaction.id())?;
         // Construct the confirmed transaction.
-        to_confirmed_transaction(confirmed_type, transaction, blob).map(Some)
+        to_confirmed_transaction(confirmed_type, transaction, blob, events).map(Some)
+    }
+
+    /// Returns the events emitted by the transaction for the given `transaction ID`.
+    fn get_transaction_events(&self, transaction_id: &N::TransactionID) -> Result<Vec<Event<N>>> {
+        match self.events_map().get_confirmed(transaction_id)? {
+            Some(events) => Ok(cow_to_cloned!(events)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the events emitted in the block for the given `block hash`.
+    fn get_block_events(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<Event<N>>>> {
+        // Retrieve the transaction IDs.
+        let transaction_ids = match self.transactions_map().get_confirmed(block_hash)? {
+            Some(transaction_ids) => transaction_ids,
+            None => return Ok(None),
+        };
+        // Retrieve the events, in the order they were emitted.
+        let mut events = Vec::new();
+        for transaction_id in transaction_ids.iter() {
+            events.extend(self.get_transaction_events(transaction_id)?);
+        }
+        Ok(Some(events))
     }
 
     /// Returns the unconfirmed transaction for the given `transaction ID`.
     fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        // Ensure the transaction is stored in full.
+        if self.is_transaction_pruned(transaction_id)? {
+            bail!("Transaction '{transaction_id}' has been pruned from storage");
+        }
         // Check if the transaction was rejected or aborted.
         // Note: We can only retrieve accepted or rejected transactions. We cannot retrieve aborted transactions.
         match self.rejected_or_aborted_transaction_id_map().get_confirmed(transaction_id)? {
@@ -945,6 +1084,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
         // Retrieve the block height.
         let Some(height) = self.get_block_height(block_hash)? else { return Ok(None) };
+        // Ensure the block is stored in full.
+        if !self.transactions_map().contains_key_confirmed(block_hash)? {
+            bail!("Block {height} ('{block_hash}') has been pruned from storage");
+        }
 
         // Retrieve the block header.
         let Some(header) = self.get_block_header(block_hash)? else {
@@ -1007,7 +1150,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn open(dev: Option<u16>) -> Result<Self> {
         // Initialize the block storage.
         let storage = B::open(dev)?;
+        // Initialize the block store.
+        Self::from(storage)
+    }
 
+    /// Initializes a block store from storage.
+    pub fn from(storage: B) -> Result<Self> {
         // Compute the block tree.
         let tree = {
             // Prepare an iterator over the block heights.
@@ -1044,10 +1192,53 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.insert((*updated_tree.root()).into(), block)?;
         // Update the block tree.
         *tree = updated_tree;
+        drop(tree);
+        // If pruning is enabled, prune the blocks beyond the pruning depth.
+        if let Some(depth) = self.storage.pruning_depth() {
+            self.prune(block.height().saturating_sub(depth))?;
+        }
         // Return success.
         Ok(())
     }
 
+    /// Prunes the blocks below the given `height`, by removing the block bodies and their transaction bodies.
+    /// The pruned blocks can no longer be retrieved, however their block hashes, state roots, and headers are kept,
+    /// along with the IDs, serial numbers, and commitments that are required to verify the next blocks.
+    /// Note: The state paths for the commitments in the pruned blocks can no longer be computed.
+    pub fn prune(&self, height: u32) -> Result<()> {
+        // If the block store is empty, return early.
+        if self.tree.read().number_of_leaves() == 0 {
+            return Ok(());
+        }
+        // Retrieve the oldest block height stored in full, including a pending update in an atomic batch.
+        let oldest_height = match self.storage.oldest_height_map().get_speculative(&OLDEST_HEIGHT_KEY)? {
+            Some(height) => cow_to_copied!(height),
+            None => 0,
+        };
+        // Ensure the latest block is not pruned.
+        ensure!(
+            height <= self.current_block_height(),
+            "Cannot prune the blocks below height {height}, as the latest block is kept"
+        );
+        // If the blocks below the given height are already pruned, return early.
+        if height <= oldest_height {
+            return Ok(());
+        }
+
+        atomic_batch_scope!(self, {
+            for height in oldest_height..height {
+                // Retrieve the block hash.
+                let Some(block_hash) = self.storage.get_block_hash(height)? else {
+                    bail!("Failed to prune block {height}: missing block hash")
+                };
+                // Prune the block.
+                self.storage.prune(&block_hash)?;
+            }
+            // Update the oldest block height stored in full.
+            self.storage.oldest_height_map().insert(OLDEST_HEIGHT_KEY, height)
+        })
+    }
+
     /// Removes the last 'n' blocks from storage.
     pub fn remove_last_n(&self, n: u32) -> Result<()> {
         // Ensure 'n' is non-zero.
@@ -1098,6 +1289,69 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Stores the given checkpoint block into an empty block store, preceded by the hashes of the earlier blocks.
+    /// Note: Only the block hashes and state roots of the earlier blocks are stored, not the blocks themselves.
+    pub(crate) fn insert_checkpoint(&self, block_hashes: &[N::BlockHash], block: &Block<N>) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Ensure the block store is empty.
+        ensure!(tree.number_of_leaves() == 0, "Cannot insert a checkpoint block into a non-empty block store");
+        // Ensure the checkpoint block is at the height after the block hashes.
+        ensure!(block.height() as usize == block_hashes.len(), "Mismatching height for the checkpoint block");
+
+        // Compute the state root after each block, by appending the block hashes to the block tree.
+        let mut updated_tree = N::merkle_tree_bhp(&[])?;
+        let mut state_roots = Vec::with_capacity(block_hashes.len());
+        for block_hash in block_hashes {
+            updated_tree = updated_tree.prepare_append(&[block_hash.to_bits_le()])?;
+            state_roots.push(N::StateRoot::from(*updated_tree.root()));
+        }
+        // Ensure the checkpoint block extends the block hashes.
+        ensure!(
+            *updated_tree.root() == *block.previous_state_root(),
+            "Mismatching state root for the checkpoint block"
+        );
+        ensure!(
+            block_hashes.last() == Some(&block.previous_hash()),
+            "Mismatching previous hash for the checkpoint block"
+        );
+        // Append the checkpoint block to the block tree.
+        let updated_tree = updated_tree.prepare_append(&[block.hash().to_bits_le()])?;
+
+        atomic_batch_scope!(self, {
+            // Store the block hashes and state roots of the earlier blocks.
+            for (height, (block_hash, state_root)) in block_hashes.iter().zip(state_roots).enumerate() {
+                let height = u32::try_from(height)?;
+                self.storage.state_root_map().insert(height, state_root)?;
+                self.storage.reverse_state_root_map().insert(state_root, height)?;
+                self.storage.id_map().insert(height, *block_hash)?;
+                self.storage.reverse_id_map().insert(*block_hash, height)?;
+            }
+            // Store the checkpoint block, which is the oldest block stored in full.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            self.storage.oldest_height_map().insert(OLDEST_HEIGHT_KEY, block.height())
+        })?;
+
+        // Update the block tree.
+        *tree = updated_tree;
+        // Return success.
+        Ok(())
+    }
+
+    /// Returns the height of the oldest block stored in full, or `None` if the block store is empty.
+    /// Note: A block store that was pruned, or initialized from a snapshot, only stores the recent blocks in full.
+    pub fn oldest_block_height(&self) -> Result<Option<u32>> {
+        // If the block store is empty, return early.
+        if self.tree.read().number_of_leaves() == 0 {
+            return Ok(None);
+        }
+        // Retrieve the oldest block height stored in full, which is the genesis block if no blocks were pruned.
+        match self.storage.oldest_height_map().get_confirmed(&OLDEST_HEIGHT_KEY)? {
+            Some(height) => Ok(Some(cow_to_copied!(height))),
+            None => Ok(Some(0)),
+        }
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -1147,6 +1401,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns the block storage.
+    pub(crate) const fn storage(&self) -> &B {
+        &self.storage
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -1175,6 +1434,13 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the current block height, from the cached block tree.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn current_block_height(&self) -> u32 {
+        // Note: The block tree has one leaf per block, and block heights are bounded by `u32`.
+        self.tree.read().number_of_leaves().saturating_sub(1) as u32
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -1251,6 +1517,16 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_confirmed_transaction(*transaction_id)
     }
 
+    /// Returns the events emitted by the transaction for the given `transaction ID`.
+    pub fn get_transaction_events(&self, transaction_id: &N::TransactionID) -> Result<Vec<Event<N>>> {
+        self.storage.get_transaction_events(transaction_id)
+    }
+
+    /// Returns the events emitted in the block for the given `block hash`.
+    pub fn get_block_events(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<Event<N>>>> {
+        self.storage.get_block_events(block_hash)
+    }
+
     /// Returns the unconfirmed transaction for the given `transaction ID`.
     pub fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
         self.storage.get_unconfirmed_transaction(transaction_id)
@@ -1303,6 +1579,16 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.contains_rejected_deployment_or_execution_id(rejected_id)
     }
 
+    /// Returns `true` if the block for the given `block hash` exists, but is no longer stored in full.
+    pub fn is_block_pruned(&self, block_hash: &N::BlockHash) -> Result<bool> {
+        self.storage.is_block_pruned(block_hash)
+    }
+
+    /// Returns `true` if the transaction for the given `transaction ID` exists, but is no longer stored in full.
+    pub fn is_transaction_pruned(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.storage.is_transaction_pruned(transaction_id)
+    }
+
     /// Returns `true` if the given certificate ID exists.
     pub fn contains_certificate(&self, certificate_id: &Field<N>) -> Result<bool> {
         self.storage.certificate_map().contains_key_confirmed(certificate_id)
@@ -1373,6 +1659,55 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_prune() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        let block_hash = block.hash();
+        assert!(block.transactions().num_accepted() > 0, "This test must be run with at least one transaction.");
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        assert_eq!(None, block_store.oldest_block_height().unwrap());
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+        assert_eq!(Some(0), block_store.oldest_block_height().unwrap());
+
+        // Ensure the latest block cannot be pruned.
+        assert!(block_store.prune(1).is_err());
+        // Ensure pruning up to the latest block is a no-op.
+        block_store.prune(0).unwrap();
+        assert!(!block_store.is_block_pruned(&block_hash).unwrap());
+
+        // Prune the block.
+        block_store.storage.prune(&block_hash).unwrap();
+        assert!(block_store.is_block_pruned(&block_hash).unwrap());
+        // Ensure the block is no longer stored in full.
+        let error = block_store.get_block(&block_hash).unwrap_err();
+        assert!(error.to_string().contains("has been pruned"));
+        // Ensure pruning is idempotent.
+        block_store.storage.prune(&block_hash).unwrap();
+
+        // Ensure the data needed for consensus is kept.
+        assert_eq!(Some(block.height()), block_store.get_block_height(&block_hash).unwrap());
+        assert_eq!(Some(*block.header()), block_store.get_block_header(&block_hash).unwrap());
+        assert!(block_store.get_state_root(block.height()).unwrap().is_some());
+        for transaction_id in block.transaction_ids() {
+            assert!(block_store.contains_transaction_id(transaction_id).unwrap());
+            assert!(block_store.is_transaction_pruned(transaction_id).unwrap());
+            let error = block_store.get_transaction(transaction_id).unwrap_err();
+            assert!(error.to_string().contains("has been pruned"));
+        }
+        for serial_number in block.serial_numbers() {
+            assert!(block_store.transition_store().contains_serial_number(serial_number).unwrap());
+        }
+        for commitment in block.commitments() {
+            assert!(block_store.transition_store().contains_commitment(commitment).unwrap());
+        }
+    }
+
     #[test]
     fn test_find_block_hash() {
         let rng = &mut TestRng::default();
@@ -1473,4 +1808,34 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_get_events() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        let block_hash = block.hash();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Ensure the block events do not exist.
+        assert_eq!(None, block_store.get_block_events(&block_hash).unwrap());
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the block events match the block.
+        let expected = block.transactions().events().cloned().collect::<Vec<_>>();
+        assert_eq!(Some(expected), block_store.get_block_events(&block_hash).unwrap());
+        for confirmed in block.transactions().iter() {
+            assert_eq!(block_store.get_transaction_events(&confirmed.id()).unwrap(), confirmed.events());
+        }
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the block events do not exist.
+        assert_eq!(None, block_store.get_block_events(&block_hash).unwrap());
+    }
 }
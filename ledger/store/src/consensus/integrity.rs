@@ -0,0 +1,673 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    atomic_batch_scope,
+    cow_to_copied,
+    helpers::{
+        BlockMap,
+        CommitteeMap,
+        ExecutionMap,
+        FeeMap,
+        Map,
+        MapID,
+        MapRead,
+        TransitionInputMap,
+        TransitionMap,
+        TransitionOutputMap,
+    },
+    CommitteeStorage,
+    ExecutionStorage,
+    FeeStorage,
+    InputStorage,
+    OutputStorage,
+};
+use console::{program::BlockTree, types::Field};
+use ledger_authority::Authority;
+
+use indexmap::IndexMap;
+
+/// An inconsistency found by the integrity check of the consensus storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntegrityIssue {
+    /// The block hash at the given height is missing.
+    MissingBlockHash(u32),
+    /// The block hash at the given height does not match the block hash recomputed from its header.
+    BlockHash(u32),
+    /// The header of the block at the given height is missing, or does not extend the previous block.
+    Header(u32, String),
+    /// The block at the given height cannot be reconstructed from its components, or does not match its header.
+    Block(u32, String),
+    /// The committee at the given height does not match the block headers.
+    Committee(u32, String),
+    /// The index does not match the maps it is derived from, with the given number of
+    /// missing or mismatching entries, and of stale entries.
+    Index { map: MapID, missing: usize, stale: usize },
+}
+
+impl IntegrityIssue {
+    /// Returns `true` if the issue is repaired by `ConsensusStore::repair_integrity`.
+    pub const fn is_repairable(&self) -> bool {
+        matches!(self, Self::Index { .. })
+    }
+}
+
+impl Display for IntegrityIssue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingBlockHash(height) => write!(f, "Missing the block hash at height {height}"),
+            Self::BlockHash(height) => write!(f, "Incorrect block hash at height {height}"),
+            Self::Header(height, reason) => write!(f, "Invalid header for block {height}: {reason}"),
+            Self::Block(height, reason) => write!(f, "Invalid block {height}: {reason}"),
+            Self::Committee(height, reason) => write!(f, "Invalid committee at height {height}: {reason}"),
+            Self::Index { map, missing, stale } => {
+                write!(f, "Inconsistent index {map:?}: {missing} missing or mismatching entries, {stale} stale entries")
+            }
+        }
+    }
+}
+
+/// The state that is not checked by the integrity check of the consensus storage,
+/// as it cannot be recomputed from the stored data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UncheckedState {
+    /// The finalize roots of the given number of blocks, as the finalize operations of the ratifications are not stored.
+    FinalizeRoots(u32),
+    /// The given number of blocks that are not stored in full, as they were pruned or precede a snapshot checkpoint.
+    PartialBlocks(u32),
+    /// The finalize mappings, as the block headers do not commit to the finalize state.
+    FinalizeMappings,
+}
+
+impl Display for UncheckedState {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::FinalizeRoots(num_blocks) => write!(f, "The finalize roots of {num_blocks} blocks"),
+            Self::PartialBlocks(num_blocks) => {
+                write!(f, "The transactions, ratifications, solutions, and authorities of {num_blocks} pruned blocks")
+            }
+            Self::FinalizeMappings => write!(f, "The finalize mappings"),
+        }
+    }
+}
+
+/// The report of an integrity check of the consensus storage.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// The number of blocks that were checked.
+    num_blocks: u32,
+    /// The issues that were found.
+    issues: Vec<IntegrityIssue>,
+    /// The state that was not checked.
+    unchecked: Vec<UncheckedState>,
+}
+
+impl IntegrityReport {
+    /// Returns the number of blocks that were checked.
+    pub const fn num_blocks(&self) -> u32 {
+        self.num_blocks
+    }
+
+    /// Returns the issues that were found.
+    pub fn issues(&self) -> &[IntegrityIssue] {
+        &self.issues
+    }
+
+    /// Returns the state that was not checked.
+    pub fn unchecked(&self) -> &[UncheckedState] {
+        &self.unchecked
+    }
+
+    /// Returns `true` if no issues were found.
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns `true` if all of the stored state was checked.
+    pub fn is_complete(&self) -> bool {
+        self.unchecked.is_empty()
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
+    /// Checks the integrity of the consensus storage, and returns the issues that were found.
+    ///
+    /// The block hashes and state roots are recomputed from the stored headers, the blocks stored in full are
+    /// reconstructed and checked against their headers, the transaction IDs are recomputed from the transactions,
+    /// the committees are checked against the block headers, and the reverse indexes are checked against the maps
+    /// they are derived from. The state that cannot be recomputed from the stored data is listed as unchecked.
+    ///
+    /// Note: The expected indexes are computed in memory, which is proportional to the size of the ledger.
+    pub fn check_integrity(&self) -> Result<IntegrityReport> {
+        let mut report = IntegrityReport::default();
+        // Check the blocks, and recompute the state roots.
+        let state_roots = self.check_blocks(&mut report)?;
+        // Note: The finalize mappings are not committed to by the block headers.
+        if self.storage.finalize_store().storage().program_id_map().keys_confirmed().next().is_some() {
+            report.unchecked.push(UncheckedState::FinalizeMappings);
+        }
+        // Check the committees.
+        self.check_committees(&mut report)?;
+        // Check the indexes.
+        self.check_indexes(&mut report, state_roots.as_deref(), false)?;
+        Ok(report)
+    }
+
+    /// Rebuilds the inconsistent indexes of the consensus storage, and returns the issues that remain.
+    /// Note: The remaining issues cannot be repaired from the stored data, and require the blocks to be synced again.
+    pub fn repair_integrity(&self) -> Result<IntegrityReport> {
+        // Recompute the state roots.
+        let state_roots = self.check_blocks(&mut IntegrityReport::default())?;
+        // Rebuild the inconsistent indexes.
+        atomic_batch_scope!(self, {
+            self.check_indexes(&mut IntegrityReport::default(), state_roots.as_deref(), true)
+        })?;
+        // Check the repaired storage.
+        self.check_integrity()
+    }
+
+    /// Checks the block hashes, the headers, and the blocks stored in full, and returns the state roots
+    /// recomputed from the block hashes, or `None` if the block hashes are inconsistent.
+    fn check_blocks(&self, report: &mut IntegrityReport) -> Result<Option<Vec<N::StateRoot>>> {
+        let storage = self.storage.block_store().storage();
+
+        // Retrieve the block hashes, in order of height.
+        let mut block_hashes = storage
+            .id_map()
+            .iter_confirmed()
+            .map(|(height, block_hash)| (cow_to_copied!(height), cow_to_copied!(block_hash)))
+            .collect::<Vec<_>>();
+        block_hashes.sort_unstable_by_key(|(height, _)| *height);
+
+        // Ensure the block hashes are stored for every height up to the latest height.
+        let num_issues = report.issues.len();
+        for (height, _) in &block_hashes {
+            report.issues.extend((report.num_blocks..*height).map(IntegrityIssue::MissingBlockHash));
+            report.num_blocks = height.saturating_add(1);
+        }
+        if report.issues.len() > num_issues {
+            return Ok(None);
+        }
+
+        let mut tree: BlockTree<N> = N::merkle_tree_bhp(&[])?;
+        let mut state_roots: Vec<N::StateRoot> = Vec::with_capacity(block_hashes.len());
+        let mut previous_hash = N::BlockHash::default();
+        let mut is_consistent = true;
+        // The number of blocks stored in full, and of blocks that are not.
+        let mut num_full_blocks = 0u32;
+        let mut num_partial_blocks = 0u32;
+
+        for (height, block_hash) in block_hashes {
+            // Check the header. Note: The blocks before a snapshot checkpoint do not store their header.
+            match storage.header_map().get_confirmed(&block_hash)? {
+                Some(header) => {
+                    // Ensure the header height matches.
+                    if header.height() != height {
+                        let reason = format!("mismatching height {}", header.height());
+                        report.issues.push(IntegrityIssue::Header(height, reason));
+                    }
+                    // Recompute the block hash from the previous block hash and the header.
+                    let candidate_hash =
+                        N::hash_bhp1024(&[previous_hash.to_bits_le(), header.to_root()?.to_bits_le()].concat())?;
+                    if N::BlockHash::from(candidate_hash) != block_hash {
+                        report.issues.push(IntegrityIssue::BlockHash(height));
+                        is_consistent = false;
+                    }
+                    // Ensure the header extends the state root of the previous block.
+                    if let Some(previous_state_root) = state_roots.last() {
+                        if header.previous_state_root() != *previous_state_root {
+                            let reason = "mismatching previous state root".to_string();
+                            report.issues.push(IntegrityIssue::Header(height, reason));
+                        }
+                    }
+                }
+                None => {
+                    if storage.transactions_map().contains_key_confirmed(&block_hash)? {
+                        report.issues.push(IntegrityIssue::Header(height, "missing header".to_string()));
+                    }
+                }
+            }
+
+            // Check the block, if it is stored in full and can be found from its block hash.
+            // Note: Otherwise, the block hash index is reported as inconsistent.
+            let is_stored_in_full = storage.transactions_map().contains_key_confirmed(&block_hash)?;
+            if is_stored_in_full && storage.reverse_id_map().get_confirmed(&block_hash)?.as_deref() == Some(&height) {
+                num_full_blocks += 1;
+                match storage.get_block(&block_hash) {
+                    Ok(Some(block)) => {
+                        let header = block.header();
+                        let mut reasons = Vec::new();
+                        // Ensure the transactions root matches.
+                        if block.transactions().to_transactions_root().ok() != Some(header.transactions_root()) {
+                            reasons.push("mismatching transactions root".to_string());
+                        }
+                        // Ensure the ratifications root matches.
+                        if block.ratifications().to_ratifications_root().ok() != Some(header.ratifications_root()) {
+                            reasons.push("mismatching ratifications root".to_string());
+                        }
+                        // Ensure the events root matches.
+                        if block.transactions().to_events_root().ok() != Some(header.events_root()) {
+                            reasons.push("mismatching events root".to_string());
+                        }
+                        // Ensure the solutions root matches.
+                        let solutions_root = match block.solutions() {
+                            Some(solutions) => solutions.to_accumulator_point().ok(),
+                            None => Some(Field::zero()),
+                        };
+                        if solutions_root != Some(header.solutions_root()) {
+                            reasons.push("mismatching solutions root".to_string());
+                        }
+                        // Ensure the subdag root matches.
+                        let subdag_root = match block.authority() {
+                            Authority::Quorum(subdag) => subdag.to_subdag_root().ok(),
+                            Authority::Beacon(_) => Some(Field::zero()),
+                        };
+                        if subdag_root != Some(header.subdag_root()) {
+                            reasons.push("mismatching subdag root".to_string());
+                        }
+                        for confirmed in block.transactions().iter() {
+                            // Ensure the transaction ID matches the transaction.
+                            let transaction = confirmed.transaction();
+                            if transaction.to_root().ok().map(N::TransactionID::from) != Some(transaction.id()) {
+                                reasons.push(format!("mismatching transaction ID '{}'", transaction.id()));
+                            }
+                            // Ensure the unconfirmed transaction ID of a rejected transaction is stored.
+                            if confirmed.is_rejected() {
+                                let is_stored = match confirmed.to_unconfirmed_transaction_id() {
+                                    Ok(transaction_id) => {
+                                        storage
+                                            .rejected_or_aborted_transaction_id_map()
+                                            .get_confirmed(&transaction_id)?
+                                            .as_deref()
+                                            == Some(&block_hash)
+                                    }
+                                    Err(_) => false,
+                                };
+                                if !is_stored {
+                                    reasons.push(format!("missing the rejected transaction '{}'", transaction.id()));
+                                }
+                            }
+                        }
+                        // Ensure the aborted transaction IDs are stored.
+                        for transaction_id in block.aborted_transaction_ids() {
+                            if storage
+                                .rejected_or_aborted_transaction_id_map()
+                                .get_confirmed(transaction_id)?
+                                .as_deref()
+                                != Some(&block_hash)
+                            {
+                                reasons.push(format!("missing the aborted transaction '{transaction_id}'"));
+                            }
+                        }
+                        report.issues.extend(reasons.into_iter().map(|reason| IntegrityIssue::Block(height, reason)));
+                    }
+                    Ok(None) => report.issues.push(IntegrityIssue::Block(height, "missing block".to_string())),
+                    Err(error) => report.issues.push(IntegrityIssue::Block(height, error.to_string())),
+                }
+            }
+
+            if !is_stored_in_full {
+                num_partial_blocks += 1;
+            }
+
+            // Append the block hash to the block tree, and compute the state root.
+            tree = tree.prepare_append(&[block_hash.to_bits_le()])?;
+            state_roots.push((*tree.root()).into());
+            previous_hash = block_hash;
+        }
+
+        // Note: The finalize roots are not recomputed, as the finalize operations of the ratifications are not stored.
+        if num_full_blocks > 0 {
+            report.unchecked.push(UncheckedState::FinalizeRoots(num_full_blocks));
+        }
+        if num_partial_blocks > 0 {
+            report.unchecked.push(UncheckedState::PartialBlocks(num_partial_blocks));
+        }
+
+        Ok(is_consistent.then_some(state_roots))
+    }
+
+    /// Checks the committees against the headers of the blocks they were stored at.
+    fn check_committees(&self, report: &mut IntegrityReport) -> Result<()> {
+        let block_storage = self.storage.block_store().storage();
+        let committee_store = self.storage.finalize_store().committee_store();
+
+        let mut latest = None;
+        for (height, committee) in committee_store.storage().committee_map().iter_confirmed() {
+            let height = cow_to_copied!(height);
+            // Retrieve the block hash.
+            let Some(block_hash) = block_storage.get_block_hash(height)? else {
+                report.issues.push(IntegrityIssue::Committee(height, "missing block".to_string()));
+                continue;
+            };
+            // Ensure the committee starts at the round of the block.
+            // Note: The blocks before a snapshot checkpoint do not store their header.
+            if let Some(header) = block_storage.header_map().get_confirmed(&block_hash)? {
+                if committee.starting_round() != header.round() {
+                    let reason = format!("mismatching starting round {}", committee.starting_round());
+                    report.issues.push(IntegrityIssue::Committee(height, reason));
+                }
+            }
+            // Track the latest committee.
+            if latest.map_or(true, |(latest_height, _)| height > latest_height) {
+                latest = Some((height, committee.starting_round()));
+            }
+        }
+
+        // Ensure the current round is the starting round of the latest committee.
+        if let Some((height, starting_round)) = latest {
+            if committee_store.current_round().ok() != Some(starting_round) {
+                report.issues.push(IntegrityIssue::Committee(height, "mismatching current round".to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the indexes against the maps they are derived from, and rebuilds the inconsistent indexes if `repair` is set.
+    /// The state root indexes are only checked if the state roots could be recomputed.
+    fn check_indexes(
+        &self,
+        report: &mut IntegrityReport,
+        state_roots: Option<&[N::StateRoot]>,
+        repair: bool,
+    ) -> Result<()> {
+        let block_storage = self.storage.block_store().storage();
+        let committee_storage = self.storage.finalize_store().committee_store().storage();
+        let transaction_storage = self.storage.transaction_store().storage();
+        let execution_storage = transaction_storage.execution_store().storage();
+        let fee_storage = transaction_storage.fee_store().storage();
+        let transition_storage = self.storage.transition_store().storage();
+        let input_storage = transition_storage.input_store().storage();
+        let output_storage = transition_storage.output_store().storage();
+
+        // Returns `true` for all the unexpected entries of an index.
+        fn always<V>(_: &V) -> Result<bool> {
+            Ok(true)
+        }
+        // Returns `true` if the given transition does not exist.
+        // Note: The transition indexes are kept when the transition is pruned.
+        let is_missing_transition = |transition_id: &N::TransitionID| -> Result<bool> {
+            Ok(!transition_storage.locator_map().contains_key_confirmed(transition_id)?)
+        };
+        // Returns `true` if the given transaction does not exist.
+        // Note: The transaction indexes of the transitions are kept when the transaction is pruned.
+        let is_missing_transaction = |transaction_id: &N::TransactionID| -> Result<bool> {
+            Ok(!transaction_storage.id_map().contains_key_confirmed(transaction_id)?)
+        };
+
+        // Check the block hash index.
+        let expected = block_storage
+            .id_map()
+            .iter_confirmed()
+            .map(|(height, block_hash)| (cow_to_copied!(block_hash), cow_to_copied!(height)))
+            .collect();
+        let map_id = MapID::Block(BlockMap::ReverseID);
+        check_index(report, map_id, block_storage.reverse_id_map(), expected, always, repair)?;
+
+        // Check the state root indexes.
+        if let Some(state_roots) = state_roots {
+            let expected = state_roots
+                .iter()
+                .enumerate()
+                .map(|(height, state_root)| Ok((u32::try_from(height)?, *state_root)))
+                .collect::<Result<IndexMap<_, _>>>()?;
+            let map_id = MapID::Block(BlockMap::StateRoot);
+            check_index(report, map_id, block_storage.state_root_map(), expected.clone(), always, repair)?;
+
+            let expected = expected.into_iter().map(|(height, state_root)| (state_root, height)).collect();
+            let map_id = MapID::Block(BlockMap::ReverseStateRoot);
+            check_index(report, map_id, block_storage.reverse_state_root_map(), expected, always, repair)?;
+        }
+
+        // Check the round to height index, where the rounds since the previous committee map to the committee height.
+        let mut committees = committee_storage
+            .committee_map()
+            .iter_confirmed()
+            .map(|(height, committee)| (cow_to_copied!(height), committee.starting_round()))
+            .collect::<Vec<_>>();
+        committees.sort_unstable_by_key(|(height, _)| *height);
+        let mut expected = IndexMap::new();
+        let mut next_round = 0;
+        for (height, starting_round) in committees {
+            expected.extend((next_round..=starting_round).map(|round| (round, height)));
+            next_round = starting_round.saturating_add(1);
+        }
+        let map_id = MapID::Committee(CommitteeMap::RoundToHeight);
+        check_index(report, map_id, committee_storage.round_to_height_map(), expected, always, repair)?;
+
+        // Check the execution transition index.
+        let mut expected = IndexMap::new();
+        for (transaction_id, ids) in execution_storage.id_map().iter_confirmed() {
+            expected.extend(ids.0.iter().map(|transition_id| (*transition_id, cow_to_copied!(transaction_id))));
+        }
+        let map_id = MapID::Execution(ExecutionMap::ReverseID);
+        check_index(report, map_id, execution_storage.reverse_id_map(), expected, is_missing_transaction, repair)?;
+
+        // Check the fee transition index.
+        let expected = fee_storage
+            .fee_map()
+            .iter_confirmed()
+            .map(|(transaction_id, fee)| (fee.0, cow_to_copied!(transaction_id)))
+            .collect();
+        let map_id = MapID::Fee(FeeMap::ReverseFee);
+        check_index(report, map_id, fee_storage.reverse_fee_map(), expected, is_missing_transaction, repair)?;
+
+        // Check the transition public key index.
+        let expected = transition_storage
+            .tpk_map()
+            .iter_confirmed()
+            .map(|(transition_id, tpk)| (cow_to_copied!(tpk), cow_to_copied!(transition_id)))
+            .collect();
+        let map_id = MapID::Transition(TransitionMap::ReverseTPK);
+        check_index(report, map_id, transition_storage.reverse_tpk_map(), expected, is_missing_transition, repair)?;
+
+        // Check the transition commitment index.
+        let expected = transition_storage
+            .tcm_map()
+            .iter_confirmed()
+            .map(|(transition_id, tcm)| (cow_to_copied!(tcm), cow_to_copied!(transition_id)))
+            .collect();
+        let map_id = MapID::Transition(TransitionMap::ReverseTCM);
+        check_index(report, map_id, transition_storage.reverse_tcm_map(), expected, is_missing_transition, repair)?;
+
+        // Check the input index.
+        let mut expected = IndexMap::new();
+        for (transition_id, input_ids) in input_storage.id_map().iter_confirmed() {
+            expected.extend(input_ids.iter().map(|input_id| (*input_id, cow_to_copied!(transition_id))));
+        }
+        let map_id = MapID::TransitionInput(TransitionInputMap::ReverseID);
+        check_index(report, map_id, input_storage.reverse_id_map(), expected, is_missing_transition, repair)?;
+
+        // Check the record tag index.
+        let expected = input_storage
+            .record_map()
+            .iter_confirmed()
+            .map(|(serial_number, tag)| (cow_to_copied!(tag), cow_to_copied!(serial_number)))
+            .collect();
+        let map_id = MapID::TransitionInput(TransitionInputMap::RecordTag);
+        check_index(report, map_id, input_storage.record_tag_map(), expected, always, repair)?;
+
+        // Check the output index.
+        let mut expected = IndexMap::new();
+        for (transition_id, output_ids) in output_storage.id_map().iter_confirmed() {
+            expected.extend(output_ids.iter().map(|output_id| (*output_id, cow_to_copied!(transition_id))));
+        }
+        let map_id = MapID::TransitionOutput(TransitionOutputMap::ReverseID);
+        check_index(report, map_id, output_storage.reverse_id_map(), expected, is_missing_transition, repair)
+    }
+}
+
+/// Checks the given index against the expected entries, and reports the missing or mismatching entries,
+/// and the stale entries. An unexpected entry is only stale if `is_stale` returns `true` for its value,
+/// as some indexes are kept when the maps they are derived from are pruned.
+/// If `repair` is set, the missing or mismatching entries are inserted, and the stale entries are removed.
+fn check_index<'a, K, V, M>(
+    report: &mut IntegrityReport,
+    map_id: MapID,
+    map: &'a M,
+    expected: IndexMap<K, V>,
+    is_stale: impl Fn(&V) -> Result<bool>,
+    repair: bool,
+) -> Result<()>
+where
+    K: 'a + Copy + Clone + PartialEq + Eq + core::hash::Hash + Serialize + Deserialize<'a> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + Deserialize<'a> + Send + Sync,
+    M: Map<'a, K, V>,
+{
+    // Retrieve the missing or mismatching entries.
+    let mut missing = Vec::new();
+    for (key, value) in &expected {
+        if map.get_confirmed(key)?.as_deref() != Some(value) {
+            missing.push((*key, value.clone()));
+        }
+    }
+    // Retrieve the stale entries.
+    let mut stale = Vec::new();
+    for (key, value) in map.iter_confirmed() {
+        if !expected.contains_key(&*key) && is_stale(&value)? {
+            stale.push(cow_to_copied!(key));
+        }
+    }
+
+    // If the index is consistent, return early.
+    if missing.is_empty() && stale.is_empty() {
+        return Ok(());
+    }
+    report.issues.push(IntegrityIssue::Index { map: map_id, missing: missing.len(), stale: stale.len() });
+
+    // Rebuild the index.
+    if repair {
+        for (key, value) in missing {
+            map.insert(key, value)?;
+        }
+        for key in &stale {
+            map.remove(key)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::memory::ConsensusMemory;
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Plaintext, ProgramID},
+    };
+    use synthesizer_program::Event;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_check_and_repair_integrity() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a consensus store with the genesis block.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        store.block_store().insert(&block).unwrap();
+
+        // Ensure the consensus store is consistent.
+        let report = store.check_integrity().unwrap();
+        assert!(report.is_consistent());
+        assert_eq!(report.num_blocks(), 1);
+        // Ensure the finalize root is reported as unchecked.
+        assert_eq!(report.unchecked(), [UncheckedState::FinalizeRoots(1)]);
+        assert!(!report.is_complete());
+
+        // Corrupt the block hash index, and the state root index.
+        let block_storage = store.block_store().storage();
+        block_storage.reverse_id_map().remove(&block.hash()).unwrap();
+        block_storage.reverse_state_root_map().insert(Field::<CurrentNetwork>::rand(rng).into(), 1).unwrap();
+        // Corrupt the transition indexes.
+        let transition_storage = store.transition_store().storage();
+        let (transition_id, tpk) = transition_storage
+            .tpk_map()
+            .iter_confirmed()
+            .map(|(transition_id, tpk)| (cow_to_copied!(transition_id), cow_to_copied!(tpk)))
+            .next()
+            .unwrap();
+        assert!(transition_storage.locator_map().contains_key_confirmed(&transition_id).unwrap());
+        transition_storage.reverse_tpk_map().remove(&tpk).unwrap();
+        transition_storage
+            .reverse_tcm_map()
+            .insert(Field::rand(rng), Field::<CurrentNetwork>::rand(rng).into())
+            .unwrap();
+
+        // Ensure the inconsistent indexes are reported.
+        let report = store.check_integrity().unwrap();
+        assert_eq!(report.issues(), [
+            IntegrityIssue::Index { map: MapID::Block(BlockMap::ReverseID), missing: 1, stale: 0 },
+            IntegrityIssue::Index { map: MapID::Block(BlockMap::ReverseStateRoot), missing: 0, stale: 1 },
+            IntegrityIssue::Index { map: MapID::Transition(TransitionMap::ReverseTPK), missing: 1, stale: 0 },
+            IntegrityIssue::Index { map: MapID::Transition(TransitionMap::ReverseTCM), missing: 0, stale: 1 },
+        ]);
+        assert!(report.issues().iter().all(IntegrityIssue::is_repairable));
+
+        // Repair the consensus store.
+        let report = store.repair_integrity().unwrap();
+        assert!(report.is_consistent());
+        assert_eq!(store.block_store().get_block_height(&block.hash()).unwrap(), Some(0));
+        assert_eq!(store.block_store().get_block(&block.hash()).unwrap(), Some(block));
+    }
+
+    #[test]
+    fn test_check_integrity_events_root() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a consensus store with the genesis block.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        store.block_store().insert(&block).unwrap();
+
+        // Store an event that is not committed to by the block header.
+        let transaction_id = *block.transaction_ids().next().unwrap();
+        let event = Event::new(
+            ProgramID::from_str("token.aleo").unwrap(),
+            Identifier::from_str("transfer").unwrap(),
+            Plaintext::from_str("{ amount: 100u64 }").unwrap(),
+        );
+        store.block_store().storage().events_map().insert(transaction_id, vec![event]).unwrap();
+
+        // Ensure the mismatching events root is reported, and is not repaired.
+        let report = store.repair_integrity().unwrap();
+        assert_eq!(report.issues(), [IntegrityIssue::Block(0, "mismatching events root".to_string())]);
+        assert!(!report.issues()[0].is_repairable());
+    }
+
+    #[test]
+    fn test_check_integrity_missing_block_hash() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a consensus store with a block hash at height 1, without the genesis block.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        store.block_store().storage().id_map().insert(1, Field::<CurrentNetwork>::rand(rng).into()).unwrap();
+
+        // Ensure the missing block hash is reported, along with the block hash index.
+        let report = store.check_integrity().unwrap();
+        assert_eq!(report.num_blocks(), 2);
+        assert_eq!(report.issues(), [IntegrityIssue::MissingBlockHash(0), IntegrityIssue::Index {
+            map: MapID::Block(BlockMap::ReverseID),
+            missing: 1,
+            stale: 0
+        },]);
+
+        // Ensure the missing block hash is not repaired.
+        let report = store.repair_integrity().unwrap();
+        assert_eq!(report.issues(), [IntegrityIssue::MissingBlockHash(0)]);
+        assert!(!report.issues()[0].is_repairable());
+    }
+}
@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod integrity;
+pub use integrity::*;
+
+mod snapshot;
+pub use snapshot::*;
+
 use crate::{
     BlockStorage,
     BlockStore,
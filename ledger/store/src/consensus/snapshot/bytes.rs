@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ConsensusSnapshot<N> {
+    /// Reads the consensus snapshot from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid consensus snapshot version"));
+        }
+
+        // Read the block hashes.
+        let block_hashes = read_vec(&mut reader)?;
+        // Read the checkpoint block.
+        let block = Block::read_le(&mut reader)?;
+        // Read the committees.
+        let committees = read_vec(&mut reader)?;
+
+        // Read the number of mappings.
+        let num_mappings = u32::read_le(&mut reader)?;
+        // Read the mappings.
+        let mut mappings = Vec::new();
+        for _ in 0..num_mappings {
+            // Read the program ID.
+            let program_id = ProgramID::read_le(&mut reader)?;
+            // Read the mapping name.
+            let mapping_name = Identifier::read_le(&mut reader)?;
+            // Read the entries.
+            let entries = read_vec(&mut reader)?;
+            mappings.push((program_id, mapping_name, entries));
+        }
+
+        // Read the deployments.
+        let deployments = read_vec(&mut reader)?;
+
+        // Read the number of transaction IDs.
+        let num_transaction_ids = u32::read_le(&mut reader)?;
+        // Read the transaction IDs.
+        let mut transaction_ids = Vec::new();
+        for _ in 0..num_transaction_ids {
+            // Read the transaction ID.
+            let transaction_id = N::TransactionID::read_le(&mut reader)?;
+            // Read the transaction type.
+            let transaction_type = match u8::read_le(&mut reader)? {
+                0 => TransactionType::Deploy,
+                1 => TransactionType::Execute,
+                2 => TransactionType::Fee,
+                variant => return Err(error(format!("Invalid transaction type variant {variant}"))),
+            };
+            transaction_ids.push((transaction_id, transaction_type));
+        }
+
+        // Read the remaining entries.
+        let rejected_or_aborted_transaction_ids = read_vec(&mut reader)?;
+        let rejected_deployments_or_executions = read_vec(&mut reader)?;
+        let certificates = read_vec(&mut reader)?;
+        let puzzle_commitments = read_vec(&mut reader)?;
+        let transition_ids = read_vec(&mut reader)?;
+        let tpks = read_vec(&mut reader)?;
+        let tcms = read_vec(&mut reader)?;
+        let input_ids = read_vec(&mut reader)?;
+        let serial_numbers = read_vec(&mut reader)?;
+        let output_ids = read_vec(&mut reader)?;
+        let commitments = read_vec(&mut reader)?;
+        let nonces = read_vec(&mut reader)?;
+
+        Ok(Self {
+            block_hashes,
+            block,
+            committees,
+            mappings,
+            deployments,
+            transaction_ids,
+            rejected_or_aborted_transaction_ids,
+            rejected_deployments_or_executions,
+            certificates,
+            puzzle_commitments,
+            transition_ids,
+            tpks,
+            tcms,
+            input_ids,
+            serial_numbers,
+            output_ids,
+            commitments,
+            nonces,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for ConsensusSnapshot<N> {
+    /// Writes the consensus snapshot to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block hashes.
+        write_vec(&self.block_hashes, &mut writer)?;
+        // Write the checkpoint block.
+        self.block.write_le(&mut writer)?;
+        // Write the committees.
+        write_vec(&self.committees, &mut writer)?;
+
+        // Write the number of mappings.
+        u32::try_from(self.mappings.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the mappings.
+        for (program_id, mapping_name, entries) in &self.mappings {
+            // Write the program ID.
+            program_id.write_le(&mut writer)?;
+            // Write the mapping name.
+            mapping_name.write_le(&mut writer)?;
+            // Write the entries.
+            write_vec(entries, &mut writer)?;
+        }
+
+        // Write the deployments.
+        write_vec(&self.deployments, &mut writer)?;
+
+        // Write the number of transaction IDs.
+        u32::try_from(self.transaction_ids.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the transaction IDs.
+        for (transaction_id, transaction_type) in &self.transaction_ids {
+            // Write the transaction ID.
+            transaction_id.write_le(&mut writer)?;
+            // Write the transaction type.
+            match transaction_type {
+                TransactionType::Deploy => 0u8.write_le(&mut writer)?,
+                TransactionType::Execute => 1u8.write_le(&mut writer)?,
+                TransactionType::Fee => 2u8.write_le(&mut writer)?,
+            }
+        }
+
+        // Write the remaining entries.
+        write_vec(&self.rejected_or_aborted_transaction_ids, &mut writer)?;
+        write_vec(&self.rejected_deployments_or_executions, &mut writer)?;
+        write_vec(&self.certificates, &mut writer)?;
+        write_vec(&self.puzzle_commitments, &mut writer)?;
+        write_vec(&self.transition_ids, &mut writer)?;
+        write_vec(&self.tpks, &mut writer)?;
+        write_vec(&self.tcms, &mut writer)?;
+        write_vec(&self.input_ids, &mut writer)?;
+        write_vec(&self.serial_numbers, &mut writer)?;
+        write_vec(&self.output_ids, &mut writer)?;
+        write_vec(&self.commitments, &mut writer)?;
+        write_vec(&self.nonces, &mut writer)
+    }
+}
+
+/// Reads a length-prefixed vector of items from the buffer.
+fn read_vec<T: FromBytes, R: Read>(mut reader: R) -> IoResult<Vec<T>> {
+    // Read the number of items.
+    let num_items = u32::read_le(&mut reader)?;
+    // Read the items.
+    (0..num_items).map(|_| T::read_le(&mut reader)).collect()
+}
+
+/// Writes a length-prefixed vector of items to the buffer.
+fn write_vec<T: ToBytes, W: Write>(items: &[T], mut writer: W) -> IoResult<()> {
+    // Write the number of items.
+    u32::try_from(items.len()).map_err(error)?.write_le(&mut writer)?;
+    // Write the items.
+    items.iter().try_for_each(|item| item.write_le(&mut writer))
+}
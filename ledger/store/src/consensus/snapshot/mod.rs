@@ -0,0 +1,412 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use super::*;
+use crate::{
+    atomic_batch_scope,
+    cow_to_cloned,
+    cow_to_copied,
+    helpers::{Map, MapRead},
+    CommitteeStorage,
+    InputStorage,
+    OutputStorage,
+    TransactionType,
+};
+use console::{
+    algorithms::Sha3_256,
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::{Field, Group},
+};
+use ledger_block::{Block, Rejected, Transaction};
+use ledger_coinbase::PuzzleCommitment;
+use ledger_committee::Committee;
+use synthesizer_program::FinalizeStoreTrait;
+
+/// A snapshot of the consensus state at a block height, which is used to initialize an empty consensus store
+/// without replaying the blocks up to that height.
+///
+/// The snapshot holds the block hashes up to the checkpoint block, the checkpoint block itself, the committees,
+/// the finalize mappings, the deployments, and the sets of IDs, serial numbers, and commitments needed to verify
+/// the blocks after the checkpoint block. The blocks before the checkpoint block are not included.
+///
+/// When the snapshot is inserted, the block hashes are checked against the checkpoint block, and the finalize
+/// mappings are checked against the given finalize checksum (see `ConsensusSnapshot::to_finalize_checksum`).
+/// The finalize checksum is the same as `FinalizeStore::get_checksum_confirmed` at the checkpoint height,
+/// so it can be cross-checked against independent nodes at that height.
+///
+/// Note: The block headers do not commit to the remaining state. A snapshot must only be loaded from a trusted
+/// source, or after checking its checksum (see `ConsensusSnapshot::to_checksum`) against a trusted checksum.
+#[allow(clippy::type_complexity)]
+#[derive(Clone, PartialEq, Eq)]
+pub struct ConsensusSnapshot<N: Network> {
+    /// The block hashes of the blocks before the checkpoint block.
+    block_hashes: Vec<N::BlockHash>,
+    /// The checkpoint block.
+    block: Block<N>,
+    /// The committees, with the block height they were stored at.
+    committees: Vec<(u32, Committee<N>)>,
+    /// The finalize mappings, with their entries in insertion order.
+    mappings: Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>,
+    /// The deployment transactions.
+    deployments: Vec<Transaction<N>>,
+    /// The transaction IDs, with their transaction types.
+    transaction_ids: Vec<(N::TransactionID, TransactionType)>,
+    /// The rejected or aborted transaction IDs, with their block hashes.
+    rejected_or_aborted_transaction_ids: Vec<(N::TransactionID, N::BlockHash)>,
+    /// The rejected deployments or executions, with their IDs.
+    rejected_deployments_or_executions: Vec<(Field<N>, Rejected<N>)>,
+    /// The certificate IDs, with their block heights and rounds.
+    certificates: Vec<(Field<N>, (u32, u64))>,
+    /// The puzzle commitments, with their block heights.
+    puzzle_commitments: Vec<(PuzzleCommitment<N>, u32)>,
+    /// The transition IDs, with their program IDs and function names.
+    transition_ids: Vec<(N::TransitionID, (ProgramID<N>, Identifier<N>))>,
+    /// The transition public keys, with their transition IDs.
+    tpks: Vec<(Group<N>, N::TransitionID)>,
+    /// The transition commitments, with their transition IDs.
+    tcms: Vec<(Field<N>, N::TransitionID)>,
+    /// The input IDs, with their transition IDs.
+    input_ids: Vec<(Field<N>, N::TransitionID)>,
+    /// The serial numbers, with their tags.
+    serial_numbers: Vec<(Field<N>, Field<N>)>,
+    /// The output IDs, with their transition IDs.
+    output_ids: Vec<(Field<N>, N::TransitionID)>,
+    /// The commitments, with their checksums.
+    commitments: Vec<(Field<N>, Field<N>)>,
+    /// The record nonces, with their commitments.
+    nonces: Vec<(Group<N>, Field<N>)>,
+}
+
+impl<N: Network> ConsensusSnapshot<N> {
+    /// Returns the height of the checkpoint block.
+    pub fn height(&self) -> u32 {
+        self.block.height()
+    }
+
+    /// Returns the checkpoint block.
+    pub const fn block(&self) -> &Block<N> {
+        &self.block
+    }
+
+    /// Returns the block hashes of the blocks before the checkpoint block.
+    pub fn block_hashes(&self) -> &[N::BlockHash] {
+        &self.block_hashes
+    }
+
+    /// Returns the checksum of the snapshot, as the SHA3-256 hash of its bytes truncated to a field element.
+    pub fn to_checksum(&self) -> Result<Field<N>> {
+        // Hash the snapshot bytes.
+        let checksum = Sha3_256::default().hash(&self.to_bytes_le()?.to_bits_le())?;
+        // Truncate the hash to a field element.
+        Field::from_bits_le(&checksum[..Field::<N>::size_in_data_bits()])
+    }
+
+    /// Returns the checksum of the finalize mappings, which matches the confirmed checksum of the finalize store.
+    pub fn to_finalize_checksum(&self) -> Result<Field<N>> {
+        crate::program::to_finalize_checksum(self.mappings.iter().flat_map(|(program_id, mapping_name, entries)| {
+            entries.iter().map(|(key, value)| ((*program_id, *mapping_name), key.clone(), value.clone()))
+        }))
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
+    /// Returns a snapshot of the consensus state at the latest block height.
+    pub fn to_snapshot(&self) -> Result<ConsensusSnapshot<N>> {
+        let block_store = self.storage.block_store();
+        let transaction_store = self.storage.transaction_store();
+        let transition_store = self.storage.transition_store();
+        let input_storage = transition_store.storage().input_store().storage();
+        let output_storage = transition_store.storage().output_store().storage();
+
+        // Retrieve the checkpoint block.
+        let height = block_store.current_block_height();
+        let block = match block_store.get_block_hash(height)? {
+            Some(block_hash) => block_store.get_block(&block_hash)?,
+            None => None,
+        };
+        let Some(block) = block else {
+            bail!("Failed to snapshot the consensus store: missing the block at height {height}")
+        };
+        // Retrieve the block hashes of the blocks before the checkpoint block.
+        let block_hashes = (0..height)
+            .map(|height| match block_store.get_block_hash(height)? {
+                Some(block_hash) => Ok(block_hash),
+                None => bail!("Failed to snapshot the consensus store: missing the block hash at height {height}"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the committees, in the order they were stored.
+        let committee_storage = self.storage.finalize_store().committee_store().storage();
+        let mut committees = to_entries(committee_storage.committee_map())?;
+        committees.sort_unstable_by_key(|(height, _)| *height);
+
+        // Retrieve the finalize mappings.
+        let finalize_store = self.storage.finalize_store();
+        let mut mappings = Vec::new();
+        for (program_id, mapping_names) in to_entries(finalize_store.storage().program_id_map())? {
+            for mapping_name in mapping_names {
+                // Retrieve the entries in insertion order, so that the mapping positions are preserved.
+                let length = finalize_store.get_mapping_length_speculative(program_id, mapping_name)?;
+                let entries = (0..length)
+                    .map(|index| match finalize_store.get_entry_at_speculative(program_id, mapping_name, index)? {
+                        Some(entry) => Ok(entry),
+                        None => bail!("Failed to snapshot '{program_id}/{mapping_name}': missing entry {index}"),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                mappings.push((program_id, mapping_name, entries));
+            }
+        }
+
+        // Retrieve the transaction IDs, and the deployment transactions.
+        let transaction_ids = to_entries(transaction_store.storage().id_map())?;
+        let deployments = transaction_ids
+            .iter()
+            .filter(|(_, transaction_type)| *transaction_type == TransactionType::Deploy)
+            .map(|(transaction_id, _)| match transaction_store.get_transaction(transaction_id)? {
+                Some(transaction) => Ok(transaction),
+                None => bail!("Failed to snapshot the consensus store: missing the deployment '{transaction_id}'"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ConsensusSnapshot {
+            block_hashes,
+            block,
+            committees,
+            mappings,
+            deployments,
+            transaction_ids,
+            rejected_or_aborted_transaction_ids: to_entries(
+                block_store.storage().rejected_or_aborted_transaction_id_map(),
+            )?,
+            rejected_deployments_or_executions: to_entries(
+                block_store.storage().rejected_deployment_or_execution_map(),
+            )?,
+            certificates: to_entries(block_store.storage().certificate_map())?,
+            puzzle_commitments: to_entries(block_store.storage().puzzle_commitments_map())?,
+            transition_ids: to_entries(transition_store.storage().locator_map())?,
+            tpks: to_entries(transition_store.storage().reverse_tpk_map())?,
+            tcms: to_entries(transition_store.storage().reverse_tcm_map())?,
+            input_ids: to_entries(input_storage.reverse_id_map())?,
+            serial_numbers: to_entries(input_storage.record_map())?,
+            output_ids: to_entries(output_storage.reverse_id_map())?,
+            commitments: to_entries(output_storage.record_map())?
+                .into_iter()
+                .map(|(commitment, (checksum, _))| (commitment, checksum))
+                .collect(),
+            nonces: to_entries(output_storage.record_nonce_map())?,
+        })
+    }
+
+    /// Initializes the consensus state of an empty consensus store from the given snapshot,
+    /// whose finalize mappings must match the given finalize checksum.
+    pub fn insert_snapshot(&self, snapshot: &ConsensusSnapshot<N>, finalize_checksum: Field<N>) -> Result<()> {
+        let block_store = self.storage.block_store();
+        let transaction_store = self.storage.transaction_store();
+        let transition_store = self.storage.transition_store();
+        let input_storage = transition_store.storage().input_store().storage();
+        let output_storage = transition_store.storage().output_store().storage();
+        let finalize_store = self.storage.finalize_store();
+
+        // Ensure the consensus store is empty.
+        if block_store.heights().next().is_some()
+            || finalize_store.storage().program_id_map().keys_confirmed().next().is_some()
+        {
+            bail!("Cannot insert a snapshot into a non-empty consensus store")
+        }
+        // Ensure the finalize mappings match the finalize checksum.
+        if snapshot.to_finalize_checksum()? != finalize_checksum {
+            bail!("Incorrect finalize checksum for the snapshot at height {}", snapshot.height())
+        }
+
+        atomic_batch_scope!(self, {
+            // Store the committees.
+            for (height, committee) in &snapshot.committees {
+                finalize_store.committee_store().insert(*height, committee.clone())?;
+            }
+            // Store the finalize mappings.
+            for (program_id, mapping_name, entries) in &snapshot.mappings {
+                finalize_store.initialize_mapping(*program_id, *mapping_name)?;
+                for (key, value) in entries {
+                    finalize_store.insert_key_value(*program_id, *mapping_name, key.clone(), value.clone())?;
+                }
+            }
+
+            // Store the transaction IDs.
+            insert_entries(transaction_store.storage().id_map(), &snapshot.transaction_ids)?;
+            // Store the deployment transactions.
+            for transaction in &snapshot.deployments {
+                transaction_store.insert(transaction)?;
+            }
+            // Store the rejected or aborted transaction IDs.
+            insert_entries(
+                block_store.storage().rejected_or_aborted_transaction_id_map(),
+                &snapshot.rejected_or_aborted_transaction_ids,
+            )?;
+            // Store the rejected deployments or executions.
+            insert_entries(
+                block_store.storage().rejected_deployment_or_execution_map(),
+                &snapshot.rejected_deployments_or_executions,
+            )?;
+            // Store the certificate IDs.
+            insert_entries(block_store.storage().certificate_map(), &snapshot.certificates)?;
+            // Store the puzzle commitments.
+            insert_entries(block_store.storage().puzzle_commitments_map(), &snapshot.puzzle_commitments)?;
+
+            // Store the transition IDs.
+            insert_entries(transition_store.storage().locator_map(), &snapshot.transition_ids)?;
+            // Store the transition public keys.
+            insert_entries(transition_store.storage().reverse_tpk_map(), &snapshot.tpks)?;
+            // Store the transition commitments.
+            insert_entries(transition_store.storage().reverse_tcm_map(), &snapshot.tcms)?;
+
+            // Store the input IDs.
+            insert_entries(input_storage.reverse_id_map(), &snapshot.input_ids)?;
+            // Store the serial numbers and tags.
+            for (serial_number, tag) in &snapshot.serial_numbers {
+                input_storage.record_map().insert(*serial_number, *tag)?;
+                input_storage.record_tag_map().insert(*tag, *serial_number)?;
+            }
+            // Store the output IDs.
+            insert_entries(output_storage.reverse_id_map(), &snapshot.output_ids)?;
+            // Store the commitments, without their record ciphertexts.
+            for (commitment, checksum) in &snapshot.commitments {
+                output_storage.record_map().insert(*commitment, (*checksum, None))?;
+            }
+            // Store the record nonces.
+            insert_entries(output_storage.record_nonce_map(), &snapshot.nonces)?;
+
+            // Store the block hashes and the checkpoint block.
+            block_store.insert_checkpoint(&snapshot.block_hashes, &snapshot.block)
+        })
+    }
+}
+
+/// Returns the confirmed entries of the given map, sorted by key bytes, so that the order is independent of the storage.
+fn to_entries<'a, K, V, M>(map: &'a M) -> Result<Vec<(K, V)>>
+where
+    K: 'a + Copy + Clone + PartialEq + Eq + core::hash::Hash + Serialize + Deserialize<'a> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + Deserialize<'a> + Send + Sync,
+    K: ToBytes,
+    M: Map<'a, K, V>,
+{
+    let mut entries = map
+        .iter_confirmed()
+        .map(|(key, value)| Ok((key.to_bytes_le()?, (cow_to_copied!(key), cow_to_cloned!(value)))))
+        .collect::<Result<Vec<_>>>()?;
+    entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(entries.into_iter().map(|(_, entry)| entry).collect())
+}
+
+/// Stores the given entries in the given map.
+fn insert_entries<'a, K, V, M>(map: &'a M, entries: &[(K, V)]) -> Result<()>
+where
+    K: 'a + Copy + Clone + PartialEq + Eq + core::hash::Hash + Serialize + Deserialize<'a> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + Deserialize<'a> + Send + Sync,
+    M: Map<'a, K, V>,
+{
+    entries.iter().try_for_each(|(key, value)| map.insert(*key, value.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        kv::{
+            internal::tests::{temp_dir, MemoryEngine},
+            ConsensusKV,
+        },
+        memory::ConsensusMemory,
+    };
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Checks that a snapshot of a consensus store initializes an empty consensus store to the same state.
+    fn check_snapshot_round_trip<C: ConsensusStorage<CurrentNetwork>>(open: impl Fn() -> C) {
+        let rng = &mut TestRng::default();
+
+        // Initialize a consensus store with the genesis block.
+        let store = ConsensusStore::<CurrentNetwork, C>::from(open());
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        store.block_store().insert(&block).unwrap();
+
+        // Store a finalize mapping.
+        let program_id = ProgramID::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let finalize_store = store.finalize_store();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for i in 0..3u64 {
+            let key = Plaintext::from_str(&format!("{i}field")).unwrap();
+            let value = Value::from_str(&format!("{i}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+
+        // Snapshot the consensus store.
+        let snapshot = store.to_snapshot().unwrap();
+        assert_eq!(snapshot.height(), 0);
+        assert_eq!(snapshot.block(), &block);
+        assert!(snapshot.block_hashes().is_empty());
+        let finalize_checksum = snapshot.to_finalize_checksum().unwrap();
+        assert_eq!(finalize_checksum, finalize_store.get_checksum_confirmed().unwrap());
+
+        // Check the byte serialization.
+        let bytes = snapshot.to_bytes_le().unwrap();
+        let candidate = ConsensusSnapshot::<CurrentNetwork>::from_bytes_le(&bytes).unwrap();
+        assert!(snapshot == candidate);
+        assert_eq!(snapshot.to_checksum().unwrap(), candidate.to_checksum().unwrap());
+
+        // Ensure the snapshot is not inserted with an incorrect finalize checksum.
+        let candidate_store = ConsensusStore::<CurrentNetwork, C>::from(open());
+        assert!(candidate_store.insert_snapshot(&snapshot, finalize_checksum + Field::one()).is_err());
+        assert!(candidate_store.block_store().heights().next().is_none());
+
+        // Initialize the new consensus store from the snapshot.
+        candidate_store.insert_snapshot(&snapshot, finalize_checksum).unwrap();
+        assert_eq!(candidate_store.block_store().current_state_root(), store.block_store().current_state_root());
+        assert_eq!(candidate_store.block_store().get_block(&block.hash()).unwrap(), Some(block));
+        assert_eq!(candidate_store.finalize_store().get_checksum_confirmed().unwrap(), finalize_checksum);
+        assert!(candidate_store.to_snapshot().unwrap() == snapshot);
+
+        // Ensure the snapshot cannot be inserted into a non-empty consensus store.
+        assert!(candidate_store.insert_snapshot(&snapshot, finalize_checksum).is_err());
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        check_snapshot_round_trip(|| ConsensusMemory::open(None).unwrap());
+    }
+
+    #[test]
+    fn test_snapshot_round_trip_kv() {
+        check_snapshot_round_trip(|| ConsensusKV::<_, MemoryEngine>::open_testing(temp_dir(), None).unwrap());
+    }
+
+    #[cfg(feature = "redb")]
+    #[test]
+    fn test_snapshot_round_trip_redb() {
+        use crate::helpers::kv::RedbEngine;
+
+        check_snapshot_round_trip(|| ConsensusKV::<_, RedbEngine>::open_testing(temp_dir(), None).unwrap());
+    }
+
+    #[cfg(feature = "rocks")]
+    #[test]
+    fn test_snapshot_round_trip_rocksdb() {
+        use crate::helpers::rocksdb::ConsensusDB;
+
+        check_snapshot_round_trip(|| ConsensusDB::open_testing(temp_dir(), None).unwrap());
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-/// The RocksDB map prefix broken down into the entry category and the specific type of the entry.
+/// The storage map prefix broken down into the entry category and the specific type of the entry.
 // Note: the order of these variants can be changed at any point in time.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u16)]
@@ -52,7 +52,7 @@ impl From<MapID> for u16 {
     }
 }
 
-/// The RocksDB map prefix for BFT-related entries.
+/// The storage map prefix for BFT-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -61,7 +61,7 @@ pub enum BFTMap {
     Transmissions = DataID::BFTTransmissionsMap as u16,
 }
 
-/// The RocksDB map prefix for block-related entries.
+/// The storage map prefix for block-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -82,9 +82,11 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    Events = DataID::BlockEventsMap as u16,
+    OldestHeight = DataID::BlockOldestHeightMap as u16,
 }
 
-/// The RocksDB map prefix for committee-related entries.
+/// The storage map prefix for committee-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -95,7 +97,7 @@ pub enum CommitteeMap {
     Committee = DataID::CommitteeMap as u16,
 }
 
-/// The RocksDB map prefix for deployment-related entries.
+/// The storage map prefix for deployment-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -110,7 +112,7 @@ pub enum DeploymentMap {
     Certificate = DataID::DeploymentCertificateMap as u16,
 }
 
-/// The RocksDB map prefix for execution-related entries.
+/// The storage map prefix for execution-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -121,7 +123,7 @@ pub enum ExecutionMap {
     Inclusion = DataID::ExecutionInclusionMap as u16,
 }
 
-/// The RocksDB map prefix for fee-related entries.
+/// The storage map prefix for fee-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -131,7 +133,7 @@ pub enum FeeMap {
     ReverseFee = DataID::FeeReverseFeeMap as u16,
 }
 
-/// The RocksDB map prefix for transition input entries.
+/// The storage map prefix for transition input entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -147,7 +149,7 @@ pub enum TransitionInputMap {
     ExternalRecord = DataID::InputExternalRecordMap as u16,
 }
 
-/// The RocksDB map prefix for transition output entries.
+/// The storage map prefix for transition output entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -162,9 +164,10 @@ pub enum TransitionOutputMap {
     RecordNonce = DataID::OutputRecordNonceMap as u16,
     ExternalRecord = DataID::OutputExternalRecordMap as u16,
     Future = DataID::OutputFutureMap as u16,
+    Memo = DataID::OutputMemoMap as u16,
 }
 
-/// The RocksDB map prefix for transaction-related entries.
+/// The storage map prefix for transaction-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -173,7 +176,7 @@ pub enum TransactionMap {
     ID = DataID::TransactionIDMap as u16,
 }
 
-/// The RocksDB map prefix for transition-related entries.
+/// The storage map prefix for transition-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -184,9 +187,10 @@ pub enum TransitionMap {
     ReverseTPK = DataID::TransitionReverseTPKMap as u16,
     TCM = DataID::TransitionTCMMap as u16,
     ReverseTCM = DataID::TransitionReverseTCMMap as u16,
+    Expiration = DataID::TransitionExpirationMap as u16,
 }
 
-/// The RocksDB map prefix for program-related entries.
+/// The storage map prefix for program-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -194,9 +198,14 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    Length = DataID::MappingLengthMap as u16,
+    KeyPosition = DataID::MappingKeyPositionMap as u16,
+    PositionKey = DataID::MappingPositionKeyMap as u16,
+    History = DataID::FinalizeHistoryMap as u16,
+    HistoryHeights = DataID::FinalizeHistoryHeightsMap as u16,
 }
 
-/// The RocksDB map prefix for test-related entries.
+/// The storage map prefix for test-related entries.
 // Note: the order of these variants can be changed at any point in time.
 #[cfg(test)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -209,7 +218,7 @@ pub enum TestMap {
     Test5 = DataID::Test5 as u16,
 }
 
-/// The RocksDB map prefix.
+/// The storage map prefix.
 // Note: the order of these variants can NOT be changed once the database is populated:
 // - any new variant MUST be added as the last one (ignoring the Test one)
 // - any deprecated variant MUST remain in its position (it can't be removed)
@@ -285,6 +294,15 @@ enum DataID {
     // TODO (howardwu): For mainnet - Reorder this up above.
     BlockRejectedDeploymentOrExecutionMap,
     BFTTransmissionsMap,
+    BlockEventsMap,
+    OutputMemoMap,
+    TransitionExpirationMap,
+    MappingLengthMap,
+    MappingKeyPositionMap,
+    MappingPositionKeyMap,
+    FinalizeHistoryMap,
+    FinalizeHistoryHeightsMap,
+    BlockOldestHeightMap,
 
     // Testing
     #[cfg(test)]
@@ -0,0 +1,236 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::kv::{BlockMap, DataMap, Engine, KVDatabase, MapID, TransactionKV, TransitionKV},
+    BlockStorage,
+    ConfirmedTxType,
+    TransactionStore,
+    TransitionStore,
+};
+use console::{prelude::*, types::Field};
+use ledger_authority::Authority;
+use ledger_block::{Header, Ratifications, Rejected};
+use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
+use synthesizer_program::Event;
+
+/// A block storage over a key-value engine.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct BlockKV<N: Network, E: Engine> {
+    /// The mapping of `block height` to `state root`.
+    state_root_map: DataMap<E, u32, N::StateRoot>,
+    /// The mapping of `state root` to `block height`.
+    reverse_state_root_map: DataMap<E, N::StateRoot, u32>,
+    /// The mapping of `block height` to `block hash`.
+    id_map: DataMap<E, u32, N::BlockHash>,
+    /// The mapping of `block hash` to `block height`.
+    reverse_id_map: DataMap<E, N::BlockHash, u32>,
+    /// The header map.
+    header_map: DataMap<E, N::BlockHash, Header<N>>,
+    /// The authority map.
+    authority_map: DataMap<E, N::BlockHash, Authority<N>>,
+    /// The certificate map.
+    certificate_map: DataMap<E, Field<N>, (u32, u64)>,
+    /// The ratifications map.
+    ratifications_map: DataMap<E, N::BlockHash, Ratifications<N>>,
+    /// The solutions map.
+    solutions_map: DataMap<E, N::BlockHash, Option<CoinbaseSolution<N>>>,
+    /// The puzzle commitments map.
+    puzzle_commitments_map: DataMap<E, PuzzleCommitment<N>, u32>,
+    /// The transactions map.
+    transactions_map: DataMap<E, N::BlockHash, Vec<N::TransactionID>>,
+    /// The aborted transaction IDs map.
+    aborted_transaction_ids_map: DataMap<E, N::BlockHash, Vec<N::TransactionID>>,
+    /// The rejected or aborted transaction ID map.
+    rejected_or_aborted_transaction_id_map: DataMap<E, N::TransactionID, N::BlockHash>,
+    /// The confirmed transactions map.
+    confirmed_transactions_map: DataMap<E, N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>,
+    /// The rejected deployment or execution map.
+    rejected_deployment_or_execution_map: DataMap<E, Field<N>, Rejected<N>>,
+    /// The events map.
+    events_map: DataMap<E, N::TransactionID, Vec<Event<N>>>,
+    /// The oldest height map.
+    oldest_height_map: DataMap<E, u8, u32>,
+    /// The transaction store.
+    transaction_store: TransactionStore<N, TransactionKV<N, E>>,
+    /// The number of recent blocks to keep in full, if pruning is enabled.
+    pruning_depth: Option<u32>,
+}
+
+impl<N: Network, E: Engine> BlockKV<N, E> {
+    /// Initializes the block storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>) -> Self {
+        // Initialize the transition store.
+        let transition_store = TransitionStore::from(TransitionKV::open_in(database));
+        // Initialize the transaction store.
+        let transaction_store = TransactionStore::from(TransactionKV::open_in(database, transition_store));
+        // Return the block storage.
+        Self {
+            state_root_map: database.map(MapID::Block(BlockMap::StateRoot)),
+            reverse_state_root_map: database.map(MapID::Block(BlockMap::ReverseStateRoot)),
+            id_map: database.map(MapID::Block(BlockMap::ID)),
+            reverse_id_map: database.map(MapID::Block(BlockMap::ReverseID)),
+            header_map: database.map(MapID::Block(BlockMap::Header)),
+            authority_map: database.map(MapID::Block(BlockMap::Authority)),
+            certificate_map: database.map(MapID::Block(BlockMap::Certificate)),
+            ratifications_map: database.map(MapID::Block(BlockMap::Ratifications)),
+            solutions_map: database.map(MapID::Block(BlockMap::Solutions)),
+            puzzle_commitments_map: database.map(MapID::Block(BlockMap::PuzzleCommitments)),
+            transactions_map: database.map(MapID::Block(BlockMap::Transactions)),
+            aborted_transaction_ids_map: database.map(MapID::Block(BlockMap::AbortedTransactionIDs)),
+            rejected_or_aborted_transaction_id_map: database
+                .map(MapID::Block(BlockMap::RejectedOrAbortedTransactionID)),
+            confirmed_transactions_map: database.map(MapID::Block(BlockMap::ConfirmedTransactions)),
+            rejected_deployment_or_execution_map: database.map(MapID::Block(BlockMap::RejectedDeploymentOrExecution)),
+            events_map: database.map(MapID::Block(BlockMap::Events)),
+            oldest_height_map: database.map(MapID::Block(BlockMap::OldestHeight)),
+            transaction_store,
+            pruning_depth: None,
+        }
+    }
+
+    /// Initializes the block storage, pruning the bodies of blocks older than the given depth.
+    pub fn open_with_pruning(dev: Option<u16>, pruning_depth: u32) -> Result<Self> {
+        // Ensure the latest block is kept in full.
+        ensure!(pruning_depth > 0, "The pruning depth must be at least 1");
+        // Initialize the block storage.
+        let storage = Self::open(dev)?;
+        // Return the block storage.
+        Ok(Self { pruning_depth: Some(pruning_depth), ..storage })
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> BlockStorage<N> for BlockKV<N, E> {
+    type StateRootMap = DataMap<E, u32, N::StateRoot>;
+    type ReverseStateRootMap = DataMap<E, N::StateRoot, u32>;
+    type IDMap = DataMap<E, u32, N::BlockHash>;
+    type ReverseIDMap = DataMap<E, N::BlockHash, u32>;
+    type HeaderMap = DataMap<E, N::BlockHash, Header<N>>;
+    type AuthorityMap = DataMap<E, N::BlockHash, Authority<N>>;
+    type CertificateMap = DataMap<E, Field<N>, (u32, u64)>;
+    type RatificationsMap = DataMap<E, N::BlockHash, Ratifications<N>>;
+    type SolutionsMap = DataMap<E, N::BlockHash, Option<CoinbaseSolution<N>>>;
+    type PuzzleCommitmentsMap = DataMap<E, PuzzleCommitment<N>, u32>;
+    type TransactionsMap = DataMap<E, N::BlockHash, Vec<N::TransactionID>>;
+    type AbortedTransactionIDsMap = DataMap<E, N::BlockHash, Vec<N::TransactionID>>;
+    type RejectedOrAbortedTransactionIDMap = DataMap<E, N::TransactionID, N::BlockHash>;
+    type ConfirmedTransactionsMap = DataMap<E, N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
+    type RejectedDeploymentOrExecutionMap = DataMap<E, Field<N>, Rejected<N>>;
+    type EventsMap = DataMap<E, N::TransactionID, Vec<Event<N>>>;
+    type OldestHeightMap = DataMap<E, u8, u32>;
+    type TransactionStorage = TransactionKV<N, E>;
+    type TransitionStorage = TransitionKV<N, E>;
+
+    /// Initializes the block storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self::open_in(&KVDatabase::open(N::ID, dev)?))
+    }
+
+    /// Returns the state root map.
+    fn state_root_map(&self) -> &Self::StateRootMap {
+        &self.state_root_map
+    }
+
+    /// Returns the reverse state root map.
+    fn reverse_state_root_map(&self) -> &Self::ReverseStateRootMap {
+        &self.reverse_state_root_map
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the header map.
+    fn header_map(&self) -> &Self::HeaderMap {
+        &self.header_map
+    }
+
+    /// Returns the authority map.
+    fn authority_map(&self) -> &Self::AuthorityMap {
+        &self.authority_map
+    }
+
+    /// Returns the certificate map.
+    fn certificate_map(&self) -> &Self::CertificateMap {
+        &self.certificate_map
+    }
+
+    /// Returns the ratifications map.
+    fn ratifications_map(&self) -> &Self::RatificationsMap {
+        &self.ratifications_map
+    }
+
+    /// Returns the solutions map.
+    fn solutions_map(&self) -> &Self::SolutionsMap {
+        &self.solutions_map
+    }
+
+    /// Returns the puzzle commitments map.
+    fn puzzle_commitments_map(&self) -> &Self::PuzzleCommitmentsMap {
+        &self.puzzle_commitments_map
+    }
+
+    /// Returns the transactions map.
+    fn transactions_map(&self) -> &Self::TransactionsMap {
+        &self.transactions_map
+    }
+
+    /// Returns the aborted transaction IDs map.
+    fn aborted_transaction_ids_map(&self) -> &Self::AbortedTransactionIDsMap {
+        &self.aborted_transaction_ids_map
+    }
+
+    /// Returns the rejected transaction ID or aborted transaction ID map.
+    fn rejected_or_aborted_transaction_id_map(&self) -> &Self::RejectedOrAbortedTransactionIDMap {
+        &self.rejected_or_aborted_transaction_id_map
+    }
+
+    /// Returns the confirmed transactions map.
+    fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap {
+        &self.confirmed_transactions_map
+    }
+
+    /// Returns the rejected deployment or execution map.
+    fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap {
+        &self.rejected_deployment_or_execution_map
+    }
+
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap {
+        &self.events_map
+    }
+
+    /// Returns the oldest height map.
+    fn oldest_height_map(&self) -> &Self::OldestHeightMap {
+        &self.oldest_height_map
+    }
+
+    /// Returns the transaction store.
+    fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
+        &self.transaction_store
+    }
+
+    /// Returns the number of recent blocks to keep in full, or `None` if the blocks are never pruned.
+    fn pruning_depth(&self) -> Option<u32> {
+        self.pruning_depth
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::kv::{BlockKV, Engine, FinalizeKV, KVDatabase, TransactionKV, TransitionKV},
+    BlockStore,
+    ConsensusStorage,
+    FinalizeStore,
+};
+use console::prelude::*;
+
+/// A consensus storage over a key-value engine.
+#[derive(Clone)]
+pub struct ConsensusKV<N: Network, E: Engine> {
+    /// The finalize store.
+    finalize_store: FinalizeStore<N, FinalizeKV<N, E>>,
+    /// The block store.
+    block_store: BlockStore<N, BlockKV<N, E>>,
+}
+
+impl<N: Network, E: Engine> ConsensusKV<N, E> {
+    /// Initializes the consensus storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>) -> Result<Self> {
+        // Initialize the finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeKV::open_in(database))?;
+        // Initialize the block store.
+        let block_store = BlockStore::from(BlockKV::open_in(database))?;
+        // Return the consensus storage.
+        Ok(Self { finalize_store, block_store })
+    }
+
+    /// Initializes the test-variant of the consensus storage.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        Self::open_in(&KVDatabase::open_testing(temp_dir, dev)?)
+    }
+
+    /// Initializes the consensus storage, pruning the bodies of blocks older than the given depth.
+    pub fn open_with_pruning(dev: Option<u16>, pruning_depth: u32) -> Result<Self> {
+        // Initialize the finalize store.
+        let finalize_store = FinalizeStore::<N, FinalizeKV<N, E>>::open(dev)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockKV<N, E>>::from(BlockKV::open_with_pruning(dev, pruning_depth)?)?;
+        // Return the consensus storage.
+        Ok(Self { finalize_store, block_store })
+    }
+
+    /// Initializes the consensus storage, recording the finalize history of the given number of recent blocks.
+    pub fn open_with_history(dev: Option<u16>, history_window: u32) -> Result<Self> {
+        // Initialize the finalize store.
+        let finalize_store =
+            FinalizeStore::<N, FinalizeKV<N, E>>::from(FinalizeKV::open_with_history(dev, history_window)?)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockKV<N, E>>::open(dev)?;
+        // Return the consensus storage.
+        Ok(Self { finalize_store, block_store })
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> ConsensusStorage<N> for ConsensusKV<N, E> {
+    type FinalizeStorage = FinalizeKV<N, E>;
+    type BlockStorage = BlockKV<N, E>;
+    type TransactionStorage = TransactionKV<N, E>;
+    type TransitionStorage = TransitionKV<N, E>;
+
+    /// Initializes the consensus storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Self::open_in(&KVDatabase::open(N::ID, dev)?)
+    }
+
+    /// Returns the finalize store.
+    fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage> {
+        &self.finalize_store
+    }
+
+    /// Returns the block store.
+    fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
+        &self.block_store
+    }
+}
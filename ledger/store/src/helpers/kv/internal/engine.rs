@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::KVDatabase;
+
+use anyhow::Result;
+
+/// An iterator over the raw key-value pairs of an engine, in ascending key order.
+pub type EngineIterator<'a> = Box<dyn Iterator<Item = Result<(Vec<u8>, Vec<u8>)>> + 'a>;
+
+/// An ordered key-value engine with atomic write batches, which can back any of the storages.
+pub trait Engine: Clone + Send + Sync + 'static {
+    /// Opens the database for the given network ID and (optional) development ID.
+    ///
+    /// Every call with the same arguments must return a clone of the same database handle,
+    /// so that all the maps of a storage share a single atomic write batch.
+    fn open(network_id: u16, dev: Option<u16>) -> Result<KVDatabase<Self>>;
+
+    /// Opens a new engine at the given path, for testing.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(path: std::path::PathBuf) -> Result<Self>;
+
+    /// Returns the value for the given key, if it exists.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>>;
+
+    /// Returns an iterator over the key-value pairs whose key starts with the given prefix, in ascending key order.
+    fn iter_prefix(&self, prefix: &[u8]) -> EngineIterator<'_>;
+
+    /// Applies all the operations in the given batch atomically, in order.
+    fn write(&self, batch: WriteBatch) -> Result<()>;
+}
+
+/// A single operation in a write batch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WriteOperation {
+    /// Inserts the given key-value pair.
+    Put(Vec<u8>, Vec<u8>),
+    /// Removes the given key.
+    Delete(Vec<u8>),
+    /// Removes every key starting with the given prefix.
+    DeletePrefix(Vec<u8>),
+}
+
+/// A list of write operations that an engine applies atomically, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WriteBatch {
+    /// The queued operations.
+    operations: Vec<WriteOperation>,
+}
+
+impl WriteBatch {
+    /// Queues the insertion of the given key-value pair.
+    pub fn put(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        self.operations.push(WriteOperation::Put(key, value));
+    }
+
+    /// Queues the removal of the given key.
+    pub fn delete(&mut self, key: Vec<u8>) {
+        self.operations.push(WriteOperation::Delete(key));
+    }
+
+    /// Queues the removal of every key starting with the given prefix.
+    pub fn delete_prefix(&mut self, prefix: Vec<u8>) {
+        self.operations.push(WriteOperation::DeletePrefix(prefix));
+    }
+
+    /// Returns the number of queued operations.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns `true` if there are no queued operations.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Removes all the queued operations.
+    pub fn clear(&mut self) {
+        self.operations.clear()
+    }
+}
+
+impl IntoIterator for WriteBatch {
+    type IntoIter = std::vec::IntoIter<WriteOperation>;
+    type Item = WriteOperation;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.operations.into_iter()
+    }
+}
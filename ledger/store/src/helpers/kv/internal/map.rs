@@ -19,24 +19,24 @@ use crate::helpers::{Map, MapRead};
 
 use core::{fmt, fmt::Debug, hash::Hash, mem};
 use indexmap::IndexMap;
-use std::{borrow::Cow, ops::Deref, sync::atomic::Ordering};
+use std::{borrow::Cow, sync::atomic::Ordering};
 use tracing::error;
 
 #[derive(Clone)]
-pub struct DataMap<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>(
-    pub(super) Arc<InnerDataMap<K, V>>,
+pub struct DataMap<E: Engine, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>(
+    pub(super) Arc<InnerDataMap<E, K, V>>,
 );
 
-impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> Deref for DataMap<K, V> {
-    type Target = InnerDataMap<K, V>;
+impl<E: Engine, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> Deref for DataMap<E, K, V> {
+    type Target = InnerDataMap<E, K, V>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-pub struct InnerDataMap<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> {
-    pub(super) database: RocksDB,
+pub struct InnerDataMap<E: Engine, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> {
+    pub(super) database: KVDatabase<E>,
     pub(super) context: Vec<u8>,
     /// The tracker for whether a database transaction is in progress.
     pub(super) batch_in_progress: AtomicBool,
@@ -48,9 +48,10 @@ pub struct InnerDataMap<K: Serialize + DeserializeOwned, V: Serialize + Deserial
 
 impl<
     'a,
+    E: Engine,
     K: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
-> Map<'a, K, V> for DataMap<K, V>
+> Map<'a, K, V> for DataMap<E, K, V>
 {
     ///
     /// Inserts the given key-value pair into the map.
@@ -65,9 +66,9 @@ impl<
             // Otherwise, insert the key-value pair directly into the map.
             false => {
                 // Prepare the prefixed key and serialized value.
-                let raw_key = self.create_prefixed_key(&key)?;
-                let raw_value = bincode::serialize(&value)?;
-                self.database.put(raw_key, raw_value)?;
+                let mut batch = WriteBatch::default();
+                batch.put(self.create_prefixed_key(&key)?, bincode::serialize(&value)?);
+                self.database.write(batch)?;
             }
         }
 
@@ -87,8 +88,9 @@ impl<
             // Otherwise, remove the key-value pair directly from the map.
             false => {
                 // Prepare the prefixed key.
-                let raw_key = self.create_prefixed_key(key)?;
-                self.database.delete(raw_key)?;
+                let mut batch = WriteBatch::default();
+                batch.delete(self.create_prefixed_key(key)?);
+                self.database.write(batch)?;
             }
         }
 
@@ -222,7 +224,7 @@ impl<
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
             // Execute all the operations atomically.
-            self.database.rocksdb.write(batch)?;
+            self.database.write(batch)?;
             // Ensure that the database atomic batch is empty.
             assert!(self.database.atomic_batch.lock().is_empty());
         }
@@ -233,9 +235,10 @@ impl<
 
 impl<
     'a,
+    E: Engine,
     K: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
-> MapRead<'a, K, V> for DataMap<K, V>
+> MapRead<'a, K, V> for DataMap<E, K, V>
 {
     type Iterator = Iter<'a, K, V>;
     type Keys = Keys<'a, K>;
@@ -327,21 +330,21 @@ impl<
     /// Returns an iterator visiting each key-value pair in the map.
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator {
-        Iter::new(self.database.prefix_iterator(&self.context))
+        Iter::new(self.database.iter_prefix(&self.context))
     }
 
     ///
     /// Returns an iterator over each key in the map.
     ///
     fn keys_confirmed(&'a self) -> Self::Keys {
-        Keys::new(self.database.prefix_iterator(&self.context))
+        Keys::new(self.database.iter_prefix(&self.context))
     }
 
     ///
     /// Returns an iterator over each value in the map.
     ///
     fn values_confirmed(&'a self) -> Self::Values {
-        Values::new(self.database.prefix_iterator(&self.context))
+        Values::new(self.database.iter_prefix(&self.context))
     }
 }
 
@@ -351,7 +354,7 @@ pub struct Iter<
     K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
 > {
-    db_iter: rocksdb::DBIterator<'a>,
+    db_iter: EngineIterator<'a>,
     _phantom: PhantomData<(K, V)>,
 }
 
@@ -361,7 +364,7 @@ impl<
     V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
 > Iter<'a, K, V>
 {
-    pub(super) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
+    pub(super) fn new(db_iter: EngineIterator<'a>) -> Self {
         Self { db_iter, _phantom: PhantomData }
     }
 }
@@ -379,19 +382,19 @@ impl<
             .db_iter
             .next()?
             .map_err(|e| {
-                error!("RocksDB Iter iterator error: {e}");
+                error!("KV Iter iterator error: {e}");
             })
             .ok()?;
 
         // Deserialize the key and value.
         let key = bincode::deserialize(&key[PREFIX_LEN..])
             .map_err(|e| {
-                error!("RocksDB Iter deserialize(key) error: {e}");
+                error!("KV Iter deserialize(key) error: {e}");
             })
             .ok()?;
         let value = bincode::deserialize(&value)
             .map_err(|e| {
-                error!("RocksDB Iter deserialize(value) error: {e}");
+                error!("KV Iter deserialize(value) error: {e}");
             })
             .ok()?;
 
@@ -401,12 +404,12 @@ impl<
 
 /// An iterator over the keys of a prefix.
 pub struct Keys<'a, K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned> {
-    db_iter: rocksdb::DBIterator<'a>,
+    db_iter: EngineIterator<'a>,
     _phantom: PhantomData<K>,
 }
 
 impl<'a, K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned> Keys<'a, K> {
-    pub(crate) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
+    pub(crate) fn new(db_iter: EngineIterator<'a>) -> Self {
         Self { db_iter, _phantom: PhantomData }
     }
 }
@@ -419,14 +422,14 @@ impl<'a, K: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + Deserialize
             .db_iter
             .next()?
             .map_err(|e| {
-                error!("RocksDB Keys iterator error: {e}");
+                error!("KV Keys iterator error: {e}");
             })
             .ok()?;
 
         // Deserialize the key.
         let key = bincode::deserialize(&key[PREFIX_LEN..])
             .map_err(|e| {
-                error!("RocksDB Keys deserialize(key) error: {e}");
+                error!("KV Keys deserialize(key) error: {e}");
             })
             .ok()?;
 
@@ -436,12 +439,12 @@ impl<'a, K: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + Deserialize
 
 /// An iterator over the values of a prefix.
 pub struct Values<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> {
-    db_iter: rocksdb::DBIterator<'a>,
+    db_iter: EngineIterator<'a>,
     _phantom: PhantomData<V>,
 }
 
 impl<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> Values<'a, V> {
-    pub(crate) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
+    pub(crate) fn new(db_iter: EngineIterator<'a>) -> Self {
         Self { db_iter, _phantom: PhantomData }
     }
 }
@@ -454,14 +457,14 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
             .db_iter
             .next()?
             .map_err(|e| {
-                error!("RocksDB Values iterator error: {e}");
+                error!("KV Values iterator error: {e}");
             })
             .ok()?;
 
         // Deserialize the value.
         let value = bincode::deserialize(&value)
             .map_err(|e| {
-                error!("RocksDB Values deserialize(value) error: {e}");
+                error!("KV Values deserialize(value) error: {e}");
             })
             .ok()?;
 
@@ -469,7 +472,7 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
     }
 }
 
-impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K, V> {
+impl<E: Engine, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<E, K, V> {
     #[inline]
     fn create_prefixed_key<Q>(&self, key: &Q) -> Result<Vec<u8>>
     where
@@ -481,32 +484,29 @@ impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K
         Ok(raw_key)
     }
 
-    fn get_raw<Q>(&self, key: &Q) -> Result<Option<rocksdb::DBPinnableSlice>>
+    fn get_raw<Q>(&self, key: &Q) -> Result<Option<Vec<u8>>>
     where
         K: Borrow<Q>,
         Q: Serialize + ?Sized,
     {
         let raw_key = self.create_prefixed_key(key)?;
-        match self.database.get_pinned(&raw_key)? {
-            Some(data) => Ok(Some(data)),
-            None => Ok(None),
-        }
+        self.database.get(&raw_key)
     }
 }
 
-impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> fmt::Debug for DataMap<K, V> {
+impl<E: Engine, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> fmt::Debug for DataMap<E, K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("DataMap").field("context", &self.context).finish()
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub(super) mod tests {
     use super::*;
     use crate::{
         atomic_batch_scope,
         atomic_finalize,
-        helpers::rocksdb::{internal::tests::temp_dir, MapID, TestMap},
+        helpers::{kv::internal::tests::temp_dir, MapID, TestMap},
         FinalizeMode,
     };
     use console::{
@@ -515,46 +515,23 @@ mod tests {
     };
 
     use anyhow::anyhow;
-    use serial_test::serial;
-    use tracing_test::traced_test;
 
     type CurrentNetwork = Testnet3;
 
     // Below are a few objects that mimic the way our DataMaps are organized,
     // in order to provide a more accurate test setup for some scenarios.
 
-    fn open_map_testing_from_db<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
-        database: RocksDB,
-        map_id: T,
-    ) -> DataMap<K, V> {
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
-        // Return the DataMap.
-        DataMap(Arc::new(InnerDataMap {
-            database,
-            context,
-            atomic_batch: Default::default(),
-            batch_in_progress: Default::default(),
-            checkpoints: Default::default(),
-        }))
-    }
-
-    struct TestStorage {
-        own_map: DataMap<usize, String>,
-        extra_maps: TestStorage2,
+    struct TestStorage<E: Engine> {
+        own_map: DataMap<E, usize, String>,
+        extra_maps: TestStorage2<E>,
     }
 
-    impl TestStorage {
+    impl<E: Engine> TestStorage<E> {
         fn open() -> Self {
             // Initialize a database.
-            let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open a test database");
+            let database = KVDatabase::<E>::open_testing(temp_dir(), None).expect("Failed to open a test database");
 
-            Self {
-                own_map: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test)),
-                extra_maps: TestStorage2::open(database),
-            }
+            Self { own_map: database.map(MapID::Test(TestMap::Test)), extra_maps: TestStorage2::open(database) }
         }
 
         fn start_atomic(&self) {
@@ -595,17 +572,17 @@ mod tests {
         }
     }
 
-    struct TestStorage2 {
-        own_map1: DataMap<usize, String>,
-        own_map2: DataMap<usize, String>,
-        extra_maps: TestStorage3,
+    struct TestStorage2<E: Engine> {
+        own_map1: DataMap<E, usize, String>,
+        own_map2: DataMap<E, usize, String>,
+        extra_maps: TestStorage3<E>,
     }
 
-    impl TestStorage2 {
-        fn open(database: RocksDB) -> Self {
+    impl<E: Engine> TestStorage2<E> {
+        fn open(database: KVDatabase<E>) -> Self {
             Self {
-                own_map1: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test2)),
-                own_map2: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test3)),
+                own_map1: database.map(MapID::Test(TestMap::Test2)),
+                own_map2: database.map(MapID::Test(TestMap::Test3)),
                 extra_maps: TestStorage3::open(database),
             }
         }
@@ -647,13 +624,13 @@ mod tests {
         }
     }
 
-    struct TestStorage3 {
-        own_map: DataMap<usize, String>,
+    struct TestStorage3<E: Engine> {
+        own_map: DataMap<E, usize, String>,
     }
 
-    impl TestStorage3 {
-        fn open(database: RocksDB) -> Self {
-            Self { own_map: open_map_testing_from_db(database, MapID::Test(TestMap::Test4)) }
+    impl<E: Engine> TestStorage3<E> {
+        fn open(database: KVDatabase<E>) -> Self {
+            Self { own_map: database.map(MapID::Test(TestMap::Test4)) }
         }
 
         fn start_atomic(&self) {
@@ -681,95 +658,27 @@ mod tests {
         }
     }
 
-    #[test]
-    #[serial]
-    fn test_contains_key_sanity_check() {
+    pub(crate) fn check_contains_key_sanity_check<E: Engine>() {
         // Initialize an address.
         let address =
             Address::<CurrentNetwork>::from_str("aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8")
                 .unwrap();
 
         // Initialize a map.
-        let map: DataMap<Address<CurrentNetwork>, ()> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<E, Address<CurrentNetwork>, ()> =
+            KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+                .expect("Failed to open data map");
         map.insert(address, ()).expect("Failed to insert into data map");
         assert!(map.contains_key_confirmed(&address).unwrap());
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_insert_and_get_speculative() {
-        // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
-
-        crate::helpers::test_helpers::map::check_insert_and_get_speculative(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_remove_and_get_speculative() {
-        // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
-
-        crate::helpers::test_helpers::map::check_remove_and_get_speculative(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_contains_key() {
-        // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
-
-        crate::helpers::test_helpers::map::check_contains_key(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_check_iterators_match() {
-        // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
-
-        crate::helpers::test_helpers::map::check_iterators_match(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_atomic_writes_are_batched() {
-        // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
-
-        crate::helpers::test_helpers::map::check_atomic_writes_are_batched(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_atomic_writes_can_be_aborted() {
-        // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
-
-        crate::helpers::test_helpers::map::check_atomic_writes_can_be_aborted(map);
-    }
-
-    #[test]
-    fn test_checkpoint_and_rewind() {
+    pub(crate) fn check_checkpoint_and_rewind<E: Engine>() {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<E, usize, String> = KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+            .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -836,14 +745,13 @@ mod tests {
         assert_eq!(map.checkpoints.lock().last(), None);
     }
 
-    #[test]
-    fn test_nested_atomic_batch_scope() -> Result<()> {
+    pub(crate) fn check_nested_atomic_batch_scope<E: Engine>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<E, usize, String> = KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+            .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -898,14 +806,13 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_failed_nested_atomic_batch_scope() {
+    pub(crate) fn check_failed_nested_atomic_batch_scope<E: Engine>() {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<E, usize, String> = KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+            .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -952,14 +859,13 @@ mod tests {
         assert!(run_nested_atomic_batch_scope().is_err());
     }
 
-    #[test]
-    fn test_atomic_finalize() -> Result<()> {
+    pub(crate) fn check_atomic_finalize<E: Engine>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<E, usize, String> = KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+            .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1031,14 +937,13 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_atomic_finalize_failing_internal_scope() -> Result<()> {
+    pub(crate) fn check_atomic_finalize_failing_internal_scope<E: Engine>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<E, usize, String> = KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+            .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1112,11 +1017,10 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_atomic_finalize_fails_to_start() {
+    pub(crate) fn check_atomic_finalize_fails_to_start<E: Engine>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<E, usize, String> = KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+            .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1145,11 +1049,10 @@ mod tests {
         assert!(outcome().is_err());
     }
 
-    #[test]
-    fn test_atomic_checkpoint_truncation() {
+    pub(crate) fn check_atomic_checkpoint_truncation<E: Engine>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<E, usize, String> = KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+            .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1203,11 +1106,10 @@ mod tests {
         assert_eq!(*map.iter_confirmed().next().unwrap().1, "1");
     }
 
-    #[test]
-    fn test_atomic_finalize_with_nested_batch_scope() -> Result<()> {
+    pub(crate) fn check_atomic_finalize_with_nested_batch_scope<E: Engine>() -> Result<()> {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<E, usize, String> = KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+            .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1324,12 +1226,9 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_nested_atomic_write_batch_success() -> Result<()> {
+    pub(crate) fn check_nested_atomic_write_batch_success<E: Engine>() -> Result<()> {
         // Initialize a multi-layer test storage.
-        let test_storage = TestStorage::open();
+        let test_storage = TestStorage::<E>::open();
 
         // Sanity check.
         assert!(test_storage.own_map.iter_confirmed().next().is_none());
@@ -1395,13 +1294,10 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_nested_atomic_write_batch_failure() {
+    pub(crate) fn check_nested_atomic_write_batch_failure<E: Engine>() {
         // We'll want to execute the atomic write batch in its own function, in order to be able to
         // inspect the aftermatch after an error, as opposed to returning from the whole test.
-        fn execute_atomic_write_batch(test_storage: &TestStorage) -> Result<()> {
+        fn execute_atomic_write_batch<E: Engine>(test_storage: &TestStorage<E>) -> Result<()> {
             // Start an atomic write batch.
             atomic_batch_scope!(test_storage, {
                 assert!(test_storage.is_atomic_in_progress_everywhere());
@@ -1445,7 +1341,7 @@ mod tests {
         }
 
         // Initialize a multi-layer test storage.
-        let test_storage = TestStorage::open();
+        let test_storage = TestStorage::<E>::open();
 
         // Sanity check.
         assert!(test_storage.own_map.iter_confirmed().next().is_none());
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use crate::helpers::id::*;
+
+mod engine;
+pub use engine::*;
+
+mod map;
+pub use map::*;
+
+mod nested_map;
+pub use nested_map::*;
+
+#[cfg(feature = "redb")]
+mod redb_engine;
+#[cfg(feature = "redb")]
+pub use redb_engine::*;
+
+#[cfg(feature = "rocks")]
+mod rocks_engine;
+#[cfg(feature = "rocks")]
+pub use rocks_engine::*;
+
+#[cfg(test)]
+pub(crate) mod tests;
+
+use anyhow::{bail, Result};
+use parking_lot::Mutex;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize},
+        Arc,
+    },
+};
+
+pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
+
+/// A database handle over an ordered key-value engine.
+#[derive(Clone)]
+pub struct KVDatabase<E: Engine> {
+    /// The key-value engine.
+    engine: E,
+    /// The network ID.
+    network_id: u16,
+    /// The optional development ID.
+    dev: Option<u16>,
+    /// The low-level write batch that gets executed atomically at the end
+    /// of a real-run `atomic_finalize` or the outermost `atomic_batch_scope`.
+    pub(super) atomic_batch: Arc<Mutex<WriteBatch>>,
+    /// The depth of the current atomic write batch; it gets incremented with every call
+    /// to `start_atomic` and decremented with each call to `finish_atomic`.
+    pub(super) atomic_depth: Arc<AtomicUsize>,
+}
+
+impl<E: Engine> Deref for KVDatabase<E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        &self.engine
+    }
+}
+
+impl<E: Engine> KVDatabase<E> {
+    /// Initializes a new database handle over the given engine.
+    ///
+    /// Note: All maps that must be committed atomically together need to share clones of the same handle.
+    pub fn new(engine: E, network_id: u16, dev: Option<u16>) -> Self {
+        Self { engine, network_id, dev, atomic_batch: Default::default(), atomic_depth: Default::default() }
+    }
+
+    /// Opens the database.
+    pub fn open(network_id: u16, dev: Option<u16>) -> Result<Self> {
+        // Retrieve the database.
+        let database = E::open(network_id, dev)?;
+
+        // Ensure the database network ID and development ID match.
+        match database.network_id == network_id && database.dev == dev {
+            true => Ok(database),
+            false => bail!("Mismatching network ID or development ID in the database"),
+        }
+    }
+
+    /// Opens the map with the given `network_id`, `(optional) development ID`, and `map_id` from storage.
+    pub fn open_map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        network_id: u16,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<DataMap<E, K, V>> {
+        // Open the database, and return the map.
+        Ok(Self::open(network_id, dev)?.into_map(map_id))
+    }
+
+    /// Opens the nested map with the given `network_id`, `(optional) development ID`, and `map_id` from storage.
+    pub fn open_nested_map<
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<NestedDataMap<E, M, K, V>> {
+        // Open the database, and return the nested map.
+        Ok(Self::open(network_id, dev)?.into_nested_map(map_id))
+    }
+
+    /// Returns the optional development ID.
+    pub const fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+
+    /// Returns the map with the given `map_id` in this database.
+    pub fn map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        &self,
+        map_id: T,
+    ) -> DataMap<E, K, V> {
+        self.clone().into_map(map_id)
+    }
+
+    /// Returns the nested map with the given `map_id` in this database.
+    pub fn nested_map<
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        &self,
+        map_id: T,
+    ) -> NestedDataMap<E, M, K, V> {
+        self.clone().into_nested_map(map_id)
+    }
+
+    /// Returns the map with the given `map_id` in this database.
+    fn into_map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        self,
+        map_id: T,
+    ) -> DataMap<E, K, V> {
+        // Combine contexts to create a new scope.
+        let context = self.create_context(map_id);
+
+        // Return the DataMap.
+        DataMap(Arc::new(InnerDataMap {
+            database: self,
+            context,
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }))
+    }
+
+    /// Returns the nested map with the given `map_id` in this database.
+    fn into_nested_map<
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        self,
+        map_id: T,
+    ) -> NestedDataMap<E, M, K, V> {
+        // Combine contexts to create a new scope.
+        let context = self.create_context(map_id);
+
+        // Return the NestedDataMap.
+        NestedDataMap {
+            database: self,
+            context,
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
+    }
+
+    /// Returns the key prefix for the map with the given `map_id`.
+    fn create_context<T: Into<u16>>(&self, map_id: T) -> Vec<u8> {
+        let mut context = self.network_id.to_le_bytes().to_vec();
+        context.extend_from_slice(&(map_id.into()).to_le_bytes());
+        context
+    }
+}
+
+impl<E: Engine> KVDatabase<E> {
+    /// Opens the test database.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        use console::prelude::{Rng, TestRng};
+
+        // Ensure the `temp_dir` is unique.
+        let temp_dir = temp_dir.join(Rng::gen::<u64>(&mut TestRng::default()).to_string());
+
+        // Construct the directory for the test database.
+        let primary = match dev {
+            Some(dev) => temp_dir.join(dev.to_string()),
+            None => temp_dir,
+        };
+
+        // Open the test engine.
+        let database = Self::new(E::open_testing(primary)?, u16::MAX, dev);
+
+        // Ensure the database development ID match.
+        match database.dev == dev {
+            true => Ok(database),
+            false => bail!("Mismatching development ID in the test database"),
+        }
+    }
+
+    /// Opens the test map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_map_testing<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<DataMap<E, K, V>> {
+        // Open the test database, and return the map.
+        Ok(Self::open_testing(temp_dir, dev)?.into_map(map_id))
+    }
+
+    /// Opens the test nested map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_nested_map_testing<
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<NestedDataMap<E, M, K, V>> {
+        // Open the test database, and return the nested map.
+        Ok(Self::open_testing(temp_dir, dev)?.into_nested_map(map_id))
+    }
+}
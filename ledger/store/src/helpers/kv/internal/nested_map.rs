@@ -24,12 +24,13 @@ use tracing::error;
 
 #[derive(Clone)]
 pub struct NestedDataMap<
+    E: Engine,
     M: Serialize + DeserializeOwned,
     K: Serialize + DeserializeOwned,
     V: Serialize + DeserializeOwned,
 > {
-    /// The rocksDB instance.
-    pub(super) database: RocksDB,
+    /// The database handle.
+    pub(super) database: KVDatabase<E>,
     /// The context for the map.
     pub(super) context: Vec<u8>,
     /// The tracker for whether a database transaction is in progress.
@@ -40,16 +41,16 @@ pub struct NestedDataMap<
     pub(super) checkpoints: Arc<Mutex<Vec<usize>>>,
 }
 
-impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> Debug
-    for NestedDataMap<M, K, V>
+impl<E: Engine, M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>
+    Debug for NestedDataMap<E, M, K, V>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("NestedDataMap").field("context", &self.context).finish()
     }
 }
 
-impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>
-    NestedDataMap<M, K, V>
+impl<E: Engine, M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>
+    NestedDataMap<E, M, K, V>
 {
     #[inline]
     fn create_prefixed_map(&self, map: &M) -> Result<Vec<u8>> {
@@ -70,14 +71,12 @@ impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serial
     }
 
     #[inline]
-    fn get_map_key_raw(&self, map: &M, key: &K) -> Result<Option<rocksdb::DBPinnableSlice>> {
+    fn get_map_key_raw(&self, map: &M, key: &K) -> Result<Option<Vec<u8>>> {
         let raw_map_key = self.create_prefixed_map_key(map, key)?;
-        match self.database.get_pinned(&raw_map_key)? {
-            Some(data) => Ok(Some(data)),
-            None => Ok(None),
-        }
+        self.database.get(&raw_map_key)
     }
 }
+
 #[inline]
 fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8])> {
     // Retrieve the map length.
@@ -99,10 +98,11 @@ fn get_map_and_key(map_key: &[u8]) -> Result<(&[u8], &[u8])> {
 
 impl<
     'a,
+    E: Engine,
     M: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
-> NestedMap<'a, M, K, V> for NestedDataMap<M, K, V>
+> NestedMap<'a, M, K, V> for NestedDataMap<E, M, K, V>
 {
     ///
     /// Inserts the given map-key-value pair.
@@ -115,9 +115,9 @@ impl<
             // Otherwise, insert the key-value pair directly into the map.
             false => {
                 // Prepare the prefixed map-key and serialized value.
-                let raw_key = self.create_prefixed_map_key(&map, &key)?;
-                let raw_value = bincode::serialize(&value)?;
-                self.database.put(raw_key, raw_value)?;
+                let mut batch = WriteBatch::default();
+                batch.put(self.create_prefixed_map_key(&map, &key)?, bincode::serialize(&value)?);
+                self.database.write(batch)?;
             }
         }
         Ok(())
@@ -133,35 +133,9 @@ impl<
             true => self.atomic_batch.lock().push((*map, None, None)),
             // Otherwise, remove the map directly from the map.
             false => {
-                // Serialize the map.
-                let serialized_map = bincode::serialize(map)?;
-
-                // Batching the delete operations to optimize the write performance and ensure atomicity.
-                let mut batch = rocksdb::WriteBatch::default();
-
-                // Construct an iterator over the DB with the specified prefix.
-                let iterator = self.database.prefix_iterator(&self.create_prefixed_map(map)?);
-
-                // Iterate over the entries in the DB with the specified prefix.
-                for entry in iterator {
-                    let (map_key, _) = entry?;
-
-                    // Extract the bytes belonging to the map and the key.
-                    let Ok((entry_map, _)) = get_map_and_key(&map_key) else {
-                        break;
-                    };
-
-                    // If the 'entry_map' matches 'serialized_map', delete the key.
-                    if entry_map == serialized_map {
-                        batch.delete(map_key);
-                    } else {
-                        // If the 'entry_map' no longer matches the 'serialized_map',
-                        // we've moved past the relevant keys and can break the loop.
-                        break;
-                    }
-                }
-
-                // Deleting the batched keys atomically from RocksDB.
+                // Remove every key with the prefix of the map.
+                let mut batch = WriteBatch::default();
+                batch.delete_prefix(self.create_prefixed_map(map)?);
                 self.database.write(batch)?;
             }
         }
@@ -179,8 +153,9 @@ impl<
             // Otherwise, remove the key-value pair directly from the map.
             false => {
                 // Prepare the prefixed map-key.
-                let map_key = self.create_prefixed_map_key(map, key)?;
-                self.database.delete(map_key)?;
+                let mut batch = WriteBatch::default();
+                batch.delete(self.create_prefixed_map_key(map, key)?);
+                self.database.write(batch)?;
             }
         }
         Ok(())
@@ -267,42 +242,27 @@ impl<
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
 
         if !operations.is_empty() {
-            // Enqueue all the operations from the map in the database-wide batch.
-            let mut atomic_batch = self.database.atomic_batch.lock();
-
-            for (map, key, value) in operations {
-                match (key, value) {
+            // Prepare the key and value for each queued operation, so that
+            // there is no chance to fail partway through enqueueing them.
+            let prepared_operations = operations
+                .into_iter()
+                .map(|(map, key, value)| match (key, value) {
                     (Some(key), Some(value)) => {
-                        atomic_batch.put(self.create_prefixed_map_key(&map, &key)?, bincode::serialize(&value)?)
-                    }
-                    (Some(key), None) => atomic_batch.delete(self.create_prefixed_map_key(&map, &key)?),
-                    (None, None) => {
-                        // Serialize the map.
-                        let serialized_map = bincode::serialize(&map)?;
-
-                        // Construct an iterator over the DB with the specified prefix.
-                        let iterator = self.database.prefix_iterator(&self.create_prefixed_map(&map)?);
-
-                        // Iterate over the entries in the DB with the specified prefix.
-                        for entry in iterator {
-                            let (map_key, _) = entry?;
-
-                            // Extract the bytes belonging to the map and the key.
-                            let Ok((entry_map, _)) = get_map_and_key(&map_key) else {
-                                break;
-                            };
-
-                            // If the 'entry_map' matches 'serialized_map', delete the key.
-                            if entry_map == serialized_map {
-                                atomic_batch.delete(map_key);
-                            } else {
-                                // If the 'entry_map' no longer matches the 'serialized_map',
-                                // we've moved past the relevant keys and can break the loop.
-                                break;
-                            }
-                        }
+                        Ok(WriteOperation::Put(self.create_prefixed_map_key(&map, &key)?, bincode::serialize(&value)?))
                     }
+                    (Some(key), None) => Ok(WriteOperation::Delete(self.create_prefixed_map_key(&map, &key)?)),
+                    (None, None) => Ok(WriteOperation::DeletePrefix(self.create_prefixed_map(&map)?)),
                     (None, Some(_)) => unreachable!("Cannot insert a value without a key"),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            // Enqueue all the operations from the map in the database-wide batch.
+            let mut atomic_batch = self.database.atomic_batch.lock();
+            for operation in prepared_operations {
+                match operation {
+                    WriteOperation::Put(raw_key, raw_value) => atomic_batch.put(raw_key, raw_value),
+                    WriteOperation::Delete(raw_key) => atomic_batch.delete(raw_key),
+                    WriteOperation::DeletePrefix(raw_map) => atomic_batch.delete_prefix(raw_map),
                 }
             }
         }
@@ -325,7 +285,7 @@ impl<
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
             // Execute all the operations atomically.
-            self.database.rocksdb.write(batch)?;
+            self.database.write(batch)?;
             // Ensure that the database atomic batch is empty.
             assert!(self.database.atomic_batch.lock().is_empty());
         }
@@ -336,10 +296,11 @@ impl<
 
 impl<
     'a,
+    E: Engine,
     M: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
-> NestedMapRead<'a, M, K, V> for NestedDataMap<M, K, V>
+> NestedMapRead<'a, M, K, V> for NestedDataMap<E, M, K, V>
 {
     type Iterator = NestedIter<'a, M, K, V>;
     type Keys = NestedKeys<'a, M, K>;
@@ -389,37 +350,22 @@ impl<
     /// Returns the key-value pairs for the given map, if it exists.
     ///
     fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>> {
-        // Serialize the map.
-        let serialized_map = bincode::serialize(map)?;
-
         // Initialize a vector for the entries.
         let mut entries = Vec::new();
 
-        // Construct an iterator over the DB with the specified prefix.
-        let iterator = self.database.prefix_iterator(&self.create_prefixed_map(map)?);
-
-        // Iterate over the entries in the DB with the specified prefix.
-        for entry in iterator {
+        // Iterate over the entries with the prefix of the map.
+        for entry in self.database.iter_prefix(&self.create_prefixed_map(map)?) {
             let (map_key, value) = entry?;
 
-            // Extract the bytes belonging to the map and the key.
-            let Ok((entry_map, entry_key)) = get_map_and_key(&map_key) else {
-                break;
-            };
-
-            // If the 'entry_map' matches 'serialized_map', deserialize the key and value.
-            if entry_map == serialized_map {
-                // Deserialize the key.
-                let key = bincode::deserialize(entry_key)?;
-                // Deserialize the value.
-                let value = bincode::deserialize(&value)?;
-                // Push the key-value pair to the vector.
-                entries.push((key, value));
-            } else {
-                // If the 'entry_map' no longer matches the 'serialized_map',
-                // we've moved past the relevant keys and can break the loop.
-                break;
-            }
+            // Extract the bytes belonging to the key.
+            let (_, entry_key) = get_map_and_key(&map_key)?;
+
+            // Deserialize the key.
+            let key = bincode::deserialize(entry_key)?;
+            // Deserialize the value.
+            let value = bincode::deserialize(&value)?;
+            // Push the key-value pair to the vector.
+            entries.push((key, value));
         }
 
         Ok(entries)
@@ -526,21 +472,21 @@ impl<
     /// Returns an iterator visiting each key-value pair in the map.
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator {
-        NestedIter::new(self.database.prefix_iterator(&self.context))
+        NestedIter::new(self.database.iter_prefix(&self.context))
     }
 
     ///
     /// Returns an iterator over each key.
     ///
     fn keys_confirmed(&'a self) -> Self::Keys {
-        NestedKeys::new(self.database.prefix_iterator(&self.context))
+        NestedKeys::new(self.database.iter_prefix(&self.context))
     }
 
     ///
     /// Returns an iterator over each value.
     ///
     fn values_confirmed(&'a self) -> Self::Values {
-        NestedValues::new(self.database.prefix_iterator(&self.context))
+        NestedValues::new(self.database.iter_prefix(&self.context))
     }
 }
 
@@ -551,7 +497,7 @@ pub struct NestedIter<
     K: 'a + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
     V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
 > {
-    db_iter: rocksdb::DBIterator<'a>,
+    db_iter: EngineIterator<'a>,
     _phantom: PhantomData<(M, K, V)>,
 }
 
@@ -562,7 +508,7 @@ impl<
     V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
 > NestedIter<'a, M, K, V>
 {
-    pub(super) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
+    pub(super) fn new(db_iter: EngineIterator<'a>) -> Self {
         Self { db_iter, _phantom: PhantomData }
     }
 }
@@ -581,32 +527,32 @@ impl<
             .db_iter
             .next()?
             .map_err(|e| {
-                error!("RocksDB NestedIter iterator error: {e}");
+                error!("KV NestedIter iterator error: {e}");
             })
             .ok()?;
 
         // Extract the bytes belonging to the map and the key.
         let (entry_map, entry_key) = get_map_and_key(&map_key)
             .map_err(|e| {
-                error!("RocksDB NestedIter get_map_and_key error: {e}");
+                error!("KV NestedIter get_map_and_key error: {e}");
             })
             .ok()?;
 
         // Deserialize the map, key, and value.
         let map = bincode::deserialize(entry_map)
             .map_err(|e| {
-                error!("RocksDB NestedIter deserialize(map) error: {e}");
+                error!("KV NestedIter deserialize(map) error: {e}");
             })
             .ok()?;
         let key = bincode::deserialize(entry_key)
             .map_err(|e| {
-                error!("RocksDB NestedIter deserialize(key) error: {e}");
+                error!("KV NestedIter deserialize(key) error: {e}");
             })
             .ok()?;
         // Deserialize the value.
         let value = bincode::deserialize(&value)
             .map_err(|e| {
-                error!("RocksDB NestedIter deserialize(value) error: {e}");
+                error!("KV NestedIter deserialize(value) error: {e}");
             })
             .ok()?;
 
@@ -620,7 +566,7 @@ pub struct NestedKeys<
     M: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
 > {
-    db_iter: rocksdb::DBIterator<'a>,
+    db_iter: EngineIterator<'a>,
     _phantom: PhantomData<(M, K)>,
 }
 
@@ -630,7 +576,7 @@ impl<
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
 > NestedKeys<'a, M, K>
 {
-    pub(crate) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
+    pub(crate) fn new(db_iter: EngineIterator<'a>) -> Self {
         Self { db_iter, _phantom: PhantomData }
     }
 }
@@ -648,26 +594,26 @@ impl<
             .db_iter
             .next()?
             .map_err(|e| {
-                error!("RocksDB NestedKeys iterator error: {e}");
+                error!("KV NestedKeys iterator error: {e}");
             })
             .ok()?;
 
         // Extract the bytes belonging to the map and the key.
         let (entry_map, entry_key) = get_map_and_key(&map_key)
             .map_err(|e| {
-                error!("RocksDB NestedKeys get_map_and_key error: {e}");
+                error!("KV NestedKeys get_map_and_key error: {e}");
             })
             .ok()?;
 
         // Deserialize the map and key.
         let map = bincode::deserialize(entry_map)
             .map_err(|e| {
-                error!("RocksDB NestedKeys deserialize(map) error: {e}");
+                error!("KV NestedKeys deserialize(map) error: {e}");
             })
             .ok()?;
         let key = bincode::deserialize(entry_key)
             .map_err(|e| {
-                error!("RocksDB NestedKeys deserialize(key) error: {e}");
+                error!("KV NestedKeys deserialize(key) error: {e}");
             })
             .ok()?;
 
@@ -677,12 +623,12 @@ impl<
 
 /// An iterator over the values of a prefix.
 pub struct NestedValues<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> {
-    db_iter: rocksdb::DBIterator<'a>,
+    db_iter: EngineIterator<'a>,
     _phantom: PhantomData<V>,
 }
 
 impl<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> NestedValues<'a, V> {
-    pub(crate) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
+    pub(crate) fn new(db_iter: EngineIterator<'a>) -> Self {
         Self { db_iter, _phantom: PhantomData }
     }
 }
@@ -695,14 +641,14 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
             .db_iter
             .next()?
             .map_err(|e| {
-                error!("RocksDB NestedValues iterator error: {e}");
+                error!("KV NestedValues iterator error: {e}");
             })
             .ok()?;
 
         // Deserialize the value.
         let value = bincode::deserialize(&value)
             .map_err(|e| {
-                error!("RocksDB NestedValues deserialize(value) error: {e}");
+                error!("KV NestedValues deserialize(value) error: {e}");
             })
             .ok()?;
 
@@ -711,15 +657,12 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
 }
 
 #[cfg(test)]
-mod tests {
+pub(super) mod tests {
     use super::*;
     use crate::{
         atomic_batch_scope,
         atomic_finalize,
-        helpers::{
-            rocksdb::{internal::tests::temp_dir, MapID, TestMap},
-            traits::Map,
-        },
+        helpers::{kv::internal::tests::temp_dir, traits::Map, MapID, TestMap},
         FinalizeMode,
     };
     use console::{
@@ -728,73 +671,23 @@ mod tests {
     };
 
     use anyhow::anyhow;
-    use serial_test::serial;
-    use tracing_test::traced_test;
 
     type CurrentNetwork = Testnet3;
 
     // Below are a few objects that mimic the way our NestedDataMaps are organized,
     // in order to provide a more accurate test setup for some scenarios.
 
-    fn open_map_testing_from_db<
-        M: Serialize + DeserializeOwned,
-        K: Serialize + DeserializeOwned,
-        V: Serialize + DeserializeOwned,
-        T: Into<u16>,
-    >(
-        database: RocksDB,
-        map_id: T,
-    ) -> NestedDataMap<M, K, V> {
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
-        // Return the NestedDataMap.
-        NestedDataMap {
-            database,
-            context,
-            atomic_batch: Default::default(),
-            batch_in_progress: Default::default(),
-            checkpoints: Default::default(),
-        }
-    }
-
-    fn open_non_nested_map_testing_from_db<
-        K: Serialize + DeserializeOwned,
-        V: Serialize + DeserializeOwned,
-        T: Into<u16>,
-    >(
-        database: RocksDB,
-        map_id: T,
-    ) -> DataMap<K, V> {
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
-        // Return the DataMap.
-        DataMap(Arc::new(InnerDataMap {
-            database,
-            context,
-            atomic_batch: Default::default(),
-            batch_in_progress: Default::default(),
-            checkpoints: Default::default(),
-        }))
-    }
-
-    struct TestStorage {
-        own_map: NestedDataMap<usize, usize, String>,
-        extra_maps: TestStorage2,
+    struct TestStorage<E: Engine> {
+        own_map: NestedDataMap<E, usize, usize, String>,
+        extra_maps: TestStorage2<E>,
     }
 
-    impl TestStorage {
+    impl<E: Engine> TestStorage<E> {
         fn open() -> Self {
             // Initialize a database.
-            let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open a test database");
+            let database = KVDatabase::<E>::open_testing(temp_dir(), None).expect("Failed to open a test database");
 
-            Self {
-                own_map: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test)),
-                extra_maps: TestStorage2::open(database),
-            }
+            Self { own_map: database.nested_map(MapID::Test(TestMap::Test)), extra_maps: TestStorage2::open(database) }
         }
 
         fn start_atomic(&self) {
@@ -835,17 +728,17 @@ mod tests {
         }
     }
 
-    struct TestStorage2 {
-        own_map1: NestedDataMap<usize, usize, String>,
-        own_map2: NestedDataMap<usize, usize, String>,
-        extra_maps: TestStorage3,
+    struct TestStorage2<E: Engine> {
+        own_map1: NestedDataMap<E, usize, usize, String>,
+        own_map2: NestedDataMap<E, usize, usize, String>,
+        extra_maps: TestStorage3<E>,
     }
 
-    impl TestStorage2 {
-        fn open(database: RocksDB) -> Self {
+    impl<E: Engine> TestStorage2<E> {
+        fn open(database: KVDatabase<E>) -> Self {
             Self {
-                own_map1: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test2)),
-                own_map2: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test3)),
+                own_map1: database.nested_map(MapID::Test(TestMap::Test2)),
+                own_map2: database.nested_map(MapID::Test(TestMap::Test3)),
                 extra_maps: TestStorage3::open(database),
             }
         }
@@ -887,16 +780,16 @@ mod tests {
         }
     }
 
-    struct TestStorage3 {
-        own_nested_map: NestedDataMap<usize, usize, String>,
-        own_map: DataMap<usize, String>,
+    struct TestStorage3<E: Engine> {
+        own_nested_map: NestedDataMap<E, usize, usize, String>,
+        own_map: DataMap<E, usize, String>,
     }
 
-    impl TestStorage3 {
-        fn open(database: RocksDB) -> Self {
+    impl<E: Engine> TestStorage3<E> {
+        fn open(database: KVDatabase<E>) -> Self {
             Self {
-                own_nested_map: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test4)),
-                own_map: open_non_nested_map_testing_from_db(database, MapID::Test(TestMap::Test5)),
+                own_nested_map: database.nested_map(MapID::Test(TestMap::Test4)),
+                own_map: database.map(MapID::Test(TestMap::Test5)),
             }
         }
 
@@ -930,9 +823,7 @@ mod tests {
         }
     }
 
-    #[test]
-    #[serial]
-    fn test_contains_key_sanity_check() {
+    pub(crate) fn check_contains_key_sanity_check<E: Engine>() {
         // Initialize 'm'.
         let m = 0usize;
         // Initialize an address.
@@ -941,79 +832,16 @@ mod tests {
                 .unwrap();
 
         // Initialize a map.
-        let map: NestedDataMap<usize, Address<CurrentNetwork>, ()> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        let map: NestedDataMap<E, usize, Address<CurrentNetwork>, ()> =
+            KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
                 .expect("Failed to open data map");
         map.insert(m, address, ()).expect("Failed to insert into data map");
         assert!(map.contains_key_confirmed(&m, &address).unwrap());
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_insert_and_get_value_speculative() {
-        // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
-
-        crate::helpers::test_helpers::nested_map::check_insert_and_get_value_speculative(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_remove_key_and_get_value_speculative() {
-        // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
-
-        crate::helpers::test_helpers::nested_map::check_remove_and_get_value_speculative(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_contains_key() {
-        // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
-
-        crate::helpers::test_helpers::nested_map::check_contains_key(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_get_map() {
-        // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
-
-        crate::helpers::test_helpers::nested_map::check_get_map(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_check_iterators_match() {
-        // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
-
-        crate::helpers::test_helpers::nested_map::check_iterators_match(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_iter_from_nested_to_non_nested() {
+    pub(crate) fn check_iter_from_nested_to_non_nested<E: Engine>() {
         // Open a storage with a DataMap right after a NestedDataMap.
-        let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open a test database");
+        let database = KVDatabase::<E>::open_testing(temp_dir(), None).expect("Failed to open a test database");
         let test_storage = TestStorage3::open(database);
 
         // Insert 5 (confirmed) records into a nested map 77.
@@ -1031,38 +859,13 @@ mod tests {
         assert_eq!(confirmed.len(), 5);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_atomic_writes_are_batched() {
-        // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
-
-        crate::helpers::test_helpers::nested_map::check_atomic_writes_are_batched(map);
-    }
-
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_atomic_writes_can_be_aborted() {
-        // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
-
-        crate::helpers::test_helpers::nested_map::check_atomic_writes_can_be_aborted(map);
-    }
-
-    #[test]
-    fn test_checkpoint_and_rewind() {
+    pub(crate) fn check_checkpoint_and_rewind<E: Engine>() {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        let map: NestedDataMap<E, usize, usize, String> =
+            KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
                 .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
@@ -1130,14 +933,13 @@ mod tests {
         assert_eq!(map.checkpoints.lock().last(), None);
     }
 
-    #[test]
-    fn test_nested_atomic_batch_scope() -> Result<()> {
+    pub(crate) fn check_nested_atomic_batch_scope<E: Engine>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        let map: NestedDataMap<E, usize, usize, String> =
+            KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
                 .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
@@ -1193,14 +995,13 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_failed_nested_atomic_batch_scope() {
+    pub(crate) fn check_failed_nested_atomic_batch_scope<E: Engine>() {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        let map: NestedDataMap<E, usize, usize, String> =
+            KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
                 .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
@@ -1248,14 +1049,13 @@ mod tests {
         assert!(run_nested_atomic_batch_scope().is_err());
     }
 
-    #[test]
-    fn test_atomic_finalize() -> Result<()> {
+    pub(crate) fn check_atomic_finalize<E: Engine>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        let map: NestedDataMap<E, usize, usize, String> =
+            KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
                 .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
@@ -1328,14 +1128,13 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_atomic_finalize_failing_internal_scope() -> Result<()> {
+    pub(crate) fn check_atomic_finalize_failing_internal_scope<E: Engine>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        let map: NestedDataMap<E, usize, usize, String> =
+            KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
                 .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
@@ -1410,11 +1209,10 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_atomic_finalize_fails_to_start() {
+    pub(crate) fn check_atomic_finalize_fails_to_start<E: Engine>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        let map: NestedDataMap<E, usize, usize, String> =
+            KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
                 .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
@@ -1444,11 +1242,10 @@ mod tests {
         assert!(outcome().is_err());
     }
 
-    #[test]
-    fn test_atomic_checkpoint_truncation() {
+    pub(crate) fn check_atomic_checkpoint_truncation<E: Engine>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        let map: NestedDataMap<E, usize, usize, String> =
+            KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
                 .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
@@ -1506,11 +1303,10 @@ mod tests {
         assert_eq!(map.iter_confirmed().next().unwrap(), (Cow::Owned(0), Cow::Owned(0), Cow::Owned("1".to_string())));
     }
 
-    #[test]
-    fn test_atomic_finalize_with_nested_batch_scope() -> Result<()> {
+    pub(crate) fn check_atomic_finalize_with_nested_batch_scope<E: Engine>() -> Result<()> {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        let map: NestedDataMap<E, usize, usize, String> =
+            KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
                 .expect("Failed to open data map");
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
@@ -1628,12 +1424,9 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_nested_atomic_write_batch_success() -> Result<()> {
+    pub(crate) fn check_nested_atomic_write_batch_success<E: Engine>() -> Result<()> {
         // Initialize a multi-layer test storage.
-        let test_storage = TestStorage::open();
+        let test_storage = TestStorage::<E>::open();
 
         // Sanity check.
         assert!(test_storage.own_map.iter_confirmed().next().is_none());
@@ -1699,13 +1492,10 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_nested_atomic_write_batch_failure() {
+    pub(crate) fn check_nested_atomic_write_batch_failure<E: Engine>() {
         // We'll want to execute the atomic write batch in its own function, in order to be able to
         // inspect the aftermatch after an error, as opposed to returning from the whole test.
-        fn execute_atomic_write_batch(test_storage: &TestStorage) -> Result<()> {
+        fn execute_atomic_write_batch<E: Engine>(test_storage: &TestStorage<E>) -> Result<()> {
             // Start an atomic write batch.
             atomic_batch_scope!(test_storage, {
                 assert!(test_storage.is_atomic_in_progress_everywhere());
@@ -1749,7 +1539,7 @@ mod tests {
         }
 
         // Initialize a multi-layer test storage.
-        let test_storage = TestStorage::open();
+        let test_storage = TestStorage::<E>::open();
 
         // Sanity check.
         assert!(test_storage.own_map.iter_confirmed().next().is_none());
@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Engine, EngineIterator, KVDatabase, WriteBatch, WriteOperation};
+
+use anyhow::Result;
+use once_cell::sync::OnceCell;
+use redb::{ReadableTable, TableDefinition};
+use std::{path::Path, sync::Arc};
+
+/// The name of the database file.
+const FILE_NAME: &str = "ledger.redb";
+
+/// The table holding all the prefixed key-value pairs.
+const TABLE: TableDefinition<&[u8], &[u8]> = TableDefinition::new("data");
+
+/// An on-disk engine backed by redb, a pure-Rust copy-on-write B-tree store.
+#[derive(Clone)]
+pub struct RedbEngine {
+    /// The redb instance.
+    redb: Arc<redb::Database>,
+}
+
+impl RedbEngine {
+    /// Opens the database file at the given path, creating it if it does not exist.
+    fn open_path(path: &Path) -> Result<Self> {
+        // Create the parent directory, if it does not exist.
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Open the database.
+        let redb = redb::Database::create(path)?;
+
+        // Ensure the table exists, so that reads do not fail on a new database.
+        let transaction = redb.begin_write()?;
+        transaction.open_table(TABLE)?;
+        transaction.commit()?;
+
+        Ok(Self { redb: Arc::new(redb) })
+    }
+}
+
+impl Engine for RedbEngine {
+    /// Opens the database.
+    ///
+    /// In production mode, the database opens file `~/.aleo/storage/ledger-{network}/ledger.redb`.
+    /// In development mode, the database opens file `/path/to/repo/.ledger-{network}-{id}/ledger.redb`.
+    fn open(network_id: u16, dev: Option<u16>) -> Result<KVDatabase<Self>> {
+        static DB: OnceCell<KVDatabase<RedbEngine>> = OnceCell::new();
+
+        // Retrieve the database.
+        DB.get_or_try_init(|| {
+            let primary = aleo_std::aleo_ledger_dir(network_id, dev).join(FILE_NAME);
+            Ok(KVDatabase::new(Self::open_path(&primary)?, network_id, dev))
+        })
+        .cloned()
+    }
+
+    /// Opens a new engine at the given path, for testing.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(path: std::path::PathBuf) -> Result<Self> {
+        Self::open_path(&path.join(FILE_NAME))
+    }
+
+    /// Returns the value for the given key, if it exists.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let transaction = self.redb.begin_read()?;
+        let table = transaction.open_table(TABLE)?;
+        let value = table.get(key)?.map(|value| value.value().to_vec());
+        Ok(value)
+    }
+
+    /// Returns an iterator over the key-value pairs whose key starts with the given prefix, in ascending key order.
+    fn iter_prefix(&self, prefix: &[u8]) -> EngineIterator<'_> {
+        // Open a range starting at the given prefix.
+        let range = || {
+            let transaction = self.redb.begin_read()?;
+            let table = transaction.open_table(TABLE)?;
+            Ok::<_, anyhow::Error>(table.range::<&[u8]>(prefix..)?)
+        };
+
+        match range() {
+            Ok(range) => {
+                let prefix = prefix.to_vec();
+                Box::new(
+                    range
+                        .map(|entry| {
+                            let (key, value) = entry?;
+                            Ok((key.value().to_vec(), value.value().to_vec()))
+                        })
+                        // Stop at the first key that is past the prefix.
+                        .take_while(move |entry| match entry {
+                            Ok((key, _)) => key.starts_with(&prefix),
+                            Err(_) => true,
+                        }),
+                )
+            }
+            Err(error) => Box::new(std::iter::once(Err(error))),
+        }
+    }
+
+    /// Applies all the operations in the given batch atomically, in order.
+    fn write(&self, batch: WriteBatch) -> Result<()> {
+        let transaction = self.redb.begin_write()?;
+        {
+            let mut table = transaction.open_table(TABLE)?;
+            for operation in batch {
+                match operation {
+                    WriteOperation::Put(key, value) => {
+                        table.insert(key.as_slice(), value.as_slice())?;
+                    }
+                    WriteOperation::Delete(key) => {
+                        table.remove(key.as_slice())?;
+                    }
+                    WriteOperation::DeletePrefix(prefix) => {
+                        // Collect the keys with the given prefix.
+                        let mut keys = Vec::new();
+                        for entry in table.range::<&[u8]>(prefix.as_slice()..)? {
+                            let (key, _) = entry?;
+                            if !key.value().starts_with(&prefix) {
+                                break;
+                            }
+                            keys.push(key.value().to_vec());
+                        }
+                        // Remove the keys.
+                        for key in keys {
+                            table.remove(key.as_slice())?;
+                        }
+                    }
+                }
+            }
+        }
+        // Commit the operations, or none of them if this fails.
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        kv::{internal::tests::temp_dir, MapID, TestMap},
+        Map,
+        MapRead,
+    };
+
+    #[test]
+    fn test_reopen() {
+        let path = temp_dir().join(FILE_NAME);
+
+        {
+            // Open the engine, and insert a few items.
+            let database = KVDatabase::new(RedbEngine::open_path(&path).unwrap(), u16::MAX, None);
+            let map = database.into_map(MapID::Test(TestMap::Test));
+            for i in 0..10usize {
+                map.insert(i, i.to_string()).unwrap();
+            }
+        }
+
+        // Reopen the engine, and ensure the items were persisted.
+        let database = KVDatabase::new(RedbEngine::open_path(&path).unwrap(), u16::MAX, None);
+        let map = database.into_map::<usize, String, _>(MapID::Test(TestMap::Test));
+        assert_eq!(map.iter_confirmed().count(), 10);
+        for i in 0..10usize {
+            assert_eq!(map.get_confirmed(&i).unwrap().unwrap().into_owned(), i.to_string());
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Engine, EngineIterator, KVDatabase, PREFIX_LEN, WriteBatch, WriteOperation};
+
+use anyhow::Result;
+use once_cell::sync::OnceCell;
+use std::{path::Path, sync::Arc};
+
+/// An on-disk engine backed by RocksDB.
+#[derive(Clone)]
+pub struct RocksEngine {
+    /// The RocksDB instance.
+    rocksdb: Arc<rocksdb::DB>,
+}
+
+impl RocksEngine {
+    /// Opens the database directory at the given path, creating it if it does not exist.
+    fn open_path(path: &Path) -> Result<Self> {
+        // Customize database options.
+        let mut options = rocksdb::Options::default();
+        options.set_compression_type(rocksdb::DBCompressionType::Lz4);
+
+        // Register the prefix length.
+        let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
+        options.set_prefix_extractor(prefix_extractor);
+
+        options.increase_parallelism(2);
+        options.set_max_background_jobs(4);
+        options.create_if_missing(true);
+
+        // Create the directory, if it does not exist.
+        std::fs::create_dir_all(path)?;
+        // Open the database.
+        let rocksdb = rocksdb::DB::open(&options, path)?;
+
+        Ok(Self { rocksdb: Arc::new(rocksdb) })
+    }
+}
+
+impl Engine for RocksEngine {
+    /// Opens the database.
+    ///
+    /// In production mode, the database opens directory `~/.aleo/storage/ledger-{network}`.
+    /// In development mode, the database opens directory `/path/to/repo/.ledger-{network}-{id}`.
+    fn open(network_id: u16, dev: Option<u16>) -> Result<KVDatabase<Self>> {
+        static DB: OnceCell<KVDatabase<RocksEngine>> = OnceCell::new();
+
+        // Retrieve the database.
+        DB.get_or_try_init(|| {
+            let primary = aleo_std::aleo_ledger_dir(network_id, dev);
+            Ok(KVDatabase::new(Self::open_path(&primary)?, network_id, dev))
+        })
+        .cloned()
+    }
+
+    /// Opens a new engine at the given path, for testing.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(path: std::path::PathBuf) -> Result<Self> {
+        Self::open_path(&path)
+    }
+
+    /// Returns the value for the given key, if it exists.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.rocksdb.get(key)?)
+    }
+
+    /// Returns an iterator over the key-value pairs whose key starts with the given prefix, in ascending key order.
+    fn iter_prefix(&self, prefix: &[u8]) -> EngineIterator<'_> {
+        let prefix = prefix.to_vec();
+        Box::new(
+            self.rocksdb
+                .prefix_iterator(&prefix)
+                .map(|entry| {
+                    let (key, value) = entry?;
+                    Ok((key.into_vec(), value.into_vec()))
+                })
+                // Stop at the first key that is past the prefix, as the prefix extractor only covers the map ID.
+                .take_while(move |entry| match entry {
+                    Ok((key, _)) => key.starts_with(&prefix),
+                    Err(_) => true,
+                }),
+        )
+    }
+
+    /// Applies all the operations in the given batch atomically, in order.
+    fn write(&self, batch: WriteBatch) -> Result<()> {
+        let mut rocksdb_batch = rocksdb::WriteBatch::default();
+        for operation in batch {
+            match operation {
+                WriteOperation::Put(key, value) => rocksdb_batch.put(key, value),
+                WriteOperation::Delete(key) => rocksdb_batch.delete(key),
+                WriteOperation::DeletePrefix(prefix) => match prefix_successor(&prefix) {
+                    // Remove the range of keys, including the ones inserted earlier in this batch.
+                    Some(end) => rocksdb_batch.delete_range(prefix, end),
+                    // Otherwise, remove the stored keys with the given prefix.
+                    None => {
+                        for entry in self.iter_prefix(&prefix) {
+                            rocksdb_batch.delete(entry?.0);
+                        }
+                    }
+                },
+            }
+        }
+        // Commit the operations, or none of them if this fails.
+        self.rocksdb.write(rocksdb_batch)?;
+        Ok(())
+    }
+}
+
+/// Returns the smallest key that is greater than every key starting with the given prefix,
+/// or `None` if the prefix consists only of `0xFF` bytes.
+fn prefix_successor(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut end = prefix.to_vec();
+    while let Some(byte) = end.pop() {
+        if byte < u8::MAX {
+            end.push(byte + 1);
+            return Some(end);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        kv::{internal::tests::temp_dir, MapID, TestMap},
+        Map,
+        MapRead,
+    };
+
+    #[test]
+    fn test_prefix_successor() {
+        assert_eq!(prefix_successor(&[0, 1]), Some(vec![0, 2]));
+        assert_eq!(prefix_successor(&[0, 255]), Some(vec![1]));
+        assert_eq!(prefix_successor(&[255, 255]), None);
+    }
+
+    #[test]
+    fn test_reopen() {
+        let path = temp_dir();
+
+        {
+            // Open the engine, and insert a few items.
+            let database = KVDatabase::new(RocksEngine::open_path(&path).unwrap(), u16::MAX, None);
+            let map = database.into_map(MapID::Test(TestMap::Test));
+            for i in 0..10usize {
+                map.insert(i, i.to_string()).unwrap();
+            }
+        }
+
+        // Reopen the engine, and ensure the items were persisted.
+        let database = KVDatabase::new(RocksEngine::open_path(&path).unwrap(), u16::MAX, None);
+        let map = database.into_map::<usize, String, _>(MapID::Test(TestMap::Test));
+        assert_eq!(map.iter_confirmed().count(), 10);
+        for i in 0..10usize {
+            assert_eq!(map.get_confirmed(&i).unwrap().unwrap().into_owned(), i.to_string());
+        }
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::helpers::{test_helpers, Map, MapRead, NestedMap, NestedMapRead};
+
+use parking_lot::RwLock;
+use std::collections::BTreeMap;
+
+pub(crate) fn temp_dir() -> std::path::PathBuf {
+    tempfile::tempdir().expect("Failed to open temporary directory").into_path()
+}
+
+/// An in-memory engine over a `BTreeMap`, used to check the engine-agnostic layer.
+///
+/// Note: Every call to `open` returns a new, empty engine.
+#[derive(Clone, Default)]
+pub(crate) struct MemoryEngine(Arc<RwLock<BTreeMap<Vec<u8>, Vec<u8>>>>);
+
+impl Engine for MemoryEngine {
+    fn open(network_id: u16, dev: Option<u16>) -> Result<KVDatabase<Self>> {
+        Ok(KVDatabase::new(Self::default(), network_id, dev))
+    }
+
+    fn open_testing(_path: std::path::PathBuf) -> Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.0.read().get(key).cloned())
+    }
+
+    fn iter_prefix(&self, prefix: &[u8]) -> EngineIterator<'_> {
+        let entries = self
+            .0
+            .read()
+            .range(prefix.to_vec()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| Ok((key.clone(), value.clone())))
+            .collect::<Vec<_>>();
+        Box::new(entries.into_iter())
+    }
+
+    fn write(&self, batch: WriteBatch) -> Result<()> {
+        let mut map = self.0.write();
+        for operation in batch {
+            match operation {
+                WriteOperation::Put(key, value) => {
+                    map.insert(key, value);
+                }
+                WriteOperation::Delete(key) => {
+                    map.remove(&key);
+                }
+                WriteOperation::DeletePrefix(prefix) => map.retain(|key, _| !key.starts_with(&prefix)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Opens a new test map on the given engine.
+fn open_map<E: Engine>() -> DataMap<E, usize, String> {
+    KVDatabase::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map")
+}
+
+/// Opens a new test nested map on the given engine.
+fn open_nested_map<E: Engine>() -> NestedDataMap<E, usize, usize, String> {
+    KVDatabase::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
+        .expect("Failed to open nested data map")
+}
+
+/// Checks the basic reads of the map over the given engine, after an insertion.
+fn check_insert_and_read<E: Engine>() {
+    let map = open_map::<E>();
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+
+    // Check `contains_key_confirmed`.
+    assert!(map.contains_key_confirmed(&123456789).expect("Failed to call contains key"));
+    assert!(!map.contains_key_confirmed(&000000000).expect("Failed to call contains key"));
+
+    // Check `get_confirmed`.
+    assert_eq!(
+        Some("123456789".to_string()),
+        map.get_confirmed(&123456789).expect("Failed to get").map(|v| v.to_string())
+    );
+    assert_eq!(None, map.get_confirmed(&000000000).expect("Failed to get"));
+
+    // Check the iterators.
+    let mut iter = map.iter_confirmed();
+    assert_eq!(Some((123456789, "123456789".to_string())), iter.next().map(|(k, v)| (*k, v.to_string())));
+    assert_eq!(None, iter.next());
+    let mut keys = map.keys_confirmed();
+    assert_eq!(Some(123456789), keys.next().map(|k| *k));
+    assert_eq!(None, keys.next());
+    let mut values = map.values_confirmed();
+    assert_eq!(Some("123456789".to_string()), values.next().map(|v| v.to_string()));
+    assert_eq!(None, values.next());
+
+    // Check `remove`.
+    map.remove(&123456789).expect("Failed to remove");
+    assert!(map.get_confirmed(&123456789).expect("Failed to get").is_none());
+}
+
+/// Checks that the iterators of the map over the given engine follow the order of the serialized keys.
+fn check_iterator_ordering<E: Engine>() {
+    let map = open_map::<E>();
+
+    // Insert values into the map.
+    map.insert(5, "d".to_string()).expect("Failed to insert");
+    map.insert(6, "c".to_string()).expect("Failed to insert");
+    map.insert(7, "b".to_string()).expect("Failed to insert");
+    map.insert(8, "a".to_string()).expect("Failed to insert");
+    map.insert(1, "h".to_string()).expect("Failed to insert");
+    map.insert(2, "g".to_string()).expect("Failed to insert");
+    map.insert(3, "f".to_string()).expect("Failed to insert");
+    map.insert(4, "e".to_string()).expect("Failed to insert");
+
+    // Define the expected order of the iterator.
+    let expected_order = vec![
+        (1, "h".to_string()),
+        (2, "g".to_string()),
+        (3, "f".to_string()),
+        (4, "e".to_string()),
+        (5, "d".to_string()),
+        (6, "c".to_string()),
+        (7, "b".to_string()),
+        (8, "a".to_string()),
+    ];
+
+    // Check that the order of the iterator is lexicographical.
+    assert_eq!(map.iter_confirmed().count(), expected_order.len());
+    for ((k1, v1), (k2, v2)) in map.iter_confirmed().zip(expected_order.iter()) {
+        assert_eq!(&*k1, k2);
+        assert_eq!(&*v1, v2);
+    }
+}
+
+/// Checks that the maps over the given engine behave like all the other map implementations.
+fn check_map_conformance<E: Engine>() {
+    test_helpers::map::check_insert_and_get_speculative(open_map::<E>());
+    test_helpers::map::check_remove_and_get_speculative(open_map::<E>());
+    test_helpers::map::check_contains_key(open_map::<E>());
+    test_helpers::map::check_iterators_match(open_map::<E>());
+    test_helpers::map::check_atomic_writes_are_batched(open_map::<E>());
+    test_helpers::map::check_atomic_writes_can_be_aborted(open_map::<E>());
+    check_insert_and_read::<E>();
+    check_iterator_ordering::<E>();
+
+    map::tests::check_contains_key_sanity_check::<E>();
+    map::tests::check_checkpoint_and_rewind::<E>();
+    map::tests::check_nested_atomic_batch_scope::<E>().unwrap();
+    map::tests::check_failed_nested_atomic_batch_scope::<E>();
+    map::tests::check_atomic_finalize::<E>().unwrap();
+    map::tests::check_atomic_finalize_failing_internal_scope::<E>().unwrap();
+    map::tests::check_atomic_finalize_fails_to_start::<E>();
+    map::tests::check_atomic_checkpoint_truncation::<E>();
+    map::tests::check_atomic_finalize_with_nested_batch_scope::<E>().unwrap();
+    map::tests::check_nested_atomic_write_batch_success::<E>().unwrap();
+    map::tests::check_nested_atomic_write_batch_failure::<E>();
+}
+
+/// Checks that the nested maps over the given engine behave like all the other nested map implementations.
+fn check_nested_map_conformance<E: Engine>() {
+    test_helpers::nested_map::check_insert_and_get_value_speculative(open_nested_map::<E>());
+    test_helpers::nested_map::check_remove_and_get_value_speculative(open_nested_map::<E>());
+    test_helpers::nested_map::check_contains_key(open_nested_map::<E>());
+    test_helpers::nested_map::check_get_map(open_nested_map::<E>());
+    test_helpers::nested_map::check_iterators_match(open_nested_map::<E>());
+    test_helpers::nested_map::check_atomic_writes_are_batched(open_nested_map::<E>());
+    test_helpers::nested_map::check_atomic_writes_can_be_aborted(open_nested_map::<E>());
+
+    nested_map::tests::check_contains_key_sanity_check::<E>();
+    nested_map::tests::check_iter_from_nested_to_non_nested::<E>();
+    nested_map::tests::check_checkpoint_and_rewind::<E>();
+    nested_map::tests::check_nested_atomic_batch_scope::<E>().unwrap();
+    nested_map::tests::check_failed_nested_atomic_batch_scope::<E>();
+    nested_map::tests::check_atomic_finalize::<E>().unwrap();
+    nested_map::tests::check_atomic_finalize_failing_internal_scope::<E>().unwrap();
+    nested_map::tests::check_atomic_finalize_fails_to_start::<E>();
+    nested_map::tests::check_atomic_checkpoint_truncation::<E>();
+    nested_map::tests::check_atomic_finalize_with_nested_batch_scope::<E>().unwrap();
+    nested_map::tests::check_nested_atomic_write_batch_success::<E>().unwrap();
+    nested_map::tests::check_nested_atomic_write_batch_failure::<E>();
+}
+
+/// Checks that the maps sharing a database are isolated from one another, and are committed together.
+fn check_shared_database<E: Engine>() {
+    // Open two maps and a nested map over the same database.
+    let database = KVDatabase::<E>::open_testing(temp_dir(), None).unwrap();
+    let map_1 = database.clone().into_map::<usize, String, _>(MapID::Test(TestMap::Test2));
+    let map_2 = database.clone().into_map::<usize, String, _>(MapID::Test(TestMap::Test3));
+    let nested_map = database.into_nested_map::<usize, usize, String, _>(MapID::Test(TestMap::Test4));
+
+    // Start an atomic write batch in all the maps.
+    map_1.start_atomic();
+    map_2.start_atomic();
+    nested_map.start_atomic();
+
+    // Queue writes to the same keys.
+    map_1.insert(0, "0".to_string()).unwrap();
+    map_2.insert(0, "1".to_string()).unwrap();
+    nested_map.insert(0, 0, "2".to_string()).unwrap();
+    nested_map.insert(1, 0, "3".to_string()).unwrap();
+    // Remove a map that was inserted into in the same batch.
+    nested_map.remove_map(&1).unwrap();
+
+    // Finish the batch in two of the maps; nothing must be written yet.
+    map_1.finish_atomic().unwrap();
+    map_2.finish_atomic().unwrap();
+    assert!(map_1.iter_confirmed().next().is_none());
+    assert!(map_2.iter_confirmed().next().is_none());
+
+    // Finish the batch in the last map, which writes all of them.
+    nested_map.finish_atomic().unwrap();
+    assert_eq!(map_1.get_confirmed(&0).unwrap().unwrap().into_owned(), "0");
+    assert_eq!(map_2.get_confirmed(&0).unwrap().unwrap().into_owned(), "1");
+    assert_eq!(nested_map.get_map_confirmed(&0).unwrap(), vec![(0, "2".to_string())]);
+    assert!(nested_map.get_map_confirmed(&1).unwrap().is_empty());
+
+    // Ensure the iterators are scoped to their own map.
+    assert_eq!(map_1.iter_confirmed().count(), 1);
+    assert_eq!(map_2.iter_confirmed().count(), 1);
+    assert_eq!(nested_map.iter_confirmed().count(), 1);
+
+    // Remove the nested map directly, and ensure the other maps are untouched.
+    nested_map.remove_map(&0).unwrap();
+    assert!(nested_map.iter_confirmed().next().is_none());
+    assert_eq!(map_1.iter_confirmed().count(), 1);
+    assert_eq!(map_2.iter_confirmed().count(), 1);
+}
+
+#[test]
+fn test_memory_engine_conformance() {
+    check_map_conformance::<MemoryEngine>();
+    check_nested_map_conformance::<MemoryEngine>();
+    check_shared_database::<MemoryEngine>();
+}
+
+#[cfg(feature = "redb")]
+#[test]
+fn test_redb_engine_conformance() {
+    check_map_conformance::<RedbEngine>();
+    check_nested_map_conformance::<RedbEngine>();
+    check_shared_database::<RedbEngine>();
+}
+
+#[cfg(feature = "rocks")]
+#[test]
+fn test_rocks_engine_conformance() {
+    check_map_conformance::<RocksEngine>();
+    check_nested_map_conformance::<RocksEngine>();
+    check_shared_database::<RocksEngine>();
+}
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    helpers::kv::{CommitteeMap, DataMap, Engine, KVDatabase, MapID, NestedDataMap, ProgramMap},
+    CommitteeStorage,
+    CommitteeStore,
+    FinalizeStorage,
+};
+use console::{
+    prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+use ledger_committee::Committee;
+
+use indexmap::IndexSet;
+
+/// A finalize storage over a key-value engine.
+#[derive(Clone)]
+pub struct FinalizeKV<N: Network, E: Engine> {
+    /// The committee store.
+    committee_store: CommitteeStore<N, CommitteeKV<N, E>>,
+    /// The program ID map.
+    program_id_map: DataMap<E, ProgramID<N>, IndexSet<Identifier<N>>>,
+    /// The key-value map.
+    key_value_map: NestedDataMap<E, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The length map.
+    length_map: DataMap<E, (ProgramID<N>, Identifier<N>), u32>,
+    /// The key-position map.
+    key_position_map: NestedDataMap<E, (ProgramID<N>, Identifier<N>), Plaintext<N>, u32>,
+    /// The position-key map.
+    position_key_map: NestedDataMap<E, (ProgramID<N>, Identifier<N>), u32, Plaintext<N>>,
+    /// The history map.
+    history_map: NestedDataMap<E, u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>,
+    /// The history heights map.
+    history_heights_map: DataMap<E, u8, (u32, u32)>,
+    /// The optional development ID.
+    dev: Option<u16>,
+    /// The number of recent blocks whose finalize state can be queried, if the history is recorded.
+    history_window: Option<u32>,
+}
+
+impl<N: Network, E: Engine> FinalizeKV<N, E> {
+    /// Initializes the finalize storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>) -> Self {
+        // Initialize the committee store.
+        let committee_store = CommitteeStore::from(CommitteeKV::open_in(database));
+        // Return the finalize storage.
+        Self {
+            committee_store,
+            program_id_map: database.map(MapID::Program(ProgramMap::ProgramID)),
+            key_value_map: database.nested_map(MapID::Program(ProgramMap::KeyValueID)),
+            length_map: database.map(MapID::Program(ProgramMap::Length)),
+            key_position_map: database.nested_map(MapID::Program(ProgramMap::KeyPosition)),
+            position_key_map: database.nested_map(MapID::Program(ProgramMap::PositionKey)),
+            history_map: database.nested_map(MapID::Program(ProgramMap::History)),
+            history_heights_map: database.map(MapID::Program(ProgramMap::HistoryHeights)),
+            dev: database.dev(),
+            history_window: None,
+        }
+    }
+
+    /// Initializes the finalize storage, recording the history of the given number of recent blocks.
+    pub fn open_with_history(dev: Option<u16>, history_window: u32) -> Result<Self> {
+        // Initialize the finalize storage.
+        let storage = Self::open(dev)?;
+        // Return the finalize storage.
+        Ok(Self { history_window: Some(history_window), ..storage })
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> FinalizeStorage<N> for FinalizeKV<N, E> {
+    type CommitteeStorage = CommitteeKV<N, E>;
+    type ProgramIDMap = DataMap<E, ProgramID<N>, IndexSet<Identifier<N>>>;
+    type KeyValueMap = NestedDataMap<E, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type LengthMap = DataMap<E, (ProgramID<N>, Identifier<N>), u32>;
+    type KeyPositionMap = NestedDataMap<E, (ProgramID<N>, Identifier<N>), Plaintext<N>, u32>;
+    type PositionKeyMap = NestedDataMap<E, (ProgramID<N>, Identifier<N>), u32, Plaintext<N>>;
+    type HistoryMap = NestedDataMap<E, u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>;
+    type HistoryHeightsMap = DataMap<E, u8, (u32, u32)>;
+
+    /// Initializes the finalize storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self::open_in(&KVDatabase::open(N::ID, dev)?))
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        Ok(Self::open_in(&KVDatabase::open_testing(temp_dir, dev)?))
+    }
+
+    /// Returns the committee store.
+    fn committee_store(&self) -> &CommitteeStore<N, Self::CommitteeStorage> {
+        &self.committee_store
+    }
+
+    /// Returns the program ID map.
+    fn program_id_map(&self) -> &Self::ProgramIDMap {
+        &self.program_id_map
+    }
+
+    /// Returns the key-value map.
+    fn key_value_map(&self) -> &Self::KeyValueMap {
+        &self.key_value_map
+    }
+
+    /// Returns the length map.
+    fn length_map(&self) -> &Self::LengthMap {
+        &self.length_map
+    }
+
+    /// Returns the key-position map.
+    fn key_position_map(&self) -> &Self::KeyPositionMap {
+        &self.key_position_map
+    }
+
+    /// Returns the position-key map.
+    fn position_key_map(&self) -> &Self::PositionKeyMap {
+        &self.position_key_map
+    }
+
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the history heights map.
+    fn history_heights_map(&self) -> &Self::HistoryHeightsMap {
+        &self.history_heights_map
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+
+    /// Returns the number of recent blocks whose finalize state can be queried, or `None` if the history is not recorded.
+    fn history_window(&self) -> Option<u32> {
+        self.history_window
+    }
+}
+
+/// A committee storage over a key-value engine.
+#[derive(Clone)]
+pub struct CommitteeKV<N: Network, E: Engine> {
+    /// The current round map.
+    current_round_map: DataMap<E, u8, u64>,
+    /// The round to height map.
+    round_to_height_map: DataMap<E, u64, u32>,
+    /// The committee map.
+    committee_map: DataMap<E, u32, Committee<N>>,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+impl<N: Network, E: Engine> CommitteeKV<N, E> {
+    /// Initializes the committee storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>) -> Self {
+        Self {
+            current_round_map: database.map(MapID::Committee(CommitteeMap::CurrentRound)),
+            round_to_height_map: database.map(MapID::Committee(CommitteeMap::RoundToHeight)),
+            committee_map: database.map(MapID::Committee(CommitteeMap::Committee)),
+            dev: database.dev(),
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> CommitteeStorage<N> for CommitteeKV<N, E> {
+    type CurrentRoundMap = DataMap<E, u8, u64>;
+    type RoundToHeightMap = DataMap<E, u64, u32>;
+    type CommitteeMap = DataMap<E, u32, Committee<N>>;
+
+    /// Initializes the committee storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self::open_in(&KVDatabase::open(N::ID, dev)?))
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        Ok(Self::open_in(&KVDatabase::open_testing(temp_dir, dev)?))
+    }
+
+    /// Returns the current round map.
+    fn current_round_map(&self) -> &Self::CurrentRoundMap {
+        &self.current_round_map
+    }
+
+    /// Returns the round to height map.
+    fn round_to_height_map(&self) -> &Self::RoundToHeightMap {
+        &self.round_to_height_map
+    }
+
+    /// Returns the committee map.
+    fn committee_map(&self) -> &Self::CommitteeMap {
+        &self.committee_map
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::kv::{
+        DataMap,
+        DeploymentMap,
+        Engine,
+        ExecutionMap,
+        FeeMap,
+        KVDatabase,
+        MapID,
+        TransactionMap,
+        TransitionKV,
+    },
+    DeploymentStorage,
+    DeploymentStore,
+    ExecutionStorage,
+    ExecutionStore,
+    FeeStorage,
+    FeeStore,
+    TransactionStorage,
+    TransactionType,
+    TransitionStore,
+};
+use console::{
+    prelude::*,
+    program::{Identifier, ProgramID, ProgramOwner},
+};
+use synthesizer_program::Program;
+use synthesizer_snark::{Certificate, Proof, VerifyingKey};
+
+/// A transaction storage over a key-value engine.
+#[derive(Clone)]
+pub struct TransactionKV<N: Network, E: Engine> {
+    /// The mapping of `transaction ID` to `transaction type`.
+    id_map: DataMap<E, N::TransactionID, TransactionType>,
+    /// The deployment store.
+    deployment_store: DeploymentStore<N, DeploymentKV<N, E>>,
+    /// The execution store.
+    execution_store: ExecutionStore<N, ExecutionKV<N, E>>,
+    /// The fee store.
+    fee_store: FeeStore<N, FeeKV<N, E>>,
+}
+
+impl<N: Network, E: Engine> TransactionKV<N, E> {
+    /// Initializes the transaction storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>, transition_store: TransitionStore<N, TransitionKV<N, E>>) -> Self {
+        // Initialize the fee store.
+        let fee_store = FeeStore::from(FeeKV::open_in(database, transition_store));
+        // Initialize the deployment store.
+        let deployment_store = DeploymentStore::from(DeploymentKV::open_in(database, fee_store.clone()));
+        // Initialize the execution store.
+        let execution_store = ExecutionStore::from(ExecutionKV::open_in(database, fee_store.clone()));
+        // Return the transaction storage.
+        Self {
+            id_map: database.map(MapID::Transaction(TransactionMap::ID)),
+            deployment_store,
+            execution_store,
+            fee_store,
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> TransactionStorage<N> for TransactionKV<N, E> {
+    type IDMap = DataMap<E, N::TransactionID, TransactionType>;
+    type DeploymentStorage = DeploymentKV<N, E>;
+    type ExecutionStorage = ExecutionKV<N, E>;
+    type FeeStorage = FeeKV<N, E>;
+    type TransitionStorage = TransitionKV<N, E>;
+
+    /// Initializes the transaction storage.
+    fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        let database = KVDatabase::open(N::ID, transition_store.dev())?;
+        Ok(Self::open_in(&database, transition_store))
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the deployment store.
+    fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
+        &self.deployment_store
+    }
+
+    /// Returns the execution store.
+    fn execution_store(&self) -> &ExecutionStore<N, Self::ExecutionStorage> {
+        &self.execution_store
+    }
+
+    /// Returns the fee store.
+    fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
+        &self.fee_store
+    }
+}
+
+/// A deployment storage over a key-value engine.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct DeploymentKV<N: Network, E: Engine> {
+    /// The ID map.
+    id_map: DataMap<E, N::TransactionID, ProgramID<N>>,
+    /// The edition map.
+    edition_map: DataMap<E, ProgramID<N>, u16>,
+    /// The reverse ID map.
+    reverse_id_map: DataMap<E, (ProgramID<N>, u16), N::TransactionID>,
+    /// The program owner map.
+    owner_map: DataMap<E, (ProgramID<N>, u16), ProgramOwner<N>>,
+    /// The program map.
+    program_map: DataMap<E, (ProgramID<N>, u16), Program<N>>,
+    /// The verifying key map.
+    verifying_key_map: DataMap<E, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
+    /// The certificate map.
+    certificate_map: DataMap<E, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    /// The fee store.
+    fee_store: FeeStore<N, FeeKV<N, E>>,
+}
+
+impl<N: Network, E: Engine> DeploymentKV<N, E> {
+    /// Initializes the deployment storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>, fee_store: FeeStore<N, FeeKV<N, E>>) -> Self {
+        Self {
+            id_map: database.map(MapID::Deployment(DeploymentMap::ID)),
+            edition_map: database.map(MapID::Deployment(DeploymentMap::Edition)),
+            reverse_id_map: database.map(MapID::Deployment(DeploymentMap::ReverseID)),
+            owner_map: database.map(MapID::Deployment(DeploymentMap::Owner)),
+            program_map: database.map(MapID::Deployment(DeploymentMap::Program)),
+            verifying_key_map: database.map(MapID::Deployment(DeploymentMap::VerifyingKey)),
+            certificate_map: database.map(MapID::Deployment(DeploymentMap::Certificate)),
+            fee_store,
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> DeploymentStorage<N> for DeploymentKV<N, E> {
+    type IDMap = DataMap<E, N::TransactionID, ProgramID<N>>;
+    type EditionMap = DataMap<E, ProgramID<N>, u16>;
+    type ReverseIDMap = DataMap<E, (ProgramID<N>, u16), N::TransactionID>;
+    type OwnerMap = DataMap<E, (ProgramID<N>, u16), ProgramOwner<N>>;
+    type ProgramMap = DataMap<E, (ProgramID<N>, u16), Program<N>>;
+    type VerifyingKeyMap = DataMap<E, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
+    type CertificateMap = DataMap<E, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type FeeStorage = FeeKV<N, E>;
+
+    /// Initializes the deployment storage.
+    fn open(fee_store: FeeStore<N, Self::FeeStorage>) -> Result<Self> {
+        let database = KVDatabase::open(N::ID, fee_store.dev())?;
+        Ok(Self::open_in(&database, fee_store))
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap {
+        &self.edition_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the program owner map.
+    fn owner_map(&self) -> &Self::OwnerMap {
+        &self.owner_map
+    }
+
+    /// Returns the program map.
+    fn program_map(&self) -> &Self::ProgramMap {
+        &self.program_map
+    }
+
+    /// Returns the verifying key map.
+    fn verifying_key_map(&self) -> &Self::VerifyingKeyMap {
+        &self.verifying_key_map
+    }
+
+    /// Returns the certificate map.
+    fn certificate_map(&self) -> &Self::CertificateMap {
+        &self.certificate_map
+    }
+
+    /// Returns the fee store.
+    fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
+        &self.fee_store
+    }
+}
+
+/// An execution storage over a key-value engine.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ExecutionKV<N: Network, E: Engine> {
+    /// The ID map.
+    id_map: DataMap<E, N::TransactionID, (Vec<N::TransitionID>, bool)>,
+    /// The reverse ID map.
+    reverse_id_map: DataMap<E, N::TransitionID, N::TransactionID>,
+    /// The inclusion map.
+    inclusion_map: DataMap<E, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The fee store.
+    fee_store: FeeStore<N, FeeKV<N, E>>,
+}
+
+impl<N: Network, E: Engine> ExecutionKV<N, E> {
+    /// Initializes the execution storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>, fee_store: FeeStore<N, FeeKV<N, E>>) -> Self {
+        Self {
+            id_map: database.map(MapID::Execution(ExecutionMap::ID)),
+            reverse_id_map: database.map(MapID::Execution(ExecutionMap::ReverseID)),
+            inclusion_map: database.map(MapID::Execution(ExecutionMap::Inclusion)),
+            fee_store,
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> ExecutionStorage<N> for ExecutionKV<N, E> {
+    type IDMap = DataMap<E, N::TransactionID, (Vec<N::TransitionID>, bool)>;
+    type ReverseIDMap = DataMap<E, N::TransitionID, N::TransactionID>;
+    type InclusionMap = DataMap<E, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type FeeStorage = FeeKV<N, E>;
+
+    /// Initializes the execution storage.
+    fn open(fee_store: FeeStore<N, Self::FeeStorage>) -> Result<Self> {
+        let database = KVDatabase::open(N::ID, fee_store.dev())?;
+        Ok(Self::open_in(&database, fee_store))
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the inclusion map.
+    fn inclusion_map(&self) -> &Self::InclusionMap {
+        &self.inclusion_map
+    }
+
+    /// Returns the fee store.
+    fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage> {
+        &self.fee_store
+    }
+}
+
+/// A fee storage over a key-value engine.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct FeeKV<N: Network, E: Engine> {
+    /// The fee map.
+    fee_map: DataMap<E, N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>,
+    /// The reverse fee map.
+    reverse_fee_map: DataMap<E, N::TransitionID, N::TransactionID>,
+    /// The transition store.
+    transition_store: TransitionStore<N, TransitionKV<N, E>>,
+}
+
+impl<N: Network, E: Engine> FeeKV<N, E> {
+    /// Initializes the fee storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>, transition_store: TransitionStore<N, TransitionKV<N, E>>) -> Self {
+        Self {
+            fee_map: database.map(MapID::Fee(FeeMap::Fee)),
+            reverse_fee_map: database.map(MapID::Fee(FeeMap::ReverseFee)),
+            transition_store,
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> FeeStorage<N> for FeeKV<N, E> {
+    type FeeMap = DataMap<E, N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
+    type ReverseFeeMap = DataMap<E, N::TransitionID, N::TransactionID>;
+    type TransitionStorage = TransitionKV<N, E>;
+
+    /// Initializes the fee storage.
+    fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        let database = KVDatabase::open(N::ID, transition_store.dev())?;
+        Ok(Self::open_in(&database, transition_store))
+    }
+
+    /// Returns the fee map.
+    fn fee_map(&self) -> &Self::FeeMap {
+        &self.fee_map
+    }
+
+    /// Returns the reverse fee map.
+    fn reverse_fee_map(&self) -> &Self::ReverseFeeMap {
+        &self.reverse_fee_map
+    }
+
+    /// Returns the transition store.
+    fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
+        &self.transition_store
+    }
+}
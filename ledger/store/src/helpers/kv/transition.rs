@@ -0,0 +1,344 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::kv::{DataMap, Engine, KVDatabase, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+    InputStorage,
+    InputStore,
+    OutputStorage,
+    OutputStore,
+    TransitionStorage,
+};
+use console::{
+    prelude::*,
+    program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
+    types::{Field, Group},
+};
+
+/// A transition storage over a key-value engine.
+#[derive(Clone)]
+pub struct TransitionKV<N: Network, E: Engine> {
+    /// The transition program IDs and function names.
+    locator_map: DataMap<E, N::TransitionID, (ProgramID<N>, Identifier<N>)>,
+    /// The transition input store.
+    input_store: InputStore<N, InputKV<N, E>>,
+    /// The transition output store.
+    output_store: OutputStore<N, OutputKV<N, E>>,
+    /// The transition public keys.
+    tpk_map: DataMap<E, N::TransitionID, Group<N>>,
+    /// The reverse `tpk` map.
+    reverse_tpk_map: DataMap<E, Group<N>, N::TransitionID>,
+    /// The transition commitments.
+    tcm_map: DataMap<E, N::TransitionID, Field<N>>,
+    /// The reverse `tcm` map.
+    reverse_tcm_map: DataMap<E, Field<N>, N::TransitionID>,
+    /// The transition public key bases and expiration heights of expiring transitions.
+    expiration_map: DataMap<E, N::TransitionID, (Group<N>, Option<u32>)>,
+}
+
+impl<N: Network, E: Engine> TransitionKV<N, E> {
+    /// Initializes the transition storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>) -> Self {
+        Self {
+            locator_map: database.map(MapID::Transition(TransitionMap::Locator)),
+            input_store: InputStore::from(InputKV::open_in(database)),
+            output_store: OutputStore::from(OutputKV::open_in(database)),
+            tpk_map: database.map(MapID::Transition(TransitionMap::TPK)),
+            reverse_tpk_map: database.map(MapID::Transition(TransitionMap::ReverseTPK)),
+            tcm_map: database.map(MapID::Transition(TransitionMap::TCM)),
+            reverse_tcm_map: database.map(MapID::Transition(TransitionMap::ReverseTCM)),
+            expiration_map: database.map(MapID::Transition(TransitionMap::Expiration)),
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> TransitionStorage<N> for TransitionKV<N, E> {
+    type LocatorMap = DataMap<E, N::TransitionID, (ProgramID<N>, Identifier<N>)>;
+    type InputStorage = InputKV<N, E>;
+    type OutputStorage = OutputKV<N, E>;
+    type TPKMap = DataMap<E, N::TransitionID, Group<N>>;
+    type ReverseTPKMap = DataMap<E, Group<N>, N::TransitionID>;
+    type TCMMap = DataMap<E, N::TransitionID, Field<N>>;
+    type ReverseTCMMap = DataMap<E, Field<N>, N::TransitionID>;
+    type ExpirationMap = DataMap<E, N::TransitionID, (Group<N>, Option<u32>)>;
+
+    /// Initializes the transition storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self::open_in(&KVDatabase::open(N::ID, dev)?))
+    }
+
+    /// Returns the transition program IDs and function names.
+    fn locator_map(&self) -> &Self::LocatorMap {
+        &self.locator_map
+    }
+
+    /// Returns the transition input store.
+    fn input_store(&self) -> &InputStore<N, Self::InputStorage> {
+        &self.input_store
+    }
+
+    /// Returns the transition output store.
+    fn output_store(&self) -> &OutputStore<N, Self::OutputStorage> {
+        &self.output_store
+    }
+
+    /// Returns the transition public keys.
+    fn tpk_map(&self) -> &Self::TPKMap {
+        &self.tpk_map
+    }
+
+    /// Returns the reverse `tpk` map.
+    fn reverse_tpk_map(&self) -> &Self::ReverseTPKMap {
+        &self.reverse_tpk_map
+    }
+
+    /// Returns the transition commitments.
+    fn tcm_map(&self) -> &Self::TCMMap {
+        &self.tcm_map
+    }
+
+    /// Returns the reverse `tcm` map.
+    fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
+        &self.reverse_tcm_map
+    }
+
+    /// Returns the transition public key bases and expiration heights.
+    fn expiration_map(&self) -> &Self::ExpirationMap {
+        &self.expiration_map
+    }
+}
+
+/// A transition input storage over a key-value engine.
+#[derive(Clone)]
+pub struct InputKV<N: Network, E: Engine> {
+    /// The mapping of `transition ID` to `input IDs`.
+    id_map: DataMap<E, N::TransitionID, Vec<Field<N>>>,
+    /// The mapping of `input ID` to `transition ID`.
+    reverse_id_map: DataMap<E, Field<N>, N::TransitionID>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    constant: DataMap<E, Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    public: DataMap<E, Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `ciphertext hash` to `(optional) ciphertext`.
+    private: DataMap<E, Field<N>, Option<Ciphertext<N>>>,
+    /// The mapping of `serial number` to `tag`.
+    record: DataMap<E, Field<N>, Field<N>>,
+    /// The mapping of `record tag` to `serial number`.
+    record_tag: DataMap<E, Field<N>, Field<N>>,
+    /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
+    external_record: DataMap<E, Field<N>, ()>,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+impl<N: Network, E: Engine> InputKV<N, E> {
+    /// Initializes the transition input storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>) -> Self {
+        Self {
+            id_map: database.map(MapID::TransitionInput(TransitionInputMap::ID)),
+            reverse_id_map: database.map(MapID::TransitionInput(TransitionInputMap::ReverseID)),
+            constant: database.map(MapID::TransitionInput(TransitionInputMap::Constant)),
+            public: database.map(MapID::TransitionInput(TransitionInputMap::Public)),
+            private: database.map(MapID::TransitionInput(TransitionInputMap::Private)),
+            record: database.map(MapID::TransitionInput(TransitionInputMap::Record)),
+            record_tag: database.map(MapID::TransitionInput(TransitionInputMap::RecordTag)),
+            external_record: database.map(MapID::TransitionInput(TransitionInputMap::ExternalRecord)),
+            dev: database.dev(),
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> InputStorage<N> for InputKV<N, E> {
+    type IDMap = DataMap<E, N::TransitionID, Vec<Field<N>>>;
+    type ReverseIDMap = DataMap<E, Field<N>, N::TransitionID>;
+    type ConstantMap = DataMap<E, Field<N>, Option<Plaintext<N>>>;
+    type PublicMap = DataMap<E, Field<N>, Option<Plaintext<N>>>;
+    type PrivateMap = DataMap<E, Field<N>, Option<Ciphertext<N>>>;
+    type RecordMap = DataMap<E, Field<N>, Field<N>>;
+    type RecordTagMap = DataMap<E, Field<N>, Field<N>>;
+    type ExternalRecordMap = DataMap<E, Field<N>, ()>;
+
+    /// Initializes the transition input storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self::open_in(&KVDatabase::open(N::ID, dev)?))
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the constant map.
+    fn constant_map(&self) -> &Self::ConstantMap {
+        &self.constant
+    }
+
+    /// Returns the public map.
+    fn public_map(&self) -> &Self::PublicMap {
+        &self.public
+    }
+
+    /// Returns the private map.
+    fn private_map(&self) -> &Self::PrivateMap {
+        &self.private
+    }
+
+    /// Returns the record map.
+    fn record_map(&self) -> &Self::RecordMap {
+        &self.record
+    }
+
+    /// Returns the record tag map.
+    fn record_tag_map(&self) -> &Self::RecordTagMap {
+        &self.record_tag
+    }
+
+    /// Returns the external record map.
+    fn external_record_map(&self) -> &Self::ExternalRecordMap {
+        &self.external_record
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
+
+/// A transition output storage over a key-value engine.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct OutputKV<N: Network, E: Engine> {
+    /// The mapping of `transition ID` to `output IDs`.
+    id_map: DataMap<E, N::TransitionID, Vec<Field<N>>>,
+    /// The mapping of `output ID` to `transition ID`.
+    reverse_id_map: DataMap<E, Field<N>, N::TransitionID>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    constant: DataMap<E, Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    public: DataMap<E, Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `ciphertext hash` to `(optional) ciphertext`.
+    private: DataMap<E, Field<N>, Option<Ciphertext<N>>>,
+    /// The mapping of `commitment` to `(checksum, (optional) record ciphertext)`.
+    record: DataMap<E, Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>,
+    /// The mapping of `record nonce` to `commitment`.
+    record_nonce: DataMap<E, Group<N>, Field<N>>,
+    /// The mapping of `external commitment` to `()`. Note: This is **not** the record commitment.
+    external_record: DataMap<E, Field<N>, ()>,
+    /// The mapping of `future hash` to `(optional) future`.
+    future: DataMap<E, Field<N>, Option<Future<N>>>,
+    /// The mapping of `memo hash` to `(nonce, (optional) memo ciphertext)`.
+    memo: DataMap<E, Field<N>, (Group<N>, Option<Ciphertext<N>>)>,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+impl<N: Network, E: Engine> OutputKV<N, E> {
+    /// Initializes the transition output storage in the given database.
+    pub fn open_in(database: &KVDatabase<E>) -> Self {
+        Self {
+            id_map: database.map(MapID::TransitionOutput(TransitionOutputMap::ID)),
+            reverse_id_map: database.map(MapID::TransitionOutput(TransitionOutputMap::ReverseID)),
+            constant: database.map(MapID::TransitionOutput(TransitionOutputMap::Constant)),
+            public: database.map(MapID::TransitionOutput(TransitionOutputMap::Public)),
+            private: database.map(MapID::TransitionOutput(TransitionOutputMap::Private)),
+            record: database.map(MapID::TransitionOutput(TransitionOutputMap::Record)),
+            record_nonce: database.map(MapID::TransitionOutput(TransitionOutputMap::RecordNonce)),
+            external_record: database.map(MapID::TransitionOutput(TransitionOutputMap::ExternalRecord)),
+            future: database.map(MapID::TransitionOutput(TransitionOutputMap::Future)),
+            memo: database.map(MapID::TransitionOutput(TransitionOutputMap::Memo)),
+            dev: database.dev(),
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, E: Engine> OutputStorage<N> for OutputKV<N, E> {
+    type IDMap = DataMap<E, N::TransitionID, Vec<Field<N>>>;
+    type ReverseIDMap = DataMap<E, Field<N>, N::TransitionID>;
+    type ConstantMap = DataMap<E, Field<N>, Option<Plaintext<N>>>;
+    type PublicMap = DataMap<E, Field<N>, Option<Plaintext<N>>>;
+    type PrivateMap = DataMap<E, Field<N>, Option<Ciphertext<N>>>;
+    type RecordMap = DataMap<E, Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
+    type RecordNonceMap = DataMap<E, Group<N>, Field<N>>;
+    type ExternalRecordMap = DataMap<E, Field<N>, ()>;
+    type FutureMap = DataMap<E, Field<N>, Option<Future<N>>>;
+    type MemoMap = DataMap<E, Field<N>, (Group<N>, Option<Ciphertext<N>>)>;
+
+    /// Initializes the transition output storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self::open_in(&KVDatabase::open(N::ID, dev)?))
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the constant map.
+    fn constant_map(&self) -> &Self::ConstantMap {
+        &self.constant
+    }
+
+    /// Returns the public map.
+    fn public_map(&self) -> &Self::PublicMap {
+        &self.public
+    }
+
+    /// Returns the private map.
+    fn private_map(&self) -> &Self::PrivateMap {
+        &self.private
+    }
+
+    /// Returns the record map.
+    fn record_map(&self) -> &Self::RecordMap {
+        &self.record
+    }
+
+    /// Returns the record nonce map.
+    fn record_nonce_map(&self) -> &Self::RecordNonceMap {
+        &self.record_nonce
+    }
+
+    /// Returns the external record map.
+    fn external_record_map(&self) -> &Self::ExternalRecordMap {
+        &self.external_record
+    }
+
+    /// Returns the future map.
+    fn future_map(&self) -> &Self::FutureMap {
+        &self.future
+    }
+
+    /// Returns the memo map.
+    fn memo_map(&self) -> &Self::MemoMap {
+        &self.memo
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
@@ -23,6 +23,7 @@ use console::{prelude::*, types::Field};
 use ledger_authority::Authority;
 use ledger_block::{Header, Ratifications, Rejected};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
+use synthesizer_program::Event;
 
 /// An in-memory block storage.
 #[derive(Clone)]
@@ -57,8 +58,26 @@ pub struct BlockMemory<N: Network> {
     confirmed_transactions_map: MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The events map.
+    events_map: MemoryMap<N::TransactionID, Vec<Event<N>>>,
+    /// The oldest height map.
+    oldest_height_map: MemoryMap<u8, u32>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
+    /// The number of recent blocks to keep in full, if pruning is enabled.
+    pruning_depth: Option<u32>,
+}
+
+impl<N: Network> BlockMemory<N> {
+    /// Initializes the block storage, pruning the bodies of blocks older than the given depth.
+    pub fn open_with_pruning(dev: Option<u16>, pruning_depth: u32) -> Result<Self> {
+        // Ensure the latest block is kept in full.
+        ensure!(pruning_depth > 0, "The pruning depth must be at least 1");
+        // Initialize the block storage.
+        let storage = Self::open(dev)?;
+        // Return the block storage.
+        Ok(Self { pruning_depth: Some(pruning_depth), ..storage })
+    }
 }
 
 #[rustfmt::skip]
@@ -78,6 +97,8 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type EventsMap = MemoryMap<N::TransactionID, Vec<Event<N>>>;
+    type OldestHeightMap = MemoryMap<u8, u32>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -104,7 +125,10 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            events_map: MemoryMap::default(),
+            oldest_height_map: MemoryMap::default(),
             transaction_store,
+            pruning_depth: None,
         })
     }
 
@@ -183,8 +207,23 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap {
+        &self.events_map
+    }
+
+    /// Returns the oldest height map.
+    fn oldest_height_map(&self) -> &Self::OldestHeightMap {
+        &self.oldest_height_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
     }
+
+    /// Returns the number of recent blocks to keep in full, or `None` if the blocks are never pruned.
+    fn pruning_depth(&self) -> Option<u32> {
+        self.pruning_depth
+    }
 }
@@ -29,6 +29,29 @@ pub struct ConsensusMemory<N: Network> {
     block_store: BlockStore<N, BlockMemory<N>>,
 }
 
+impl<N: Network> ConsensusMemory<N> {
+    /// Initializes the consensus storage, pruning the bodies of blocks older than the given depth.
+    pub fn open_with_pruning(dev: Option<u16>, pruning_depth: u32) -> Result<Self> {
+        // Initialize the finalize store.
+        let finalize_store = FinalizeStore::<N, FinalizeMemory<N>>::open(dev)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockMemory<N>>::from(BlockMemory::open_with_pruning(dev, pruning_depth)?)?;
+        // Return the consensus storage.
+        Ok(Self { finalize_store, block_store })
+    }
+
+    /// Initializes the consensus storage, recording the finalize history of the given number of recent blocks.
+    pub fn open_with_history(dev: Option<u16>, history_window: u32) -> Result<Self> {
+        // Initialize the finalize store.
+        let finalize_store =
+            FinalizeStore::<N, FinalizeMemory<N>>::from(FinalizeMemory::open_with_history(dev, history_window)?)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockMemory<N>>::open(dev)?;
+        // Return the consensus storage.
+        Ok(Self { finalize_store, block_store })
+    }
+}
+
 #[rustfmt::skip]
 impl<N: Network> ConsensusStorage<N> for ConsensusMemory<N> {
     type FinalizeStorage = FinalizeMemory<N>;
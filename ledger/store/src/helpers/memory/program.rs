@@ -37,8 +37,30 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The length map.
+    length_map: MemoryMap<(ProgramID<N>, Identifier<N>), u32>,
+    /// The key-position map.
+    key_position_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>,
+    /// The position-key map.
+    position_key_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), u32, Plaintext<N>>,
+    /// The history map.
+    history_map: NestedMemoryMap<u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>,
+    /// The history heights map.
+    history_heights_map: MemoryMap<u8, (u32, u32)>,
     /// The optional development ID.
     dev: Option<u16>,
+    /// The number of recent blocks whose finalize state can be queried, if the history is recorded.
+    history_window: Option<u32>,
+}
+
+impl<N: Network> FinalizeMemory<N> {
+    /// Initializes the finalize storage, recording the history of the given number of recent blocks.
+    pub fn open_with_history(dev: Option<u16>, history_window: u32) -> Result<Self> {
+        // Initialize the finalize storage.
+        let storage = Self::open(dev)?;
+        // Return the finalize storage.
+        Ok(Self { history_window: Some(history_window), ..storage })
+    }
 }
 
 #[rustfmt::skip]
@@ -46,6 +68,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type LengthMap = MemoryMap<(ProgramID<N>, Identifier<N>), u32>;
+    type KeyPositionMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>;
+    type PositionKeyMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), u32, Plaintext<N>>;
+    type HistoryMap = NestedMemoryMap<u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>;
+    type HistoryHeightsMap = MemoryMap<u8, (u32, u32)>;
 
     /// Initializes the finalize storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -56,7 +83,13 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            length_map: MemoryMap::default(),
+            key_position_map: NestedMemoryMap::default(),
+            position_key_map: NestedMemoryMap::default(),
+            history_map: NestedMemoryMap::default(),
+            history_heights_map: MemoryMap::default(),
             dev,
+            history_window: None,
         })
     }
 
@@ -81,10 +114,40 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the length map.
+    fn length_map(&self) -> &Self::LengthMap {
+        &self.length_map
+    }
+
+    /// Returns the key-position map.
+    fn key_position_map(&self) -> &Self::KeyPositionMap {
+        &self.key_position_map
+    }
+
+    /// Returns the position-key map.
+    fn position_key_map(&self) -> &Self::PositionKeyMap {
+        &self.position_key_map
+    }
+
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the history heights map.
+    fn history_heights_map(&self) -> &Self::HistoryHeightsMap {
+        &self.history_heights_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
     }
+
+    /// Returns the number of recent blocks whose finalize state can be queried, or `None` if the history is not recorded.
+    fn history_window(&self) -> Option<u32> {
+        self.history_window
+    }
 }
 
 /// An in-memory committee storage.
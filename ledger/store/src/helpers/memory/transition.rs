@@ -36,6 +36,8 @@ pub struct TransitionMemory<N: Network> {
     tcm_map: MemoryMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
+    /// The transition public key bases and expiration heights of expiring transitions.
+    expiration_map: MemoryMap<N::TransitionID, (Group<N>, Option<u32>)>,
 }
 
 #[rustfmt::skip]
@@ -47,6 +49,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type ReverseTPKMap = MemoryMap<Group<N>, N::TransitionID>;
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
+    type ExpirationMap = MemoryMap<N::TransitionID, (Group<N>, Option<u32>)>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -58,6 +61,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             reverse_tpk_map: MemoryMap::default(),
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
+            expiration_map: MemoryMap::default(),
         })
     }
 
@@ -95,6 +99,11 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the transition public key bases and expiration heights.
+    fn expiration_map(&self) -> &Self::ExpirationMap {
+        &self.expiration_map
+    }
 }
 
 /// An in-memory transition input storage.
@@ -214,6 +223,8 @@ pub struct OutputMemory<N: Network> {
     external_record: MemoryMap<Field<N>, ()>,
     /// The mapping of `future hash` to `(optional) future`.
     future: MemoryMap<Field<N>, Option<Future<N>>>,
+    /// The mapping of `memo hash` to `(nonce, (optional) memo ciphertext)`.
+    memo: MemoryMap<Field<N>, (Group<N>, Option<Ciphertext<N>>)>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -229,6 +240,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
     type RecordNonceMap = MemoryMap<Group<N>, Field<N>>;
     type ExternalRecordMap = MemoryMap<Field<N>, ()>;
     type FutureMap = MemoryMap<Field<N>, Option<Future<N>>>;
+    type MemoMap = MemoryMap<Field<N>, (Group<N>, Option<Ciphertext<N>>)>;
 
     /// Initializes the transition output storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -242,6 +254,7 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
             record_nonce: Default::default(),
             external_record: Default::default(),
             future: Default::default(),
+            memo: Default::default(),
             dev,
         })
     }
@@ -291,6 +304,11 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
         &self.future
     }
 
+    /// Returns the memo map.
+    fn memo_map(&self) -> &Self::MemoMap {
+        &self.memo
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
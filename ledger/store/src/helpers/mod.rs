@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod id;
+pub use id::*;
+
+pub mod kv;
 pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
@@ -12,20 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub mod internal;
-pub use internal::*;
-
-mod block;
-pub use block::*;
-
-mod consensus;
-pub use consensus::*;
-
-mod program;
-pub use program::*;
-
-mod transaction;
-pub use transaction::*;
-
-mod transition;
-pub use transition::*;
+pub use crate::helpers::kv::RocksEngine;
+
+use crate::helpers::kv::{
+    BlockKV,
+    CommitteeKV,
+    ConsensusKV,
+    DeploymentKV,
+    ExecutionKV,
+    FeeKV,
+    FinalizeKV,
+    InputKV,
+    OutputKV,
+    TransactionKV,
+    TransitionKV,
+};
+
+/// A consensus storage backed by RocksDB.
+pub type ConsensusDB<N> = ConsensusKV<N, RocksEngine>;
+/// A block storage backed by RocksDB.
+pub type BlockDB<N> = BlockKV<N, RocksEngine>;
+/// A finalize storage backed by RocksDB.
+pub type FinalizeDB<N> = FinalizeKV<N, RocksEngine>;
+/// A committee storage backed by RocksDB.
+pub type CommitteeDB<N> = CommitteeKV<N, RocksEngine>;
+/// A transaction storage backed by RocksDB.
+pub type TransactionDB<N> = TransactionKV<N, RocksEngine>;
+/// A deployment storage backed by RocksDB.
+pub type DeploymentDB<N> = DeploymentKV<N, RocksEngine>;
+/// An execution storage backed by RocksDB.
+pub type ExecutionDB<N> = ExecutionKV<N, RocksEngine>;
+/// A fee storage backed by RocksDB.
+pub type FeeDB<N> = FeeKV<N, RocksEngine>;
+/// A transition storage backed by RocksDB.
+pub type TransitionDB<N> = TransitionKV<N, RocksEngine>;
+/// A transition input storage backed by RocksDB.
+pub type InputDB<N> = InputKV<N, RocksEngine>;
+/// A transition output storage backed by RocksDB.
+pub type OutputDB<N> = OutputKV<N, RocksEngine>;
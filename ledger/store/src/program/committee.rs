@@ -359,6 +359,11 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns the committee storage.
+    pub(crate) const fn storage(&self) -> &C {
+        &self.storage
+    }
 }
 
 impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
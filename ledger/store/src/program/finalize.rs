@@ -27,9 +27,15 @@ use console::{
 use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 
 use anyhow::Result;
-use core::marker::PhantomData;
+use core::{
+    marker::PhantomData,
+    ops::{Range, RangeInclusive},
+};
 use indexmap::IndexSet;
 
+/// The key of the `(oldest height, latest height)` entry in the history heights map.
+const HISTORY_HEIGHTS_KEY: u8 = 0;
+
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
 fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<Field<N>> {
@@ -59,6 +65,35 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// Returns the checksum of the given finalize entries, which is independent of the order of the entries.
+pub(crate) fn to_finalize_checksum<N: Network>(
+    entries: impl Iterator<Item = ((ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>)>,
+) -> Result<Field<N>> {
+    // Compute all mapping checksums.
+    let preimage: std::collections::BTreeMap<_, _> = entries
+        .map(|(m, k, v)| {
+            let mut preimage = Vec::new();
+            m.write_bits_le(&mut preimage);
+            false.write_bits_le(&mut preimage); // Separator.
+            k.write_bits_le(&mut preimage);
+            false.write_bits_le(&mut preimage); // Separator.
+
+            // Compute the mapping checksum as `Hash( m || k )`.
+            let mapping_checksum = N::hash_bhp1024(&preimage)?;
+
+            v.write_bits_le(&mut preimage);
+            false.write_bits_le(&mut preimage); // Separator.
+
+            // Compute the entry checksum as `Hash( m || k || v )`.
+            let entry_checksum = N::hash_bhp1024(&preimage)?;
+            // Return the mapping checksum and entry checksum.
+            Ok::<_, Error>((mapping_checksum, entry_checksum.to_bits_le()))
+        })
+        .try_collect()?;
+    // Compute the checksum as `Hash( all mapping checksums )`.
+    N::hash_bhp1024(&preimage.into_values().flatten().collect::<Vec<_>>())
+}
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
@@ -69,6 +104,17 @@ fn to_key_id<N: Network>(
 /// // (program_id => (mapping_name => (key => value)))
 /// BTreeMap<ProgramID<N>, BTreeMap<Identifier<N>, BTreeMap<Key, Value>>>
 /// ```
+///
+/// Each mapping also maintains its number of entries, and a position for each key, so that
+/// the length of a mapping and the entry at a given position can be read without loading the mapping.
+/// The entries are positioned in insertion order, and removing an entry moves the last entry into its position.
+/// As the mappings stored before their positions were maintained cannot recover their insertion order,
+/// the entries of every mapping are repositioned in the order of their key bytes at `N::MAPPING_POSITIONS_HEIGHT`,
+/// and the positions are only read from that height.
+///
+/// If a history window is set, each change to a key is also recorded in the history, as the value of the key
+/// before the block that changed it. The changes are recorded by the same operations that return the
+/// `FinalizeOperation`s of a block, so that the value of a key after any recent block can be recovered.
 pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The committee storage.
     type CommitteeStorage: CommitteeStorage<N>;
@@ -76,6 +122,16 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `(program ID, mapping name)` to `number of entries`.
+    type LengthMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>), u32>;
+    /// The mapping of `(program ID, mapping name)` to `[(key, position)]`.
+    type KeyPositionMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, u32>;
+    /// The mapping of `(program ID, mapping name)` to `[(position, key)]`.
+    type PositionKeyMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), u32, Plaintext<N>>;
+    /// The mapping of `block height` to `[((program ID, mapping name, key), value before the block)]`.
+    type HistoryMap: for<'a> NestedMap<'a, u32, (ProgramID<N>, Identifier<N>, Plaintext<N>), Option<Value<N>>>;
+    /// The mapping of `HISTORY_HEIGHTS_KEY` to the `(oldest height, latest height)` that can be queried in the history.
+    type HistoryHeightsMap: for<'a> Map<'a, u8, (u32, u32)>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -90,15 +146,35 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the length map.
+    fn length_map(&self) -> &Self::LengthMap;
+    /// Returns the key-position map.
+    fn key_position_map(&self) -> &Self::KeyPositionMap;
+    /// Returns the position-key map.
+    fn position_key_map(&self) -> &Self::PositionKeyMap;
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap;
+    /// Returns the history heights map.
+    fn history_heights_map(&self) -> &Self::HistoryHeightsMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
 
+    /// Returns the number of recent blocks whose finalize state can be queried, or `None` if the history is not recorded.
+    fn history_window(&self) -> Option<u32> {
+        None
+    }
+
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.length_map().start_atomic();
+        self.key_position_map().start_atomic();
+        self.position_key_map().start_atomic();
+        self.history_map().start_atomic();
+        self.history_heights_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -106,6 +182,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.length_map().is_atomic_in_progress()
+            || self.key_position_map().is_atomic_in_progress()
+            || self.position_key_map().is_atomic_in_progress()
+            || self.history_map().is_atomic_in_progress()
+            || self.history_heights_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -113,6 +194,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.length_map().atomic_checkpoint();
+        self.key_position_map().atomic_checkpoint();
+        self.position_key_map().atomic_checkpoint();
+        self.history_map().atomic_checkpoint();
+        self.history_heights_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -120,6 +206,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.length_map().clear_latest_checkpoint();
+        self.key_position_map().clear_latest_checkpoint();
+        self.position_key_map().clear_latest_checkpoint();
+        self.history_map().clear_latest_checkpoint();
+        self.history_heights_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -127,6 +218,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.length_map().atomic_rewind();
+        self.key_position_map().atomic_rewind();
+        self.position_key_map().atomic_rewind();
+        self.history_map().atomic_rewind();
+        self.history_heights_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -134,13 +230,23 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.length_map().abort_atomic();
+        self.key_position_map().abort_atomic();
+        self.position_key_map().abort_atomic();
+        self.history_map().abort_atomic();
+        self.history_heights_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.length_map().finish_atomic()?;
+        self.key_position_map().finish_atomic()?;
+        self.position_key_map().finish_atomic()?;
+        self.history_map().finish_atomic()?;
+        self.history_heights_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -204,6 +310,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
 
         atomic_batch_scope!(self, {
+            // Record the previous value of the key in the history.
+            self.record_history(program_id, mapping_name, &key)?;
+            // Append the new key to the positions of the mapping.
+            self.push_key_position(program_id, mapping_name, &key)?;
             // Update the key-value map with the new key-value.
             self.key_value_map().insert((program_id, mapping_name), key, value)?;
 
@@ -236,6 +346,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
 
         atomic_batch_scope!(self, {
+            // Record the previous value of the key in the history.
+            self.record_history(program_id, mapping_name, &key)?;
+            // If the key is new, append it to the positions of the mapping.
+            if !self.contains_key_speculative(program_id, mapping_name, &key)? {
+                self.push_key_position(program_id, mapping_name, &key)?;
+            }
             // Update the key-value map with the new key-value.
             self.key_value_map().insert((program_id, mapping_name), key, value)?;
 
@@ -264,6 +380,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
 
         atomic_batch_scope!(self, {
+            // Record the previous value of the key in the history.
+            self.record_history(program_id, mapping_name, key)?;
+            // Remove the key from the positions of the mapping.
+            self.swap_remove_key_position(program_id, mapping_name, key)?;
             // Update the key-value map with the new key.
             self.key_value_map().remove_key(&(program_id, mapping_name), key)?;
 
@@ -288,11 +408,19 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
 
         atomic_batch_scope!(self, {
-            // Remove the existing key-value entries.
+            // Record the previous values of the existing and new keys in the history.
+            self.record_mapping_history(program_id, mapping_name)?;
+            for (key, _) in &entries {
+                self.record_history(program_id, mapping_name, key)?;
+            }
+            // Remove the existing key-value entries and their positions.
             self.key_value_map().remove_map(&(program_id, mapping_name))?;
+            self.remove_positions(program_id, mapping_name)?;
 
             // Insert the new key-value entries.
             for (key, value) in entries {
+                // Append the key to the positions of the mapping.
+                self.push_key_position(program_id, mapping_name, &key)?;
                 // Insert the key-value entry.
                 self.key_value_map().insert((program_id, mapping_name), key, value)?;
             }
@@ -318,10 +446,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
 
         atomic_batch_scope!(self, {
+            // Record the previous values of the existing keys in the history.
+            self.record_mapping_history(program_id, mapping_name)?;
             // Update the mapping names.
             self.program_id_map().insert(program_id, mapping_names)?;
             // Remove the mapping.
             self.key_value_map().remove_map(&(program_id, mapping_name))?;
+            // Remove the positions of the mapping.
+            self.remove_positions(program_id, mapping_name)?;
 
             Ok(())
         })?;
@@ -344,13 +476,85 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 
             // Remove each mapping.
             for mapping_name in mapping_names.iter() {
+                // Record the previous values of the existing keys in the history.
+                self.record_mapping_history(*program_id, *mapping_name)?;
                 // Remove the mapping.
                 self.key_value_map().remove_map(&(*program_id, *mapping_name))?;
+                // Remove the positions of the mapping.
+                self.remove_positions(*program_id, *mapping_name)?;
             }
             Ok(())
         })
     }
 
+    /// Starts recording the history of the block at the given height, if a history window is set.
+    /// This must be called in the atomic batch that finalizes the block, before any changes are made.
+    /// The entries older than the history window are removed.
+    fn start_history(&self, height: u32) -> Result<()> {
+        // Retrieve the history window.
+        let Some(history_window) = self.history_window() else {
+            return Ok(());
+        };
+        // Retrieve the heights that can be queried in the history.
+        let oldest_height = match self.history_heights_map().get_speculative(&HISTORY_HEIGHTS_KEY)? {
+            Some(heights) => match cow_to_copied!(heights) {
+                // If the history is contiguous, continue from the oldest height.
+                (oldest_height, latest_height) if latest_height.checked_add(1) == Some(height) => oldest_height,
+                // Otherwise, restart the history from the previous height.
+                (oldest_height, latest_height) => {
+                    for height in oldest_height..=latest_height {
+                        self.history_map().remove_map(&height)?;
+                    }
+                    height.saturating_sub(1)
+                }
+            },
+            // If the history is empty, start the history from the previous height.
+            None => height.saturating_sub(1),
+        };
+        // Compute the oldest height that can be queried after the block.
+        let next_oldest_height = oldest_height.max(height.saturating_sub(history_window));
+
+        // Remove the entries that are no longer needed.
+        // Note: The entries at a given height are only needed to recover the values at the previous height.
+        for height in oldest_height..=next_oldest_height {
+            self.history_map().remove_map(&height)?;
+        }
+        // Update the heights that can be queried in the history.
+        self.history_heights_map().insert(HISTORY_HEIGHTS_KEY, (next_oldest_height, height))
+    }
+
+    /// Records the value of the given `program ID`, `mapping name`, and `key` in the history,
+    /// if the history of a block is being recorded in the current atomic batch.
+    /// Only the first change to a key in a block is recorded, as the value of the key before the block.
+    fn record_history(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>, key: &Plaintext<N>) -> Result<()> {
+        // Retrieve the height of the block that is being recorded.
+        let Some(Some((_, height))) = self.history_heights_map().get_pending(&HISTORY_HEIGHTS_KEY) else {
+            return Ok(());
+        };
+        // Construct the history entry.
+        let entry = (program_id, mapping_name, key.clone());
+        // If the key has not been changed in the block, record its current value.
+        if !self.history_map().contains_key_speculative(&height, &entry)? {
+            let value = self.get_value_speculative(program_id, mapping_name, key)?;
+            self.history_map().insert(height, entry, value)?;
+        }
+        Ok(())
+    }
+
+    /// Records the values of all keys for the given `program ID` and `mapping name` in the history,
+    /// if the history of a block is being recorded in the current atomic batch.
+    fn record_mapping_history(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<()> {
+        // If no block is being recorded, return early.
+        if self.history_heights_map().get_pending(&HISTORY_HEIGHTS_KEY).is_none() {
+            return Ok(());
+        }
+        // Record the value of each key.
+        for (key, _) in self.key_value_map().get_map_speculative(&(program_id, mapping_name))? {
+            self.record_history(program_id, mapping_name, &key)?;
+        }
+        Ok(())
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
@@ -424,6 +628,178 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_map().get_map_speculative(&(program_id, mapping_name))
     }
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get length (S).")
+        }
+        // Retrieve the number of entries.
+        match self.length_map().get_speculative(&(program_id, mapping_name))? {
+            Some(length) => Ok(cow_to_copied!(length) as usize),
+            None => Ok(0),
+        }
+    }
+
+    /// Returns the speculative entry at the given `index` for the given `program ID` and `mapping name`.
+    /// See `FinalizeStorage` for the order of the entries.
+    fn get_entry_at_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        index: usize,
+    ) -> Result<Option<(Plaintext<N>, Value<N>)>> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get entry (S).")
+        }
+        // If the index is out of bounds, return early.
+        let Ok(position) = u32::try_from(index) else {
+            return Ok(None);
+        };
+        // Retrieve the key at the given position.
+        let key = match self.position_key_map().get_value_speculative(&(program_id, mapping_name), &position)? {
+            Some(key) => cow_to_cloned!(key),
+            None => return Ok(None),
+        };
+        // Retrieve the value for the key.
+        match self.key_value_map().get_value_speculative(&(program_id, mapping_name), &key)? {
+            Some(value) => Ok(Some((key, cow_to_cloned!(value)))),
+            None => bail!("Missing value for the key '{key}' at position {position} in '{program_id}/{mapping_name}'"),
+        }
+    }
+
+    /// Appends the given `key` to the positions of the given `program ID` and `mapping name`.
+    /// Note: This method must be called within an atomic batch, and only for a key that is not in the mapping.
+    fn push_key_position(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<()> {
+        // Retrieve the number of entries, which is the position of the new key.
+        let position = u32::try_from(self.get_mapping_length_speculative(program_id, mapping_name)?)?;
+        // Compute the new number of entries.
+        let Some(length) = position.checked_add(1) else {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' has reached the maximum number of entries")
+        };
+        // Store the key at the new position.
+        self.key_position_map().insert((program_id, mapping_name), key.clone(), position)?;
+        self.position_key_map().insert((program_id, mapping_name), position, key.clone())?;
+        // Update the number of entries.
+        self.length_map().insert((program_id, mapping_name), length)
+    }
+
+    /// Removes the given `key` from the positions of the given `program ID` and `mapping name`,
+    /// by moving the last key into its position.
+    /// Note: This method must be called within an atomic batch, and only for a key that is in the mapping.
+    fn swap_remove_key_position(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<()> {
+        // Retrieve the position of the key.
+        let position = match self.key_position_map().get_value_speculative(&(program_id, mapping_name), key)? {
+            Some(position) => cow_to_copied!(position),
+            None => bail!("Missing position for the key '{key}' in '{program_id}/{mapping_name}'"),
+        };
+        // Retrieve the position of the last key, which is the new number of entries.
+        let length = u32::try_from(self.get_mapping_length_speculative(program_id, mapping_name)?)?;
+        let Some(last_position) = length.checked_sub(1) else {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is empty - cannot remove the key '{key}'")
+        };
+        // If the key is not the last key, move the last key into its position.
+        if position != last_position {
+            let last_key =
+                match self.position_key_map().get_value_speculative(&(program_id, mapping_name), &last_position)? {
+                    Some(last_key) => cow_to_cloned!(last_key),
+                    None => bail!("Missing key at position {last_position} in '{program_id}/{mapping_name}'"),
+                };
+            self.key_position_map().insert((program_id, mapping_name), last_key.clone(), position)?;
+            self.position_key_map().insert((program_id, mapping_name), position, last_key)?;
+        }
+        // Remove the key and the last position.
+        self.key_position_map().remove_key(&(program_id, mapping_name), key)?;
+        self.position_key_map().remove_key(&(program_id, mapping_name), &last_position)?;
+        // Update the number of entries.
+        self.length_map().insert((program_id, mapping_name), last_position)
+    }
+
+    /// Repositions the entries of every mapping in the order of their key bytes.
+    /// Note: This method must be called at `N::MAPPING_POSITIONS_HEIGHT`, before any mapping is changed in the block.
+    fn reposition_mappings(&self) -> Result<()> {
+        // Retrieve the mappings.
+        let mappings = self
+            .program_id_map()
+            .iter_confirmed()
+            .flat_map(|(program_id, mapping_names)| {
+                mapping_names.iter().map(|mapping_name| (*program_id, *mapping_name)).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        atomic_batch_scope!(self, {
+            for (program_id, mapping_name) in mappings {
+                self.reposition_mapping(program_id, mapping_name)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Repositions the entries of the given `program ID` and `mapping name` in the order of their key bytes,
+    /// which does not depend on the order in which the entries were stored.
+    /// Note: This method must be called within an atomic batch.
+    fn reposition_mapping(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<()> {
+        // Retrieve the keys of the mapping, sorted by their bytes.
+        let mut keys = self
+            .key_value_map()
+            .get_map_speculative(&(program_id, mapping_name))?
+            .into_iter()
+            .map(|(key, _)| Ok((key.to_bytes_le()?, key)))
+            .collect::<Result<Vec<_>>>()?;
+        keys.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        // Remove the existing positions of the mapping.
+        self.remove_positions(program_id, mapping_name)?;
+        // Append each key to the positions of the mapping, in the sorted order.
+        for (_, key) in keys {
+            self.push_key_position(program_id, mapping_name, &key)?;
+        }
+        Ok(())
+    }
+
+    /// Backfills the number of entries and the key positions of the mappings that have entries but no
+    /// recorded number of entries, i.e. the mappings stored before their positions were maintained.
+    /// Note: The backfilled positions are in the order of the key bytes, as the insertion order is not stored.
+    /// The positions are only read from `N::MAPPING_POSITIONS_HEIGHT`, at which every mapping is repositioned.
+    fn backfill_positions(&self) -> Result<()> {
+        // Retrieve the mappings without a recorded number of entries.
+        let mut mappings = Vec::new();
+        for (program_id, mapping_names) in self.program_id_map().iter_confirmed() {
+            for mapping_name in mapping_names.iter() {
+                if !self.length_map().contains_key_confirmed(&(*program_id, *mapping_name))? {
+                    mappings.push((*program_id, *mapping_name));
+                }
+            }
+        }
+        for (program_id, mapping_name) in mappings {
+            // Retrieve the entries of the mapping.
+            let entries = self.key_value_map().get_map_confirmed(&(program_id, mapping_name))?;
+            // If the mapping is empty, there is nothing to backfill.
+            if entries.is_empty() {
+                continue;
+            }
+            atomic_batch_scope!(self, { self.reposition_mapping(program_id, mapping_name) })?;
+        }
+        Ok(())
+    }
+
+    /// Removes the positions and the number of entries of the given `program ID` and `mapping name`.
+    /// Note: This method must be called within an atomic batch.
+    fn remove_positions(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<()> {
+        self.key_position_map().remove_map(&(program_id, mapping_name))?;
+        self.position_key_map().remove_map(&(program_id, mapping_name))?;
+        self.length_map().remove(&(program_id, mapping_name))
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     fn get_value_confirmed(
         &self,
@@ -450,37 +826,89 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
-    /// Returns the confirmed checksum of the finalize storage.
-    fn get_checksum_confirmed(&self) -> Result<Field<N>> {
-        // Compute all mapping checksums.
-        let preimage: std::collections::BTreeMap<_, _> = self
-            .key_value_map()
-            .iter_confirmed()
-            .map(|(m, k, v)| {
-                let m = cow_to_copied!(m);
-                let k = cow_to_cloned!(k);
-                let v = cow_to_cloned!(v);
+    /// Returns the heights that can be queried in the history, or `None` if the history is not recorded.
+    fn get_history_heights(&self) -> Result<Option<RangeInclusive<u32>>> {
+        match self.history_heights_map().get_confirmed(&HISTORY_HEIGHTS_KEY)? {
+            Some(heights) => {
+                let (oldest_height, latest_height) = cow_to_copied!(heights);
+                Ok(Some(oldest_height..=latest_height))
+            }
+            None => Ok(None),
+        }
+    }
 
-                let mut preimage = Vec::new();
-                m.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-                k.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`,
+    /// after the block at the given height.
+    fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Retrieve the value at the given height.
+        match self.get_value_history(program_id, mapping_name, key, height..height.saturating_add(1))?.pop() {
+            Some((_, value)) => Ok(value),
+            None => bail!("The finalize state at height {height} is not in the history"),
+        }
+    }
 
-                // Compute the mapping checksum as `Hash( m || k )`.
-                let mapping_checksum = N::hash_bhp1024(&preimage)?;
+    /// Returns the confirmed values for the given `program ID`, `mapping name`, and `key`,
+    /// after each block in the given range of heights.
+    fn get_value_history(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<(u32, Option<Value<N>>)>> {
+        // Retrieve the heights that can be queried in the history.
+        let Some(history_heights) = self.get_history_heights()? else {
+            bail!("The finalize history is not recorded in storage")
+        };
+        // Ensure the given heights can be queried.
+        if heights.is_empty() {
+            return Ok(Vec::new());
+        }
+        if !history_heights.contains(&heights.start) || !history_heights.contains(&(heights.end - 1)) {
+            bail!(
+                "The finalize state at heights {}..{} is not in the history (heights {}..={})",
+                heights.start,
+                heights.end,
+                history_heights.start(),
+                history_heights.end()
+            )
+        }
 
-                v.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
+        // Construct the history entry.
+        let entry = (program_id, mapping_name, key.clone());
+        // Start from the latest value, and undo the changes of each block in reverse order.
+        let mut value = self.get_value_confirmed(program_id, mapping_name, key)?;
+        let mut values = Vec::with_capacity(heights.len());
+        for height in (heights.start..=*history_heights.end()).rev() {
+            // Store the value after the block at this height.
+            if heights.contains(&height) {
+                values.push((height, value.clone()));
+            }
+            // Undo the change of the block at this height, to recover the value at the previous height.
+            if height > heights.start {
+                if let Some(previous_value) = self.history_map().get_value_confirmed(&height, &entry)? {
+                    value = cow_to_cloned!(previous_value);
+                }
+            }
+        }
+        // Return the values in ascending order of height.
+        values.reverse();
+        Ok(values)
+    }
 
-                // Compute the entry checksum as `Hash( m || k || v )`.
-                let entry_checksum = N::hash_bhp1024(&preimage)?;
-                // Return the mapping checksum and entry checksum.
-                Ok::<_, Error>((mapping_checksum, entry_checksum.to_bits_le()))
-            })
-            .try_collect()?;
-        // Compute the checksum as `Hash( all mapping checksums )`.
-        N::hash_bhp1024(&preimage.into_values().flatten().collect::<Vec<_>>())
+    /// Returns the confirmed checksum of the finalize storage.
+    fn get_checksum_confirmed(&self) -> Result<Field<N>> {
+        to_finalize_checksum(
+            self.key_value_map()
+                .iter_confirmed()
+                .map(|(m, k, v)| (cow_to_copied!(m), cow_to_cloned!(k), cow_to_cloned!(v))),
+        )
     }
 
     /// Returns the pending checksum of the finalize storage.
@@ -542,6 +970,8 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
+        // Backfill the positions of the mappings stored before their positions were maintained.
+        storage.backfill_positions()?;
         // Return the finalize store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -585,6 +1015,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns the finalize storage.
+    pub(crate) const fn storage(&self) -> &P {
+        &self.storage
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
@@ -620,6 +1055,22 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize> {
+        self.storage.get_mapping_length_speculative(program_id, mapping_name)
+    }
+
+    /// Returns the speculative entry at the given `index` for the given `program ID` and `mapping name`.
+    /// See `FinalizeStorage` for the order of the entries.
+    fn get_entry_at_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        index: usize,
+    ) -> Result<Option<(Plaintext<N>, Value<N>)>> {
+        self.storage.get_entry_at_speculative(program_id, mapping_name, index)
+    }
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -695,6 +1146,19 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
         self.storage.remove_program(program_id)
     }
+
+    /// Starts recording the history of the block at the given height, if a history window is set.
+    /// This must be called in the atomic batch that finalizes the block, before any changes are made.
+    pub fn start_history(&self, height: u32) -> Result<()> {
+        self.storage.start_history(height)
+    }
+
+    /// Repositions the entries of every mapping in the order of their key bytes.
+    /// This must be called in the atomic batch that finalizes the block at `N::MAPPING_POSITIONS_HEIGHT`,
+    /// before any changes are made.
+    pub fn reposition_mappings(&self) -> Result<()> {
+        self.storage.reposition_mappings()
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
@@ -764,17 +1228,56 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     }
 }
 
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns the number of recent blocks whose finalize state can be queried, or `None` if the history is not recorded.
+    pub fn history_window(&self) -> Option<u32> {
+        self.storage.history_window()
+    }
+
+    /// Returns the heights that can be queried in the history, or `None` if the history is not recorded.
+    pub fn get_history_heights(&self) -> Result<Option<RangeInclusive<u32>>> {
+        self.storage.get_history_heights()
+    }
+
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`,
+    /// after the block at the given height.
+    pub fn get_mapping_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.storage.get_value_at_height(program_id, mapping_name, key, height)
+    }
+
+    /// Returns the confirmed values for the given `program ID`, `mapping name`, and `key`,
+    /// after each block in the given range of heights.
+    pub fn get_mapping_value_history(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<(u32, Option<Value<N>>)>> {
+        self.storage.get_value_history(program_id, mapping_name, key, heights)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::helpers::memory::FinalizeMemory;
+    use crate::helpers::{
+        kv::{internal::tests::MemoryEngine, FinalizeKV},
+        memory::FinalizeMemory,
+    };
     use console::{network::Testnet3, program::Literal, types::U64};
 
     type CurrentNetwork = Testnet3;
 
     /// Checks `initialize_mapping`, `insert_key_value`, `remove_key_value`, and `remove_mapping`.
-    fn check_initialize_insert_remove<N: Network>(
-        finalize_store: &FinalizeStore<N, FinalizeMemory<N>>,
+    fn check_initialize_insert_remove<N: Network, P: FinalizeStorage<N>>(
+        finalize_store: &FinalizeStore<N, P>,
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) {
@@ -846,8 +1349,8 @@ mod tests {
     }
 
     /// Checks `initialize_mapping`, `update_key_value`, `remove_key_value`, and `remove_mapping`.
-    fn check_initialize_update_remove<N: Network>(
-        finalize_store: &FinalizeStore<N, FinalizeMemory<N>>,
+    fn check_initialize_update_remove<N: Network, P: FinalizeStorage<N>>(
+        finalize_store: &FinalizeStore<N, P>,
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) {
@@ -987,6 +1490,31 @@ mod tests {
         check_initialize_update_remove(&finalize_store, program_id, mapping_name);
     }
 
+    #[test]
+    fn test_kv_engines() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store over the in-memory engine.
+        let temp_dir = crate::helpers::kv::internal::tests::temp_dir();
+        let finalize_store = FinalizeStore::<_, FinalizeKV<_, MemoryEngine>>::open_testing(temp_dir, None).unwrap();
+        // Check the operations.
+        check_initialize_insert_remove(&finalize_store, program_id, mapping_name);
+        check_initialize_update_remove(&finalize_store, program_id, mapping_name);
+
+        // Initialize a new finalize store over the redb engine.
+        #[cfg(feature = "redb")]
+        {
+            let temp_dir = crate::helpers::kv::internal::tests::temp_dir();
+            let finalize_store =
+                FinalizeStore::<_, FinalizeKV<_, crate::helpers::kv::RedbEngine>>::open_testing(temp_dir, None).unwrap();
+            // Check the operations.
+            check_initialize_insert_remove(&finalize_store, program_id, mapping_name);
+            check_initialize_update_remove(&finalize_store, program_id, mapping_name);
+        }
+    }
+
     #[test]
     fn test_remove_key_value() {
         // Initialize a program ID and mapping name.
@@ -1076,6 +1604,184 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_entry_at() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Initialize the mapping.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        // Ensure the mapping is empty.
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 0);
+        assert!(finalize_store.get_entry_at_speculative(program_id, mapping_name, 0).unwrap().is_none());
+
+        // A helper to prepare the key and value.
+        let entry = |item: u8| {
+            (Plaintext::from_str(&format!("{item}u8")).unwrap(), Value::from_str(&format!("{item}u64")).unwrap())
+        };
+
+        // Insert the keys in a shuffled order.
+        for item in [3u8, 1, 4, 0] {
+            let (key, value) = entry(item);
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+
+        // Insert and remove keys in an atomic batch, and check the speculative entries.
+        finalize_store.start_atomic();
+        let (key, value) = entry(2);
+        finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        finalize_store.remove_key_value(program_id, mapping_name, &entry(3).0).unwrap();
+        // Ensure the entries are ordered by insertion, where the removed key is replaced by the last key.
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 4);
+        for (index, item) in [2u8, 1, 4, 0].into_iter().enumerate() {
+            let expected = entry(item);
+            let candidate = finalize_store.get_entry_at_speculative(program_id, mapping_name, index).unwrap();
+            assert_eq!(candidate, Some(expected));
+        }
+        // Ensure the index is bounded.
+        assert!(finalize_store.get_entry_at_speculative(program_id, mapping_name, 4).unwrap().is_none());
+        finalize_store.finish_atomic().unwrap();
+
+        // Ensure the confirmed entries match.
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 4);
+        for (index, item) in [2u8, 1, 4, 0].into_iter().enumerate() {
+            let candidate = finalize_store.get_entry_at_speculative(program_id, mapping_name, index).unwrap();
+            assert_eq!(candidate, Some(entry(item)));
+        }
+
+        // Update an existing key, and remove the last key.
+        let (key, value) = entry(1);
+        finalize_store.update_key_value(program_id, mapping_name, key, value).unwrap();
+        finalize_store.remove_key_value(program_id, mapping_name, &entry(0).0).unwrap();
+        // Ensure the positions of the remaining keys are unchanged.
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 3);
+        for (index, item) in [2u8, 1, 4].into_iter().enumerate() {
+            let candidate = finalize_store.get_entry_at_speculative(program_id, mapping_name, index).unwrap();
+            assert_eq!(candidate, Some(entry(item)));
+        }
+
+        // Remove the mapping, and ensure its positions are removed.
+        finalize_store.remove_mapping(program_id, mapping_name).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 0);
+        assert!(finalize_store.get_entry_at_speculative(program_id, mapping_name, 0).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_backfill_positions() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let empty_name = Identifier::from_str("empty").unwrap();
+
+        // A helper to prepare the key and value.
+        let entry = |item: u8| {
+            (Plaintext::from_str(&format!("{item}u8")).unwrap(), Value::from_str(&format!("{item}u64")).unwrap())
+        };
+
+        // Initialize a storage with mapping entries, but without their number of entries or positions.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        program_memory.program_id_map().insert(program_id, IndexSet::from([mapping_name, empty_name])).unwrap();
+        for item in [3u8, 1, 4, 0] {
+            let (key, value) = entry(item);
+            program_memory.key_value_map().insert((program_id, mapping_name), key, value).unwrap();
+        }
+        assert!(program_memory.length_map().get_confirmed(&(program_id, mapping_name)).unwrap().is_none());
+
+        // Open the finalize store, which backfills the positions.
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Ensure the number of entries and the positions are backfilled, in the order of the key bytes.
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 4);
+        for (index, item) in [0u8, 1, 3, 4].into_iter().enumerate() {
+            let candidate = finalize_store.get_entry_at_speculative(program_id, mapping_name, index).unwrap();
+            assert_eq!(candidate, Some(entry(item)));
+        }
+        assert!(finalize_store.get_entry_at_speculative(program_id, mapping_name, 4).unwrap().is_none());
+        // Ensure the empty mapping is unchanged.
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, empty_name).unwrap(), 0);
+
+        // Ensure the backfilled positions are maintained by later updates.
+        finalize_store.remove_key_value(program_id, mapping_name, &entry(3).0).unwrap();
+        let (key, value) = entry(2);
+        finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 4);
+        let mut candidates = (0..4)
+            .map(|index| finalize_store.get_entry_at_speculative(program_id, mapping_name, index).unwrap().unwrap())
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|(key, _)| key.to_string());
+        assert_eq!(candidates, [0u8, 1, 2, 4].map(entry));
+
+        // Ensure reopening the storage does not backfill the positions again.
+        let finalize_store = FinalizeStore::from(finalize_store.storage).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_reposition_mappings() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // A helper to prepare the key and value.
+        let entry = |item: u8| {
+            (Plaintext::from_str(&format!("{item}u8")).unwrap(), Value::from_str(&format!("{item}u64")).unwrap())
+        };
+
+        // Initialize a store that maintained the positions from the start, by inserting and removing entries.
+        let replayed_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        replayed_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for item in [3u8, 1, 2, 4, 0] {
+            let (key, value) = entry(item);
+            replayed_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+        replayed_store.remove_key_value(program_id, mapping_name, &entry(2).0).unwrap();
+
+        // Initialize a store with the same entries, stored in another order before their positions were maintained.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        program_memory.program_id_map().insert(program_id, IndexSet::from([mapping_name])).unwrap();
+        for item in [0u8, 4, 1, 3] {
+            let (key, value) = entry(item);
+            program_memory.key_value_map().insert((program_id, mapping_name), key, value).unwrap();
+        }
+        // Open the finalize store, which backfills the positions.
+        let backfilled_store = FinalizeStore::from(program_memory).unwrap();
+
+        // A helper to retrieve the entries of the mapping by position.
+        let entries_at = |finalize_store: &FinalizeStore<_, FinalizeMemory<_>>| {
+            let length = finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap();
+            (0..length)
+                .map(|index| finalize_store.get_entry_at_speculative(program_id, mapping_name, index).unwrap().unwrap())
+                .collect::<Vec<_>>()
+        };
+        // Ensure the positions differ before the mappings are repositioned.
+        assert_ne!(entries_at(&replayed_store), entries_at(&backfilled_store));
+
+        for finalize_store in [&replayed_store, &backfilled_store] {
+            // Reposition the mappings.
+            finalize_store.reposition_mappings().unwrap();
+            // Ensure the entries are positioned in the order of the key bytes.
+            assert_eq!(entries_at(finalize_store), [0u8, 1, 3, 4].map(entry));
+
+            // Insert and remove entries after the mappings are repositioned.
+            finalize_store.remove_key_value(program_id, mapping_name, &entry(1).0).unwrap();
+            let (key, value) = entry(5);
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+            let (key, value) = entry(2);
+            finalize_store.update_key_value(program_id, mapping_name, key, value).unwrap();
+            finalize_store.remove_key_value(program_id, mapping_name, &entry(0).0).unwrap();
+        }
+
+        // Ensure both stores return the same entry at each position.
+        let entries = entries_at(&replayed_store);
+        assert_eq!(entries, [2u8, 4, 3, 5].map(entry));
+        assert_eq!(entries, entries_at(&backfilled_store));
+        assert!(backfilled_store.get_entry_at_speculative(program_id, mapping_name, 4).unwrap().is_none());
+    }
+
     #[test]
     fn test_remove_mapping() {
         // Initialize a program ID and mapping name.
@@ -1204,6 +1910,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_history() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Prepare the key and values.
+        let key = Plaintext::from_str("0field").unwrap();
+        let value = |item: u64| Value::from_str(&format!("{item}u64")).unwrap();
+
+        // Initialize a new finalize store, which records the history of the latest 2 blocks.
+        let program_memory = FinalizeMemory::open_with_history(None, 2).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Ensure the history is empty.
+        assert_eq!(None, finalize_store.get_history_heights().unwrap());
+        assert!(finalize_store.get_mapping_value_at_height(program_id, mapping_name, &key, 0).is_err());
+
+        // Finalizes a block at the given height, with the given changes.
+        let finalize_block = |height: u32, changes: &dyn Fn()| {
+            finalize_store.start_atomic();
+            finalize_store.start_history(height).unwrap();
+            changes();
+            finalize_store.finish_atomic().unwrap();
+        };
+
+        // Block 0: Initialize the mapping, and insert the key.
+        finalize_block(0, &|| {
+            finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value(0)).unwrap();
+        });
+        // Block 1: Update the key twice.
+        finalize_block(1, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(5)).unwrap();
+            finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(1)).unwrap();
+        });
+        // Block 2: No changes.
+        finalize_block(2, &|| {});
+
+        // Ensure the values after each block are recovered.
+        assert_eq!(Some(0..=2), finalize_store.get_history_heights().unwrap());
+        let history = finalize_store.get_mapping_value_history(program_id, mapping_name, &key, 0..3).unwrap();
+        assert_eq!(vec![(0, Some(value(0))), (1, Some(value(1))), (2, Some(value(1)))], history);
+
+        // Block 3: Remove the key.
+        finalize_block(3, &|| {
+            finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+        });
+        // Changes outside of a recorded block are not recorded.
+        finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value(7)).unwrap();
+        finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+        // Block 4: Insert the key again.
+        finalize_block(4, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(4)).unwrap();
+        });
+
+        // Ensure only the latest 2 blocks, and the block before them, can be queried.
+        assert_eq!(Some(2..=4), finalize_store.get_history_heights().unwrap());
+        assert!(finalize_store.get_mapping_value_at_height(program_id, mapping_name, &key, 1).is_err());
+        assert!(finalize_store.get_mapping_value_at_height(program_id, mapping_name, &key, 5).is_err());
+        assert_eq!(
+            Some(value(1)),
+            finalize_store.get_mapping_value_at_height(program_id, mapping_name, &key, 2).unwrap()
+        );
+        assert_eq!(None, finalize_store.get_mapping_value_at_height(program_id, mapping_name, &key, 3).unwrap());
+        assert_eq!(
+            Some(value(4)),
+            finalize_store.get_mapping_value_at_height(program_id, mapping_name, &key, 4).unwrap()
+        );
+        // Ensure the entries that are no longer needed are removed.
+        assert!(finalize_store.storage.history_map().get_map_confirmed(&1).unwrap().is_empty());
+        assert!(!finalize_store.storage.history_map().get_map_confirmed(&3).unwrap().is_empty());
+
+        // Ensure a gap in the history restarts it.
+        finalize_block(6, &|| {});
+        assert_eq!(Some(5..=6), finalize_store.get_history_heights().unwrap());
+        assert!(finalize_store.storage.history_map().get_map_confirmed(&3).unwrap().is_empty());
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
@@ -217,10 +217,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_transaction_edition(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // Ensure the edition is the latest, as an edition may only be removed after the editions that upgrade it.
+        if self.get_edition(&program_id)? != Some(edition) {
+            bail!("Failed to remove program '{program_id}' (edition {edition}), as it is not the latest edition")
+        }
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -230,8 +234,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Restore the previous edition, or remove the edition if this is the initial deployment.
+            match edition > N::EDITION {
+                true => self.edition_map().insert(program_id, edition - 1)?,
+                false => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -276,6 +283,18 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the transaction ID that deployed the given `program ID` at the given `edition`.
+    fn find_transaction_id_at_edition(
+        &self,
+        program_id: &ProgramID<N>,
+        edition: u16,
+    ) -> Result<Option<N::TransactionID>> {
+        match self.reverse_id_map().get_confirmed(&(*program_id, edition))? {
+            Some(transaction_id) => Ok(Some(cow_to_copied!(transaction_id))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -308,6 +327,29 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the edition deployed by the given `transaction ID`.
+    fn get_transaction_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Retrieve the latest edition.
+        let latest_edition = match self.get_edition(&program_id)? {
+            Some(edition) => edition,
+            None => bail!("Failed to get the edition for program '{program_id}'"),
+        };
+        // Search the editions, starting from the latest, for the one deployed by the transaction ID.
+        for edition in (N::EDITION..=latest_edition).rev() {
+            if let Some(candidate) = self.reverse_id_map().get_confirmed(&(program_id, edition))? {
+                if cow_to_copied!(candidate) == *transaction_id {
+                    return Ok(Some(edition));
+                }
+            }
+        }
+        bail!("Failed to find the edition of program '{program_id}' for transaction '{transaction_id}'")
+    }
+
     /// Returns the program for the given `program ID`.
     fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -389,7 +431,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_transaction_edition(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to get the edition for program '{program_id}'"),
         };
@@ -427,8 +469,17 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().get_fee(transaction_id)
     }
 
-    /// Returns the owner for the given `program ID`.
+    /// Returns the owner for the latest edition of the given `program ID`.
     fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        // Retrieve the edition.
+        match self.get_edition(program_id)? {
+            Some(edition) => self.get_owner_at_edition(program_id, edition),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the owner for the given `program ID` and `edition`.
+    fn get_owner_at_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<ProgramOwner<N>>> {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
         // TODO (howardwu): After we update 'fee' rules and 'Ratify' in genesis, we can remove this.
@@ -436,12 +487,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             return Ok(None);
         }
 
-        // TODO (raychu86): Consider program upgrades and edition changes.
-        // Retrieve the edition.
-        let edition = match self.get_edition(program_id)? {
-            Some(edition) => edition,
-            None => return Ok(None),
-        };
+        // Ensure the edition has been deployed.
+        match self.get_edition(program_id)? {
+            Some(latest_edition) if edition <= latest_edition => (),
+            _ => return Ok(None),
+        }
 
         // Retrieve the owner.
         match self.owner_map().get_confirmed(&(*program_id, edition))? {
@@ -464,7 +514,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         };
 
         // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
+        let owner = match self.get_owner_at_edition(deployment.program_id(), deployment.edition())? {
             Some(owner) => owner,
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
@@ -569,6 +619,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_edition(program_id)
     }
 
+    /// Returns the edition deployed by the given `transaction ID`.
+    pub fn get_transaction_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        self.storage.get_transaction_edition(transaction_id)
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.get_program_id(transaction_id)
@@ -601,6 +656,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
     }
+
+    /// Returns the owner for the given `program ID` and `edition`.
+    pub fn get_owner_at_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner_at_edition(program_id, edition)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -609,6 +669,15 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.find_transaction_id_from_program_id(program_id)
     }
 
+    /// Returns the transaction ID that deployed the given `program ID` at the given `edition`.
+    pub fn find_transaction_id_at_edition(
+        &self,
+        program_id: &ProgramID<N>,
+        edition: u16,
+    ) -> Result<Option<N::TransactionID>> {
+        self.storage.find_transaction_id_at_edition(program_id, edition)
+    }
+
     /// Returns the transaction ID that deployed the given `transition ID`.
     pub fn find_transaction_id_from_transition_id(
         &self,
@@ -633,10 +702,7 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 
     /// Returns an iterator over the program IDs, for all deployments.
     pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
-        self.storage.id_map().values_confirmed().map(|id| match id {
-            Cow::Borrowed(id) => Cow::Borrowed(id),
-            Cow::Owned(id) => Cow::Owned(id),
-        })
+        self.storage.edition_map().keys_confirmed()
     }
 
     /// Returns an iterator over the programs, for all deployments.
@@ -752,4 +818,69 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_insert_remove_upgrade() {
+        let rng = &mut TestRng::default();
+
+        // Sample the initial deployment transaction.
+        let transaction_0 = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let (owner_0, deployment_0) = match &transaction_0 {
+            Transaction::Deploy(_, owner, deployment, _) => (*owner, deployment.clone()),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_id = *deployment_0.program_id();
+
+        // Construct the upgrade to the next edition.
+        let deployment_1 = Deployment::new(
+            deployment_0.edition() + 1,
+            deployment_0.program().clone(),
+            deployment_0.verifying_keys().clone(),
+        )
+        .unwrap();
+        let deployment_id = deployment_1.to_deployment_id().unwrap();
+        let private_key = console::account::PrivateKey::new(rng).unwrap();
+        let owner_1 = ProgramOwner::new(&private_key, deployment_id, rng).unwrap();
+        let fee = ledger_test_helpers::sample_fee_public(deployment_id, rng);
+        let transaction_1 = Transaction::from_deployment(owner_1, deployment_1, fee).unwrap();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentMemory::open(fee_store).unwrap();
+
+        // Insert both editions.
+        deployment_store.insert(&transaction_0).unwrap();
+        deployment_store.insert(&transaction_1).unwrap();
+
+        // Ensure the latest edition is tracked, and the earlier edition remains retrievable.
+        assert_eq!(Some(deployment_0.edition() + 1), deployment_store.get_edition(&program_id).unwrap());
+        assert_eq!(
+            Some(transaction_1.id()),
+            deployment_store.find_transaction_id_from_program_id(&program_id).unwrap()
+        );
+        assert_eq!(Some(transaction_0.clone()), deployment_store.get_transaction(&transaction_0.id()).unwrap());
+        assert_eq!(Some(transaction_1.clone()), deployment_store.get_transaction(&transaction_1.id()).unwrap());
+        assert_eq!(Some(owner_0), deployment_store.get_owner_at_edition(&program_id, deployment_0.edition()).unwrap());
+        assert_eq!(Some(owner_1), deployment_store.get_owner(&program_id).unwrap());
+
+        // Ensure the initial edition cannot be removed before its upgrade.
+        assert!(deployment_store.remove(&transaction_0.id()).is_err());
+
+        // Remove the upgrade, and ensure the initial edition is restored.
+        deployment_store.remove(&transaction_1.id()).unwrap();
+        assert_eq!(Some(deployment_0.edition()), deployment_store.get_edition(&program_id).unwrap());
+        assert_eq!(
+            Some(transaction_0.id()),
+            deployment_store.find_transaction_id_from_program_id(&program_id).unwrap()
+        );
+        assert_eq!(None, deployment_store.get_transaction(&transaction_1.id()).unwrap());
+
+        // Remove the initial edition.
+        deployment_store.remove(&transaction_0.id()).unwrap();
+        assert_eq!(None, deployment_store.get_edition(&program_id).unwrap());
+        assert_eq!(None, deployment_store.get_transaction(&transaction_0.id()).unwrap());
+    }
 }
@@ -193,6 +193,37 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the execution for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transition IDs and fee boolean.
+        let (transition_ids, has_fee) = match self.id_map().get_confirmed(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the transition IDs.
+            self.id_map().remove(transaction_id)?;
+
+            // Prune the execution.
+            // Note: The transaction ID of each transition is kept, to locate the transaction that spent a record.
+            for transition_id in transition_ids {
+                // Prune the transition.
+                self.transition_store().prune(&transition_id)?;
+            }
+
+            // Remove the global state root and proof.
+            self.inclusion_map().remove(transaction_id)?;
+
+            // Prune the fee.
+            if has_fee {
+                self.fee_store().prune(transaction_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -318,6 +349,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the execution for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -357,6 +393,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns the execution storage.
+    pub(crate) const fn storage(&self) -> &E {
+        &self.storage
+    }
 }
 
 impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
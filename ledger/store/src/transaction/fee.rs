@@ -136,6 +136,26 @@ pub trait FeeStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the fee for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the fee transition ID.
+        let (transition_id, _, _) = match self.fee_map().get_confirmed(transaction_id)? {
+            Some(fee_id) => cow_to_cloned!(fee_id),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the fee.
+            // Note: The transaction ID of the fee transition is kept, to locate the transaction that spent a record.
+            self.fee_map().remove(transaction_id)?;
+
+            // Prune the fee transition.
+            self.transition_store().prune(&transition_id)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -195,6 +215,11 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the fee for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, F::TransitionStorage> {
         self.storage.transition_store()
@@ -239,6 +264,11 @@ impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns the fee storage.
+    pub(crate) const fn storage(&self) -> &F {
+        &self.storage
+    }
 }
 
 impl<N: Network, F: FeeStorage<N>> FeeStore<N, F> {
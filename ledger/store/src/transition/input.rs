@@ -216,6 +216,32 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the input bodies for the given `transition ID`.
+    /// Note: The input IDs, serial numbers, and tags are kept, so that they cannot be reused.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the input IDs.
+        let input_ids: Vec<_> = match self.id_map().get_confirmed(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the input IDs.
+            self.id_map().remove(transition_id)?;
+
+            // Remove the input bodies.
+            for input_id in input_ids {
+                self.constant_map().remove(&input_id)?;
+                self.public_map().remove(&input_id)?;
+                self.private_map().remove(&input_id)?;
+                self.external_record_map().remove(&input_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transition ID that contains the given `input ID`.
     fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(input_id)? {
@@ -343,6 +369,11 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the input bodies for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -382,6 +413,11 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns the transition input storage.
+    pub(crate) const fn storage(&self) -> &I {
+        &self.storage
+    }
 }
 
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
@@ -50,6 +50,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type TCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
     /// The mapping of `transition commitment` to `transition ID`.
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
+    /// The transition public key bases and expiration heights of expiring transitions.
+    type ExpirationMap: for<'a> Map<'a, N::TransitionID, (Group<N>, Option<u32>)>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -68,6 +70,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn tcm_map(&self) -> &Self::TCMMap;
     /// Returns the reverse `tcm` map.
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
+    /// Returns the transition expirations map.
+    fn expiration_map(&self) -> &Self::ExpirationMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
@@ -84,6 +88,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().start_atomic();
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
+        self.expiration_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -95,6 +100,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.reverse_tpk_map().is_atomic_in_progress()
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
+            || self.expiration_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -106,6 +112,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().atomic_checkpoint();
         self.tcm_map().atomic_checkpoint();
         self.reverse_tcm_map().atomic_checkpoint();
+        self.expiration_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -117,6 +124,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().clear_latest_checkpoint();
         self.tcm_map().clear_latest_checkpoint();
         self.reverse_tcm_map().clear_latest_checkpoint();
+        self.expiration_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -128,6 +136,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().atomic_rewind();
         self.tcm_map().atomic_rewind();
         self.reverse_tcm_map().atomic_rewind();
+        self.expiration_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -139,6 +148,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().abort_atomic();
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
+        self.expiration_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -149,7 +159,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tpk_map().finish_atomic()?;
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
-        self.reverse_tcm_map().finish_atomic()
+        self.reverse_tcm_map().finish_atomic()?;
+        self.expiration_map().finish_atomic()
     }
 
     /// Stores the given `transition` into storage.
@@ -171,6 +182,11 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().insert(transition_id, *transition.tcm())?;
             // Store the reverse `tcm` entry.
             self.reverse_tcm_map().insert(*transition.tcm(), transition_id)?;
+            // Store the transition public key base and expiration height, if the transition expires or has a memo.
+            if transition.expiration_height().is_some() || transition.memo().is_some() {
+                self.expiration_map()
+                    .insert(transition_id, (*transition.tpk_base(), transition.expiration_height()))?;
+            }
 
             Ok(())
         })
@@ -204,6 +220,28 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().remove(transition_id)?;
             // Remove the reverse `tcm` entry.
             self.reverse_tcm_map().remove(&tcm)?;
+            // Remove the transition public key base and expiration height.
+            self.expiration_map().remove(transition_id)?;
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the transition body for the given `transition ID`.
+    /// Note: The transition ID, the reverse `tpk` and `tcm` entries, and the input and output sets are kept,
+    /// so that they cannot be reused.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Prune the inputs.
+            self.input_store().prune(transition_id)?;
+            // Prune the outputs.
+            self.output_store().prune(transition_id)?;
+            // Remove `tpk`.
+            self.tpk_map().remove(transition_id)?;
+            // Remove `tcm`.
+            self.tcm_map().remove(transition_id)?;
+            // Remove the transition public key base and expiration height.
+            self.expiration_map().remove(transition_id)?;
 
             Ok(())
         })
@@ -224,17 +262,27 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         let tpk = self.tpk_map().get_confirmed(transition_id)?;
         // Retrieve `tcm`.
         let tcm = self.tcm_map().get_confirmed(transition_id)?;
+        // Retrieve the transition public key base and expiration height.
+        let expiration = self.expiration_map().get_confirmed(transition_id)?;
 
         match (tpk, tcm) {
             (Some(tpk), Some(tcm)) => {
+                let tpk = cow_to_cloned!(tpk);
+                // Note: A transition without an expiration height or memo has `tpk == R_0`.
+                let (tpk_base, expiration_height) = match expiration {
+                    Some(expiration) => cow_to_cloned!(expiration),
+                    None => (tpk, None),
+                };
                 // Construct the transition.
                 let transition = Transition::new(
                     program_id,
                     function_name,
                     inputs,
                     outputs,
-                    cow_to_cloned!(tpk),
+                    tpk,
                     cow_to_cloned!(tcm),
+                    tpk_base,
+                    expiration_height,
                 )?;
                 // Ensure the transition ID matches.
                 match transition.id() == transition_id {
@@ -310,6 +358,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the transition body for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -349,6 +402,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns the transition storage.
+    pub(crate) const fn storage(&self) -> &T {
+        &self.storage
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
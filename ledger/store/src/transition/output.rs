@@ -46,6 +46,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     type ExternalRecordMap: for<'a> Map<'a, Field<N>, ()>;
     /// The mapping of `future hash` to `(optional) future`.
     type FutureMap: for<'a> Map<'a, Field<N>, Option<Future<N>>>;
+    /// The mapping of `memo hash` to `(nonce, (optional) memo ciphertext)`.
+    type MemoMap: for<'a> Map<'a, Field<N>, (Group<N>, Option<Ciphertext<N>>)>;
 
     /// Initializes the transition output storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -68,6 +70,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     fn external_record_map(&self) -> &Self::ExternalRecordMap;
     /// Returns the future map.
     fn future_map(&self) -> &Self::FutureMap;
+    /// Returns the memo map.
+    fn memo_map(&self) -> &Self::MemoMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -83,6 +87,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().start_atomic();
         self.external_record_map().start_atomic();
         self.future_map().start_atomic();
+        self.memo_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -96,6 +101,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
             || self.record_nonce_map().is_atomic_in_progress()
             || self.external_record_map().is_atomic_in_progress()
             || self.future_map().is_atomic_in_progress()
+            || self.memo_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -109,6 +115,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().atomic_checkpoint();
         self.external_record_map().atomic_checkpoint();
         self.future_map().atomic_checkpoint();
+        self.memo_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -122,6 +129,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().clear_latest_checkpoint();
         self.external_record_map().clear_latest_checkpoint();
         self.future_map().clear_latest_checkpoint();
+        self.memo_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -135,6 +143,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().atomic_rewind();
         self.external_record_map().atomic_rewind();
         self.future_map().atomic_rewind();
+        self.memo_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -148,6 +157,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_nonce_map().abort_atomic();
         self.external_record_map().abort_atomic();
         self.future_map().abort_atomic();
+        self.memo_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -160,7 +170,8 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         self.record_map().finish_atomic()?;
         self.record_nonce_map().finish_atomic()?;
         self.external_record_map().finish_atomic()?;
-        self.future_map().finish_atomic()
+        self.future_map().finish_atomic()?;
+        self.memo_map().finish_atomic()
     }
 
     /// Stores the given `(transition ID, output)` pair into storage.
@@ -188,6 +199,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                     }
                     Output::ExternalRecord(output_id) => self.external_record_map().insert(output_id, ())?,
                     Output::Future(output_id, future) => self.future_map().insert(output_id, future)?,
+                    Output::Memo(output_id, nonce, memo) => self.memo_map().insert(output_id, (nonce, memo))?,
                 }
             }
 
@@ -227,6 +239,41 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                 self.record_map().remove(&output_id)?;
                 self.external_record_map().remove(&output_id)?;
                 self.future_map().remove(&output_id)?;
+                self.memo_map().remove(&output_id)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the output bodies for the given `transition ID`.
+    /// Note: The output IDs, commitments, checksums, and record nonces are kept, so that they cannot be reused.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the output IDs.
+        let output_ids: Vec<_> = match self.id_map().get_confirmed(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            // Remove the output IDs.
+            self.id_map().remove(transition_id)?;
+
+            // Remove the output bodies.
+            for output_id in output_ids {
+                // If the output is a record, remove the record ciphertext.
+                if let Some(record) = self.record_map().get_confirmed(&output_id)? {
+                    let checksum = record.0;
+                    self.record_map().insert(output_id, (checksum, None))?;
+                }
+
+                self.constant_map().remove(&output_id)?;
+                self.public_map().remove(&output_id)?;
+                self.private_map().remove(&output_id)?;
+                self.external_record_map().remove(&output_id)?;
+                self.future_map().remove(&output_id)?;
+                self.memo_map().remove(&output_id)?;
             }
 
             Ok(())
@@ -262,6 +309,12 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                     Cow::Owned((checksum, opt_record)) => Output::Record($output_id, checksum, opt_record),
                 }
             };
+            (Output::Memo($output_id:ident, $output:expr)) => {
+                match $output {
+                    Cow::Borrowed((nonce, opt_memo)) => Output::Memo($output_id, *nonce, opt_memo.clone()),
+                    Cow::Owned((nonce, opt_memo)) => Output::Memo($output_id, nonce, opt_memo),
+                }
+            };
             (Output::$Variant:ident($output_id:ident, $output:expr)) => {
                 match $output {
                     Cow::Borrowed(output) => Output::$Variant($output_id, output.clone()),
@@ -290,6 +343,9 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
             if let Some(future) = self.future_map().get_confirmed(&output_id)? {
                 return Ok(into_output!(Output::Future(output_id, future)));
             }
+            if let Some(memo) = self.memo_map().get_confirmed(&output_id)? {
+                return Ok(into_output!(Output::Memo(output_id, memo)));
+            }
 
             bail!("Missing output '{output_id}' in transition '{transition_id}'")
         };
@@ -320,6 +376,8 @@ pub struct OutputStore<N: Network, O: OutputStorage<N>> {
     external_record: O::ExternalRecordMap,
     /// The map of future outputs.
     future: O::FutureMap,
+    /// The map of memo outputs.
+    memo: O::MemoMap,
     /// The output storage.
     storage: O,
 }
@@ -338,6 +396,7 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
             record_nonce: storage.record_nonce_map().clone(),
             external_record: storage.external_record_map().clone(),
             future: storage.future_map().clone(),
+            memo: storage.memo_map().clone(),
             storage,
         })
     }
@@ -352,6 +411,7 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
             record_nonce: storage.record_nonce_map().clone(),
             external_record: storage.external_record_map().clone(),
             future: storage.future_map().clone(),
+            memo: storage.memo_map().clone(),
             storage,
         }
     }
@@ -366,6 +426,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the output bodies for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -405,6 +470,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns the transition output storage.
+    pub(crate) const fn storage(&self) -> &O {
+        &self.storage
+    }
 }
 
 impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
@@ -499,6 +569,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     pub fn future_output_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
         self.future.keys_confirmed()
     }
+
+    /// Returns an iterator over the memo output IDs, for all transition outputs that are memos.
+    pub fn memo_output_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
+        self.memo.keys_confirmed()
+    }
 }
 
 impl<N: Network, I: OutputStorage<N>> OutputStore<N, I> {
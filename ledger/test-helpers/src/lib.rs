@@ -109,6 +109,8 @@ pub fn sample_outputs() -> Vec<(<CurrentNetwork as Network>::TransitionID, Outpu
     ).unwrap();
     let record_ciphertext = record.encrypt(randomizer).unwrap();
     let record_checksum = CurrentNetwork::hash_bhp1024(&record_ciphertext.to_bits_le()).unwrap();
+    // Sample a random memo.
+    let memo = Output::new_memo(&plaintext, &Address::rand(rng), rng).unwrap();
 
     vec![
         (transition_id, input),
@@ -121,6 +123,8 @@ pub fn sample_outputs() -> Vec<(<CurrentNetwork as Network>::TransitionID, Outpu
         (Uniform::rand(rng), Output::Record(Uniform::rand(rng), Uniform::rand(rng), None)),
         (Uniform::rand(rng), Output::Record(Uniform::rand(rng), record_checksum, Some(record_ciphertext))),
         (Uniform::rand(rng), Output::ExternalRecord(Uniform::rand(rng))),
+        (Uniform::rand(rng), Output::Memo(Uniform::rand(rng), Uniform::rand(rng), None)),
+        (Uniform::rand(rng), memo),
     ]
 }
 
@@ -167,7 +171,11 @@ pub fn sample_execution(rng: &mut TestRng) -> Execution<CurrentNetwork> {
     // Retrieve a transaction.
     let transaction = block.transactions().iter().next().unwrap().deref().clone();
     // Retrieve the execution.
-    if let Transaction::Execute(_, execution, _) = transaction { execution } else { unreachable!() }
+    if let Transaction::Execute(_, execution, _) = transaction {
+        execution
+    } else {
+        unreachable!()
+    }
 }
 
 /********************************************** Fee ***********************************************/
@@ -411,7 +419,7 @@ fn sample_genesis_block_and_components_raw(
     // Construct the transaction.
     let transaction = Transaction::from_execution(execution, None).unwrap();
     // Prepare the confirmed transaction.
-    let confirmed = ConfirmedTransaction::accepted_execute(0, transaction.clone(), vec![]).unwrap();
+    let confirmed = ConfirmedTransaction::accepted_execute(0, transaction.clone(), vec![], vec![]).unwrap();
     // Prepare the transactions.
     let transactions = Transactions::from_iter([confirmed].into_iter());
 
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Returns a balance attestation, proving the given `credits.aleo` records, owned by the private key,
+    /// total at least `threshold` microcredits and exist at the global state root of their state paths.
+    /// Note: This does *not* check that the records are unspent. See `VM::check_balance_attestation`.
+    #[inline]
+    pub fn prove_balance_attestation<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: Vec<(Record<N, Plaintext<N>>, StatePath<N>)>,
+        threshold: u64,
+        rng: &mut R,
+    ) -> Result<BalanceAttestation<N>> {
+        let timer = timer!("Process::prove_balance_attestation");
+
+        // Construct the balance assignment.
+        let balance_assignment = BalanceAssignment::new(private_key, records, threshold)?;
+        // Compute the circuit assignment.
+        let assignment = balance_assignment.to_circuit_assignment::<A>()?;
+        lap!(timer, "Construct the balance assignment");
+
+        // Synthesize the circuit key for the number of records.
+        let (proving_key, _) = self.universal_srs.to_circuit_key(BALANCE_ATTESTATION_FUNCTION_NAME, &assignment)?;
+        lap!(timer, "Synthesize the circuit key");
+
+        // Compute the proof.
+        let proof = proving_key.prove(BALANCE_ATTESTATION_FUNCTION_NAME, &assignment, rng)?;
+        lap!(timer, "Compute the proof");
+
+        // Construct the balance attestation.
+        let attestation = BalanceAttestation::try_from((
+            balance_assignment.state_root(),
+            balance_assignment.to_serial_numbers()?,
+            proof,
+        ))?;
+        finish!(timer);
+
+        Ok(attestation)
+    }
+
+    /// Verifies the given balance attestation proves the address owns records totalling at least
+    /// `threshold` microcredits, which exist at the given global state root.
+    /// Note: This does *not* check that the global state root exists in the ledger, nor that the records are unspent.
+    #[inline]
+    pub fn verify_balance_attestation<A: circuit::Aleo<Network = N>>(
+        &self,
+        address: &Address<N>,
+        threshold: u64,
+        state_root: N::StateRoot,
+        attestation: &BalanceAttestation<N>,
+    ) -> Result<()> {
+        let timer = timer!("Process::verify_balance_attestation");
+
+        // Ensure the attestation is for the given global state root.
+        ensure!(
+            attestation.state_root() == state_root,
+            "The balance attestation is for state root '{}', expected '{state_root}'",
+            attestation.state_root()
+        );
+
+        // Synthesize the verifying key from a sample assignment with the same number of records.
+        let sample =
+            BalanceAssignment::<N>::sample(attestation.serial_numbers().len())?.to_circuit_assignment::<A>()?;
+        let (_, verifying_key) = self.universal_srs.to_circuit_key(BALANCE_ATTESTATION_FUNCTION_NAME, &sample)?;
+        lap!(timer, "Synthesize the verifying key");
+
+        // Verify the proof.
+        ensure!(
+            verifying_key.verify(
+                BALANCE_ATTESTATION_FUNCTION_NAME,
+                &attestation.to_verifier_inputs(address, threshold),
+                attestation.proof()
+            ),
+            "Balance attestation proof is invalid"
+        );
+        finish!(timer);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance_attestation::test_helpers::sample_balance_attestation;
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_verify_balance_attestation() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample a balance attestation of 300 microcredits.
+        let (attestation, address) = sample_balance_attestation(rng);
+        let state_root = attestation.state_root();
+
+        // Ensure the attestation verifies for any threshold up to the balance.
+        process.verify_balance_attestation::<CurrentAleo>(&address, 300, state_root, &attestation).unwrap();
+        process.verify_balance_attestation::<CurrentAleo>(&address, 0, state_root, &attestation).unwrap();
+
+        // Ensure the attestation fails for a higher threshold.
+        assert!(process.verify_balance_attestation::<CurrentAleo>(&address, 301, state_root, &attestation).is_err());
+        // Ensure the attestation fails for a different address.
+        let other = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        assert!(process.verify_balance_attestation::<CurrentAleo>(&other, 300, state_root, &attestation).is_err());
+        // Ensure the attestation fails for a different state root.
+        let other_root = Field::<CurrentNetwork>::rand(rng).into();
+        assert!(process.verify_balance_attestation::<CurrentAleo>(&address, 300, other_root, &attestation).is_err());
+    }
+}
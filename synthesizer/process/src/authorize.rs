@@ -29,6 +29,53 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
     }
 
+    /// Authorizes a call to the program function for the given inputs,
+    /// which expires after the given block height, if any.
+    #[inline]
+    pub fn authorize_with_expiration<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        expiration_height: Option<u32>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Authorize the call.
+        self.get_stack(program_id)?.authorize_with_expiration::<A, R>(
+            private_key,
+            function_name,
+            inputs,
+            expiration_height,
+            rng,
+        )
+    }
+
+    /// Authorizes a call to the program function for the given inputs,
+    /// which expires after the given block height, if any, and carries the given memo to its recipient, if any.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn authorize_with_memo<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        expiration_height: Option<u32>,
+        memo: Option<(Plaintext<N>, Address<N>)>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Authorize the call.
+        self.get_stack(program_id)?.authorize_with_memo::<A, R>(
+            private_key,
+            function_name,
+            inputs,
+            expiration_height,
+            memo,
+            rng,
+        )
+    }
+
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
     /// and the deployment or execution ID.
     #[inline]
@@ -0,0 +1,392 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(debug_assertions)]
+use crate::Stack;
+
+use console::{
+    account::PrivateKey,
+    program::{
+        BlockPath,
+        Entry,
+        HeaderLeaf,
+        HeaderPath,
+        Identifier,
+        Literal,
+        Plaintext,
+        ProgramID,
+        Record,
+        StatePath,
+        TransactionLeaf,
+        TransactionPath,
+        TransactionsPath,
+        TransitionLeaf,
+        TransitionPath,
+        BLOCKS_DEPTH,
+        HEADER_DEPTH,
+        TRANSACTIONS_DEPTH,
+        TRANSACTION_DEPTH,
+        TRANSITION_DEPTH,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct BalanceAssignment<N: Network> {
+    /// The private key of the owner of the records.
+    private_key: PrivateKey<N>,
+    /// The `credits.aleo/credits` records, and their state paths to the global state root.
+    records: Vec<(Record<N, Plaintext<N>>, StatePath<N>)>,
+    /// The threshold (in microcredits).
+    threshold: u64,
+}
+
+impl<N: Network> BalanceAssignment<N> {
+    /// Initializes a new balance assignment, for records that total at least the given threshold (in microcredits).
+    pub fn new(
+        private_key: &PrivateKey<N>,
+        records: Vec<(Record<N, Plaintext<N>>, StatePath<N>)>,
+        threshold: u64,
+    ) -> Result<Self> {
+        // Ensure the number of records is within the allowed range.
+        ensure!(
+            (1..=MAX_BALANCE_ATTESTATION_RECORDS).contains(&records.len()),
+            "A balance attestation must contain between 1 and {MAX_BALANCE_ATTESTATION_RECORDS} records, found {}",
+            records.len()
+        );
+        // Retrieve the address of the private key.
+        let address = Address::try_from(private_key)?;
+        // Retrieve the state root of the first record.
+        let state_root = records[0].1.global_state_root();
+
+        // Compute the balance, while checking each record.
+        let mut balance = 0u64;
+        for (record, state_path) in &records {
+            // Ensure the record belongs to the private key.
+            ensure!(**record.owner() == address, "The record does not belong to '{address}'");
+            // Ensure the state path is for the record, and is to the same state root as the other records.
+            let commitment = Self::to_commitment(record)?;
+            ensure!(
+                state_path.transition_leaf().id() == commitment,
+                "The state path is not for the record '{commitment}'"
+            );
+            ensure!(state_path.global_state_root() == state_root, "The state paths must be to the same state root");
+            state_path.verify(true, Field::zero())?;
+            // Add the microcredits of the record to the balance.
+            balance = balance
+                .checked_add(Self::microcredits(record)?)
+                .ok_or_else(|| anyhow!("The balance of the records overflowed"))?;
+        }
+        // Ensure the balance is at least the threshold.
+        ensure!(balance >= threshold, "The records total {balance} microcredits, below the threshold of {threshold}");
+
+        Ok(Self { private_key: *private_key, records, threshold })
+    }
+
+    /// Initializes a sample balance assignment for the given number of records,
+    /// which has the same circuit as any balance assignment for this number of records.
+    /// Note: The sample is not satisfiable, and is only used to synthesize the circuit keys.
+    pub fn sample(num_records: usize) -> Result<Self> {
+        // Sample a private key.
+        let private_key = PrivateKey::try_from(Field::zero())?;
+        // Sample a `credits.aleo/credits` record for the private key.
+        let address = Address::try_from(&private_key)?;
+        let record = Record::from_str(&format!(
+            "{{ owner: {address}.private, microcredits: 0u64.private, _nonce: 0group.public }}"
+        ))?;
+        // Sample a state path of the correct depth.
+        let state_path = StatePath::from(
+            Field::<N>::zero().into(),
+            BlockPath::try_from((U64::zero(), vec![Field::zero(); BLOCKS_DEPTH as usize]))?,
+            Field::<N>::zero().into(),
+            Field::<N>::zero().into(),
+            Field::zero(),
+            HeaderPath::try_from((U64::zero(), vec![Field::zero(); HEADER_DEPTH as usize]))?,
+            HeaderLeaf::new(1, Field::zero()),
+            TransactionsPath::try_from((U64::zero(), vec![Field::zero(); TRANSACTIONS_DEPTH as usize]))?,
+            Field::<N>::zero().into(),
+            TransactionPath::try_from((U64::zero(), vec![Field::zero(); TRANSACTION_DEPTH as usize]))?,
+            TransactionLeaf::new_execution(0, Field::zero()),
+            Field::zero(),
+            Field::zero(),
+            TransitionPath::try_from((U64::zero(), vec![Field::zero(); TRANSITION_DEPTH as usize]))?,
+            TransitionLeaf::new_with_version(0, 3, Field::zero()),
+        );
+        Ok(Self { private_key, records: vec![(record, state_path); num_records], threshold: 0 })
+    }
+
+    /// Returns the global state root of the records.
+    pub fn state_root(&self) -> N::StateRoot {
+        self.records[0].1.global_state_root()
+    }
+
+    /// Returns the serial numbers of the records.
+    pub fn to_serial_numbers(&self) -> Result<Vec<Field<N>>> {
+        self.records
+            .iter()
+            .map(|(record, _)| Record::<N, Plaintext<N>>::serial_number(self.private_key, Self::to_commitment(record)?))
+            .collect()
+    }
+
+    /// The circuit for the balance attestation.
+    ///
+    /// # Diagram
+    /// The `[[ ]]` notation is used to denote public inputs.
+    /// ```ignore
+    ///  [[ address ]] := private_key.to_address()            [[ threshold ]] <= sum( microcredits_i )
+    ///
+    ///  For each record `i`:
+    ///             [[ global_state_root ]]
+    ///                       |
+    ///                  state_path_i
+    ///                      |
+    ///     commitment_i := Hash( credits.aleo || credits || record_i ),  where record_i.owner == address
+    ///                     |
+    ///  [[ serial_number_i ]] := Commit( commitment_i || Hash( COFACTOR * sk_sig * HashToGroup(commitment_i) ) )
+    /// ```
+    pub fn to_circuit_assignment<A: circuit::Aleo<Network = N>>(&self) -> Result<circuit::Assignment<N::Field>> {
+        use circuit::Inject;
+
+        // Ensure the circuit environment is clean.
+        assert_eq!(A::count(), (0, 1, 0, 0, (0, 0, 0)));
+        A::reset();
+
+        // Inject the address as `Mode::Public`.
+        let address = circuit::Address::<A>::new(circuit::Mode::Public, Address::try_from(&self.private_key)?);
+        // Inject the threshold as `Mode::Public`.
+        let threshold = circuit::U64::<A>::new(circuit::Mode::Public, U64::new(self.threshold));
+        // Inject the private key as `Mode::Private`.
+        let private_key = circuit::PrivateKey::<A>::new(circuit::Mode::Private, self.private_key);
+        // Enforce the private key belongs to the address.
+        A::assert(private_key.to_compute_key().to_address().is_equal(&address));
+
+        // Prepare the program ID, record name, and entry name of `credits.aleo/credits` as constants.
+        let program_id = circuit::ProgramID::<A>::constant(ProgramID::from_str("credits.aleo")?);
+        let record_name = circuit::Identifier::<A>::constant(Identifier::from_str("credits")?);
+        let microcredits = circuit::Identifier::<A>::constant(Identifier::from_str("microcredits")?);
+
+        // Initialize the balance.
+        let mut balance = circuit::U64::<A>::constant(U64::zero());
+        for ((record, state_path), serial_number) in self.records.iter().zip_eq(self.to_serial_numbers()?) {
+            // Inject the record as `Mode::Private`.
+            let record = circuit::Record::<A, circuit::Plaintext<A>>::new(circuit::Mode::Private, record.clone());
+            // Inject the state path as `Mode::Private` (with a global state root as `Mode::Public`).
+            let state_path = circuit::StatePath::<A>::new(circuit::Mode::Private, state_path.clone());
+            // Inject the serial number as `Mode::Public`.
+            let serial_number = circuit::Field::<A>::new(circuit::Mode::Public, serial_number);
+
+            // Enforce the record belongs to the address.
+            A::assert((**record.owner()).is_equal(&address));
+            // Compute the record commitment.
+            let commitment = record.to_commitment(&program_id, &record_name);
+            // Enforce the starting leaf is the record commitment.
+            A::assert_eq(state_path.transition_leaf().id(), &commitment);
+            // Enforce the state path from leaf to the global state root is correct.
+            A::assert(state_path.verify(&circuit::Boolean::constant(true), &circuit::Field::constant(Field::zero())));
+
+            // Compute the generator `H` as `HashToGroup(commitment)`.
+            let h = A::hash_to_group_psd2(&[A::serial_number_domain(), commitment.clone()]);
+            // Compute `gamma` as `sk_sig * H`.
+            let gamma = h * private_key.sk_sig();
+            // Enforce the serial number is derived from the private key and commitment.
+            A::assert_eq(
+                circuit::Record::<A, circuit::Plaintext<A>>::serial_number_from_gamma(&gamma, commitment),
+                serial_number,
+            );
+
+            // Add the microcredits of the record to the balance.
+            match record.data().get(&microcredits) {
+                Some(circuit::Entry::Private(circuit::Plaintext::Literal(circuit::Literal::U64(amount), _))) => {
+                    balance = &balance + amount
+                }
+                _ => bail!("The record is not a 'credits.aleo/credits' record"),
+            }
+        }
+        // Enforce the balance is at least the threshold.
+        A::assert(balance.is_greater_than_or_equal(&threshold));
+
+        #[cfg(debug_assertions)]
+        Stack::log_circuit::<A, _>(&format!("Balance Attestation for {} records", self.records.len()));
+
+        // Eject the assignment and reset the circuit environment.
+        Ok(A::eject_assignment_and_reset())
+    }
+}
+
+impl<N: Network> BalanceAssignment<N> {
+    /// Returns the commitment of the given `credits.aleo/credits` record.
+    fn to_commitment(record: &Record<N, Plaintext<N>>) -> Result<Field<N>> {
+        record.to_commitment(&ProgramID::from_str("credits.aleo")?, &Identifier::from_str("credits")?)
+    }
+
+    /// Returns the microcredits of the given `credits.aleo/credits` record.
+    fn microcredits(record: &Record<N, Plaintext<N>>) -> Result<u64> {
+        // Ensure the record has a private owner and exactly one entry, as declared by `credits.aleo/credits`.
+        ensure!(
+            record.owner().is_private() && record.data().len() == 1,
+            "The record is not a 'credits.aleo/credits' record"
+        );
+        match record.data().get(&Identifier::from_str("microcredits")?) {
+            Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => Ok(**amount),
+            _ => bail!("The record is not a 'credits.aleo/credits' record"),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::program::TransitionTree;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Returns sample `credits.aleo/credits` records for the given address with the given amounts,
+    /// and their state paths to a shared global state root.
+    pub fn sample_records(
+        address: &Address<CurrentNetwork>,
+        amounts: &[u64],
+        rng: &mut TestRng,
+    ) -> Vec<(Record<CurrentNetwork, Plaintext<CurrentNetwork>>, StatePath<CurrentNetwork>)> {
+        type N = CurrentNetwork;
+
+        // Sample the records.
+        let records = amounts
+            .iter()
+            .map(|amount| {
+                Record::from_str(&format!(
+                    "{{ owner: {address}.private, microcredits: {amount}u64.private, _nonce: {}.public }}",
+                    N::g_scalar_multiply(&Uniform::rand(rng))
+                ))
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Construct a transition with the records as its outputs.
+        let leaves = records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| {
+                let commitment = BalanceAssignment::to_commitment(record).unwrap();
+                TransitionLeaf::new_with_version(index as u8, 3, commitment)
+            })
+            .collect::<Vec<_>>();
+        let transition_tree: TransitionTree<N> =
+            N::merkle_tree_bhp(&leaves.iter().map(|leaf| leaf.to_bits_le()).collect::<Vec<_>>()).unwrap();
+        let transition_root = *transition_tree.root();
+        let tcm = Field::rand(rng);
+        let transition_id = N::hash_bhp512(&(transition_root, tcm).to_bits_le()).unwrap();
+
+        // Construct the transaction, block header, and block with the transition.
+        let transaction_leaf = TransactionLeaf::new_execution(0, transition_id);
+        let transaction_tree = N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&[transaction_leaf.to_bits_le()]).unwrap();
+        let transaction_id = *transaction_tree.root();
+        let transactions_tree = N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&[transaction_id.to_bits_le()]).unwrap();
+        let header_leaf = HeaderLeaf::<N>::new(1, *transactions_tree.root());
+        let header_tree =
+            N::merkle_tree_bhp::<HEADER_DEPTH>(&[Field::<N>::zero().to_bits_le(), header_leaf.to_bits_le()]).unwrap();
+        let header_root = *header_tree.root();
+        let previous_block_hash: <N as Network>::BlockHash = Field::<N>::rand(rng).into();
+        let block_hash =
+            N::hash_bhp1024(&[(*previous_block_hash).to_bits_le(), header_root.to_bits_le()].concat()).unwrap();
+        let block_tree = N::merkle_tree_bhp::<BLOCKS_DEPTH>(&[block_hash.to_bits_le()]).unwrap();
+
+        // Construct the state path of each record.
+        records
+            .into_iter()
+            .zip_eq(leaves)
+            .map(|(record, leaf)| {
+                let state_path = StatePath::from(
+                    (*block_tree.root()).into(),
+                    block_tree.prove(0, &block_hash.to_bits_le()).unwrap(),
+                    block_hash.into(),
+                    previous_block_hash,
+                    header_root,
+                    header_tree.prove(1, &header_leaf.to_bits_le()).unwrap(),
+                    header_leaf,
+                    transactions_tree.prove(0, &transaction_id.to_bits_le()).unwrap(),
+                    transaction_id.into(),
+                    transaction_tree.prove(0, &transaction_leaf.to_bits_le()).unwrap(),
+                    transaction_leaf,
+                    transition_root,
+                    tcm,
+                    transition_tree.prove(leaf.index() as usize, &leaf.to_bits_le()).unwrap(),
+                    leaf,
+                );
+                (record, state_path)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key, and records for its address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let records = test_helpers::sample_records(&address, &[100, 200], rng);
+
+        // Ensure the records are accepted up to their balance.
+        assert!(BalanceAssignment::new(&private_key, records.clone(), 300).is_ok());
+        assert!(BalanceAssignment::new(&private_key, records.clone(), 301).is_err());
+        // Ensure no records are rejected.
+        assert!(BalanceAssignment::new(&private_key, vec![], 0).is_err());
+
+        // Ensure records of another account are rejected.
+        let other_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        assert!(BalanceAssignment::new(&other_key, records.clone(), 0).is_err());
+
+        // Ensure records with state paths to different state roots are rejected.
+        let other_records = test_helpers::sample_records(&address, &[100], rng);
+        let candidate = vec![records[0].clone(), other_records[0].clone()];
+        assert!(BalanceAssignment::new(&private_key, candidate, 0).is_err());
+
+        // Ensure a record with the state path of another record is rejected.
+        let candidate = vec![(records[0].0.clone(), records[1].1.clone())];
+        assert!(BalanceAssignment::new(&private_key, candidate, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_circuit_assignment() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key, and records for its address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let records = test_helpers::sample_records(&address, &[100, 200], rng);
+
+        // Compute the circuit assignment.
+        let assignment = BalanceAssignment::new(&private_key, records, 250)?;
+        let circuit = assignment.to_circuit_assignment::<CurrentAleo>()?;
+        // Ensure the public inputs are the address, the threshold, and a state root and serial number per record.
+        assert_eq!(circuit.num_public(), 1 + 2 + 64 + 2 * 2);
+
+        // Ensure the sample has the same circuit, for the same number of records.
+        let sample = BalanceAssignment::sample(2)?.to_circuit_assignment::<CurrentAleo>()?;
+        assert_eq!(circuit.num_public(), sample.num_public());
+        assert_eq!(circuit.num_private(), sample.num_private());
+        assert_eq!(circuit.num_constraints(), sample.num_constraints());
+        Ok(())
+    }
+}
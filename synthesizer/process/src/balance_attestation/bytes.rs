@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> FromBytes for BalanceAttestation<N> {
+    /// Reads the balance attestation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid balance attestation version"));
+        }
+
+        // Read the state root.
+        let state_root = N::StateRoot::read_le(&mut reader)?;
+        // Read the number of records.
+        let num_records = u8::read_le(&mut reader)?;
+        // Ensure the number of records is within the allowed range.
+        if num_records as usize > MAX_BALANCE_ATTESTATION_RECORDS {
+            return Err(error(format!("Invalid number of records in balance attestation ({num_records})")));
+        }
+        // Read the serial numbers.
+        let serial_numbers = (0..num_records).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the proof.
+        let proof = Proof::read_le(&mut reader)?;
+
+        // Return the new `BalanceAttestation` instance.
+        Self::try_from((state_root, serial_numbers, proof)).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for BalanceAttestation<N> {
+    /// Writes the balance attestation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the state root.
+        self.state_root.write_le(&mut writer)?;
+        // Write the number of records.
+        u8::try_from(self.serial_numbers.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the serial numbers.
+        self.serial_numbers.write_le(&mut writer)?;
+        // Write the proof.
+        self.proof.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a balance attestation.
+        let (expected, _) = crate::balance_attestation::test_helpers::sample_balance_attestation(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, BalanceAttestation::read_le(&expected_bytes[..])?);
+        assert!(BalanceAttestation::<console::network::Testnet3>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
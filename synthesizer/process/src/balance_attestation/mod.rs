@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod assignment;
+pub use assignment::BalanceAssignment;
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    account::Address,
+    network::prelude::*,
+    types::{Field, U64},
+};
+use synthesizer_snark::Proof;
+
+/// The function name of the balance attestation circuit.
+pub const BALANCE_ATTESTATION_FUNCTION_NAME: &str = "balance_attestation";
+
+/// The maximum number of records in a balance attestation.
+pub const MAX_BALANCE_ATTESTATION_RECORDS: usize = 32;
+
+/// A balance attestation, proving that an address owns `credits.aleo` records totalling at least
+/// a threshold (in microcredits), which exist in the ledger at a global state root.
+///
+/// The attestation reveals the number of records and their serial numbers, but not the records or the balance.
+/// As the ledger only reveals a serial number once its record is spent, the verifier checks that the records
+/// were unspent at the state root against the ledger. See `VM::check_balance_attestation`.
+/// Note: Once a record is spent, its serial number links the spend to the attestation.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BalanceAttestation<N: Network> {
+    /// The global state root that the records exist at.
+    state_root: N::StateRoot,
+    /// The serial numbers of the records.
+    serial_numbers: Vec<Field<N>>,
+    /// The proof of the balance.
+    proof: Proof<N>,
+}
+
+impl<N: Network> BalanceAttestation<N> {
+    /// Returns the global state root that the records exist at.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the serial numbers of the records.
+    pub fn serial_numbers(&self) -> &[Field<N>] {
+        &self.serial_numbers
+    }
+
+    /// Returns the proof of the balance.
+    pub const fn proof(&self) -> &Proof<N> {
+        &self.proof
+    }
+
+    /// Returns the public inputs to verify the proof for the given address and threshold, as
+    /// `[1, address.x, address.y, threshold bits, (state root, serial number) for each record]`.
+    pub fn to_verifier_inputs(&self, address: &Address<N>, threshold: u64) -> Vec<N::Field> {
+        // Initialize the verifier inputs with the address.
+        let mut inputs =
+            vec![N::Field::one(), *address.to_group().to_x_coordinate(), *address.to_group().to_y_coordinate()];
+        // Extend the verifier inputs with the bits of the threshold.
+        inputs.extend(
+            U64::<N>::new(threshold)
+                .to_bits_le()
+                .into_iter()
+                .map(|bit| if bit { N::Field::one() } else { N::Field::zero() }),
+        );
+        // Extend the verifier inputs with the state root and serial number of each record.
+        for serial_number in &self.serial_numbers {
+            inputs.extend([**self.state_root, **serial_number]);
+        }
+        inputs
+    }
+}
+
+impl<N: Network> TryFrom<(N::StateRoot, Vec<Field<N>>, Proof<N>)> for BalanceAttestation<N> {
+    type Error = Error;
+
+    /// Initializes a balance attestation from `(state_root, serial_numbers, proof)`.
+    fn try_from((state_root, serial_numbers, proof): (N::StateRoot, Vec<Field<N>>, Proof<N>)) -> Result<Self> {
+        // Ensure the number of records is within the allowed range.
+        ensure!(
+            (1..=MAX_BALANCE_ATTESTATION_RECORDS).contains(&serial_numbers.len()),
+            "A balance attestation must contain between 1 and {MAX_BALANCE_ATTESTATION_RECORDS} records, found {}",
+            serial_numbers.len()
+        );
+        // Ensure the serial numbers are unique, so that no record is counted twice.
+        ensure!(!has_duplicates(serial_numbers.iter()), "A balance attestation cannot contain a record twice");
+        Ok(Self { state_root, serial_numbers, proof })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::Process;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a sample balance attestation, for two records totalling 300 microcredits.
+    pub fn sample_balance_attestation(
+        rng: &mut TestRng,
+    ) -> (BalanceAttestation<CurrentNetwork>, Address<CurrentNetwork>) {
+        static INSTANCE: once_cell::sync::OnceCell<(BalanceAttestation<CurrentNetwork>, Address<CurrentNetwork>)> =
+            once_cell::sync::OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Initialize the process.
+                let process = Process::<CurrentNetwork>::load().unwrap();
+                // Sample a private key.
+                let private_key = PrivateKey::new(rng).unwrap();
+                let address = Address::try_from(&private_key).unwrap();
+                // Sample the records, and their state paths to a shared state root.
+                let records = assignment::test_helpers::sample_records(&address, &[100, 200], rng);
+                // Prove the balance attestation.
+                let attestation =
+                    process.prove_balance_attestation::<CurrentAleo, _>(&private_key, records, 300, rng).unwrap();
+                (attestation, address)
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a balance attestation.
+        let (attestation, _) = test_helpers::sample_balance_attestation(rng);
+        let proof = attestation.proof().clone();
+        assert_eq!(
+            attestation,
+            BalanceAttestation::try_from((
+                attestation.state_root(),
+                attestation.serial_numbers().to_vec(),
+                proof.clone()
+            ))?
+        );
+
+        // Ensure an attestation without records is rejected.
+        assert!(BalanceAttestation::try_from((attestation.state_root(), vec![], proof.clone())).is_err());
+        // Ensure an attestation with a duplicate record is rejected.
+        let serial_number = attestation.serial_numbers()[0];
+        assert!(
+            BalanceAttestation::try_from((attestation.state_root(), vec![serial_number; 2], proof.clone())).is_err()
+        );
+        // Ensure an attestation with too many records is rejected.
+        let serial_numbers = (0..=MAX_BALANCE_ATTESTATION_RECORDS).map(|_| Field::rand(rng)).collect::<Vec<_>>();
+        assert!(BalanceAttestation::try_from((attestation.state_root(), serial_numbers, proof)).is_err());
+        Ok(())
+    }
+}
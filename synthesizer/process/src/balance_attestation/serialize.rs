@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Serialize for BalanceAttestation<N> {
+    /// Serializes the balance attestation into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut attestation = serializer.serialize_struct("BalanceAttestation", 4)?;
+                attestation.serialize_field("version", &1u8)?;
+                attestation.serialize_field("state_root", &self.state_root)?;
+                attestation.serialize_field("serial_numbers", &self.serial_numbers)?;
+                attestation.serialize_field("proof", &self.proof)?;
+                attestation.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for BalanceAttestation<N> {
+    /// Deserializes the balance attestation from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the balance attestation from a string into a value.
+                let mut attestation = serde_json::Value::deserialize(deserializer)?;
+                // Ensure the version is valid.
+                let version: u8 = DeserializeExt::take_from_value::<D>(&mut attestation, "version")?;
+                if version != 1 {
+                    return Err(de::Error::custom(format!("Invalid balance attestation version '{version}'")));
+                }
+                // Recover the balance attestation.
+                Self::try_from((
+                    // Retrieve the state root.
+                    DeserializeExt::take_from_value::<D>(&mut attestation, "state_root")?,
+                    // Retrieve the serial numbers.
+                    DeserializeExt::take_from_value::<D>(&mut attestation, "serial_numbers")?,
+                    // Retrieve the proof.
+                    DeserializeExt::take_from_value::<D>(&mut attestation, "proof")?,
+                ))
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "balance attestation"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a balance attestation.
+        let (expected, _) = crate::balance_attestation::test_helpers::sample_balance_attestation(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, BalanceAttestation::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a balance attestation.
+        let (expected, _) = crate::balance_attestation::test_helpers::sample_balance_attestation(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, BalanceAttestation::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
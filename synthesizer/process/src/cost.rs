@@ -0,0 +1,377 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FinalizeRegisters, Stack, StackProgramTypes};
+use console::{
+    account::Signature,
+    network::prelude::*,
+    program::{FinalizeType, Identifier, LiteralType, Plaintext, PlaintextType, Register, Value},
+    types::{Address, Boolean, Decimal, Field, Group, Scalar, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8},
+};
+use synthesizer_program::{Command, Finalize, Instruction, Operand, RegistersLoad, StackProgram};
+
+/// The number of bytes of a `hash` input that are covered by the base cost of the command.
+const HASH_BASE_BYTES: u64 = 64;
+/// The cost in microcredits of each byte of a `hash` input beyond the bytes covered by the base cost.
+const HASH_BYTE_COST: u64 = 500;
+/// The number of bytes of a `set` key and value that are covered by the base cost of the command.
+const SET_BASE_BYTES: u64 = 128;
+/// The cost in microcredits of each byte of a `set` key and value beyond the bytes covered by the base cost.
+const SET_BYTE_COST: u64 = 1_000;
+/// The number of bytes of an `emit` value that are covered by the base cost of the command.
+const EMIT_BASE_BYTES: u64 = 128;
+/// The cost in microcredits of each byte of an `emit` value beyond the bytes covered by the base cost.
+const EMIT_BYTE_COST: u64 = 1_000;
+/// The cost in microcredits of a `decimal` multiplication, which rescales the 128-bit product.
+const DECIMAL_MUL_COST: u64 = 200_000;
+/// The cost in microcredits of a `decimal` division, which rescales the dividend to a 128-bit intermediate.
+const DECIMAL_DIV_COST: u64 = 20_000;
+/// The number of bytes of a mapping entry read that are covered by the base cost of the command.
+const READ_BASE_BYTES: u64 = 128;
+/// The cost in microcredits of each byte of a mapping entry read beyond the bytes covered by the base cost.
+const READ_BYTE_COST: u64 = 500;
+
+/// Returns the storage cost in microcredits of the given execution.
+pub fn execution_storage_cost<N: Network>(execution: &ledger_block::Execution<N>) -> Result<u64> {
+    execution.size_in_bytes()
+}
+
+/// Returns the maximum number of microcredits required to run the finalize, including the routines it invokes.
+/// Note: Each command is charged once per iteration of its enclosing loops, and each data-dependent cost
+/// is charged for the largest value of its operand type. As such, the metered cost of any run is at most this amount.
+pub fn finalize_cost<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>) -> Result<u64> {
+    // Retrieve the finalize types.
+    let finalize_types = stack.get_finalize_types(finalize.name())?;
+    // Bounds the size in bytes of an operand by the size of its type.
+    let size_of = |operand: &Operand<N>| match finalize_types.get_type_from_operand(stack, operand)? {
+        FinalizeType::Plaintext(plaintext_type) => plaintext_type_size_in_bytes(stack, &plaintext_type),
+        FinalizeType::Future(..) => bail!("A future operand does not have a size in bytes"),
+    };
+    // Retrieves the type of a destination register.
+    let type_of = |register: &Register<N>| finalize_types.get_type(stack, register);
+    // Defines the cost of each command, including the routine of an `invoke` command.
+    let cost = |command: &Command<N>| {
+        let cost = command_cost(command, stack, size_of, type_of)?;
+        match command {
+            Command::Invoke(invoke) => {
+                let routine = stack.program().get_routine(invoke.routine())?;
+                cost.checked_add(finalize_cost(stack, routine.body())?).ok_or(anyhow!("Finalize cost overflowed"))
+            }
+            _ => Ok(cost),
+        }
+    };
+
+    // Initialize a stack of the active loops, as (index of the ending position, bound).
+    let mut loops: Vec<(usize, u64)> = Vec::new();
+    // Accumulate the cost of each command, charging the commands in a loop body once per iteration.
+    finalize.commands().iter().enumerate().try_fold(0u64, |acc, (index, command)| {
+        // Exit the loop that ends at this position, if any.
+        if matches!(loops.last(), Some((end, _)) if *end == index) {
+            loops.pop();
+        }
+        // Enter the loop, if the command is a loop. Note that the `loop` command is charged once per iteration.
+        if let Command::Loop(loop_) = command {
+            match finalize.positions().get(loop_.position()) {
+                Some(end) => loops.push((*end, u64::from(**loop_.bound()))),
+                None => bail!("The position '{}' does not exist.", loop_.position()),
+            }
+        }
+        // Compute the number of times the command may be executed.
+        let multiplier = loops
+            .iter()
+            .try_fold(1u64, |multiplier, (_, bound)| multiplier.checked_mul(*bound))
+            .ok_or(anyhow!("Finalize cost overflowed"))?;
+        // Accumulate the cost of the command.
+        let command_cost: u64 = cost(command)?;
+        command_cost
+            .checked_mul(multiplier)
+            .and_then(|cost| acc.checked_add(cost))
+            .ok_or(anyhow!("Finalize cost overflowed"))
+    })
+}
+
+/// Returns the minimum number of microcredits required to run the finalize, including the routines it invokes.
+/// Note: Each command on the cheapest path through the branches is charged once, each loop may be skipped,
+/// and each data-dependent cost is charged for an empty operand. As such, the metered cost of any run
+/// is at least this amount.
+pub fn finalize_floor_cost<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>) -> Result<u64> {
+    // Retrieve the finalize types.
+    let finalize_types = stack.get_finalize_types(finalize.name())?;
+    // Retrieves the type of a destination register.
+    let type_of = |register: &Register<N>| finalize_types.get_type(stack, register);
+    // Retrieves the index of the given position.
+    let index_of = |position: &Identifier<N>| match finalize.positions().get(position) {
+        Some(index) => Ok(*index),
+        None => bail!("The position '{position}' does not exist."),
+    };
+
+    // Initialize the minimum cost to run the finalize from each index.
+    // Note: Branches and loops only jump forward, so the cost from each target is known when it is read.
+    let mut floors = vec![0u64; finalize.commands().len() + 1];
+    for (index, command) in finalize.commands().iter().enumerate().rev() {
+        // Compute the cost of the command, including the minimum cost of the routine of an `invoke` command.
+        let mut cost = command_cost(command, stack, |_| Ok(0), type_of)?;
+        if let Command::Invoke(invoke) = command {
+            let routine = stack.program().get_routine(invoke.routine())?;
+            cost = cost
+                .checked_add(finalize_floor_cost(stack, routine.body())?)
+                .ok_or(anyhow!("Finalize cost overflowed"))?;
+        }
+        // Compute the minimum cost of the commands that may run next.
+        let next = match command {
+            Command::BranchEq(branch) => floors[index + 1].min(floors[index_of(branch.position())?]),
+            Command::BranchNeq(branch) => floors[index + 1].min(floors[index_of(branch.position())?]),
+            Command::Loop(loop_) => floors[index + 1].min(floors[index_of(loop_.position())?]),
+            _ => floors[index + 1],
+        };
+        floors[index] = cost.checked_add(next).ok_or(anyhow!("Finalize cost overflowed"))?;
+    }
+    Ok(floors[0])
+}
+
+/// Returns the number of microcredits required to run the given command once, in the given registers.
+pub(crate) fn metered_command_cost<N: Network>(
+    command: &Command<N>,
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+) -> Result<u64> {
+    command_cost(
+        command,
+        stack,
+        |operand| match registers.load(stack, operand)? {
+            Value::Plaintext(plaintext) => Ok(plaintext_size_in_bytes(&plaintext)),
+            value => bail!("The operand '{operand}' is not a plaintext value ('{value}')"),
+        },
+        // Note: The destination is charged before the command is run, and is bounded by the size of its type.
+        |register| registers.finalize_types().get_type(stack, register),
+    )
+}
+
+/// Returns the number of microcredits required to run the given command once,
+/// where `size_of` returns the size in bytes of the value of the given operand,
+/// and `type_of` returns the type of the given destination register.
+/// Note: The commands of a routine are charged when they are run, and are not included in the cost of `invoke`.
+fn command_cost<N: Network>(
+    command: &Command<N>,
+    stack: &Stack<N>,
+    size_of: impl Fn(&Operand<N>) -> Result<u64>,
+    type_of: impl Fn(&Register<N>) -> Result<FinalizeType<N>>,
+) -> Result<u64> {
+    // Returns the base cost, plus the cost of each byte beyond the bytes covered by the base cost.
+    let sized_cost = |base_cost: u64, base_bytes: u64, byte_cost: u64, num_bytes: u64| {
+        num_bytes
+            .saturating_sub(base_bytes)
+            .checked_mul(byte_cost)
+            .and_then(|cost| cost.checked_add(base_cost))
+            .ok_or(anyhow!("Finalize cost overflowed"))
+    };
+    // Returns the largest size in bytes of a value of the type of the given destination register.
+    let type_size_of = |register: &Register<N>| match type_of(register)? {
+        FinalizeType::Plaintext(plaintext_type) => plaintext_type_size_in_bytes(stack, &plaintext_type),
+        FinalizeType::Future(..) => bail!("A future register does not have a size in bytes"),
+    };
+    // Returns `true` if the given destination registers are of the decimal type.
+    let is_decimal = |destinations: Vec<Register<N>>| match destinations.as_slice() {
+        [destination] => {
+            Ok(matches!(type_of(destination)?, FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Decimal))))
+        }
+        _ => bail!("Expected 1 destination for an arithmetic instruction, found {}", destinations.len()),
+    };
+    // Returns the cost of a `hash` command, given its base cost.
+    let hash_cost = |base_cost: u64, operands: &[Operand<N>]| match operands {
+        [operand] => sized_cost(base_cost, HASH_BASE_BYTES, HASH_BYTE_COST, size_of(operand)?),
+        _ => bail!("Expected 1 operand for a hash, found {}", operands.len()),
+    };
+
+    match command {
+        Command::Instruction(Instruction::Abs(_)) => Ok(2_000),
+        Command::Instruction(Instruction::AbsWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Add(_)) => Ok(2_000),
+        Command::Instruction(Instruction::AddWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::And(_)) => Ok(2_000),
+        Command::Instruction(Instruction::AssertEq(_)) => Ok(2_000),
+        Command::Instruction(Instruction::AssertNeq(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Async(_)) => bail!("`async` is not supported in finalize."),
+        Command::Instruction(Instruction::Call(_)) => bail!("`call` is not supported in finalize."),
+        Command::Instruction(Instruction::Cast(_)) => Ok(2_000),
+        Command::Instruction(Instruction::CastLossy(_)) => Ok(2_000),
+        Command::Instruction(Instruction::CommitBHP256(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitBHP512(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitBHP768(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitBHP1024(_)) => Ok(200_000),
+        Command::Instruction(Instruction::CommitPED64(_)) => Ok(100_000),
+        Command::Instruction(Instruction::CommitPED128(_)) => Ok(100_000),
+        // Note: The `decimal` variants of `div` and `mul` rescale over a 128-bit intermediate.
+        Command::Instruction(Instruction::Div(div)) => match is_decimal(div.destinations())? {
+            true => Ok(DECIMAL_DIV_COST),
+            false => Ok(10_000),
+        },
+        Command::Instruction(Instruction::DivWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Double(_)) => Ok(2_000),
+        Command::Instruction(Instruction::GreaterThan(_)) => Ok(2_000),
+        Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(2_000),
+        Command::Instruction(Instruction::HashBHP256(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashBHP512(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashBHP768(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashBHP1024(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashKeccak256(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashKeccak384(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashKeccak512(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashPED64(hash)) => hash_cost(20_000, hash.operands()),
+        Command::Instruction(Instruction::HashPED128(hash)) => hash_cost(30_000, hash.operands()),
+        Command::Instruction(Instruction::HashPSD2(hash)) => match hash.destination_type() {
+            PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group) => {
+                hash_cost(600_000, hash.operands())
+            }
+            PlaintextType::Literal(..) => hash_cost(60_000, hash.operands()),
+            plaintext_type => bail!("`hash.psd2` is not supported for plaintext type '{plaintext_type}'"),
+        },
+        Command::Instruction(Instruction::HashPSD4(hash)) => match hash.destination_type() {
+            PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group) => {
+                hash_cost(700_000, hash.operands())
+            }
+            PlaintextType::Literal(..) => hash_cost(100_000, hash.operands()),
+            plaintext_type => bail!("`hash.psd4` is not supported for plaintext type '{plaintext_type}'"),
+        },
+        Command::Instruction(Instruction::HashPSD8(hash)) => match hash.destination_type() {
+            PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group) => {
+                hash_cost(800_000, hash.operands())
+            }
+            PlaintextType::Literal(..) => hash_cost(200_000, hash.operands()),
+            plaintext_type => bail!("`hash.psd8` is not supported for plaintext type '{plaintext_type}'"),
+        },
+        Command::Instruction(Instruction::HashSha3_256(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashSha3_384(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashSha3_512(hash)) => hash_cost(100_000, hash.operands()),
+        Command::Instruction(Instruction::HashManyPSD2(_)) => {
+            bail!("`hash_many.psd2` is not supported in finalize.")
+        }
+        Command::Instruction(Instruction::HashManyPSD4(_)) => {
+            bail!("`hash_many.psd4` is not supported in finalize.")
+        }
+        Command::Instruction(Instruction::HashManyPSD8(_)) => {
+            bail!("`hash_many.psd8` is not supported in finalize.")
+        }
+        Command::Instruction(Instruction::Inv(_)) => Ok(10_000),
+        Command::Instruction(Instruction::IsEq(_)) => Ok(2_000),
+        Command::Instruction(Instruction::IsNeq(_)) => Ok(2_000),
+        Command::Instruction(Instruction::LessThan(_)) => Ok(2_000),
+        Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Modulo(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Mul(mul)) => match is_decimal(mul.destinations())? {
+            true => Ok(DECIMAL_MUL_COST),
+            false => Ok(150_000),
+        },
+        Command::Instruction(Instruction::MulWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Nand(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Neg(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Nor(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Not(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Or(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Pow(_)) => Ok(20_000),
+        Command::Instruction(Instruction::PowWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Rem(_)) => Ok(2_000),
+        Command::Instruction(Instruction::RemWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::SignVerify(_)) => Ok(250_000),
+        Command::Instruction(Instruction::Shl(_)) => Ok(2_000),
+        Command::Instruction(Instruction::ShlWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Shr(_)) => Ok(2_000),
+        Command::Instruction(Instruction::ShrWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Square(_)) => Ok(2_000),
+        Command::Instruction(Instruction::SquareRoot(_)) => Ok(120_000),
+        Command::Instruction(Instruction::Sub(_)) => Ok(10_000),
+        Command::Instruction(Instruction::SubWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Ternary(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Xor(_)) => Ok(2_000),
+        // TODO: The following 'finalize' commands are currently priced higher than expected.
+        //  Expect these numbers to change as their usage is stabilized.
+        Command::Await(_) => Ok(2_000),
+        Command::Contains(contains) => sized_cost(12_500, READ_BASE_BYTES, READ_BYTE_COST, size_of(contains.key())?),
+        // Note: The entry read by `get` and `get.or_use` is bounded by the key and the type of the destination.
+        Command::Get(get) => {
+            let num_bytes = size_of(get.key())?.saturating_add(type_size_of(get.destination())?);
+            sized_cost(25_000, READ_BASE_BYTES, READ_BYTE_COST, num_bytes)
+        }
+        Command::GetOrUse(get_or_use) => {
+            let num_bytes = size_of(get_or_use.key())?.saturating_add(type_size_of(get_or_use.destination())?);
+            sized_cost(25_000, READ_BASE_BYTES, READ_BYTE_COST, num_bytes)
+        }
+        Command::RandChaCha(_) => Ok(25_000),
+        Command::Remove(_) => Ok(10_000),
+        Command::Set(set) => {
+            let num_bytes = size_of(set.key())?.saturating_add(size_of(set.value())?);
+            sized_cost(100_000, SET_BASE_BYTES, SET_BYTE_COST, num_bytes)
+        }
+        Command::BranchEq(_) | Command::BranchNeq(_) => Ok(5_000),
+        Command::Position(_) => Ok(1_000),
+        Command::Loop(_) => Ok(5_000),
+        // Note: The number of entries of a mapping is maintained in storage, and is read without touching any entry.
+        Command::Count(_) => Ok(25_000),
+        // Note: The entry at an index is read from the position index, and touches exactly one entry.
+        Command::GetAt(get_at) => {
+            let num_bytes =
+                type_size_of(get_at.key_destination())?.saturating_add(type_size_of(get_at.value_destination())?);
+            sized_cost(50_000, READ_BASE_BYTES, READ_BYTE_COST, num_bytes)
+        }
+        Command::Invoke(_) => Ok(2_000),
+        Command::Emit(emit) => sized_cost(50_000, EMIT_BASE_BYTES, EMIT_BYTE_COST, size_of(emit.operand())?),
+    }
+}
+
+/// Returns the size in bytes of the given plaintext value, as the sum of the sizes of its literals.
+fn plaintext_size_in_bytes<N: Network>(plaintext: &Plaintext<N>) -> u64 {
+    match plaintext {
+        Plaintext::Literal(literal, _) => (u64::from(literal.size_in_bits()) + 7) / 8,
+        Plaintext::Struct(members, _) => members.values().map(plaintext_size_in_bytes).sum(),
+        Plaintext::Array(elements, _) => elements.iter().map(plaintext_size_in_bytes).sum(),
+    }
+}
+
+/// Returns the largest size in bytes of a plaintext value of the given type.
+fn plaintext_type_size_in_bytes<N: Network>(stack: &Stack<N>, plaintext_type: &PlaintextType<N>) -> Result<u64> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => {
+            let size_in_bits = match literal_type {
+                LiteralType::Address => Address::<N>::size_in_bits(),
+                LiteralType::Boolean => Boolean::<N>::size_in_bits(),
+                LiteralType::Field => Field::<N>::size_in_bits(),
+                LiteralType::Group => Group::<N>::size_in_bits(),
+                LiteralType::I8 => I8::<N>::size_in_bits(),
+                LiteralType::I16 => I16::<N>::size_in_bits(),
+                LiteralType::I32 => I32::<N>::size_in_bits(),
+                LiteralType::I64 => I64::<N>::size_in_bits(),
+                LiteralType::I128 => I128::<N>::size_in_bits(),
+                LiteralType::U8 => U8::<N>::size_in_bits(),
+                LiteralType::U16 => U16::<N>::size_in_bits(),
+                LiteralType::U32 => U32::<N>::size_in_bits(),
+                LiteralType::U64 => U64::<N>::size_in_bits(),
+                LiteralType::U128 => U128::<N>::size_in_bits(),
+                LiteralType::Scalar => Scalar::<N>::size_in_bits(),
+                LiteralType::Signature => Signature::<N>::size_in_bits(),
+                LiteralType::String => return Ok(u64::from(N::MAX_STRING_BYTES)),
+                LiteralType::Decimal => Decimal::<N>::size_in_bits(),
+            };
+            Ok((size_in_bits as u64 + 7) / 8)
+        }
+        PlaintextType::Struct(struct_name) => {
+            stack.program().get_struct(struct_name)?.members().values().try_fold(0u64, |acc, member_type| {
+                Ok(acc.saturating_add(plaintext_type_size_in_bytes(stack, member_type)?))
+            })
+        }
+        PlaintextType::Array(array_type) => {
+            let element_size = plaintext_type_size_in_bytes(stack, array_type.next_element_type())?;
+            Ok(element_size.saturating_mul(u64::from(**array_type.length())))
+        }
+    }
+}
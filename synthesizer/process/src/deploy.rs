@@ -16,6 +16,7 @@ use super::*;
 
 impl<N: Network> Process<N> {
     /// Deploys the given program ID, if it does not exist.
+    /// If the program already exists, the deployment upgrades it to the next edition.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -25,7 +26,20 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::deploy");
 
         // Compute the stack.
-        let stack = Stack::new(self, program)?;
+        let stack = match self.contains_program(program.id()) {
+            true => {
+                // Determine the next edition.
+                let edition = self.get_stack(program.id())?.edition().saturating_add(1);
+                // Ensure the program is a valid upgrade.
+                self.check_upgrade(program, edition)?;
+                // Compute the stack for the next edition.
+                let stack = Stack::new_edition(self, program, edition)?;
+                // Ensure the programs that import the program remain valid.
+                self.check_importers(&stack)?;
+                stack
+            }
+            false => Stack::new(self, program)?,
+        };
         lap!(timer, "Compute the stack");
 
         // Return the deployment.
@@ -43,8 +57,18 @@ impl<N: Network> Process<N> {
     pub fn load_deployment(&mut self, deployment: &Deployment<N>) -> Result<()> {
         let timer = timer!("Process::load_deployment");
 
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Ensure the edition does not already exist in the process.
+        // Note: Editions may be loaded in any order, so the upgrade checks are not repeated here.
+        ensure!(
+            !self.contains_program_edition(program_id, deployment.edition()),
+            "Program '{program_id}' (edition {}) already exists",
+            deployment.edition()
+        );
+
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = Stack::new_edition(self, deployment.program(), deployment.edition())?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -54,10 +78,83 @@ impl<N: Network> Process<N> {
         lap!(timer, "Insert the verifying keys");
 
         // Add the stack to the process.
-        self.add_stack(stack);
+        self.add_stack(stack)?;
 
         finish!(timer);
 
         Ok(())
     }
+
+    /// Checks that the given program is a valid upgrade to the given edition.
+    /// An upgrade must succeed the latest edition in the process, and retain every existing mapping
+    /// with the same key and value types, so that the finalize state remains usable.
+    /// An upgrade must also retain every interface it declares and implements, so that callers may rely on them.
+    #[inline]
+    pub fn check_upgrade(&self, program: &Program<N>, edition: u16) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::from_str("credits.aleo")?, "Program 'credits.aleo' cannot be upgraded");
+        // Retrieve the stack for the latest edition.
+        let stack = self.get_stack(program_id)?;
+        // Ensure the edition succeeds the latest edition.
+        ensure!(
+            stack.edition().checked_add(1) == Some(edition),
+            "Program '{program_id}' must be upgraded from edition {}, found edition {edition}",
+            stack.edition()
+        );
+        // Ensure each existing mapping is retained, with the same key and value types.
+        for (name, mapping) in stack.program().mappings() {
+            match program.mappings().get(name) {
+                Some(candidate) => ensure!(
+                    candidate.key() == mapping.key() && candidate.value() == mapping.value(),
+                    "Program '{program_id}' (edition {edition}) changes the types of mapping '{name}'"
+                ),
+                None => bail!("Program '{program_id}' (edition {edition}) removes mapping '{name}'"),
+            }
+        }
+        // Ensure each existing interface declaration is retained, without changes.
+        for (name, interface) in stack.program().interfaces() {
+            ensure!(
+                program.interfaces().get(name) == Some(interface),
+                "Program '{program_id}' (edition {edition}) removes or changes interface '{name}'"
+            );
+        }
+        // Ensure each existing implemented interface is retained.
+        // Note: The conformance of the upgrade to each interface is checked when its stack is initialized.
+        for locator in stack.program().implements() {
+            ensure!(
+                program.implements().contains(locator),
+                "Program '{program_id}' (edition {edition}) no longer implements '{locator}'"
+            );
+        }
+        Ok(())
+    }
+
+    /// Computes the stack for the given deployment.
+    /// If the deployment is an upgrade, this ensures it is valid for the latest edition in the process.
+    #[inline]
+    pub(crate) fn stack_for_deployment(&self, deployment: &Deployment<N>) -> Result<Stack<N>> {
+        match deployment.edition() == N::EDITION {
+            true => Stack::new(self, deployment.program()),
+            false => {
+                // Ensure the program is a valid upgrade.
+                self.check_upgrade(deployment.program(), deployment.edition())?;
+                // Compute the stack for the edition.
+                let stack = Stack::new_edition(self, deployment.program(), deployment.edition())?;
+                // Ensure the programs that import the program remain valid.
+                self.check_importers(&stack)?;
+                Ok(stack)
+            }
+        }
+    }
+
+    /// Checks that the stacks of the programs that import the given upgrade can be rebuilt to call into it.
+    #[inline]
+    fn check_importers(&self, stack: &Stack<N>) -> Result<()> {
+        let program_id = stack.program_id();
+        self.clone().insert_stack(Arc::new(stack.clone())).map_err(|error| {
+            anyhow!("Program '{program_id}' (edition {}) breaks an importer - {error}", stack.edition())
+        })
+    }
 }
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer_program::StackMatches;
+
+impl<N: Network> Process<N> {
+    /// Returns an entry disclosure, proving the given record of `program_id/record_name` contains the entry
+    /// with the given name, where the record is decrypted with its record view key.
+    #[inline]
+    pub fn prove_entry_disclosure<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        record: &Record<N, Ciphertext<N>>,
+        record_view_key: &Field<N>,
+        entry_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<EntryDisclosure<N>> {
+        let timer = timer!("Process::prove_entry_disclosure");
+
+        // Decrypt the record.
+        let record = record.decrypt_symmetric_unchecked(record_view_key)?;
+        // Ensure the record is of the given record type.
+        self.get_stack(program_id)?.matches_record(&record, record_name)?;
+
+        // Construct the entry assignment.
+        let entry_assignment = EntryAssignment::new(*program_id, *record_name, record, *entry_name)?;
+        // Compute the circuit assignment.
+        let assignment = entry_assignment.to_circuit_assignment::<A>()?;
+        lap!(timer, "Construct the entry assignment");
+
+        // Synthesize the circuit key for the record type.
+        let (proving_key, _) = self.universal_srs.to_circuit_key(ENTRY_DISCLOSURE_FUNCTION_NAME, &assignment)?;
+        lap!(timer, "Synthesize the circuit key");
+
+        // Compute the proof.
+        let proof = proving_key.prove(ENTRY_DISCLOSURE_FUNCTION_NAME, &assignment, rng)?;
+        lap!(timer, "Compute the proof");
+
+        // Construct the entry disclosure.
+        let disclosure = EntryDisclosure::from((
+            *program_id,
+            *record_name,
+            entry_assignment.to_commitment()?,
+            *entry_name,
+            entry_assignment.entry()?.clone(),
+            proof,
+        ));
+        finish!(timer);
+
+        Ok(disclosure)
+    }
+
+    /// Verifies the given entry disclosure proves the record with the given commitment contains the disclosed entry.
+    /// Note: This does *not* check that the commitment exists in the ledger.
+    #[inline]
+    pub fn verify_entry_disclosure<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        commitment: Field<N>,
+        disclosure: &EntryDisclosure<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("Process::verify_entry_disclosure");
+
+        // Ensure the disclosure is for the given commitment.
+        ensure!(
+            disclosure.commitment() == commitment,
+            "The entry disclosure is for commitment '{}', expected '{commitment}'",
+            disclosure.commitment()
+        );
+
+        // Retrieve the stack of the record.
+        let stack = self.get_stack(disclosure.program_id())?;
+        // Initialize a burner address.
+        let burner_address = Address::try_from(&PrivateKey::new(rng)?)?;
+        // Synthesize the verifying key from a sample record of the same record type.
+        let sample = EntryAssignment::new(
+            *disclosure.program_id(),
+            *disclosure.record_name(),
+            stack.sample_record(&burner_address, disclosure.record_name(), rng)?,
+            *disclosure.entry_name(),
+        )?
+        .to_circuit_assignment::<A>()?;
+        let (_, verifying_key) = self.universal_srs.to_circuit_key(ENTRY_DISCLOSURE_FUNCTION_NAME, &sample)?;
+        lap!(timer, "Synthesize the verifying key");
+
+        // Verify the proof.
+        ensure!(
+            verifying_key.verify(ENTRY_DISCLOSURE_FUNCTION_NAME, &disclosure.to_verifier_inputs()?, disclosure.proof()),
+            "Entry disclosure proof is invalid"
+        );
+        finish!(timer);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry_disclosure::test_helpers::{sample_entry_disclosure, sample_record};
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_verify_entry_disclosure() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample an entry disclosure of a record with 100 microcredits.
+        let disclosure = sample_entry_disclosure(rng);
+        let commitment = disclosure.commitment();
+        assert_eq!(disclosure.entry(), &Plaintext::from_str("100u64").unwrap());
+
+        // Ensure the disclosure verifies for its commitment.
+        process.verify_entry_disclosure::<CurrentAleo, _>(commitment, &disclosure, rng).unwrap();
+        // Ensure the disclosure fails for a different commitment.
+        assert!(process.verify_entry_disclosure::<CurrentAleo, _>(Field::rand(rng), &disclosure, rng).is_err());
+
+        // Ensure the disclosure fails for a different entry value.
+        let candidate = EntryDisclosure::from((
+            *disclosure.program_id(),
+            *disclosure.record_name(),
+            commitment,
+            *disclosure.entry_name(),
+            Plaintext::from_str("200u64").unwrap(),
+            disclosure.proof().clone(),
+        ));
+        assert!(process.verify_entry_disclosure::<CurrentAleo, _>(commitment, &candidate, rng).is_err());
+        // Ensure the disclosure fails for a different commitment, with the same proof.
+        let other_commitment = Field::rand(rng);
+        let candidate = EntryDisclosure::from((
+            *disclosure.program_id(),
+            *disclosure.record_name(),
+            other_commitment,
+            *disclosure.entry_name(),
+            disclosure.entry().clone(),
+            disclosure.proof().clone(),
+        ));
+        assert!(process.verify_entry_disclosure::<CurrentAleo, _>(other_commitment, &candidate, rng).is_err());
+    }
+
+    #[test]
+    fn test_prove_entry_disclosure_invalid() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample a record, and its record view key.
+        let (record, record_view_key) = sample_record(100, rng);
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let record_name = Identifier::from_str("credits").unwrap();
+        let entry_name = Identifier::from_str("microcredits").unwrap();
+
+        // Ensure an entry that is not in the record is rejected.
+        let other_entry = Identifier::from_str("amount").unwrap();
+        assert!(
+            process
+                .prove_entry_disclosure::<CurrentAleo, _>(
+                    &program_id,
+                    &record_name,
+                    &record,
+                    &record_view_key,
+                    &other_entry,
+                    rng
+                )
+                .is_err()
+        );
+        // Ensure a record of another record type is rejected.
+        let other_record = Identifier::from_str("token").unwrap();
+        assert!(
+            process
+                .prove_entry_disclosure::<CurrentAleo, _>(
+                    &program_id,
+                    &other_record,
+                    &record,
+                    &record_view_key,
+                    &entry_name,
+                    rng
+                )
+                .is_err()
+        );
+        // Ensure an incorrect record view key is rejected.
+        assert!(
+            process
+                .prove_entry_disclosure::<CurrentAleo, _>(
+                    &program_id,
+                    &record_name,
+                    &record,
+                    &Field::rand(rng),
+                    &entry_name,
+                    rng
+                )
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(debug_assertions)]
+use crate::Stack;
+
+use console::program::{Entry, Record};
+
+#[derive(Clone, Debug)]
+pub struct EntryAssignment<N: Network> {
+    /// The program ID of the record.
+    program_id: ProgramID<N>,
+    /// The record name.
+    record_name: Identifier<N>,
+    /// The record.
+    record: Record<N, Plaintext<N>>,
+    /// The name of the disclosed entry.
+    entry_name: Identifier<N>,
+}
+
+impl<N: Network> EntryAssignment<N> {
+    /// Initializes a new entry assignment, for the entry with the given name in the record.
+    pub fn new(
+        program_id: ProgramID<N>,
+        record_name: Identifier<N>,
+        record: Record<N, Plaintext<N>>,
+        entry_name: Identifier<N>,
+    ) -> Result<Self> {
+        // Ensure the record contains the entry.
+        ensure!(record.data().contains_key(&entry_name), "The record does not contain the entry '{entry_name}'");
+        Ok(Self { program_id, record_name, record, entry_name })
+    }
+
+    /// Returns the record commitment.
+    pub fn to_commitment(&self) -> Result<Field<N>> {
+        self.record.to_commitment(&self.program_id, &self.record_name)
+    }
+
+    /// Returns the value of the disclosed entry.
+    pub fn entry(&self) -> Result<&Plaintext<N>> {
+        match self.record.data().get(&self.entry_name) {
+            Some(Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext)) => Ok(plaintext),
+            None => bail!("The record does not contain the entry '{}'", self.entry_name),
+        }
+    }
+
+    /// The circuit for the entry disclosure.
+    ///
+    /// # Diagram
+    /// The `[[ ]]` notation is used to denote public inputs.
+    /// ```ignore
+    ///  [[ commitment ]] := Hash( program_id || record_name || record )
+    ///  [[ entry_hash ]] := Hash( record.entry_name )
+    /// ```
+    pub fn to_circuit_assignment<A: circuit::Aleo<Network = N>>(&self) -> Result<circuit::Assignment<N::Field>> {
+        use circuit::{Inject, prelude::ToFields};
+
+        // Ensure the circuit environment is clean.
+        assert_eq!(A::count(), (0, 1, 0, 0, (0, 0, 0)));
+        A::reset();
+
+        // Inject the commitment as `Mode::Public`.
+        let commitment = circuit::Field::<A>::new(circuit::Mode::Public, self.to_commitment()?);
+        // Inject the entry hash as `Mode::Public`.
+        let entry_hash = circuit::Field::<A>::new(circuit::Mode::Public, to_entry_hash(self.entry()?)?);
+        // Inject the record as `Mode::Private`.
+        let record = circuit::Record::<A, circuit::Plaintext<A>>::new(circuit::Mode::Private, self.record.clone());
+
+        // Prepare the program ID, record name, and entry name as constants.
+        let program_id = circuit::ProgramID::<A>::constant(self.program_id);
+        let record_name = circuit::Identifier::<A>::constant(self.record_name);
+        let entry_name = circuit::Identifier::<A>::constant(self.entry_name);
+
+        // Enforce the commitment is for the record.
+        A::assert_eq(commitment, record.to_commitment(&program_id, &record_name));
+
+        // Enforce the entry hash is for the entry of the record.
+        match record.data().get(&entry_name) {
+            Some(
+                circuit::Entry::Constant(plaintext)
+                | circuit::Entry::Public(plaintext)
+                | circuit::Entry::Private(plaintext),
+            ) => A::assert_eq(entry_hash, A::hash_psd8(&plaintext.to_fields())),
+            None => bail!("The record does not contain the entry '{}'", self.entry_name),
+        }
+
+        #[cfg(debug_assertions)]
+        Stack::log_circuit::<A, _>(&format!("Entry Disclosure for '{}/{}'", self.program_id, self.record_name));
+
+        // Eject the assignment and reset the circuit environment.
+        Ok(A::eject_assignment_and_reset())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry_disclosure::test_helpers::sample_record;
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_circuit_assignment() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a record.
+        let (record, record_view_key) = sample_record(100, rng);
+        let record = record.decrypt_symmetric_unchecked(&record_view_key)?;
+        let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo")?;
+        let record_name = Identifier::from_str("credits")?;
+        let entry_name = Identifier::from_str("microcredits")?;
+
+        // Ensure an entry that is not in the record is rejected.
+        assert!(
+            EntryAssignment::new(program_id, record_name, record.clone(), Identifier::from_str("amount")?).is_err()
+        );
+
+        // Compute the circuit assignment.
+        let assignment = EntryAssignment::new(program_id, record_name, record, entry_name)?;
+        let circuit = assignment.to_circuit_assignment::<CurrentAleo>()?;
+        // Ensure the public inputs are the commitment and the entry hash.
+        assert_eq!(circuit.num_public(), 1 + 2);
+
+        // Ensure a record with another entry value has the same circuit.
+        let (other, other_view_key) = sample_record(200, rng);
+        let other = other.decrypt_symmetric_unchecked(&other_view_key)?;
+        let sample =
+            EntryAssignment::new(program_id, record_name, other, entry_name)?.to_circuit_assignment::<CurrentAleo>()?;
+        assert_eq!(circuit.num_public(), sample.num_public());
+        assert_eq!(circuit.num_private(), sample.num_private());
+        assert_eq!(circuit.num_constraints(), sample.num_constraints());
+        Ok(())
+    }
+}
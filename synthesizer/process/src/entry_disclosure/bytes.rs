@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for EntryDisclosure<N> {
+    /// Reads the entry disclosure from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid entry disclosure version"));
+        }
+
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the record name.
+        let record_name = Identifier::read_le(&mut reader)?;
+        // Read the commitment.
+        let commitment = Field::read_le(&mut reader)?;
+        // Read the entry name.
+        let entry_name = Identifier::read_le(&mut reader)?;
+        // Read the entry.
+        let entry = Plaintext::read_le(&mut reader)?;
+        // Read the proof.
+        let proof = Proof::read_le(&mut reader)?;
+
+        // Return the new `EntryDisclosure` instance.
+        Ok(Self::from((program_id, record_name, commitment, entry_name, entry, proof)))
+    }
+}
+
+impl<N: Network> ToBytes for EntryDisclosure<N> {
+    /// Writes the entry disclosure to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the record name.
+        self.record_name.write_le(&mut writer)?;
+        // Write the commitment.
+        self.commitment.write_le(&mut writer)?;
+        // Write the entry name.
+        self.entry_name.write_le(&mut writer)?;
+        // Write the entry.
+        self.entry.write_le(&mut writer)?;
+        // Write the proof.
+        self.proof.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an entry disclosure.
+        let expected = crate::entry_disclosure::test_helpers::sample_entry_disclosure(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, EntryDisclosure::read_le(&expected_bytes[..])?);
+        assert!(EntryDisclosure::<console::network::Testnet3>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod assignment;
+pub use assignment::EntryAssignment;
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID},
+    types::Field,
+};
+use synthesizer_snark::Proof;
+
+/// The function name of the entry disclosure circuit.
+pub const ENTRY_DISCLOSURE_FUNCTION_NAME: &str = "entry_disclosure";
+
+/// An entry disclosure, proving that the record with a given commitment contains the given entry,
+/// without revealing the owner, nonce, or other entries of the record.
+///
+/// The disclosure is created from the record ciphertext and its record view key, and is verified against
+/// the record commitment, which the ledger stores with the ciphertext in the output of its transition.
+/// Note: The disclosure does *not* prove that the commitment exists in the ledger, so the verifier
+/// provides the commitment of the output it trusts. See `Process::verify_entry_disclosure`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EntryDisclosure<N: Network> {
+    /// The program ID of the record.
+    program_id: ProgramID<N>,
+    /// The record name.
+    record_name: Identifier<N>,
+    /// The record commitment.
+    commitment: Field<N>,
+    /// The name of the disclosed entry.
+    entry_name: Identifier<N>,
+    /// The value of the disclosed entry.
+    entry: Plaintext<N>,
+    /// The proof of the entry.
+    proof: Proof<N>,
+}
+
+impl<N: Network> EntryDisclosure<N> {
+    /// Returns the program ID of the record.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the record name.
+    pub const fn record_name(&self) -> &Identifier<N> {
+        &self.record_name
+    }
+
+    /// Returns the record commitment.
+    pub const fn commitment(&self) -> Field<N> {
+        self.commitment
+    }
+
+    /// Returns the name of the disclosed entry.
+    pub const fn entry_name(&self) -> &Identifier<N> {
+        &self.entry_name
+    }
+
+    /// Returns the value of the disclosed entry.
+    pub const fn entry(&self) -> &Plaintext<N> {
+        &self.entry
+    }
+
+    /// Returns the proof of the entry.
+    pub const fn proof(&self) -> &Proof<N> {
+        &self.proof
+    }
+
+    /// Returns the public inputs to verify the proof, as `[1, commitment, Hash(entry)]`.
+    pub fn to_verifier_inputs(&self) -> Result<Vec<N::Field>> {
+        Ok(vec![N::Field::one(), *self.commitment, *to_entry_hash(&self.entry)?])
+    }
+}
+
+impl<N: Network> From<(ProgramID<N>, Identifier<N>, Field<N>, Identifier<N>, Plaintext<N>, Proof<N>)>
+    for EntryDisclosure<N>
+{
+    /// Initializes an entry disclosure from `(program_id, record_name, commitment, entry_name, entry, proof)`.
+    fn from(
+        (program_id, record_name, commitment, entry_name, entry, proof): (
+            ProgramID<N>,
+            Identifier<N>,
+            Field<N>,
+            Identifier<N>,
+            Plaintext<N>,
+            Proof<N>,
+        ),
+    ) -> Self {
+        Self { program_id, record_name, commitment, entry_name, entry, proof }
+    }
+}
+
+/// Returns the hash of the given entry value, as `Hash(entry)`.
+fn to_entry_hash<N: Network>(entry: &Plaintext<N>) -> Result<Field<N>> {
+    N::hash_psd8(&entry.to_fields()?)
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::Process;
+    use console::{
+        account::{Address, PrivateKey},
+        program::{Ciphertext, Record},
+        types::{Group, Scalar},
+    };
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a sample `credits.aleo/credits` record ciphertext with the given microcredits, and its record view key.
+    pub fn sample_record(
+        microcredits: u64,
+        rng: &mut TestRng,
+    ) -> (Record<CurrentNetwork, Ciphertext<CurrentNetwork>>, Field<CurrentNetwork>) {
+        // Sample an address.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        // Sample the randomizer and nonce of the record.
+        let randomizer = Scalar::rand(rng);
+        let nonce: Group<CurrentNetwork> = CurrentNetwork::g_scalar_multiply(&randomizer);
+        // Construct and encrypt the record.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, microcredits: {microcredits}u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap();
+        // Compute the record view key.
+        let record_view_key = (*address * randomizer).to_x_coordinate();
+        (ciphertext, record_view_key)
+    }
+
+    /// Returns a sample entry disclosure, for the microcredits of a `credits.aleo/credits` record of 100 microcredits.
+    pub fn sample_entry_disclosure(rng: &mut TestRng) -> EntryDisclosure<CurrentNetwork> {
+        static INSTANCE: once_cell::sync::OnceCell<EntryDisclosure<CurrentNetwork>> = once_cell::sync::OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Initialize the process.
+                let process = Process::<CurrentNetwork>::load().unwrap();
+                // Sample a record, and its record view key.
+                let (record, record_view_key) = sample_record(100, rng);
+                // Prove the entry disclosure.
+                process
+                    .prove_entry_disclosure::<CurrentAleo, _>(
+                        &ProgramID::from_str("credits.aleo").unwrap(),
+                        &Identifier::from_str("credits").unwrap(),
+                        &record,
+                        &record_view_key,
+                        &Identifier::from_str("microcredits").unwrap(),
+                        rng,
+                    )
+                    .unwrap()
+            })
+            .clone()
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for EntryDisclosure<N> {
+    /// Serializes the entry disclosure into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut disclosure = serializer.serialize_struct("EntryDisclosure", 7)?;
+                disclosure.serialize_field("version", &1u8)?;
+                disclosure.serialize_field("program_id", &self.program_id)?;
+                disclosure.serialize_field("record_name", &self.record_name)?;
+                disclosure.serialize_field("commitment", &self.commitment)?;
+                disclosure.serialize_field("entry_name", &self.entry_name)?;
+                disclosure.serialize_field("entry", &self.entry)?;
+                disclosure.serialize_field("proof", &self.proof)?;
+                disclosure.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for EntryDisclosure<N> {
+    /// Deserializes the entry disclosure from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the entry disclosure from a string into a value.
+                let mut disclosure = serde_json::Value::deserialize(deserializer)?;
+                // Ensure the version is valid.
+                let version: u8 = DeserializeExt::take_from_value::<D>(&mut disclosure, "version")?;
+                if version != 1 {
+                    return Err(de::Error::custom(format!("Invalid entry disclosure version '{version}'")));
+                }
+                // Recover the entry disclosure.
+                Ok(Self::from((
+                    // Retrieve the program ID.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "program_id")?,
+                    // Retrieve the record name.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "record_name")?,
+                    // Retrieve the commitment.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "commitment")?,
+                    // Retrieve the entry name.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "entry_name")?,
+                    // Retrieve the entry.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "entry")?,
+                    // Retrieve the proof.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "proof")?,
+                )))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "entry disclosure"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an entry disclosure.
+        let expected = crate::entry_disclosure::test_helpers::sample_entry_disclosure(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, EntryDisclosure::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an entry disclosure.
+        let expected = crate::entry_disclosure::test_helpers::sample_entry_disclosure(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, EntryDisclosure::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
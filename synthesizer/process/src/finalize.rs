@@ -13,8 +13,11 @@
 // limitations under the License.
 
 use super::*;
-use console::program::{Future, Register};
-use synthesizer_program::{Await, FinalizeRegistersState, Operand};
+use console::{
+    program::{Future, Literal, Register},
+    types::{Address, U32},
+};
+use synthesizer_program::{Await, Event, FinalizeRegistersState, Invoke, Loop, Operand, Routine};
 use utilities::handle_halting;
 
 impl<N: Network> Process<N> {
@@ -24,7 +27,7 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn finalize_deployment<P: FinalizeStorage<N>>(
         &self,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         store: &FinalizeStore<N, P>,
         deployment: &Deployment<N>,
         fee: &Fee<N>,
@@ -32,7 +35,8 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::finalize_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        // Note: If the deployment is an upgrade, this ensures it is still valid for the latest edition.
+        let stack = self.stack_for_deployment(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -58,8 +62,17 @@ impl<N: Network> Process<N> {
 
             // Retrieve the program ID.
             let program_id = deployment.program_id();
+            // Retrieve the previous edition of the program, if the deployment is an upgrade.
+            let previous_program = match deployment.edition() == N::EDITION {
+                true => None,
+                false => Some(self.get_program(program_id)?),
+            };
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
+                // Skip the mappings retained from the previous edition, as their entries are kept.
+                if previous_program.map_or(false, |program| program.contains_mapping(mapping.name())) {
+                    continue;
+                }
                 // Initialize the mapping.
                 finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
             }
@@ -70,17 +83,17 @@ impl<N: Network> Process<N> {
         })
     }
 
-    /// Finalizes the execution and fee.
+    /// Finalizes the execution and fee, returning the finalize operations and the emitted events.
     /// This method assumes the given execution **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
     pub fn finalize_execution<P: FinalizeStorage<N>>(
         &self,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         store: &FinalizeStore<N, P>,
         execution: &Execution<N>,
         fee: Option<&Fee<N>>,
-    ) -> Result<Vec<FinalizeOperation<N>>> {
+    ) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
@@ -103,11 +116,19 @@ impl<N: Network> Process<N> {
         // Construct the call graph.
         let call_graph = self.construct_call_graph(execution)?;
 
+        // Compute the gas budget for finalize, as the base fee less the storage cost of the execution.
+        // Note: An execution without a fee is not metered, as the fee may only be omitted for a lone `split`.
+        let budget = match fee {
+            Some(fee) => Some((*fee.base_amount()?).saturating_sub(execution_storage_cost(execution)?)),
+            None => None,
+        };
+
         atomic_batch_scope!(store, {
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let (mut finalize_operations, events) =
+                finalize_transition(state, store, stack, transition, call_graph, budget)?;
 
             /* Finalize the fee. */
 
@@ -120,8 +141,8 @@ impl<N: Network> Process<N> {
             }
 
             finish!(timer);
-            // Return the finalize operations.
-            Ok(finalize_operations)
+            // Return the finalize operations and events.
+            Ok((finalize_operations, events))
         })
     }
 
@@ -131,7 +152,7 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn finalize_fee<P: FinalizeStorage<N>>(
         &self,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         store: &FinalizeStore<N, P>,
         fee: &Fee<N>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
@@ -151,7 +172,7 @@ impl<N: Network> Process<N> {
 
 /// Finalizes the given fee transition.
 fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
-    state: FinalizeGlobalState,
+    state: FinalizeGlobalState<N>,
     store: &FinalizeStore<N, P>,
     stack: &Stack<N>,
     fee: &Fee<N>,
@@ -162,22 +183,25 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     call_graph.insert(*fee.transition_id(), Vec::new());
 
     // Finalize the transition.
-    match finalize_transition(state, store, stack, fee, call_graph) {
+    match finalize_transition(state, store, stack, fee, call_graph, None) {
         // If the evaluation succeeds, return the finalize operations.
-        Ok(finalize_operations) => Ok(finalize_operations),
+        // Note: The fee functions in `credits.aleo` do not emit events.
+        Ok((finalize_operations, _)) => Ok(finalize_operations),
         // If the evaluation fails, bail and return the error.
         Err(error) => bail!("'finalize' failed on '{}/{}' - {error}", fee.program_id(), fee.function_name()),
     }
 }
 
-/// Finalizes the given transition.
+/// Finalizes the given transition, returning the finalize operations and the emitted events.
+/// If a gas budget is given, each command is charged as it runs, and finalize halts once the budget is exhausted.
 fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
-    state: FinalizeGlobalState,
+    state: FinalizeGlobalState<N>,
     store: &FinalizeStore<N, P>,
     stack: &Stack<N>,
     transition: &Transition<N>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
-) -> Result<Vec<FinalizeOperation<N>>> {
+    budget: Option<u64>,
+) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
     // Retrieve the program ID.
     let program_id = transition.program_id();
     // Retrieve the function name.
@@ -190,7 +214,7 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     // If the last output of the transition is a future, retrieve and finalize it. Otherwise, there are no operations to finalize.
     let future = match transition.outputs().last().and_then(|output| output.future()) {
         Some(future) => future,
-        _ => return Ok(Vec::new()),
+        _ => return Ok((Vec::new(), Vec::new())),
     };
 
     // Check that the program ID and function name of the transition match those in the future.
@@ -201,27 +225,68 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
 
     // Initialize a list for finalize operations.
     let mut finalize_operations = Vec::new();
+    // Initialize a list for the emitted events.
+    let mut events = Vec::new();
 
     // Initialize a stack of active finalize states.
     let mut states = Vec::new();
 
+    // Initialize the remaining gas, shared by all of the finalize states.
+    let mut gas = budget;
+
     // Initialize the top-level finalize state.
-    states.push(initialize_finalize_state(state, future, stack, *transition.id())?);
+    // Note: The caller of the root transition is its signer, which is not publicly known.
+    states.push(initialize_finalize_state(state, future, stack, *transition.id(), None)?);
 
     // While there are active finalize states, finalize them.
-    while let Some(FinalizeState {
+    'states: while let Some(FinalizeState {
         mut counter,
         finalize,
         mut registers,
         stack,
         mut call_counter,
         mut recent_call_locator,
+        mut loops,
+        invocation,
     }) = states.pop()
     {
         // Evaluate the commands.
         while counter < finalize.commands().len() {
+            // If the end of the innermost loop is reached, start the next iteration or exit the loop.
+            if let Some(loop_state) = loops.last_mut() {
+                if counter == loop_state.end {
+                    // Increment the iteration.
+                    loop_state.iteration += 1;
+                    // If there are iterations remaining, jump back to the start of the loop body.
+                    if loop_state.iteration < loop_state.count {
+                        let result = handle_halting!(panic::AssertUnwindSafe(|| {
+                            // Charge the `loop` command for the iteration.
+                            charge_gas(&mut gas, &finalize.commands()[loop_state.start], stack, &registers)?;
+                            next_iteration(stack, &mut registers, loop_state)
+                        }));
+                        match result {
+                            Ok(Ok(())) => counter = loop_state.start + 1,
+                            // If the evaluation fails, bail and return the error.
+                            Ok(Err(error)) => bail!("'finalize' failed to start the next loop iteration: {error}"),
+                            // If the evaluation fails, bail and return the error.
+                            Err(_) => bail!("'finalize' failed to start the next loop iteration"),
+                        }
+                        continue;
+                    }
+                    // Otherwise, exit the loop.
+                    loops.pop();
+                }
+            }
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // Charge the command against the remaining gas.
+            match handle_halting!(panic::AssertUnwindSafe(|| charge_gas(&mut gas, command, stack, &registers))) {
+                Ok(Ok(())) => (),
+                // If the charge fails, bail and return the error.
+                Ok(Err(error)) => bail!("'finalize' failed to charge for command ({command}): {error}"),
+                // If the charge fails, bail and return the error.
+                Err(_) => bail!("'finalize' failed to charge for command ({command})"),
+            }
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
@@ -252,6 +317,22 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
                     }
                 }
+                Command::Loop(loop_) => {
+                    let result = handle_halting!(panic::AssertUnwindSafe(|| {
+                        enter_loop(counter, loop_, finalize, stack, &mut registers)
+                    }));
+                    match result {
+                        Ok(Ok((new_counter, loop_state))) => {
+                            // If the loop has iterations, track the loop state.
+                            loops.extend(loop_state);
+                            counter = new_counter;
+                        }
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    }
+                }
                 Command::Await(await_) => {
                     // Check that the `await` register's locator is greater than the last seen call locator.
                     // This ensures that futures are invoked in the order they are called.
@@ -296,15 +377,73 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     counter += 1;
 
                     // Aggregate the caller state.
-                    let caller_state =
-                        FinalizeState { counter, finalize, registers, stack, call_counter, recent_call_locator };
+                    let caller_state = FinalizeState {
+                        counter,
+                        finalize,
+                        registers,
+                        stack,
+                        call_counter,
+                        recent_call_locator,
+                        loops,
+                        invocation,
+                    };
 
                     // Push the caller state onto the stack.
                     states.push(caller_state);
                     // Push the callee state onto the stack.
                     states.push(callee_state);
 
-                    break;
+                    continue 'states;
+                }
+                Command::Invoke(invoke) => {
+                    let routine_state = match handle_halting!(panic::AssertUnwindSafe(|| {
+                        // Set up the finalize state for the routine.
+                        setup_invoke(invoke, stack, &registers)
+                    })) {
+                        Ok(Ok(routine_state)) => routine_state,
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    };
+
+                    // Increment the counter.
+                    counter += 1;
+
+                    // Aggregate the invoking state.
+                    let invoking_state = FinalizeState {
+                        counter,
+                        finalize,
+                        registers,
+                        stack,
+                        call_counter,
+                        recent_call_locator,
+                        loops,
+                        invocation,
+                    };
+
+                    // Push the invoking state onto the stack.
+                    states.push(invoking_state);
+                    // Push the routine state onto the stack.
+                    states.push(routine_state);
+
+                    continue 'states;
+                }
+                Command::Emit(emit) => {
+                    // Ensure the transaction does not exceed the maximum number of events.
+                    ensure!(
+                        events.len() < N::MAX_EVENTS,
+                        "'finalize' failed to evaluate command ({command}): exceeds the maximum number of events"
+                    );
+                    match handle_halting!(panic::AssertUnwindSafe(|| emit.finalize(stack, &registers))) {
+                        // If the evaluation succeeds, add the event to the list.
+                        Ok(Ok(event)) => events.push(event),
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    }
+                    counter += 1;
                 }
                 _ => {
                     let result =
@@ -323,10 +462,29 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                 }
             };
         }
+
+        // If the finalize state is a routine, return its outputs to the invoking state.
+        if let Some((routine, invoke)) = invocation {
+            // Retrieve the invoking state.
+            let invoking_state = match states.last_mut() {
+                Some(invoking_state) => invoking_state,
+                None => bail!("'finalize' failed to find the invoker of routine '{}'", routine.name()),
+            };
+            let result = handle_halting!(panic::AssertUnwindSafe(|| {
+                return_outputs(stack, routine, invoke, &registers, &mut invoking_state.registers)
+            }));
+            match result {
+                Ok(Ok(())) => {}
+                // If the evaluation fails, bail and return the error.
+                Ok(Err(error)) => bail!("'finalize' failed to return from routine '{}': {error}", routine.name()),
+                // If the evaluation fails, bail and return the error.
+                Err(_) => bail!("'finalize' failed to return from routine '{}'", routine.name()),
+            }
+        }
     }
 
-    // Return the finalize operations.
-    Ok(finalize_operations)
+    // Return the finalize operations and events.
+    Ok((finalize_operations, events))
 }
 
 // A helper struct to track the execution of a finalize block.
@@ -343,14 +501,33 @@ struct FinalizeState<'a, N: Network> {
     call_counter: usize,
     // Recent call register.
     recent_call_locator: Option<u64>,
+    // The active loops, from outermost to innermost.
+    loops: Vec<LoopState<N>>,
+    // The routine and the `invoke` command, if the finalize logic is a routine.
+    invocation: Option<(&'a Routine<N>, &'a Invoke<N>)>,
+}
+
+// A helper struct to track the execution of a loop.
+struct LoopState<N: Network> {
+    // The index of the `loop` command.
+    start: usize,
+    // The index of the `position` that ends the loop body.
+    end: usize,
+    // The current iteration.
+    iteration: u32,
+    // The number of iterations.
+    count: u32,
+    // The destination register for the iteration index.
+    destination: Register<N>,
 }
 
 // A helper function to initialize the finalize state.
 fn initialize_finalize_state<'a, N: Network>(
-    state: FinalizeGlobalState,
+    state: FinalizeGlobalState<N>,
     future: &Future<N>,
     stack: &'a Stack<N>,
     transition_id: N::TransitionID,
+    caller: Option<Address<N>>,
 ) -> Result<FinalizeState<'a, N>> {
     // Get the finalize logic and the stack.
     let (finalize, stack) = match stack.program_id() == future.program_id() {
@@ -374,6 +551,7 @@ fn initialize_finalize_state<'a, N: Network>(
         state,
         transition_id,
         *future.function_name(),
+        caller,
         stack.get_finalize_types(future.function_name())?.clone(),
     );
 
@@ -385,13 +563,42 @@ fn initialize_finalize_state<'a, N: Network>(
         },
     )?;
 
-    Ok(FinalizeState { counter: 0, finalize, registers, stack, call_counter: 0, recent_call_locator: None })
+    Ok(FinalizeState {
+        counter: 0,
+        finalize,
+        registers,
+        stack,
+        call_counter: 0,
+        recent_call_locator: None,
+        loops: Vec::new(),
+        invocation: None,
+    })
+}
+
+// A helper function that charges the cost of the command against the remaining gas, if finalize is metered.
+#[inline]
+fn charge_gas<N: Network>(
+    gas: &mut Option<u64>,
+    command: &Command<N>,
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+) -> Result<()> {
+    if let Some(remaining) = gas {
+        // Compute the cost of the command.
+        let cost = metered_command_cost(command, stack, registers)?;
+        // Deduct the cost from the remaining gas.
+        *remaining = match remaining.checked_sub(cost) {
+            Some(remaining) => remaining,
+            None => bail!("Out of gas - the command costs {cost} microcredits, but only {remaining} remain"),
+        };
+    }
+    Ok(())
 }
 
 // A helper function that sets up the await operation.
 #[inline]
 fn setup_await<'a, N: Network>(
-    state: FinalizeGlobalState,
+    state: FinalizeGlobalState<N>,
     await_: &Await<N>,
     stack: &'a Stack<N>,
     registers: &FinalizeRegisters<N>,
@@ -402,8 +609,61 @@ fn setup_await<'a, N: Network>(
         Value::Future(future) => future,
         _ => bail!("The input to 'await' is not a future"),
     };
+    // The caller of the awaited transition is the awaiting program.
+    let caller = stack.program_id().to_address()?;
     // Initialize the state.
-    initialize_finalize_state(state, &future, stack, transition_id)
+    initialize_finalize_state(state, &future, stack, transition_id, Some(caller))
+}
+
+// A helper function that sets up the invoke operation.
+#[inline]
+fn setup_invoke<'a, N: Network>(
+    invoke: &'a Invoke<N>,
+    stack: &'a Stack<N>,
+    registers: &FinalizeRegisters<N>,
+) -> Result<FinalizeState<'a, N>> {
+    // Retrieve the routine.
+    let routine = stack.program().get_routine(invoke.routine())?;
+    // Initialize the registers, in the scope of the invoking finalize.
+    let mut routine_registers = FinalizeRegisters::new(
+        *registers.state(),
+        *registers.transition_id(),
+        *registers.function_name(),
+        registers.caller(),
+        stack.get_finalize_types(invoke.routine())?.clone(),
+    );
+
+    // Store the inputs.
+    routine.inputs().iter().map(|i| i.register()).zip_eq(invoke.operands()).try_for_each(|(register, operand)| {
+        // Assign the operand value to the input register.
+        routine_registers.store(stack, register, registers.load(stack, operand)?)
+    })?;
+
+    Ok(FinalizeState {
+        counter: 0,
+        finalize: routine.body(),
+        registers: routine_registers,
+        stack,
+        call_counter: 0,
+        recent_call_locator: None,
+        loops: Vec::new(),
+        invocation: Some((routine, invoke)),
+    })
+}
+
+// A helper function that stores the outputs of the routine into the destinations of the `invoke` command.
+#[inline]
+fn return_outputs<N: Network>(
+    stack: &Stack<N>,
+    routine: &Routine<N>,
+    invoke: &Invoke<N>,
+    registers: &FinalizeRegisters<N>,
+    invoking_registers: &mut FinalizeRegisters<N>,
+) -> Result<()> {
+    routine.outputs().iter().zip_eq(invoke.destinations()).try_for_each(|(output, destination)| {
+        // Assign the output value to the destination register.
+        invoking_registers.store(stack, destination, registers.load(stack, output.operand())?)
+    })
 }
 
 // A helper function that returns the index to branch to.
@@ -438,6 +698,58 @@ fn branch_to<N: Network, const VARIANT: u8>(
     }
 }
 
+// A helper function that enters a loop, returning the index to continue from and the loop state.
+// If the loop has no iterations, the loop body is skipped and no loop state is returned.
+#[inline]
+fn enter_loop<N: Network>(
+    counter: usize,
+    loop_: &Loop<N>,
+    finalize: &Finalize<N>,
+    stack: &Stack<N>,
+    registers: &mut FinalizeRegisters<N>,
+) -> Result<(usize, Option<LoopState<N>>)> {
+    // Retrieve the number of iterations.
+    let count = match registers.load_literal(stack, loop_.count())? {
+        Literal::U32(count) => *count,
+        _ => bail!("The count of a 'loop' must be a 'u32'"),
+    };
+    // Ensure the number of iterations does not exceed the bound.
+    ensure!(count <= **loop_.bound(), "The loop count '{count}' exceeds the bound '{}'", loop_.bound());
+    // Retrieve the index of the position that ends the loop body.
+    let end = match finalize.positions().get(loop_.position()) {
+        Some(index) if *index > counter => *index,
+        Some(_) => bail!("Cannot end a loop at an earlier position '{}' in the program", loop_.position()),
+        None => bail!("The position '{}' does not exist.", loop_.position()),
+    };
+    // If there are no iterations, skip the loop body.
+    if count == 0 {
+        return Ok((end, None));
+    }
+    // Store the first iteration index.
+    registers.store_literal(stack, loop_.destination(), Literal::U32(U32::new(0)))?;
+    // Return the index of the first command in the loop body, and the loop state.
+    let loop_state = LoopState { start: counter, end, iteration: 0, count, destination: loop_.destination().clone() };
+    Ok((counter + 1, Some(loop_state)))
+}
+
+// A helper function that prepares the registers for the next iteration of the loop.
+#[inline]
+fn next_iteration<N: Network>(
+    stack: &Stack<N>,
+    registers: &mut FinalizeRegisters<N>,
+    loop_state: &LoopState<N>,
+) -> Result<()> {
+    // Retrieve the locator of the iteration index.
+    let locator = match loop_state.destination {
+        Register::Locator(locator) => locator,
+        Register::Access(..) => bail!("The destination of a 'loop' must be a locator"),
+    };
+    // Clear the registers assigned in the previous iteration.
+    registers.clear_from(locator);
+    // Store the next iteration index.
+    registers.store_literal(stack, &loop_state.destination, Literal::U32(U32::new(loop_state.iteration)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -508,7 +820,7 @@ function compute:
         let (stack, _) =
             process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
         // Add the stack *manually* to the process.
-        process.add_stack(stack);
+        process.add_stack(stack).unwrap();
 
         // Ensure the program exists.
         assert!(process.contains_program(program.id()));
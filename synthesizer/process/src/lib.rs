@@ -18,6 +18,15 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod balance_attestation;
+pub use balance_attestation::*;
+
+mod cost;
+pub use cost::*;
+
+mod entry_disclosure;
+pub use entry_disclosure::*;
+
 mod stack;
 pub use stack::*;
 
@@ -27,8 +36,10 @@ pub use trace::*;
 mod traits;
 pub use traits::*;
 
+mod attest_balance;
 mod authorize;
 mod deploy;
+mod disclose_entry;
 mod evaluate;
 mod execute;
 mod finalize;
@@ -40,9 +51,9 @@ mod verify_fee;
 mod tests;
 
 use console::{
-    account::PrivateKey,
+    account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
+    program::{Ciphertext, Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, StatePath, Value},
     types::{Field, U16, U64},
 };
 use ledger_block::{Deployment, Execution, Fee, Input, Transition};
@@ -65,7 +76,11 @@ use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
@@ -76,6 +91,10 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The mapping of program IDs to the stacks of their previous editions.
+    previous_editions: IndexMap<ProgramID<N>, BTreeMap<u16, Arc<Stack<N>>>>,
+    /// The mapping of program addresses to the latest stacks, for dynamic calls.
+    program_addresses: ProgramAddresses<N>,
 }
 
 impl<N: Network> Process<N> {
@@ -85,7 +104,12 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            previous_editions: IndexMap::new(),
+            program_addresses: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -104,7 +128,7 @@ impl<N: Network> Process<N> {
         lap!(timer, "Synthesize credits program keys");
 
         // Add the 'credits.aleo' stack to the process.
-        process.add_stack(stack);
+        process.add_stack(stack)?;
 
         finish!(timer);
         // Return the process.
@@ -119,17 +143,88 @@ impl<N: Network> Process<N> {
         let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
         // If the program is not 'credits.aleo', compute the program stack, and add it to the process.
         if program.id() != &credits_program_id {
-            self.add_stack(Stack::new(self, program)?);
+            self.add_stack(Stack::new(self, program)?)?;
         }
         Ok(())
     }
 
     /// Adds a new stack to the process.
+    /// If the program already exists, the stack with the later edition becomes the latest edition,
+    /// and the other stack is retained as a previous edition.
+    /// The stacks of the programs that import the latest edition are rebuilt, so that they call into it.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
-    pub fn add_stack(&mut self, stack: Stack<N>) {
-        // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), Arc::new(stack));
+    pub fn add_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        self.add_stacks([stack])
+    }
+
+    /// Adds the given stacks to the process, or none of them if the programs that import them cannot be rebuilt.
+    #[inline]
+    pub fn add_stacks(&mut self, stacks: impl IntoIterator<Item = Stack<N>>) -> Result<()> {
+        // Add the stacks to a copy of the process.
+        let mut process = self.clone();
+        for stack in stacks {
+            process.insert_stack(Arc::new(stack))?;
+        }
+        // Register the addresses of the latest stacks that changed, for dynamic calls.
+        {
+            let mut program_addresses = self.program_addresses.write();
+            for (program_id, stack) in &process.stacks {
+                if self.stacks.get(program_id).map_or(true, |previous| !Arc::ptr_eq(previous, stack)) {
+                    if let Ok(address) = program_id.to_address() {
+                        program_addresses.insert(address, Arc::downgrade(stack));
+                    }
+                }
+            }
+        }
+        // Replace the process.
+        *self = process;
+        Ok(())
+    }
+
+    /// Inserts the given stack, and rebuilds the stacks of the programs that import the latest stack.
+    #[inline]
+    fn insert_stack(&mut self, stack: Arc<Stack<N>>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
+        match self.stacks.get(&program_id).cloned() {
+            // If the stack is an earlier edition, retain it as a previous edition.
+            Some(latest) if stack.edition() < latest.edition() => {
+                self.previous_editions.entry(program_id).or_default().insert(stack.edition(), stack);
+                Ok(())
+            }
+            // If the stack is an upgrade, retain the latest stack as a previous edition.
+            Some(latest) if stack.edition() > latest.edition() => {
+                self.previous_editions.entry(program_id).or_default().insert(latest.edition(), latest);
+                self.stacks.insert(program_id, stack);
+                self.rebuild_importers()
+            }
+            // Otherwise, add the stack to the process.
+            _ => {
+                self.stacks.insert(program_id, stack);
+                self.rebuild_importers()
+            }
+        }
+    }
+
+    /// Rebuilds the stacks that call into a stack which is no longer the stack of its program in the process.
+    #[inline]
+    fn rebuild_importers(&mut self) -> Result<()> {
+        // Note: Each pass rebuilds the importers one level further down, so the passes are bounded by the
+        // number of programs, unless the imports are cyclic.
+        for _ in 0..=self.stacks.len() {
+            // Retrieve the stacks that are out of date.
+            let stale = self.stacks.values().filter(|stack| stack.is_stale(self)).cloned().collect::<Vec<_>>();
+            if stale.is_empty() {
+                return Ok(());
+            }
+            // Rebuild the stacks against the current stacks of their imports.
+            for stack in stale {
+                let rebuilt = stack.rebuild(self)?;
+                self.stacks.insert(*rebuilt.program_id(), Arc::new(rebuilt));
+            }
+        }
+        bail!("Failed to rebuild the program stacks, as their imports are cyclic")
     }
 }
 
@@ -140,7 +235,12 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            previous_editions: IndexMap::new(),
+            program_addresses: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -161,7 +261,7 @@ impl<N: Network> Process<N> {
         lap!(timer, "Load circuit keys");
 
         // Add the stack to the process.
-        process.add_stack(stack);
+        process.add_stack(stack)?;
 
         finish!(timer, "Process::load");
         // Return the process.
@@ -173,7 +273,12 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            previous_editions: IndexMap::new(),
+            program_addresses: Default::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -182,7 +287,7 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(&process, &program)?;
 
         // Add the stack to the process.
-        process.add_stack(stack);
+        process.add_stack(stack)?;
 
         // Return the process.
         Ok(process)
@@ -194,12 +299,25 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the mapping of program addresses to the latest stacks.
+    #[inline]
+    pub(crate) const fn program_addresses(&self) -> &ProgramAddresses<N> {
+        &self.program_addresses
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
         self.stacks.contains_key(program_id)
     }
 
+    /// Returns `true` if the process contains the given edition of the program with the given ID.
+    #[inline]
+    pub fn contains_program_edition(&self, program_id: &ProgramID<N>, edition: u16) -> bool {
+        self.stacks.get(program_id).map_or(false, |stack| stack.edition() == edition)
+            || self.previous_editions.get(program_id).map_or(false, |stacks| stacks.contains_key(&edition))
+    }
+
     /// Returns the stack for the given program ID.
     #[inline]
     pub fn get_stack(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Arc<Stack<N>>> {
@@ -213,6 +331,44 @@ impl<N: Network> Process<N> {
         Ok(stack)
     }
 
+    /// Returns the stack for the given program ID and edition.
+    #[inline]
+    pub fn get_stack_at_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<&Arc<Stack<N>>> {
+        // Retrieve the latest stack.
+        let stack = self.get_stack(program_id)?;
+        // Return the latest stack, if it is the given edition.
+        if stack.edition() == edition {
+            return Ok(stack);
+        }
+        // Retrieve the stack from the previous editions.
+        self.previous_editions
+            .get(program_id)
+            .and_then(|stacks| stacks.get(&edition))
+            .ok_or_else(|| anyhow!("Program '{program_id}' (edition {edition}) does not exist"))
+    }
+
+    /// Returns the process with the given editions of the programs, in place of their latest editions.
+    /// The stacks of the programs that import them are rebuilt, so that they call into the given editions.
+    /// Note: The process is only cloned if an edition is not the latest edition.
+    #[inline]
+    pub fn at_editions(&self, editions: &IndexMap<ProgramID<N>, u16>) -> Result<Cow<'_, Self>> {
+        let mut process = Cow::Borrowed(self);
+        for (program_id, edition) in editions {
+            // Skip the program, if the edition is the latest edition.
+            if self.get_stack(program_id)?.edition() == *edition {
+                continue;
+            }
+            // Replace the stack with the stack of the given edition.
+            let stack = self.get_stack_at_edition(program_id, *edition)?.clone();
+            process.to_mut().stacks.insert(*program_id, stack);
+        }
+        // Rebuild the importers of the replaced stacks.
+        if let Cow::Owned(process) = &mut process {
+            process.rebuild_importers()?;
+        }
+        Ok(process)
+    }
+
     /// Returns the program for the given program ID.
     #[inline]
     pub fn get_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Program<N>> {
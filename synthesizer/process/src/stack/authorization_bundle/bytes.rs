@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> FromBytes for AuthorizationBundle<N> {
+    /// Reads the authorization bundle from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid authorization bundle version"));
+        }
+
+        // Read the execution authorization.
+        let authorization = Authorization::read_le(&mut reader)?;
+        // Read the fee authorization.
+        let fee_authorization = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Authorization::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid fee authorization variant '{variant}'"))),
+        };
+        // Read the state root.
+        let state_root = N::StateRoot::read_le(&mut reader)?;
+        // Read the expiration height.
+        let expiration_height = u32::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = Signature::read_le(&mut reader)?;
+
+        // Return the new `AuthorizationBundle` instance.
+        Self::try_from((authorization, fee_authorization, state_root, expiration_height, signature)).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for AuthorizationBundle<N> {
+    /// Writes the authorization bundle to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the execution authorization.
+        self.authorization.write_le(&mut writer)?;
+        // Write the fee authorization.
+        match &self.fee_authorization {
+            None => 0u8.write_le(&mut writer)?,
+            Some(fee_authorization) => {
+                1u8.write_le(&mut writer)?;
+                fee_authorization.write_le(&mut writer)?;
+            }
+        }
+        // Write the state root.
+        self.state_root.write_le(&mut writer)?;
+        // Write the expiration height.
+        self.expiration_height.write_le(&mut writer)?;
+        // Write the signature.
+        self.signature.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new authorization bundle.
+        let expected = crate::stack::authorization_bundle::test_helpers::sample_authorization_bundle(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, AuthorizationBundle::read_le(&expected_bytes[..])?);
+        assert!(AuthorizationBundle::<console::network::Testnet3>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
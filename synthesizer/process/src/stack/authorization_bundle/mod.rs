@@ -0,0 +1,351 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::Authorization;
+use console::{
+    account::{Address, PrivateKey, Signature},
+    network::prelude::*,
+    program::{Literal, Value},
+    types::Field,
+};
+
+/// The domain separator of the authorization bundle signature.
+const AUTHORIZATION_BUNDLE_DOMAIN: &str = "AleoAuthorizationBundle0";
+
+/// An authorization bundle, for delegating the proving of an execution to an untrusted prover.
+///
+/// The bundle anchors the authorizations to a state root of the ledger, and expires at a given block height,
+/// which the execution request commits to.
+/// It is signed by the signer of the execution, so that the prover cannot modify the fee, the anchor, or the expiration.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AuthorizationBundle<N: Network> {
+    /// The execution authorization.
+    authorization: Authorization<N>,
+    /// The fee authorization, if one is included.
+    fee_authorization: Option<Authorization<N>>,
+    /// The state root that the bundle is anchored to.
+    state_root: N::StateRoot,
+    /// The block height at which the bundle expires.
+    expiration_height: u32,
+    /// The signature of the execution signer over the bundle.
+    signature: Signature<N>,
+}
+
+impl<N: Network> AuthorizationBundle<N> {
+    /// Initializes a new authorization bundle, signed with the private key of the execution signer.
+    pub fn new<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        authorization: Authorization<N>,
+        fee_authorization: Option<Authorization<N>>,
+        state_root: N::StateRoot,
+        expiration_height: u32,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the private key belongs to the execution signer.
+        ensure!(
+            Address::try_from(private_key)? == *authorization.peek_next()?.signer(),
+            "The private key does not belong to the signer of the execution"
+        );
+        // Construct the message.
+        let message = Self::to_message(&authorization, &fee_authorization, state_root, expiration_height)?;
+        // Sign the message.
+        let signature = Signature::sign(private_key, &message, rng)?;
+        // Return the bundle.
+        Self::from_unchecked(authorization, fee_authorization, state_root, expiration_height, signature)
+    }
+
+    /// Returns the execution authorization.
+    pub const fn authorization(&self) -> &Authorization<N> {
+        &self.authorization
+    }
+
+    /// Returns the fee authorization, if one is included.
+    pub const fn fee_authorization(&self) -> Option<&Authorization<N>> {
+        self.fee_authorization.as_ref()
+    }
+
+    /// Returns the state root that the bundle is anchored to.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the block height at which the bundle expires.
+    pub const fn expiration_height(&self) -> u32 {
+        self.expiration_height
+    }
+
+    /// Returns the signature over the bundle.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the signer of the execution.
+    pub fn signer(&self) -> Result<Address<N>> {
+        Ok(*self.authorization.peek_next()?.signer())
+    }
+
+    /// Returns the execution ID of the bundle.
+    pub fn to_execution_id(&self) -> Result<Field<N>> {
+        self.authorization.to_execution_id()
+    }
+}
+
+impl<N: Network> TryFrom<(Authorization<N>, Option<Authorization<N>>, N::StateRoot, u32, Signature<N>)>
+    for AuthorizationBundle<N>
+{
+    type Error = Error;
+
+    /// Initializes an authorization bundle from its parts, and verifies the signature.
+    fn try_from(
+        (authorization, fee_authorization, state_root, expiration_height, signature): (
+            Authorization<N>,
+            Option<Authorization<N>>,
+            N::StateRoot,
+            u32,
+            Signature<N>,
+        ),
+    ) -> Result<Self> {
+        // Initialize the bundle.
+        let bundle = Self::from_unchecked(authorization, fee_authorization, state_root, expiration_height, signature)?;
+        // Construct the message.
+        let message = Self::to_message(
+            &bundle.authorization,
+            &bundle.fee_authorization,
+            bundle.state_root,
+            bundle.expiration_height,
+        )?;
+        // Ensure the signature is valid.
+        ensure!(bundle.signature.verify(&bundle.signer()?, &message), "Invalid signature for the authorization bundle");
+        Ok(bundle)
+    }
+}
+
+impl<N: Network> AuthorizationBundle<N> {
+    /// Initializes an authorization bundle, checking the authorizations, but not the signature.
+    fn from_unchecked(
+        authorization: Authorization<N>,
+        fee_authorization: Option<Authorization<N>>,
+        state_root: N::StateRoot,
+        expiration_height: u32,
+        signature: Signature<N>,
+    ) -> Result<Self> {
+        // Ensure the execution authorization is not a fee.
+        ensure!(
+            !authorization.is_fee_private() && !authorization.is_fee_public(),
+            "The execution authorization of a bundle cannot be a fee"
+        );
+        // Ensure the execution request expires at the expiration height of the bundle.
+        // Note: As the request commits to its expiration height, this ensures the chain rejects
+        // the execution once the bundle expires, regardless of the prover.
+        let expected = Some(expiration_height);
+        ensure!(
+            authorization.peek_next()?.expiration_height() == expected,
+            "The execution request in the bundle must expire at block {expiration_height}"
+        );
+        // Ensure the fee authorization is for the execution.
+        if let Some(fee_authorization) = &fee_authorization {
+            // Ensure the fee authorization is a fee.
+            ensure!(
+                fee_authorization.is_fee_private() || fee_authorization.is_fee_public(),
+                "The fee authorization of a bundle must be a fee"
+            );
+            // Retrieve the execution ID of the fee, which is the last input of the fee request.
+            let fee_request = fee_authorization.peek_next()?;
+            let expected = Value::from(Literal::Field(authorization.to_execution_id()?));
+            ensure!(
+                fee_request.inputs().last() == Some(&expected),
+                "The fee authorization is not for the execution in the bundle"
+            );
+        }
+        Ok(Self { authorization, fee_authorization, state_root, expiration_height, signature })
+    }
+
+    /// Returns the message that is signed for the bundle, as
+    /// `[domain, execution ID, fee transition ID, state root, expiration height]`.
+    fn to_message(
+        authorization: &Authorization<N>,
+        fee_authorization: &Option<Authorization<N>>,
+        state_root: N::StateRoot,
+        expiration_height: u32,
+    ) -> Result<Vec<Field<N>>> {
+        // Retrieve the fee transition ID, or zero if there is no fee.
+        let fee_transition_id = match fee_authorization {
+            Some(fee_authorization) => match fee_authorization.transitions().keys().next() {
+                Some(transition_id) => **transition_id,
+                None => bail!("The fee authorization in the bundle has no transition"),
+            },
+            None => Field::zero(),
+        };
+        Ok(vec![
+            Field::new_domain_separator(AUTHORIZATION_BUNDLE_DOMAIN),
+            authorization.to_execution_id()?,
+            fee_transition_id,
+            *state_root,
+            Field::from_u32(expiration_height),
+        ])
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::Process;
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns a sample authorization bundle.
+    pub fn sample_authorization_bundle(rng: &mut TestRng) -> AuthorizationBundle<CurrentNetwork> {
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Authorize a public transfer, which expires with the bundle.
+        let authorization = process
+            .authorize_with_expiration::<CurrentAleo, _>(
+                &private_key,
+                "credits.aleo",
+                "transfer_public",
+                [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()].into_iter(),
+                Some(100),
+                rng,
+            )
+            .unwrap();
+        // Authorize the fee.
+        let fee_authorization = process
+            .authorize_fee_public::<CurrentAleo, _>(
+                &private_key,
+                1_000_000,
+                0,
+                authorization.to_execution_id().unwrap(),
+                rng,
+            )
+            .unwrap();
+
+        // Construct the bundle.
+        AuthorizationBundle::new(&private_key, authorization, Some(fee_authorization), Uniform::rand(rng), 100, rng)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_signature() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a bundle.
+        let bundle = test_helpers::sample_authorization_bundle(rng);
+        let parts = || {
+            (
+                bundle.authorization.replicate(),
+                bundle.fee_authorization.as_ref().map(Authorization::replicate),
+                bundle.state_root,
+                bundle.expiration_height,
+                bundle.signature,
+            )
+        };
+        assert_eq!(bundle, AuthorizationBundle::try_from(parts())?);
+
+        // Ensure a modified expiration height is rejected.
+        let (authorization, fee_authorization, state_root, expiration_height, signature) = parts();
+        let candidate = (authorization, fee_authorization, state_root, expiration_height + 1, signature);
+        assert!(AuthorizationBundle::try_from(candidate).is_err());
+
+        // Ensure a modified state root is rejected.
+        let (authorization, fee_authorization, _, expiration_height, signature) = parts();
+        let candidate = (authorization, fee_authorization, Uniform::rand(rng), expiration_height, signature);
+        assert!(AuthorizationBundle::try_from(candidate).is_err());
+
+        // Ensure a removed fee is rejected.
+        let (authorization, _, state_root, expiration_height, signature) = parts();
+        let candidate = (authorization, None, state_root, expiration_height, signature);
+        assert!(AuthorizationBundle::try_from(candidate).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fee_for_another_execution() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load()?;
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Authorize a public transfer.
+        let authorization = process.authorize_with_expiration::<CurrentAleo, _>(
+            &private_key,
+            "credits.aleo",
+            "transfer_public",
+            [Value::from_str(&address.to_string())?, Value::from_str("1u64")?].into_iter(),
+            Some(100),
+            rng,
+        )?;
+        // Authorize a fee for another execution.
+        let fee_authorization =
+            process.authorize_fee_public::<CurrentAleo, _>(&private_key, 1_000_000, 0, Field::rand(rng), rng)?;
+
+        // Ensure the fee is rejected.
+        let candidate = AuthorizationBundle::new(
+            &private_key,
+            authorization,
+            Some(fee_authorization),
+            Uniform::rand(rng),
+            100,
+            rng,
+        );
+        assert!(candidate.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_expiration_is_bound_to_request() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load()?;
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        for expiration_height in [None, Some(99), Some(101)] {
+            // Authorize a public transfer, which does not expire with the bundle.
+            let authorization = process.authorize_with_expiration::<CurrentAleo, _>(
+                &private_key,
+                "credits.aleo",
+                "transfer_public",
+                [Value::from_str(&address.to_string())?, Value::from_str("1u64")?].into_iter(),
+                expiration_height,
+                rng,
+            )?;
+            // Ensure the bundle is rejected.
+            let candidate = AuthorizationBundle::new(&private_key, authorization, None, Uniform::rand(rng), 100, rng);
+            assert!(candidate.is_err());
+        }
+        Ok(())
+    }
+}
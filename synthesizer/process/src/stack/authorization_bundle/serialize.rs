@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Serialize for AuthorizationBundle<N> {
+    /// Serializes the authorization bundle into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut bundle = serializer.serialize_struct("AuthorizationBundle", 6)?;
+                bundle.serialize_field("version", &1u8)?;
+                bundle.serialize_field("authorization", &self.authorization)?;
+                if let Some(fee_authorization) = &self.fee_authorization {
+                    bundle.serialize_field("fee_authorization", fee_authorization)?;
+                }
+                bundle.serialize_field("state_root", &self.state_root)?;
+                bundle.serialize_field("expiration_height", &self.expiration_height)?;
+                bundle.serialize_field("signature", &self.signature)?;
+                bundle.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for AuthorizationBundle<N> {
+    /// Deserializes the authorization bundle from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the authorization bundle from a string into a value.
+                let mut bundle = serde_json::Value::deserialize(deserializer)?;
+                // Ensure the version is valid.
+                let version: u8 = DeserializeExt::take_from_value::<D>(&mut bundle, "version")?;
+                if version != 1 {
+                    return Err(de::Error::custom(format!("Invalid authorization bundle version '{version}'")));
+                }
+                // Retrieve the execution authorization.
+                let authorization = DeserializeExt::take_from_value::<D>(&mut bundle, "authorization")?;
+                // Retrieve the fee authorization, if it exists.
+                let fee_authorization = serde_json::from_value(
+                    bundle.get_mut("fee_authorization").unwrap_or(&mut serde_json::Value::Null).take(),
+                )
+                .map_err(de::Error::custom)?;
+                // Retrieve the state root.
+                let state_root = DeserializeExt::take_from_value::<D>(&mut bundle, "state_root")?;
+                // Retrieve the expiration height.
+                let expiration_height = DeserializeExt::take_from_value::<D>(&mut bundle, "expiration_height")?;
+                // Retrieve the signature.
+                let signature = DeserializeExt::take_from_value::<D>(&mut bundle, "signature")?;
+                // Recover the authorization bundle.
+                Self::try_from((authorization, fee_authorization, state_root, expiration_height, signature))
+                    .map_err(de::Error::custom)
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "authorization bundle")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the authorization bundle.
+        let expected = crate::stack::authorization_bundle::test_helpers::sample_authorization_bundle(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, AuthorizationBundle::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the authorization bundle.
+        let expected = crate::stack::authorization_bundle::test_helpers::sample_authorization_bundle(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, AuthorizationBundle::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
@@ -23,6 +23,35 @@ impl<N: Network> Stack<N> {
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_expiration::<A, R>(private_key, function_name, inputs, None, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs,
+    /// which expires after the given block height, if any.
+    #[inline]
+    pub fn authorize_with_expiration<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        expiration_height: Option<u32>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_memo::<A, R>(private_key, function_name, inputs, expiration_height, None, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs,
+    /// which expires after the given block height, if any, and carries the given memo to its recipient, if any.
+    #[inline]
+    pub fn authorize_with_memo<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        expiration_height: Option<u32>,
+        memo: Option<(Plaintext<N>, Address<N>)>,
+        rng: &mut R,
     ) -> Result<Authorization<N>> {
         let timer = timer!("Stack::authorize");
 
@@ -34,8 +63,21 @@ impl<N: Network> Stack<N> {
         let input_types = self.get_function(&function_name)?.input_types();
         lap!(timer, "Retrieve the input types");
 
+        // Encrypt the memo to its recipient, if any.
+        let memo = memo.map(|(memo, recipient)| Request::encrypt_memo(&memo, &recipient, rng)).transpose()?;
+        lap!(timer, "Encrypt the memo");
+
         // Compute the request.
-        let request = Request::sign(private_key, program_id, function_name, inputs, &input_types, rng)?;
+        let request = Request::sign_with_memo(
+            private_key,
+            program_id,
+            function_name,
+            inputs,
+            &input_types,
+            expiration_height,
+            memo,
+            rng,
+        )?;
         lap!(timer, "Compute the request");
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
@@ -14,10 +14,15 @@
 
 use crate::{CallStack, Registers, RegistersCall, StackEvaluate, StackExecute};
 use aleo_std::prelude::{finish, lap, timer};
-use console::{network::prelude::*, program::Request};
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, Request, Value},
+    types::{Address, U16},
+};
 use synthesizer_program::{
     Call,
     CallOperator,
+    Operand,
     RegistersLoad,
     RegistersLoadCircuit,
     RegistersSigner,
@@ -64,12 +69,26 @@ impl<N: Network> CallTrait<N> for Call<N> {
         let inputs: Vec<_> =
             self.operands().iter().map(|operand| registers.load(stack.deref(), operand)).try_collect()?;
 
+        // Initialize a placeholder for the stack of a dynamic call.
+        let dynamic_stack;
         // Retrieve the substack and resource.
         let (substack, resource) = match self.operator() {
             // Retrieve the call stack and resource from the locator.
             CallOperator::Locator(locator) => {
                 (stack.get_external_stack(locator.program_id())?.as_ref(), locator.resource())
             }
+            // Retrieve the call stack from the program address, and the resource from the interface.
+            CallOperator::Dynamic(register, locator) => {
+                // Retrieve the program address.
+                let program_address = to_program_address(registers.load(stack, &Operand::Register(register.clone()))?)?;
+                // Retrieve the stack of the program.
+                dynamic_stack = stack.get_dynamic_stack(&program_address)?;
+                // Ensure the program conforms to the interface.
+                let interface = stack.get_external_stack(locator.program_id())?.get_function_ref(locator.resource())?;
+                Call::check_dynamic_callee(interface, dynamic_stack.as_ref())?;
+
+                (dynamic_stack.as_ref(), locator.resource())
+            }
             CallOperator::Resource(resource) => {
                 // TODO (howardwu): Revisit this decision to forbid calling internal functions. A record cannot be spent again.
                 //  But there are legitimate uses for passing a record through to an internal function.
@@ -147,8 +166,42 @@ impl<N: Network> CallTrait<N> for Call<N> {
         let inputs: Vec<_> =
             self.operands().iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
 
+        // Initialize a placeholder for the stack of a dynamic call.
+        let dynamic_stack;
         // Retrieve the substack and resource.
         let (substack, resource) = match self.operator() {
+            // Retrieve the call stack from the program address, and the resource from the interface.
+            CallOperator::Dynamic(register, locator) => {
+                use circuit::Eject;
+                // Retrieve the program address.
+                let program_address = to_program_address(
+                    registers.load_circuit(stack, &Operand::Register(register.clone()))?.eject_value(),
+                )?;
+                // Retrieve the stack of the interface.
+                let interface_stack = stack.get_external_stack(locator.program_id())?;
+                // Retrieve the stack of the program.
+                dynamic_stack = match stack.get_dynamic_stack(&program_address) {
+                    Ok(dynamic_stack) => dynamic_stack,
+                    // If the circuit is in synthesize or check deployment mode, the program address is sampled,
+                    // so the interface is used in place of the program, as it has the same inputs and outputs.
+                    Err(_)
+                        if matches!(
+                            registers.call_stack(),
+                            CallStack::Synthesize(..) | CallStack::CheckDeployment(..)
+                        ) =>
+                    {
+                        interface_stack.clone()
+                    }
+                    Err(error) => return Err(error),
+                };
+                // Ensure the program conforms to the interface.
+                Call::check_dynamic_callee(
+                    interface_stack.get_function_ref(locator.resource())?,
+                    dynamic_stack.as_ref(),
+                )?;
+
+                (dynamic_stack.as_ref(), locator.resource())
+            }
             // Retrieve the call stack and resource from the locator.
             CallOperator::Locator(locator) => {
                 // Check the external call locator.
@@ -270,6 +323,11 @@ impl<N: Network> CallTrait<N> for Call<N> {
                     CallStack::Execute(authorization, ..) => {
                         // Retrieve the next request (without popping it).
                         let request = authorization.peek_next()?;
+                        // Ensure the request is for the called function.
+                        ensure!(
+                            request.program_id() == substack.program_id() && request.function_name() == function.name(),
+                            "Request does not match the function in a 'call' instruction."
+                        );
                         // Ensure the inputs match the original inputs.
                         request.inputs().iter().zip_eq(&inputs).try_for_each(|(request_input, input)| {
                             ensure!(request_input == input, "Inputs do not match in a 'call' instruction.");
@@ -310,6 +368,40 @@ impl<N: Network> CallTrait<N> for Call<N> {
             // Ensure the number of public variables remains the same.
             ensure!(A::num_public() == num_public, "Forbidden: 'call' injected excess public variables");
 
+            // If the call is dynamic, inject the program address and function ID of the callee as `Mode::Public`.
+            // Note: The verifier provides these from the callee transition, binding the callee to the register.
+            let dynamic_function_id = match self.operator() {
+                CallOperator::Dynamic(register, _) => {
+                    // Retrieve the program address from the register.
+                    let candidate_address = match registers.load_circuit(stack, &Operand::Register(register.clone()))? {
+                        circuit::Value::Plaintext(circuit::Plaintext::Literal(
+                            circuit::Literal::Address(address),
+                            _,
+                        )) => address,
+                        _ => bail!("Dynamic call register '{register}' must contain an address."),
+                    };
+                    // Inject the program address as `Mode::Public`.
+                    let program_address =
+                        circuit::Address::<A>::new(circuit::Mode::Public, candidate_address.eject_value());
+                    // Ensure the program address matches the register.
+                    A::assert_eq(&program_address, &candidate_address);
+
+                    // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+                    let function_id = N::hash_bhp1024(
+                        &(
+                            U16::<N>::new(N::ID),
+                            substack.program_id().name(),
+                            substack.program_id().network(),
+                            function.name(),
+                        )
+                            .to_bits_le(),
+                    )?;
+                    // Inject the function ID as `Mode::Public`.
+                    Some(circuit::Field::new(circuit::Mode::Public, function_id))
+                }
+                _ => None,
+            };
+
             // Inject the `signer` (from the request) as `Mode::Private`.
             let signer = circuit::Address::new(circuit::Mode::Private, *request.signer());
             // Inject the `sk_tag` (from the request) as `Mode::Private`.
@@ -330,33 +422,58 @@ impl<N: Network> CallTrait<N> for Call<N> {
                 .collect::<Vec<_>>();
 
             // Ensure the candidate input IDs match their computed inputs.
-            let (check_input_ids, _) = circuit::Request::check_input_ids::<false>(
-                &network_id,
-                &program_id,
-                &function_name,
-                &input_ids,
-                &inputs,
-                &function.input_types(),
-                &signer,
-                &sk_tag,
-                &tvk,
-                &tcm,
-                None,
-            );
+            let (check_input_ids, _) = match &dynamic_function_id {
+                Some(function_id) => circuit::Request::check_input_ids_with_function_id::<false>(
+                    function_id,
+                    &program_id,
+                    &input_ids,
+                    &inputs,
+                    &function.input_types(),
+                    &signer,
+                    &sk_tag,
+                    &tvk,
+                    &tcm,
+                    None,
+                ),
+                None => circuit::Request::check_input_ids::<false>(
+                    &network_id,
+                    &program_id,
+                    &function_name,
+                    &input_ids,
+                    &inputs,
+                    &function.input_types(),
+                    &signer,
+                    &sk_tag,
+                    &tvk,
+                    &tcm,
+                    None,
+                ),
+            };
             A::assert(check_input_ids);
             lap!(timer, "Checked the input ids");
 
             // Inject the outputs as `Mode::Private` (with the 'tcm' and output IDs as `Mode::Public`).
-            let outputs = circuit::Response::process_outputs_from_callback(
-                &network_id,
-                &program_id,
-                &function_name,
-                num_inputs,
-                &tvk,
-                &tcm,
-                response.outputs().to_vec(),
-                &function.output_types(),
-            );
+            let outputs = match &dynamic_function_id {
+                Some(function_id) => circuit::Response::process_outputs_from_callback_with_function_id(
+                    function_id,
+                    &program_id,
+                    num_inputs,
+                    &tvk,
+                    &tcm,
+                    response.outputs().to_vec(),
+                    &function.output_types(),
+                ),
+                None => circuit::Response::process_outputs_from_callback(
+                    &network_id,
+                    &program_id,
+                    &function_name,
+                    num_inputs,
+                    &tvk,
+                    &tcm,
+                    response.outputs().to_vec(),
+                    &function.output_types(),
+                ),
+            };
             lap!(timer, "Checked the outputs");
             // Return the circuit outputs.
             outputs
@@ -378,3 +495,11 @@ impl<N: Network> CallTrait<N> for Call<N> {
         Ok(())
     }
 }
+
+/// Returns the program address of a dynamic call, given the value in its register.
+fn to_program_address<N: Network>(value: Value<N>) -> Result<Address<N>> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::Address(address), _)) => Ok(address),
+        _ => bail!("Dynamic call register must contain an address, found '{value}'"),
+    }
+}
@@ -82,6 +82,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
+                    // If the operand is the block or transaction context, throw an error.
+                    Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                        bail!("Cannot retrieve the block or transaction context from a closure scope.")
+                    }
                 }
             })
             .collect();
@@ -213,6 +217,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
+                    // If the operand is the block or transaction context, throw an error.
+                    Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                        bail!("Cannot retrieve the block or transaction context from a function scope.")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
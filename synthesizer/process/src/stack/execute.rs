@@ -115,6 +115,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a closure scope")
                     }
+                    // If the operand is the block or transaction context, throw an error.
+                    Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                        bail!("Illegal operation: cannot retrieve the block or transaction context in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -322,6 +326,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a function scope")
                     }
+                    // If the operand is the block or transaction context, throw an error.
+                    Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                        bail!("Illegal operation: cannot retrieve the block or transaction context in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
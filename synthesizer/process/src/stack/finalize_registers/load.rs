@@ -35,12 +35,30 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             }
             // If the operand is the signer, throw an error.
             Operand::Signer => bail!("Forbidden operation: Cannot use 'self.signer' in 'finalize'"),
-            // If the operand is the caller, throw an error.
-            Operand::Caller => bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'"),
+            // If the operand is the caller, load the caller, if it is a program.
+            Operand::Caller => match self.caller {
+                Some(caller) => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(caller)))),
+                None => bail!("The caller of the root transition is not available in 'finalize'"),
+            },
             // If the operand is the block height, load the block height.
             Operand::BlockHeight => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
             }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
+            // If the operand is the network ID, load the network ID.
+            Operand::NetworkID => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::U16(U16::new(self.state.network_id())))));
+            }
+            // If the operand is the transaction ID, load the transaction ID.
+            Operand::TransactionID => match self.state.transaction_id() {
+                Some(transaction_id) => {
+                    return Ok(Value::Plaintext(Plaintext::from(Literal::Field(*transaction_id))));
+                }
+                None => bail!("The transaction ID is not available in this 'finalize' scope"),
+            },
         };
 
         // Retrieve the value.
@@ -19,7 +19,7 @@ use crate::FinalizeTypes;
 use console::{
     network::prelude::*,
     program::{Identifier, Literal, Plaintext, Register, Value},
-    types::U32,
+    types::{Address, I64, U16, U32},
 };
 use synthesizer_program::{
     FinalizeGlobalState,
@@ -36,11 +36,13 @@ use indexmap::IndexMap;
 #[derive(Clone)]
 pub struct FinalizeRegisters<N: Network> {
     /// The global state for the finalize scope.
-    state: FinalizeGlobalState,
+    state: FinalizeGlobalState<N>,
     /// The transition ID for the finalize scope.
     transition_id: N::TransitionID,
     /// The function name for the finalize scope.
     function_name: Identifier<N>,
+    /// The caller of the transition, if it is a program.
+    caller: Option<Address<N>>,
     /// The mapping of all registers to their defined types.
     finalize_types: FinalizeTypes<N>,
     /// The mapping of assigned registers to their values.
@@ -53,19 +55,50 @@ impl<N: Network> FinalizeRegisters<N> {
     /// Initializes a new set of registers, given the finalize types.
     #[inline]
     pub fn new(
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         transition_id: N::TransitionID,
         function_name: Identifier<N>,
+        caller: Option<Address<N>>,
         finalize_types: FinalizeTypes<N>,
     ) -> Self {
-        Self { state, transition_id, finalize_types, function_name, registers: IndexMap::new(), last_register: None }
+        Self {
+            state,
+            transition_id,
+            finalize_types,
+            function_name,
+            caller,
+            registers: IndexMap::new(),
+            last_register: None,
+        }
+    }
+
+    /// Returns the caller of the transition, if it is a program.
+    #[inline]
+    pub const fn caller(&self) -> Option<Address<N>> {
+        self.caller
+    }
+
+    /// Returns the mapping of all registers to their defined types.
+    #[inline]
+    pub(crate) const fn finalize_types(&self) -> &FinalizeTypes<N> {
+        &self.finalize_types
+    }
+
+    /// Clears the registers starting from the given locator, so that they may be assigned again.
+    /// This is used to reassign the registers in the body of a `loop` on each iteration.
+    #[inline]
+    pub(crate) fn clear_from(&mut self, locator: u64) {
+        // Remove the registers starting from the given locator.
+        self.registers.retain(|register, _| *register < locator);
+        // Reset the last register locator.
+        self.last_register = self.registers.keys().max().copied();
     }
 }
 
 impl<N: Network> FinalizeRegistersState<N> for FinalizeRegisters<N> {
     /// Returns the global state for the finalize scope.
     #[inline]
-    fn state(&self) -> &FinalizeGlobalState {
+    fn state(&self) -> &FinalizeGlobalState<N> {
         &self.state
     }
 
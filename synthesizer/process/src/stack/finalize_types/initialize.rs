@@ -19,8 +19,14 @@ use synthesizer_program::{
     Branch,
     CastType,
     Contains,
+    Count,
+    Emit,
     Get,
+    GetAt,
     GetOrUse,
+    Invoke,
+    Loop,
+    Mapping,
     MappingLocator,
     RandChaCha,
     Remove,
@@ -53,6 +59,9 @@ impl<N: Network> FinalizeTypes<N> {
             }
         }
 
+        // Ensure every loop is closed, so that the checks on each loop body have been performed.
+        finalize.ensure_loops_closed()?;
+
         // Initialize a list of consumed futures.
         let mut consumed_futures = Vec::new();
 
@@ -81,6 +90,44 @@ impl<N: Network> FinalizeTypes<N> {
 
         Ok(finalize_types)
     }
+
+    /// Initializes a new instance of `FinalizeTypes` for the given routine.
+    /// Checks that the given routine is well-formed for the given stack.
+    #[inline]
+    pub(super) fn initialize_routine_types(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        routine: &Routine<N>,
+    ) -> Result<Self> {
+        // Step 1. Check the inputs and commands are well-formed.
+        // Note that the routine inputs are plaintexts, so the body does not consume any futures.
+        let finalize_types = Self::initialize_finalize_types(stack, routine.body())?;
+
+        // Step 2. Check the outputs are well-formed.
+        for output in routine.outputs() {
+            // Ensure the output type is defined in the program.
+            match output.finalize_type() {
+                FinalizeType::Plaintext(PlaintextType::Literal(..)) => (),
+                FinalizeType::Plaintext(PlaintextType::Struct(struct_name)) => {
+                    RegisterTypes::check_struct(stack, struct_name)?
+                }
+                FinalizeType::Plaintext(PlaintextType::Array(array_type)) => {
+                    RegisterTypes::check_array(stack, array_type)?
+                }
+                FinalizeType::Future(..) => bail!("Routine '{}' cannot output a future", routine.name()),
+            };
+            // Ensure the operand type and the output type match.
+            let operand_type = finalize_types.get_type_from_operand(stack, output.operand())?;
+            ensure!(
+                &operand_type == output.finalize_type(),
+                "Output '{}' in routine '{}' is of type '{operand_type}', but expected '{}'",
+                output.operand(),
+                routine.name(),
+                output.finalize_type()
+            );
+        }
+
+        Ok(finalize_types)
+    }
 }
 
 impl<N: Network> FinalizeTypes<N> {
@@ -184,6 +231,11 @@ impl<N: Network> FinalizeTypes<N> {
             Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, branch_neq)?,
             // Note that the `Position`s are checked for uniqueness when constructing `Finalize`.
             Command::Position(_) => (),
+            Command::Loop(loop_) => self.check_loop(stack, finalize, loop_)?,
+            Command::Count(count) => self.check_count(stack, count)?,
+            Command::GetAt(get_at) => self.check_get_at(stack, get_at)?,
+            Command::Invoke(invoke) => self.check_invoke(stack, invoke)?,
+            Command::Emit(emit) => self.check_emit(stack, emit)?,
         }
         Ok(())
     }
@@ -247,6 +299,181 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Checks that the given `loop` command is well-formed.
+    #[inline]
+    fn check_loop(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize: &Finalize<N>,
+        loop_: &Loop<N>,
+    ) -> Result<()> {
+        // Ensure the count operand is a `u32`.
+        match self.get_type_from_operand(stack, loop_.count())? {
+            FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)) => (),
+            FinalizeType::Plaintext(plaintext_type) => {
+                bail!("Command '{}' expects a 'u32' count. Found '{plaintext_type}'", Loop::<N>::opcode())
+            }
+            FinalizeType::Future(..) => bail!("A future cannot be used as the count in a `loop` command"),
+        }
+        // Check that the `Position` has been defined.
+        ensure!(
+            finalize.positions().get(loop_.position()).is_some(),
+            "Command '{}' expects a defined position to end the loop. Found undefined position '{}'",
+            Loop::<N>::opcode(),
+            loop_.position()
+        );
+        // Get the destination register.
+        let destination = loop_.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)))?;
+        Ok(())
+    }
+
+    /// Ensures the given `count` command is well-formed.
+    #[inline]
+    fn check_count(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), count: &Count<N>) -> Result<()> {
+        // Ensure the mapping is defined.
+        Self::check_mapping_locator(stack, count.mapping())?;
+        // Get the destination register.
+        let destination = count.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)))?;
+        Ok(())
+    }
+
+    /// Ensures the given `get.at` command is well-formed.
+    #[inline]
+    fn check_get_at(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), get_at: &GetAt<N>) -> Result<()> {
+        // Retrieve the mapping.
+        let mapping = Self::check_mapping_locator(stack, get_at.mapping())?;
+        // Ensure the index operand is a `u32`.
+        match self.get_type_from_operand(stack, get_at.index())? {
+            FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)) => (),
+            FinalizeType::Plaintext(plaintext_type) => {
+                bail!("Command '{}' expects a 'u32' index. Found '{plaintext_type}'", GetAt::<N>::opcode())
+            }
+            FinalizeType::Future(..) => bail!("A future cannot be used as an index in a `get.at` command"),
+        }
+        // Get the destination registers.
+        let key_destination = get_at.key_destination().clone();
+        let value_destination = get_at.value_destination().clone();
+        // Ensure the destination registers are locators (and do not reference an access).
+        ensure!(matches!(key_destination, Register::Locator(..)), "Destination '{key_destination}' must be a locator.");
+        ensure!(
+            matches!(value_destination, Register::Locator(..)),
+            "Destination '{value_destination}' must be a locator."
+        );
+        // Insert the destination registers.
+        self.add_destination(key_destination, FinalizeType::Plaintext(mapping.key().plaintext_type().clone()))?;
+        self.add_destination(value_destination, FinalizeType::Plaintext(mapping.value().plaintext_type().clone()))?;
+        Ok(())
+    }
+
+    /// Ensures the given `invoke` command is well-formed.
+    #[inline]
+    fn check_invoke(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), invoke: &Invoke<N>) -> Result<()> {
+        // Retrieve the routine.
+        let routine = stack.program().get_routine(invoke.routine())?;
+
+        // Ensure the number of operands matches the number of routine inputs.
+        ensure!(
+            invoke.operands().len() == routine.inputs().len(),
+            "Routine '{}' expects {} inputs, but found {} operands",
+            invoke.routine(),
+            routine.inputs().len(),
+            invoke.operands().len()
+        );
+        // Ensure the operand types match the routine input types.
+        for (operand, input) in invoke.operands().iter().zip(routine.inputs()) {
+            let operand_type = self.get_type_from_operand(stack, operand)?;
+            ensure!(
+                &operand_type == input.finalize_type(),
+                "Operand '{operand}' is of type '{operand_type}', but routine '{}' expects '{}'",
+                invoke.routine(),
+                input.finalize_type()
+            );
+        }
+
+        // Ensure the number of destinations matches the number of routine outputs.
+        ensure!(
+            invoke.destinations().len() == routine.outputs().len(),
+            "Routine '{}' returns {} outputs, but found {} destinations",
+            invoke.routine(),
+            routine.outputs().len(),
+            invoke.destinations().len()
+        );
+        // Insert the destination registers, with the routine output types.
+        for (destination, output) in invoke.destinations().iter().zip(routine.outputs()) {
+            // Ensure the destination register is a locator (and does not reference an access).
+            ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+            // Insert the destination register.
+            self.add_destination(destination.clone(), output.finalize_type().clone())?;
+        }
+        Ok(())
+    }
+
+    /// Ensures the given `emit` command is well-formed.
+    #[inline]
+    fn check_emit(&self, stack: &(impl StackMatches<N> + StackProgram<N>), emit: &Emit<N>) -> Result<()> {
+        // Ensure the event struct is defined in the program.
+        if !stack.program().contains_struct(emit.name()) {
+            bail!("Event '{}' in '{}' is not a defined struct.", emit.name(), stack.program_id())
+        }
+        // Ensure the operand is an instance of the event struct.
+        match self.get_type_from_operand(stack, emit.operand())? {
+            FinalizeType::Plaintext(PlaintextType::Struct(struct_name)) if &struct_name == emit.name() => Ok(()),
+            operand_type => {
+                bail!("Operand '{}' is of type '{operand_type}', but expected '{}'", emit.operand(), emit.name())
+            }
+        }
+    }
+
+    /// Ensures the given mapping locator references a mapping that is defined, and returns the mapping.
+    /// An external mapping must be defined in a program that is imported by the current program.
+    #[inline]
+    fn check_mapping_locator(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        mapping: &MappingLocator<N>,
+    ) -> Result<Mapping<N>> {
+        match mapping {
+            MappingLocator::Locator(locator) => {
+                // Retrieve the program ID.
+                let program_id = locator.program_id();
+                // Retrieve the mapping_name.
+                let mapping_name = locator.resource();
+
+                // Ensure the locator does not reference the current program.
+                if stack.program_id() == program_id {
+                    bail!("Locator '{locator}' does not reference an external mapping.");
+                }
+                // Ensure the current program contains an import for this external program.
+                if !stack.program().imports().keys().contains(program_id) {
+                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
+                }
+                // Retrieve the program.
+                let external = stack.get_external_program(program_id)?;
+                // Ensure the mapping exists in the program.
+                if !external.contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
+                }
+                // Retrieve the mapping from the program.
+                external.get_mapping(mapping_name)
+            }
+            MappingLocator::Resource(mapping_name) => {
+                // Ensure the mapping is defined in the current program.
+                if !stack.program().contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
+                }
+                // Retrieve the mapping from the program.
+                stack.program().get_mapping(mapping_name)
+            }
+        }
+    }
+
     /// Ensures the given `contains` command is well-formed.
     #[inline]
     fn check_contains(
@@ -68,32 +68,28 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{plaintext_type}' in the operand '{operand}'.",
                     )
                 }
-                // Ensure the program ID type (address) matches the member type.
-                Operand::ProgramID(..) => {
-                    // Retrieve the program ID type.
-                    let program_ref_type = PlaintextType::Literal(LiteralType::Address);
-                    // Ensure the program ID type matches the member type.
+                // Ensure the program ID and caller types (address) match the member type.
+                Operand::ProgramID(..) | Operand::Caller => {
+                    // Retrieve the address type.
+                    let address_type = PlaintextType::Literal(LiteralType::Address);
+                    // Ensure the address type matches the member type.
                     ensure!(
-                        &program_ref_type == member_type,
-                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{program_ref_type}' in the operand '{operand}'.",
+                        &address_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{address_type}' in the operand '{operand}'.",
                     )
                 }
                 // If the operand is a signer, throw an error.
                 Operand::Signer => bail!(
                     "Struct member '{struct_name}.{member_name}' cannot be cast from a signer in a finalize scope."
                 ),
-                // If the operand is a caller, throw an error.
-                Operand::Caller => bail!(
-                    "Struct member '{struct_name}.{member_name}' cannot be cast from a caller in a finalize scope."
-                ),
-                // Ensure the block height type (u32) matches the member type.
-                Operand::BlockHeight => {
-                    // Retrieve the block height type.
-                    let block_height_type = PlaintextType::Literal(LiteralType::U32);
-                    // Ensure the block height type matches the member type.
+                // Ensure the block or transaction context type matches the member type.
+                Operand::BlockHeight | Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                    // Retrieve the context type.
+                    let context_type = self.get_type_from_operand(stack, operand)?;
+                    // Ensure the context type matches the member type.
                     ensure!(
-                        &block_height_type == member_type,
-                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_height_type}' in the operand '{operand}'.",
+                        context_type == FinalizeType::Plaintext(member_type.clone()),
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{context_type}' in the operand '{operand}'.",
                     )
                 }
             }
@@ -151,29 +147,27 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
-                // Ensure the program ID type (address) matches the member type.
-                Operand::ProgramID(..) => {
-                    // Retrieve the program ID type.
-                    let program_ref_type = PlaintextType::Literal(LiteralType::Address);
-                    // Ensure the program ID type matches the member type.
+                // Ensure the program ID and caller types (address) match the element type.
+                Operand::ProgramID(..) | Operand::Caller => {
+                    // Retrieve the address type.
+                    let address_type = PlaintextType::Literal(LiteralType::Address);
+                    // Ensure the address type matches the element type.
                     ensure!(
-                        &program_ref_type == array_type.next_element_type(),
-                        "Array element expects {}, but found '{program_ref_type}' in the operand '{operand}'.",
+                        &address_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{address_type}' in the operand '{operand}'.",
                         array_type.next_element_type()
                     )
                 }
                 // If the operand is a signer, throw an error.
                 Operand::Signer => bail!("Array element cannot be cast from a signer in a finalize scope."),
-                // If the operand is a caller, throw an error.
-                Operand::Caller => bail!("Array element cannot be cast from a caller in a finalize scope."),
-                // Ensure the block height type (u32) matches the member type.
-                Operand::BlockHeight => {
-                    // Retrieve the block height type.
-                    let block_height_type = PlaintextType::Literal(LiteralType::U32);
-                    // Ensure the block height type matches the member type.
+                // Ensure the block or transaction context type matches the element type.
+                Operand::BlockHeight | Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                    // Retrieve the context type.
+                    let context_type = self.get_type_from_operand(stack, operand)?;
+                    // Ensure the context type matches the element type.
                     ensure!(
-                        &block_height_type == array_type.next_element_type(),
-                        "Array element expects {}, but found '{block_height_type}' in the operand '{operand}'.",
+                        context_type == FinalizeType::Plaintext(array_type.next_element_type().clone()),
+                        "Array element expects {}, but found '{context_type}' in the operand '{operand}'.",
                         array_type.next_element_type()
                     )
                 }
@@ -27,6 +27,7 @@ use synthesizer_program::{
     Opcode,
     Operand,
     Program,
+    Routine,
     StackMatches,
     StackProgram,
 };
@@ -52,6 +53,13 @@ impl<N: Network> FinalizeTypes<N> {
         Self::initialize_finalize_types(stack, finalize)
     }
 
+    /// Initializes a new instance of `FinalizeTypes` for the given routine.
+    /// Checks that the given routine is well-formed for the given stack.
+    #[inline]
+    pub fn from_routine(stack: &(impl StackMatches<N> + StackProgram<N>), routine: &Routine<N>) -> Result<Self> {
+        Self::initialize_routine_types(stack, routine)
+    }
+
     /// Returns `true` if the given register exists.
     pub fn contains(&self, register: &Register<N>) -> bool {
         // Retrieve the register locator.
@@ -75,10 +83,14 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(match operand {
             Operand::Literal(literal) => FinalizeType::Plaintext(PlaintextType::from(literal.to_type())),
             Operand::Register(register) => self.get_type(stack, register)?,
-            Operand::ProgramID(_) => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::ProgramID(_) | Operand::Caller => {
+                FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Address))
+            }
             Operand::Signer => bail!("'self.signer' is not a valid operand in a finalize context."),
-            Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+            Operand::BlockTimestamp => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
+            Operand::NetworkID => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U16)),
+            Operand::TransactionID => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
         })
     }
 
@@ -15,13 +15,15 @@
 use super::*;
 
 impl<N: Network> Stack<N> {
-    /// Initializes a new stack, given the process and program.
+    /// Initializes a new stack, given the process, program, and edition.
     #[inline]
-    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
+            edition,
             external_stacks: Default::default(),
+            program_addresses: process.program_addresses().clone(),
             register_types: Default::default(),
             finalize_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
@@ -40,11 +42,26 @@ impl<N: Network> Stack<N> {
             // Add the external stack to the stack.
             stack.insert_external_stack(external_stack.clone())?;
         }
+        // Ensure the program conforms to the interfaces that it implements from its imports.
+        // Note: The conformance to interfaces in the program itself is checked when the program is parsed.
+        for locator in program.implements() {
+            if locator.program_id() != program.id() {
+                // Retrieve the program that declares the interface.
+                let home = stack.get_external_program(locator.program_id())?;
+                // Ensure the program conforms to the interface.
+                home.get_interface(locator.resource())?.check_conformance(home, program)?;
+            }
+        }
         // Add the program closures to the stack.
         for closure in program.closures().values() {
             // Add the closure to the stack.
             stack.insert_closure(closure)?;
         }
+        // Add the program routines to the stack.
+        for routine in program.routines().values() {
+            // Add the routine to the stack.
+            stack.insert_routine(routine)?;
+        }
         // Add the program functions to the stack.
         for function in program.functions().values() {
             // Add the function to the stack.
@@ -56,6 +73,23 @@ impl<N: Network> Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns `true` if an external stack is not the stack of its program in the given process.
+    #[inline]
+    pub(crate) fn is_stale(&self, process: &Process<N>) -> bool {
+        self.external_stacks.iter().any(|(program_id, external_stack)| {
+            process.get_stack(program_id).map_or(true, |stack| !Arc::ptr_eq(stack, external_stack))
+        })
+    }
+
+    /// Rebuilds the stack against the stacks of its imports in the given process, retaining its circuit keys.
+    #[inline]
+    pub(crate) fn rebuild(&self, process: &Process<N>) -> Result<Self> {
+        // Initialize the stack.
+        let stack = Self::initialize(process, &self.program, self.edition)?;
+        // Return the stack, with the existing proving and verifying keys.
+        Ok(Self { proving_keys: self.proving_keys.clone(), verifying_keys: self.verifying_keys.clone(), ..stack })
+    }
+
     /// Inserts the given external stack to the stack.
     #[inline]
     fn insert_external_stack(&mut self, external_stack: Arc<Stack<N>>) -> Result<()> {
@@ -89,6 +123,22 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Inserts the given routine to the stack.
+    #[inline]
+    fn insert_routine(&mut self, routine: &Routine<N>) -> Result<()> {
+        // Retrieve the routine name.
+        let name = routine.name();
+        // Ensure the routine name is not already added.
+        ensure!(!self.finalize_types.contains_key(name), "Routine '{name}' already exists");
+
+        // Compute the finalize types.
+        let finalize_types = FinalizeTypes::from_routine(self, routine)?;
+        // Add the routine name and finalize types to the stack.
+        self.finalize_types.insert(*name, finalize_types);
+        // Return success.
+        Ok(())
+    }
+
     /// Adds the given function name and register types to the stack.
     #[inline]
     fn insert_function(&mut self, function: &Function<N>) -> Result<()> {
@@ -15,6 +15,9 @@
 mod authorization;
 pub use authorization::*;
 
+mod authorization_bundle;
+pub use authorization_bundle::*;
+
 mod call;
 pub use call::*;
 
@@ -62,19 +65,22 @@ use console::{
     types::{Field, Group},
 };
 use ledger_block::{Deployment, Transition};
-use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
+use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program, Routine};
 use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
 pub type Assignments<N> = Arc<RwLock<Vec<(circuit::Assignment<<N as Environment>::Field>, CallMetrics<N>)>>>;
 
+/// The mapping of program addresses to the latest stacks in the process, for dynamic calls.
+pub(crate) type ProgramAddresses<N> = Arc<RwLock<IndexMap<Address<N>, Weak<Stack<N>>>>>;
+
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
@@ -168,8 +174,12 @@ impl<N: Network> CallStack<N> {
 pub struct Stack<N: Network> {
     /// The program (record types, structs, functions).
     program: Program<N>,
+    /// The edition of the program.
+    edition: u16,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The mapping of program addresses to the latest stacks in the process.
+    program_addresses: ProgramAddresses<N>,
     /// The mapping of closure and function names to their register types.
     register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
     /// The mapping of finalize names to their register types.
@@ -190,6 +200,18 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Return the stack.
+        Self::new_edition(process, program, N::EDITION)
+    }
+
+    /// Initializes a new stack for the given edition of the program.
+    /// Unlike `Stack::new`, the program may already exist in the process, as an upgrade replaces its edition.
+    #[inline]
+    pub fn new_edition(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the edition is not below the initial edition.
+        ensure!(edition >= N::EDITION, "Program '{program_id}' has an invalid edition {edition}");
         // Ensure the program contains functions.
         ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
 
@@ -204,7 +226,7 @@ impl<N: Network> Stack<N> {
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
 
         // Return the stack.
-        Stack::initialize(process, program)
+        Stack::initialize(process, program, edition)
     }
 }
 
@@ -250,6 +272,17 @@ impl<N: Network> StackProgram<N> for Stack<N> {
         }
     }
 
+    /// Returns the stack of the program with the given address, for a dynamic call.
+    #[inline]
+    fn get_dynamic_stack(&self, program_address: &Address<N>) -> Result<Arc<Stack<N>>> {
+        // Retrieve the stack, if it is still in the process.
+        self.program_addresses
+            .read()
+            .get(program_address)
+            .and_then(Weak::upgrade)
+            .ok_or_else(|| anyhow!("Program with address '{program_address}' does not exist."))
+    }
+
     /// Returns the external record if the stack contains the external record.
     #[inline]
     fn get_external_record(&self, locator: &Locator<N>) -> Result<&RecordType<N>> {
@@ -286,6 +319,8 @@ impl<N: Network> StackProgram<N> for Stack<N> {
                             self.get_external_stack(locator.program_id())?.get_number_of_calls(locator.resource())?
                         }
                         CallOperator::Resource(resource) => self.get_number_of_calls(resource)?,
+                        // Note: The callee of a dynamic call cannot call other functions.
+                        CallOperator::Dynamic(..) => 1,
                     };
                 }
             }
@@ -311,6 +346,25 @@ impl<N: Network> StackProgramTypes<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns the edition of the program.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Returns the locators of the interfaces that the program implements.
+    #[inline]
+    pub fn interfaces(&self) -> &[Locator<N>] {
+        self.program.implements()
+    }
+
+    /// Returns `true` if the program implements the given interface.
+    /// Note: The program's conformance to each interface it implements is checked when the stack is initialized.
+    #[inline]
+    pub fn implements(&self, interface: &Locator<N>) -> bool {
+        self.program.implements().contains(interface)
+    }
+
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
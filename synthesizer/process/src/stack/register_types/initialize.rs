@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use synthesizer_program::CastType;
+use synthesizer_program::{Call, CastType};
 
 impl<N: Network> RegisterTypes<N> {
     /// Initializes a new instance of `RegisterTypes` for the given closure.
@@ -480,6 +480,37 @@ impl<N: Network> RegisterTypes<N> {
                             bail!("'{resource}' is not defined in '{}'.", external.id())
                         }
                     }
+                    CallOperator::Dynamic(register, locator) => {
+                        // Retrieve the program ID of the interface.
+                        let program_id = locator.program_id();
+                        // Retrieve the resource from the locator.
+                        let resource = locator.resource();
+
+                        // Ensure the dynamic call is in a function.
+                        if !stack.program().contains_function(closure_or_function_name) {
+                            bail!("Cannot invoke a dynamic 'call' in closure '{closure_or_function_name}'.")
+                        }
+                        // Ensure the register is an address.
+                        match self.get_type(stack, register)? {
+                            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) => (),
+                            register_type => {
+                                bail!("Dynamic call register '{register}' must be an address, found '{register_type}'.")
+                            }
+                        }
+                        // Ensure the locator does not reference the current program.
+                        if stack.program_id() == program_id {
+                            bail!("Locator '{locator}' does not reference an external program.");
+                        }
+                        // Ensure the current program contains an import for this external program.
+                        if !stack.program().imports().keys().contains(program_id) {
+                            bail!("External program '{}' is not imported by '{program_id}'.", locator.program_id());
+                        }
+                        // Retrieve the external stack.
+                        let external = stack.get_external_stack(program_id)?;
+                        // Ensure the interface is a function that a dynamic call may invoke.
+                        let interface = external.get_function_ref(resource)?;
+                        Call::check_dynamic_callee(interface, external.as_ref())?;
+                    }
                     CallOperator::Resource(resource) => {
                         // Ensure the resource does not reference this closure or function.
                         if resource == closure_or_function_name {
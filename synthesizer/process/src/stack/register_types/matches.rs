@@ -84,9 +84,9 @@ impl<N: Network> RegisterTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{operand_type}' in the operand '{operand}'.",
                     )
                 }
-                // If the operand is a block height type, throw an error.
-                Operand::BlockHeight => bail!(
-                    "Struct member '{struct_name}.{member_name}' cannot be from a block height in a non-finalize scope"
+                // If the operand is a block or transaction context type, throw an error.
+                Operand::BlockHeight | Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be from '{operand}' in a non-finalize scope"
                 ),
             }
         }
@@ -160,8 +160,10 @@ impl<N: Network> RegisterTypes<N> {
                         array_type.next_element_type()
                     )
                 }
-                // If the operand is a block height type, throw an error.
-                Operand::BlockHeight => bail!("Array element cannot be from a block height in a non-finalize scope"),
+                // If the operand is a block or transaction context type, throw an error.
+                Operand::BlockHeight | Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                    bail!("Array element cannot be from '{operand}' in a non-finalize scope")
+                }
             }
         }
         Ok(())
@@ -221,8 +223,8 @@ impl<N: Network> RegisterTypes<N> {
             Operand::Signer | Operand::Caller => {
                 // No-op.
             }
-            Operand::BlockHeight => {
-                bail!("Forbidden operation: Cannot cast a block height as a record owner")
+            Operand::BlockHeight | Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                bail!("Forbidden operation: Cannot cast '{}' as a record owner", operands[0])
             }
         }
 
@@ -273,10 +275,13 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{operand_type}' in the operand '{operand}'.",
                             )
                         }
-                        // Fail if the operand is a block height.
-                        Operand::BlockHeight => {
+                        // Fail if the operand is a block or transaction context.
+                        Operand::BlockHeight
+                        | Operand::BlockTimestamp
+                        | Operand::NetworkID
+                        | Operand::TransactionID => {
                             bail!(
-                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block height in the operand '{operand}'."
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found the block or transaction context in the operand '{operand}'."
                             )
                         }
                     }
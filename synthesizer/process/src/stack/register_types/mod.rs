@@ -96,7 +96,9 @@ impl<N: Network> RegisterTypes<N> {
             Operand::ProgramID(_) | Operand::Signer | Operand::Caller => {
                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address))
             }
-            Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
+            Operand::BlockHeight | Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                bail!("'{operand}' is not a valid operand in a non-finalize context.")
+            }
         })
     }
 
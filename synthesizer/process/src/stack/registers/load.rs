@@ -38,6 +38,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the block or transaction context, throw an error.
+            Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                bail!("Cannot load the block or transaction context in a non-finalize context")
+            }
         };
 
         // Retrieve the stack value.
@@ -121,6 +125,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             }
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the block or transaction context, throw an error.
+            Operand::BlockTimestamp | Operand::NetworkID | Operand::TransactionID => {
+                bail!("Cannot load the block or transaction context in a non-finalize context")
+            }
         };
 
         // Retrieve the circuit value.
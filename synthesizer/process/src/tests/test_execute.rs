@@ -22,8 +22,8 @@ use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, Testnet3},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    program::{Identifier, Literal, Locator, Plaintext, ProgramID, Record, Value},
+    types::{Field, U16, U64},
 };
 use ledger_block::Fee;
 use ledger_query::Query;
@@ -34,7 +34,7 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program};
+use synthesizer_program::{Event, FinalizeGlobalState, FinalizeStoreTrait, Program, StackProgram};
 use synthesizer_snark::UniversalSRS;
 
 use indexmap::IndexMap;
@@ -45,8 +45,8 @@ type CurrentNetwork = Testnet3;
 type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
-pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState<CurrentNetwork> {
+    FinalizeGlobalState::from(block_height as u64, block_height, block_height as i64, [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -55,6 +55,17 @@ pub fn sample_fee<N: Network, A: Aleo<Network = N>, B: BlockStorage<N>, P: Final
     block_store: &BlockStore<N, B>,
     finalize_store: &FinalizeStore<N, P>,
     rng: &mut TestRng,
+) -> Fee<N> {
+    sample_fee_with_base_amount::<N, A, B, P>(process, block_store, finalize_store, 100, rng)
+}
+
+/// Samples a valid fee with the given base fee in microcredits, for the given process, block store, and finalize store.
+pub fn sample_fee_with_base_amount<N: Network, A: Aleo<Network = N>, B: BlockStorage<N>, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    block_store: &BlockStore<N, B>,
+    finalize_store: &FinalizeStore<N, P>,
+    base_fee_in_microcredits: u64,
+    rng: &mut TestRng,
 ) -> Fee<N> {
     let program_id = ProgramID::from_str("credits.aleo").unwrap();
     let account_mapping = Identifier::from_str("account").unwrap();
@@ -69,12 +80,10 @@ pub fn sample_fee<N: Network, A: Aleo<Network = N>, B: BlockStorage<N>, P: Final
     // Construct the key.
     let key = Plaintext::from(Literal::Address(address));
     // Construct the public balance.
-    let value = Value::from(Literal::U64(U64::new(100)));
+    let value = Value::from(Literal::U64(U64::new(base_fee_in_microcredits)));
     // Update the public balance in finalize storage.
     finalize_store.update_key_value(program_id, account_mapping, key, value).unwrap();
 
-    // Sample a base fee in microcredits.
-    let base_fee_in_microcredits = 100;
     // Sample a priority fee in microcredits.
     let priority_fee_in_microcredits = 0;
     // Sample a dummy ID.
@@ -1253,7 +1262,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -1303,6 +1312,220 @@ finalize compute:
     assert_eq!(candidate, Value::from_str("8u64").unwrap());
 }
 
+#[test]
+fn test_process_upgrade_program() {
+    // Initialize the initial edition of the program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    async compute r0 r3 into r4;
+    output r4 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+
+    // Initialize the upgraded edition, which changes the function and adds a mapping.
+    let upgrade = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+mapping counter:
+    key as boolean.public;
+    value as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    mul r1 r2 into r3;
+    async compute r0 r3 into r4;
+    output r4 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+
+    // Initialize a program that imports the program.
+    let importer = Program::<CurrentNetwork>::from_str(
+        r"
+import testing.aleo;
+
+program importer.aleo;
+
+function relay:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    call testing.aleo/compute r0 r1 r2 into r3;
+    async relay r3 into r4;
+    output r4 as importer.aleo/relay.future;
+
+finalize relay:
+    input r0 as testing.aleo/compute.future;
+    await r0;
+",
+    )
+    .unwrap();
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mappings.
+    let account_mapping = Identifier::from_str("account").unwrap();
+    let counter_mapping = Identifier::from_str("counter").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the initial edition.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    assert_eq!(deployment.edition(), CurrentNetwork::EDITION);
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack).unwrap();
+
+    // Execute the initial edition.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    let inputs = [
+        Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(),
+        Value::<CurrentNetwork>::from_str("3u64").unwrap(),
+        Value::<CurrentNetwork>::from_str("5u64").unwrap(),
+    ];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+        .unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(block_store.clone())).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Deploy the importer.
+    let importer_deployment = process.deploy::<CurrentAleo, _>(&importer, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&importer_deployment, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &importer_deployment, &fee).unwrap();
+    process.add_stack(stack).unwrap();
+
+    // Ensure an upgrade that changes the inputs of a function called by the importer is rejected.
+    let incompatible_source =
+        upgrade.to_string().replacen("    input r2 as u64.public;\n    mul r1 r2", "    mul r1 r1", 1);
+    assert_ne!(incompatible_source, upgrade.to_string());
+    let incompatible = Program::<CurrentNetwork>::from_str(&incompatible_source).unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&incompatible, rng).is_err());
+
+    // Ensure an upgrade that changes the type of a mapping is rejected.
+    let incompatible = Program::<CurrentNetwork>::from_str(&upgrade.to_string().replacen(
+        "value as u64.public;",
+        "value as u32.public;",
+        1,
+    ))
+    .unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&incompatible, rng).is_err());
+    // Ensure an upgrade that removes a mapping is rejected.
+    let incompatible = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function compute:
+    input r0 as u64.public;
+    output r0 as u64.public;
+",
+    )
+    .unwrap();
+    assert!(process.deploy::<CurrentAleo, _>(&incompatible, rng).is_err());
+
+    // Deploy the upgraded edition.
+    let upgrade_deployment = process.deploy::<CurrentAleo, _>(&upgrade, rng).unwrap();
+    assert_eq!(upgrade_deployment.edition(), CurrentNetwork::EDITION + 1);
+    process.verify_deployment::<CurrentAleo, _>(&upgrade_deployment, rng).unwrap();
+    // Ensure the initial edition cannot be deployed again.
+    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(2), &finalize_store, &upgrade_deployment, &fee).unwrap();
+    process.add_stack(stack).unwrap();
+
+    // Ensure the upgrade is the latest edition, and the initial edition is retained.
+    assert_eq!(process.get_stack(program_id).unwrap().edition(), CurrentNetwork::EDITION + 1);
+    assert!(process.contains_program_edition(program_id, CurrentNetwork::EDITION));
+    assert!(process.get_stack_at_edition(program_id, CurrentNetwork::EDITION).unwrap().program() == &program);
+    // Ensure the upgrade cannot be deployed again.
+    assert!(process.verify_deployment::<CurrentAleo, _>(&upgrade_deployment, rng).is_err());
+
+    // Ensure the existing mapping is kept, and the new mapping is initialized.
+    let key = Plaintext::from(Literal::Address(caller));
+    let candidate = finalize_store.get_value_speculative(*program_id, account_mapping, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("8u64").unwrap());
+    assert!(finalize_store.contains_mapping_confirmed(program_id, &counter_mapping).unwrap());
+
+    // Ensure the execution of the initial edition only verifies against the initial edition.
+    assert!(process.verify_execution(&execution).is_err());
+    let editions = IndexMap::from([(*program_id, CurrentNetwork::EDITION)]);
+    process.at_editions(&editions).unwrap().verify_execution(&execution).unwrap();
+
+    // Ensure the importer calls into the upgrade.
+    let importer_stack = process.get_stack(importer.id()).unwrap();
+    assert!(!importer_stack.is_stale(&process));
+    assert_eq!(importer_stack.get_external_stack(program_id).unwrap().edition(), CurrentNetwork::EDITION + 1);
+    // Ensure the importer calls into the initial edition, at the initial edition.
+    let process_at_editions = process.at_editions(&editions).unwrap();
+    let importer_stack = process_at_editions.get_stack(importer.id()).unwrap();
+    assert_eq!(importer_stack.get_external_stack(program_id).unwrap().edition(), CurrentNetwork::EDITION);
+
+    // Execute the importer, which computes the product with the upgrade.
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, importer.id(), "relay", inputs.iter(), rng).unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(block_store.clone())).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("importer", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+    process.finalize_execution(sample_finalize_state(3), &finalize_store, &execution, None).unwrap();
+
+    // Check that the account balance is now 8 + 3 * 5.
+    let candidate = finalize_store.get_value_speculative(*program_id, account_mapping, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("23u64").unwrap());
+}
+
 #[test]
 fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
     // Initialize a new program.
@@ -1366,7 +1589,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -1493,7 +1716,7 @@ finalize mint_public:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
     //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
@@ -1622,7 +1845,7 @@ finalize mint_public:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
     //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
@@ -1662,7 +1885,7 @@ finalize init:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(2), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
     //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
@@ -1780,7 +2003,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -2209,7 +2432,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -2259,111 +2482,1052 @@ finalize compute:
 }
 
 #[test]
-fn test_process_execute_and_verify_call_to_closure() {
+fn test_process_execute_and_finalize_with_loop() {
     // Initialize a new program.
     let (string, program) = Program::<CurrentNetwork>::parse(
         r"
-program testing.aleo;
+program loops.aleo;
 
-// (a + (a + b)) + (a + b) == (3a + 2b)
-closure execute:
-    input r0 as field;
-    input r1 as field;
-    add r0 r1 into r2;
-    add r0 r2 into r3;
-    add r2 r3 into r4;
-    output r4 as field;
-    output r3 as field;
-    output r2 as field;
+mapping total:
+    key as address.public;
+    value as u32.public;
 
-closure check_not_equal:
-    input r0 as field;
-    input r1 as field;
-    assert.neq r0 r1;
+function accumulate:
+    input r0 as address.public;
+    input r1 as u32.public;
+    async accumulate r0 r1 into r2;
+    output r2 as loops.aleo/accumulate.future;
 
-function compute:
-    input r0 as field.private;
-    input r1 as field.public;
-    call check_not_equal r0 r1;
-    call execute r0 r1 into r2 r3 r4;
-    output r2 as field.private;
-    output r3 as field.private;
-    output r4 as field.private;",
+finalize accumulate:
+    input r0 as address.public;
+    input r1 as u32.public;
+    loop r1 bound 5u32 into r2 to end;
+    branch.eq r2 2u32 to skip;
+    get.or_use total[r0] 0u32 into r3;
+    add r3 r2 into r4;
+    set r4 into total[r0];
+    position skip;
+    position end;
+",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
+    // Declare the program ID.
+    let program_id = program.id();
     // Declare the function name.
-    let function_name = Identifier::from_str("compute").unwrap();
+    let function_name = Identifier::from_str("accumulate").unwrap();
 
     // Initialize the RNG.
     let rng = &mut TestRng::default();
 
     // Construct the process.
-    let process = crate::test_helpers::sample_process(&program);
-    // Check that the circuit key can be synthesized.
-    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    let mut process = Process::load().unwrap();
 
-    // Reset the process.
-    let process = crate::test_helpers::sample_process(&program);
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
 
-    // Declare the input value.
-    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+    // A helper to execute the function with the given count.
+    let mut execute = |count: &str| {
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str(count).unwrap();
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+        // Finalize the execution.
+        process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).map(|_| ())
+    };
 
-    // Authorize the function call.
-    let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+    // Finalize a loop of 4 iterations, which skips the iteration with index 2.
+    execute("4u32").unwrap();
+    // Check that the loop body was executed once per iteration.
+    let candidate = finalize_store
+        .get_value_speculative(
+            *program_id,
+            Identifier::from_str("total").unwrap(),
+            &Plaintext::from_str(&caller.to_string()).unwrap(),
+        )
+        .unwrap()
         .unwrap();
-    assert_eq!(authorization.len(), 1);
+    assert_eq!(candidate, Value::from_str("4u32").unwrap());
 
-    let r2 = Value::from_str("19field").unwrap();
-    let r3 = Value::from_str("11field").unwrap();
-    let r4 = Value::from_str("8field").unwrap();
+    // Ensure a loop with a count above its bound fails to finalize.
+    assert!(execute("6u32").is_err());
 
-    // Check again to make sure we didn't modify the authorization before calling `evaluate`.
-    assert_eq!(authorization.len(), 1);
+    // Ensure a program with a loop to an earlier position is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program backward.aleo;
 
-    // Compute the output value.
-    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(3, candidate.len());
-    assert_eq!(r2, candidate[0]);
-    assert_eq!(r3, candidate[1]);
-    assert_eq!(r4, candidate[2]);
+function accumulate:
+    input r0 as u32.public;
+    async accumulate r0 into r1;
+    output r1 as backward.aleo/accumulate.future;
 
-    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 1);
+finalize accumulate:
+    input r0 as u32.public;
+    position start;
+    loop r0 bound 5u32 into r1 to start;
+    add r1 r1 into r2;
+",
+    );
+    assert!(program.is_err());
+}
 
-    // Execute the request.
-    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(3, candidate.len());
-    assert_eq!(r2, candidate[0]);
-    assert_eq!(r3, candidate[1]);
-    assert_eq!(r4, candidate[2]);
+#[test]
+fn test_process_execute_and_finalize_with_routine() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program routines.aleo;
 
-    // Initialize a new block store.
-    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
-    // Prepare the trace.
-    trace.prepare(Query::from(block_store)).unwrap();
-    // Prove the execution.
-    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+mapping account:
+    key as address.public;
+    value as u64.public;
 
-    // Verify the execution.
-    process.verify_execution(&execution).unwrap();
-}
+mapping fees:
+    key as u8.public;
+    value as u64.public;
 
-#[test]
-fn test_process_deploy_credits_program() {
+routine charge_fee:
+    input r0 as u64.public;
+    div r0 10u64 into r1;
+    get.or_use fees[0u8] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into fees[0u8];
+    sub r0 r1 into r4;
+    output r4 as u64.public;
+
+function deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u32.public;
+    async deposit r0 r1 r2 into r3;
+    output r3 as routines.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u32.public;
+    loop r2 bound 4u32 into r3 to end;
+    invoke charge_fee r1 into r4;
+    get.or_use account[r0] 0u64 into r5;
+    add r5 r4 into r6;
+    set r6 into account[r0];
+    position end;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("deposit").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("3u32").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0, r1, r2].iter(), rng)
+        .unwrap();
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the routine was invoked once per iteration.
+    let candidate = finalize_store
+        .get_value_speculative(
+            *program_id,
+            Identifier::from_str("account").unwrap(),
+            &Plaintext::from_str(&caller.to_string()).unwrap(),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("270u64").unwrap());
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("fees").unwrap(), &Plaintext::from_str("0u8").unwrap())
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("30u64").unwrap());
+
+    // Ensure a program that invokes a routine with mismatched operands is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program mismatch.aleo;
+
+mapping fees:
+    key as u8.public;
+    value as u64.public;
+
+routine charge_fee:
+    input r0 as u64.public;
+    set r0 into fees[0u8];
+
+function deposit:
+    input r0 as u32.public;
+    async deposit r0 into r1;
+    output r1 as mismatch.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as u32.public;
+    invoke charge_fee r0;
+",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_with_context_operands() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program ping.aleo;
+
+mapping callers:
+    key as u8.public;
+    value as address.public;
+
+function ping:
+    async ping into r0;
+    output r0 as ping.aleo/ping.future;
+
+finalize ping:
+    set self.caller into callers[0u8];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program0, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import ping.aleo;
+
+program context.aleo;
+
+mapping timestamps:
+    key as u8.public;
+    value as i64.public;
+
+mapping networks:
+    key as u8.public;
+    value as u16.public;
+
+mapping transactions:
+    key as u8.public;
+    value as field.public;
+
+function record_context:
+    call ping.aleo/ping into r0;
+    async record_context r0 into r1;
+    output r1 as context.aleo/record_context.future;
+
+finalize record_context:
+    input r0 as ping.aleo/ping.future;
+    await r0;
+    set block.timestamp into timestamps[0u8];
+    set network.id into networks[0u8];
+    set transaction.id into transactions[0u8];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("record_context").unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program1, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(2), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Initialize caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program1.id(),
+            function_name,
+            Vec::<Value<_>>::new().iter(),
+            rng,
+        )
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("context", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure finalize halts if the transaction context is not provided.
+    assert!(process.finalize_execution(sample_finalize_state(3), &finalize_store, &execution, None).is_err());
+
+    // Now, finalize the execution with the transaction context.
+    let transaction_id = <CurrentNetwork as Network>::TransactionID::from(Field::rand(rng));
+    let state = sample_finalize_state(3).with_transaction(transaction_id);
+    process.finalize_execution(state, &finalize_store, &execution, None).unwrap();
+
+    // Retrieves the value stored at the given key in the given mapping.
+    let get_value = |program_id: &ProgramID<CurrentNetwork>, mapping_name: &str| {
+        finalize_store
+            .get_value_speculative(
+                *program_id,
+                Identifier::from_str(mapping_name).unwrap(),
+                &Plaintext::from_str("0u8").unwrap(),
+            )
+            .unwrap()
+            .unwrap()
+    };
+
+    // Check that the caller of the nested transition is the calling program.
+    let expected = Value::from(Literal::Address(program1.id().to_address().unwrap()));
+    assert_eq!(get_value(program0.id(), "callers"), expected);
+    // Check the block and transaction context.
+    assert_eq!(get_value(program1.id(), "timestamps"), Value::from_str("3i64").unwrap());
+    assert_eq!(get_value(program1.id(), "networks"), Value::from(Literal::U16(U16::new(CurrentNetwork::ID))));
+    assert_eq!(get_value(program1.id(), "transactions"), Value::from(Literal::Field(*transaction_id)));
+
+    // Ensure a program that stores a context operand with a mismatched type is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program mismatch.aleo;
+
+mapping timestamps:
+    key as u8.public;
+    value as u64.public;
+
+function record_timestamp:
+    async record_timestamp into r0;
+    output r0 as mismatch.aleo/record_timestamp.future;
+
+finalize record_timestamp:
+    set block.timestamp into timestamps[0u8];
+",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure a program that uses the signer in finalize is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program signer.aleo;
+
+mapping signers:
+    key as u8.public;
+    value as address.public;
+
+function record_signer:
+    async record_signer into r0;
+    output r0 as signer.aleo/record_signer.future;
+
+finalize record_signer:
+    set self.signer into signers[0u8];
+",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_with_gas() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program gas.aleo;
+
+mapping counter:
+    key as u8.public;
+    value as u64.public;
+
+function bump:
+    input r0 as boolean.public;
+    async bump r0 into r1;
+    output r1 as gas.aleo/bump.future;
+
+finalize bump:
+    input r0 as boolean.public;
+    branch.eq r0 false to end;
+    mul 2u64 3u64 into r1;
+    mul r1 3u64 into r2;
+    mul r2 3u64 into r3;
+    position end;
+    set 1u64 into counter[0u8];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("bump").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Check that the finalize cost is charged for every command.
+    let stack = process.get_stack(program_id).unwrap();
+    let finalize = stack.get_function_ref(&function_name).unwrap().finalize_logic().unwrap();
+    assert_eq!(crate::finalize_cost(stack, finalize).unwrap(), 556_000);
+    // Check that the finalize floor cost is charged for the commands on the cheapest path.
+    assert_eq!(crate::finalize_floor_cost(stack, finalize).unwrap(), 106_000);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Executes `bump` with the given input.
+    let mut execute = |input: &str| {
+        // Authorize the function call.
+        let inputs = [Value::<CurrentNetwork>::from_str(input).unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(&block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("gas", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+        execution
+    };
+    let expensive = execute("true");
+    let cheap = execute("false");
+
+    // Sample a fee that covers the storage cost, and the cost of the commands that run when the branch is taken.
+    let storage_cost =
+        crate::execution_storage_cost(&expensive).unwrap().max(crate::execution_storage_cost(&cheap).unwrap());
+    let fee = sample_fee_with_base_amount::<_, CurrentAleo, _, _>(
+        &process,
+        &block_store,
+        &finalize_store,
+        storage_cost + 200_000,
+        rng,
+    );
+
+    // Ensure the execution that does not take the branch runs out of gas.
+    assert!(process.finalize_execution(sample_finalize_state(1), &finalize_store, &expensive, Some(&fee)).is_err());
+    let key = Plaintext::from_str("0u8").unwrap();
+    assert!(
+        finalize_store
+            .get_value_speculative(*program_id, Identifier::from_str("counter").unwrap(), &key)
+            .unwrap()
+            .is_none()
+    );
+
+    // Ensure the execution that takes the branch is only charged for the commands that run.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &cheap, Some(&fee)).unwrap();
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, Identifier::from_str("counter").unwrap(), &key)
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("1u64").unwrap());
+}
+
+#[test]
+fn test_process_execute_and_finalize_with_emit() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program events.aleo;
+
+struct transfer:
+    receiver as address;
+    amount as u64;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function transfer_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async transfer_public r0 r1 into r2;
+    output r2 as events.aleo/transfer_public.future;
+
+finalize transfer_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+    cast r0 r1 into r4 as transfer;
+    emit r4 as transfer;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("transfer_public").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    let (_, events) = process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the event was emitted.
+    let expected = Event::new(
+        *program_id,
+        Identifier::from_str("transfer").unwrap(),
+        Plaintext::from_str(&format!("{{ receiver: {caller}, amount: 100u64 }}")).unwrap(),
+    );
+    assert_eq!(events, vec![expected]);
+
+    // Ensure a program that emits a value that does not match the event struct is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program mismatch.aleo;
+
+struct transfer:
+    receiver as address;
+    amount as u64;
+
+function transfer_public:
+    input r0 as u64.public;
+    async transfer_public r0 into r1;
+    output r1 as mismatch.aleo/transfer_public.future;
+
+finalize transfer_public:
+    input r0 as u64.public;
+    emit r0 as transfer;
+",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_verify_call_to_closure() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+// (a + (a + b)) + (a + b) == (3a + 2b)
+closure execute:
+    input r0 as field;
+    input r1 as field;
+    add r0 r1 into r2;
+    add r0 r2 into r3;
+    add r2 r3 into r4;
+    output r4 as field;
+    output r3 as field;
+    output r2 as field;
+
+closure check_not_equal:
+    input r0 as field;
+    input r1 as field;
+    assert.neq r0 r1;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    call check_not_equal r0 r1;
+    call execute r0 r1 into r2 r3 r4;
+    output r2 as field.private;
+    output r3 as field.private;
+    output r4 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    let r2 = Value::from_str("19field").unwrap();
+    let r3 = Value::from_str("11field").unwrap();
+    let r4 = Value::from_str("8field").unwrap();
+
+    // Check again to make sure we didn't modify the authorization before calling `evaluate`.
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(3, candidate.len());
+    assert_eq!(r2, candidate[0]);
+    assert_eq!(r3, candidate[1]);
+    assert_eq!(r4, candidate[2]);
+
+    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(3, candidate.len());
+    assert_eq!(r2, candidate[0]);
+    assert_eq!(r3, candidate[1]);
+    assert_eq!(r4, candidate[2]);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_execute_and_verify_dynamic_call() {
+    // Initialize the interface program.
+    let interface = Program::<CurrentNetwork>::from_str(
+        r"
+program interface.aleo;
+
+function compute:
+    input r0 as u64.public;
+    add r0 1u64 into r1;
+    output r1 as u64.public;",
+    )
+    .unwrap();
+    // Initialize a program that conforms to the interface.
+    let double = Program::<CurrentNetwork>::from_str(
+        r"
+program double.aleo;
+
+function compute:
+    input r0 as u64.public;
+    mul r0 2u64 into r1;
+    output r1 as u64.public;",
+    )
+    .unwrap();
+    // Initialize a program that does not conform to the interface.
+    let mismatch = Program::<CurrentNetwork>::from_str(
+        r"
+program mismatch.aleo;
+
+function compute:
+    input r0 as u64.private;
+    mul r0 3u64 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+    // Initialize the caller program.
+    let caller = Program::<CurrentNetwork>::from_str(
+        r"
+import interface.aleo;
+
+program caller.aleo;
+
+function run:
+    input r0 as address.public;
+    input r1 as u64.public;
+    call r0 as interface.aleo/compute r1 into r2;
+    output r2 as u64.public;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("run").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&interface);
+    process.add_program(&double).unwrap();
+    process.add_program(&mismatch).unwrap();
+    process.add_program(&caller).unwrap();
+    // Ensure the register of a dynamic call must be an address.
+    let invalid = Program::<CurrentNetwork>::from_str(
+        r"
+import interface.aleo;
+
+program invalid.aleo;
+
+function run:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    call r0 as interface.aleo/compute r1 into r2;
+    output r2 as u64.public;",
+    )
+    .unwrap();
+    assert!(process.add_program(&invalid).is_err());
+    // Check that the circuit key can be synthesized, without knowing the callee.
+    process.synthesize_key::<CurrentAleo, _>(caller.id(), &function_name, rng).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Ensure a program that does not conform to the interface cannot be called.
+    let r0 = Value::<CurrentNetwork>::from_str(&mismatch.id().to_address().unwrap().to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+    let result =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, caller.id(), function_name, [r0, r1].iter(), rng);
+    assert!(result.is_err());
+
+    // Ensure a program that does not exist cannot be called.
+    let r0 = Value::<CurrentNetwork>::from_str(
+        &ProgramID::<CurrentNetwork>::from_str("missing.aleo").unwrap().to_address().unwrap().to_string(),
+    )
+    .unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+    let result =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, caller.id(), function_name, [r0, r1].iter(), rng);
+    assert!(result.is_err());
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&double.id().to_address().unwrap().to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, caller.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+
+    let r2 = Value::from_str("10u64").unwrap();
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(r2, candidate[0]);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(r2, candidate[0]);
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("caller.aleo/run", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+}
+
+#[test]
+fn test_process_add_program_with_imported_interface() {
+    // Initialize a program that declares an interface.
+    let standard = Program::<CurrentNetwork>::from_str(
+        r"
+program standard.aleo;
+
+interface mintable:
+    mapping account: address into u64;
+    function mint: address.public u64.public;
+
+function noop:
+    input r0 as u64.public;",
+    )
+    .unwrap();
+    // Initialize a program that conforms to the interface.
+    let token = Program::<CurrentNetwork>::from_str(
+        r"
+import standard.aleo;
+
+program token.aleo implements standard.aleo/mintable;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;",
+    )
+    .unwrap();
+    // Initialize a program that does not conform to the interface.
+    let mismatch = Program::<CurrentNetwork>::from_str(
+        r"
+import standard.aleo;
+
+program mismatch.aleo implements standard.aleo/mintable;
+
+mapping account:
+    key as address.public;
+    value as u128.public;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&standard);
+    // Add the conforming program.
+    process.add_program(&token).unwrap();
+
+    // Ensure the stack records the implemented interface.
+    let stack = process.get_stack(token.id()).unwrap();
+    assert!(stack.implements(&Locator::from_str("standard.aleo/mintable").unwrap()));
+    assert!(!stack.implements(&Locator::from_str("standard.aleo/burnable").unwrap()));
+
+    // Ensure the non-conforming program is rejected.
+    assert!(process.add_program(&mismatch).is_err());
+}
+
+#[test]
+fn test_process_interface_conformance_and_upgrade() {
+    // Initialize a program that declares an interface over a struct.
+    let standard = Program::<CurrentNetwork>::from_str(
+        r"
+program standard.aleo;
+
+struct amount:
+    microcredits as u64;
+
+interface payable:
+    function pay: amount.public;
+
+function noop:
+    input r0 as u64.public;",
+    )
+    .unwrap();
+    // Initialize a program whose struct has the same members.
+    let token = Program::<CurrentNetwork>::from_str(
+        r"
+import standard.aleo;
+
+program token.aleo implements standard.aleo/payable;
+
+struct amount:
+    microcredits as u64;
+
+function pay:
+    input r0 as amount.public;",
+    )
+    .unwrap();
+    // Initialize a program whose struct has the same name, but different members.
+    let mismatch = Program::<CurrentNetwork>::from_str(
+        r"
+import standard.aleo;
+
+program mismatch.aleo implements standard.aleo/payable;
+
+struct amount:
+    microcredits as u128;
+
+function pay:
+    input r0 as amount.public;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&standard);
+    // Ensure the struct members are compared.
+    process.add_program(&token).unwrap();
+    assert!(process.add_program(&mismatch).is_err());
+
+    // Ensure an upgrade that retains the interface is accepted.
+    process.check_upgrade(&token, CurrentNetwork::EDITION + 1).unwrap();
+    // Ensure an upgrade that drops the interface is rejected.
+    let upgrade =
+        Program::<CurrentNetwork>::from_str(&token.to_string().replace(" implements standard.aleo/payable", ""))
+            .unwrap();
+    assert!(upgrade.implements().is_empty());
+    assert!(process.check_upgrade(&upgrade, CurrentNetwork::EDITION + 1).is_err());
+}
+
+#[test]
+fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        previous_editions: IndexMap::new(),
+        program_addresses: Default::default(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2436,7 +3600,7 @@ function {function_name}:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
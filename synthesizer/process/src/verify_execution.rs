@@ -15,7 +15,8 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Verifies the given execution is valid.
+    /// Verifies the given execution is valid, against the latest editions in the process.
+    /// To verify an execution of a previous edition, use `Process::at_editions` to select the editions.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
@@ -179,17 +180,46 @@ impl<N: Network> Process<N> {
         // [Inputs] Extend the verifier inputs with the public inputs for 'self.caller'.
         inputs.extend([*is_root, *parent_x, *parent_y]);
 
+        // Retrieve the function calls of the transition, in the order that they are invoked.
+        let stack = self.get_stack(transition.program_id())?;
+        let mut function_calls = Vec::new();
+        for instruction in stack.get_function_ref(transition.function_name())?.instructions() {
+            if let Instruction::Call(call) = instruction {
+                if call.is_function_call(stack.as_ref())? {
+                    function_calls.push(call.operator());
+                }
+            }
+        }
+
         // If there are function calls, append their inputs and outputs.
-        for transition_id in call_graph.get(transition.id()).unwrap() {
+        for (transition_id, operator) in call_graph.get(transition.id()).unwrap().iter().zip_eq(function_calls) {
             // Note: This unwrap is safe, as we are processing transitions in post-order,
             // which implies that all child transition IDs have been added to `transition_map`.
             let transition: &&Transition<N> = transition_map.get(transition_id).unwrap();
+            // If the call is dynamic, extend the verifier inputs with the program address and function ID of the callee.
+            if let synthesizer_program::CallOperator::Dynamic(..) = operator {
+                // Compute the x- and y-coordinate of the program address.
+                let (address_x, address_y) = transition.program_id().to_address()?.to_xy_coordinates();
+                // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+                let function_id = N::hash_bhp1024(
+                    &(
+                        U16::<N>::new(N::ID),
+                        transition.program_id().name(),
+                        transition.program_id().network(),
+                        transition.function_name(),
+                    )
+                        .to_bits_le(),
+                )?;
+                // [Inputs] Extend the verifier inputs with the program address and function ID.
+                inputs.extend([*address_x, *address_y, *function_id]);
+            }
             // [Inputs] Extend the verifier inputs with the transition commitment of the external call.
             inputs.extend([**transition.tcm()]);
             // [Inputs] Extend the verifier inputs with the input IDs of the external call.
             inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
             // [Inputs] Extend the verifier inputs with the output IDs of the external call.
-            inputs.extend(transition.output_ids().map(|id| **id));
+            // Note: Memos are attached after proving, so they are not outputs of the external call.
+            inputs.extend(transition.outputs().iter().filter(|output| !output.is_memo()).map(|output| **output.id()));
         }
 
         // [Inputs] Extend the verifier inputs with the output IDs.
@@ -235,13 +265,15 @@ impl<N: Network> Process<N> {
             fname: Identifier<N>,
             tid: Option<N::TransitionID>,
             children: Option<Vec<usize>>,
+            // Note: For a dynamic call, `pid` is the interface program ID, until the transition is processed.
+            is_dynamic: bool,
         }
 
         impl<N: Network> TransitionMetadata<N> {
             fn new(counter: &mut usize, pid: ProgramID<N>, fname: Identifier<N>, tid: Option<N::TransitionID>) -> Self {
                 let uid = *counter;
                 *counter += 1;
-                Self { uid, pid, fname, tid, children: None }
+                Self { uid, pid, fname, tid, children: None, is_dynamic: false }
             }
 
             /// Returns 'true' if the subgraph starting from this transition has been fully-indexed.
@@ -300,6 +332,23 @@ impl<N: Network> Process<N> {
                         Some(*transition.id()),
                     ));
                 }
+                // If the entry is a dynamic call, then ensure the transition conforms to the interface,
+                // and add the current transition ID and program ID to the entry.
+                Some(head) if head.is_dynamic => {
+                    ensure!(
+                        head.fname == *transition.function_name(),
+                        "Invalid traversal - unexpected transition in the execution"
+                    );
+                    // Retrieve the interface.
+                    let interface = self.get_stack(head.pid)?.get_function(&head.fname)?;
+                    // Ensure the program conforms to the interface.
+                    synthesizer_program::Call::check_dynamic_callee(
+                        &interface,
+                        self.get_stack(transition.program_id())?.as_ref(),
+                    )?;
+                    head.pid = *transition.program_id();
+                    head.tid = Some(*transition.id());
+                }
                 // If the stack is not empty, then add the current transition ID to the entry.
                 Some(head) => match head.pid == *transition.program_id() && head.fname == *transition.function_name() {
                     true => head.tid = Some(*transition.id()),
@@ -323,15 +372,21 @@ impl<N: Network> Process<N> {
                 let mut children = Vec::new();
                 for instruction in function.instructions() {
                     if let Instruction::Call(call) = instruction {
-                        let (pid, fname) = match call.operator() {
+                        let (pid, fname, is_dynamic) = match call.operator() {
                             synthesizer_program::CallOperator::Locator(locator) => {
-                                (locator.program_id(), locator.resource())
+                                (locator.program_id(), locator.resource(), false)
+                            }
+                            synthesizer_program::CallOperator::Resource(fname) => (&top.pid, fname, false),
+                            // Note: The program ID of a dynamic call is resolved from its transition.
+                            synthesizer_program::CallOperator::Dynamic(_, locator) => {
+                                (locator.program_id(), locator.resource(), true)
                             }
-                            synthesizer_program::CallOperator::Resource(fname) => (&top.pid, fname),
                         };
                         // Add the child to the traversal stack, only if it is a call to a transition.
                         if self.get_stack(pid)?.get_function(fname).is_ok() {
-                            children.push(TransitionMetadata::new(&mut counter, *pid, *fname, None));
+                            let mut child = TransitionMetadata::new(&mut counter, *pid, *fname, None);
+                            child.is_dynamic = is_dynamic;
+                            children.push(child);
                         }
                     }
                 }
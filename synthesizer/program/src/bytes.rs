@@ -21,7 +21,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid program version"));
         }
 
@@ -55,11 +55,25 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
                 3 => program.add_closure(ClosureCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the function.
                 4 => program.add_function(FunctionCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the interface.
+                5 => program.add_interface(Interface::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the routine.
+                6 => program.add_routine(RoutineCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Invalid variant.
                 _ => return Err(error(format!("Failed to parse program. Invalid component variant '{variant}'"))),
             }
         }
 
+        // If the version is 2, read the implemented interfaces.
+        if version == 2 {
+            // Read the number of implemented interfaces.
+            let implements_len = u8::read_le(&mut reader)?;
+            // Read the implemented interfaces.
+            for _ in 0..implements_len {
+                program.add_implements(Locator::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?;
+            }
+        }
+
         Ok(program)
     }
 }
@@ -69,7 +83,12 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
 {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: Version 2 is only used if the program implements interfaces, to preserve the encoding of other programs.
+        let version = match self.implements.is_empty() {
+            true => 1u8,
+            false => 2u8,
+        };
+        version.write_le(&mut writer)?;
 
         // Write the program ID.
         self.id.write_le(&mut writer)?;
@@ -131,6 +150,34 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                     }
                     None => return Err(error(format!("Function '{identifier}' is not defined."))),
                 },
+                ProgramDefinition::Interface => match self.interfaces.get(identifier) {
+                    Some(interface) => {
+                        // Write the variant.
+                        5u8.write_le(&mut writer)?;
+                        // Write the interface.
+                        interface.write_le(&mut writer)?;
+                    }
+                    None => return Err(error(format!("Interface '{identifier}' is not defined."))),
+                },
+                ProgramDefinition::Routine => match self.routines.get(identifier) {
+                    Some(routine) => {
+                        // Write the variant.
+                        6u8.write_le(&mut writer)?;
+                        // Write the routine.
+                        routine.write_le(&mut writer)?;
+                    }
+                    None => return Err(error(format!("Routine '{identifier}' is not defined."))),
+                },
+            }
+        }
+
+        // If the program implements interfaces, write the implemented interfaces.
+        if version == 2 {
+            // Write the number of implemented interfaces.
+            u8::try_from(self.implements.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+            // Write the implemented interfaces.
+            for locator in &self.implements {
+                locator.write_le(&mut writer)?;
             }
         }
 
@@ -172,4 +219,31 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_interface() -> Result<()> {
+        let program = r"
+import standard.aleo;
+
+program token.aleo implements standard.aleo/fungible token.aleo/mintable;
+
+interface mintable:
+    function mint: address.public u64.public;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;";
+
+        // Initialize a new program.
+        let expected = Program::<CurrentNetwork>::from_str(program)?;
+        let expected_bytes = expected.to_bytes_le()?;
+        // Ensure the version indicates the implemented interfaces.
+        assert_eq!(2, expected_bytes[0]);
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        Ok(())
+    }
 }
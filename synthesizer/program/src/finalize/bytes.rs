@@ -48,6 +48,7 @@ impl<N: Network, Command: CommandTrait<N>> FromBytes for FinalizeCore<N, Command
         let mut finalize = Self::new(name);
         inputs.into_iter().try_for_each(|input| finalize.add_input(input)).map_err(error)?;
         commands.into_iter().try_for_each(|command| finalize.add_command(command)).map_err(error)?;
+        finalize.ensure_loops_closed().map_err(error)?;
 
         Ok(finalize)
     }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod input;
+pub(crate) mod input;
 use input::*;
 
 mod bytes;
@@ -40,12 +40,21 @@ pub struct FinalizeCore<N: Network, Command: CommandTrait<N>> {
     num_writes: u16,
     /// A mapping from `Position`s to their index in `commands`.
     positions: HashMap<Identifier<N>, usize>,
+    /// The loops that have not yet been closed by their `Position`, as (position, index, bound).
+    open_loops: Vec<(Identifier<N>, usize, u32)>,
 }
 
 impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     /// Initializes a new finalize with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), commands: Vec::new(), num_writes: 0, positions: HashMap::new() }
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            commands: Vec::new(),
+            num_writes: 0,
+            positions: HashMap::new(),
+            open_loops: Vec::new(),
+        }
     }
 
     /// Returns the name of the associated function.
@@ -77,6 +86,29 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     pub const fn positions(&self) -> &HashMap<Identifier<N>, usize> {
         &self.positions
     }
+
+    /// Returns the maximum number of times each command may be executed, given the bounds of its enclosing loops.
+    pub fn max_executions(&self) -> Vec<u64> {
+        // Initialize a stack of the active loops, as (index of the ending position, bound).
+        let mut loops: Vec<(usize, u64)> = Vec::new();
+        self.commands
+            .iter()
+            .enumerate()
+            .map(|(index, command)| {
+                // Exit the loop that ends at this position, if any.
+                if matches!(loops.last(), Some((end, _)) if *end == index) {
+                    loops.pop();
+                }
+                // Enter the loop, if the command is a loop. Note that the `loop` command is executed once per iteration.
+                if let (Some(bound), Some(end)) =
+                    (command.loop_bound(), command.branch_to().and_then(|position| self.positions.get(position)))
+                {
+                    loops.push((*end, u64::from(bound)));
+                }
+                loops.iter().fold(1u64, |executions, (_, bound)| executions.saturating_mul(*bound))
+            })
+            .collect()
+    }
 }
 
 impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
@@ -87,7 +119,7 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     /// This method will halt if the maximum number of inputs has been reached.
     /// This method will halt if the input statement was previously added.
     #[inline]
-    fn add_input(&mut self, input: Input<N>) -> Result<()> {
+    pub(crate) fn add_input(&mut self, input: Input<N>) -> Result<()> {
         // Ensure there are no commands in memory.
         ensure!(self.commands.is_empty(), "Cannot add inputs after commands have been added");
 
@@ -108,6 +140,8 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     ///
     /// # Errors
     /// This method will halt if the maximum number of commands has been reached.
+    /// This method will halt if the maximum number of writes has been reached, counting loop iterations.
+    /// This method will halt if loops are not properly nested, or if a branch enters or leaves a loop body.
     #[inline]
     pub fn add_command(&mut self, command: Command) -> Result<()> {
         // Ensure the maximum number of commands has not been exceeded.
@@ -126,9 +160,10 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
             ensure!(matches!(register, Register::Locator(..)), "Destination register must be a locator");
         }
 
-        // Check if the command is a branch command.
+        // Check if the command is a branch or loop command.
         if let Some(position) = command.branch_to() {
             // Ensure the branch target does not reference an earlier position.
+            // Note: A loop must end at a later position, as its body is the commands before its position.
             ensure!(!self.positions.contains_key(position), "Cannot branch to an earlier position '{position}'");
         }
 
@@ -138,20 +173,96 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
             ensure!(!self.positions.contains_key(position), "Cannot redefine position '{position}'");
             // Ensure that there are less than `u8::MAX` positions.
             ensure!(self.positions.len() < u8::MAX as usize, "Cannot add more than {} positions", u8::MAX);
+            // Ensure the position does not end a loop other than the innermost one.
+            ensure!(
+                self.open_loops.iter().rev().skip(1).all(|(end, ..)| end != position),
+                "Loops must be properly nested, found an early end at position '{position}'"
+            );
             // Insert the position.
             self.positions.insert(*position, self.commands.len());
+            // If the position ends the innermost loop, close the loop.
+            if matches!(self.open_loops.last(), Some((end, ..)) if end == position) {
+                self.close_loop()?;
+            }
         }
 
         // Check if the command is a write command.
         if command.is_write() {
+            // Each write in a loop body may be executed once per iteration of its enclosing loops.
+            let num_writes = self
+                .open_loops
+                .iter()
+                .try_fold(1u16, |acc, (_, _, bound)| acc.checked_mul(u16::try_from(*bound).unwrap_or(u16::MAX)));
             // Increment the number of write commands.
-            self.num_writes += 1;
+            self.num_writes = match num_writes.and_then(|num_writes| self.num_writes.checked_add(num_writes)) {
+                Some(num_writes) if num_writes <= N::MAX_WRITES => num_writes,
+                _ => bail!("Cannot add more than {} 'set' commands, including loop iterations", N::MAX_WRITES),
+            };
+        }
+
+        // Check if the command is a loop command.
+        if let (Some(bound), Some(position)) = (command.loop_bound(), command.branch_to()) {
+            // Open the loop, which is closed when its position is added.
+            self.open_loops.push((*position, self.commands.len(), bound));
         }
 
         // Insert the command.
         self.commands.push(command);
         Ok(())
     }
+
+    /// Ensures every loop has been closed by its `Position`.
+    /// This must be checked once all commands have been added, as an open loop skips the checks on its body.
+    #[inline]
+    pub fn ensure_loops_closed(&self) -> Result<()> {
+        match self.open_loops.last() {
+            Some((end, ..)) => bail!("The loop ending at position '{end}' is never closed in '{}'", self.name),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
+    /// Closes the innermost loop, whose body consists of the commands after the `loop` command.
+    ///
+    /// # Errors
+    /// This method will halt if the loop body contains an `await` command.
+    /// This method will halt if a branch in the loop body leaves the loop body.
+    /// This method will halt if a branch before the loop body enters the loop body.
+    fn close_loop(&mut self) -> Result<()> {
+        // Retrieve the innermost loop.
+        let (end, start, _) = match self.open_loops.pop() {
+            Some(open_loop) => open_loop,
+            None => bail!("There is no loop to close"),
+        };
+        // A helper to determine if a position is strictly inside the loop body.
+        let is_in_body = |position: &Identifier<N>| match self.positions.get(position) {
+            Some(index) => *index > start && *position != end,
+            None => false,
+        };
+        // Check the commands in the loop body.
+        for command in &self.commands[start + 1..] {
+            // Ensure the command is not an await command.
+            ensure!(
+                !command.is_await(),
+                "Forbidden operation: Finalize cannot 'await' inside the loop ending at '{end}'"
+            );
+            // Ensure the branch target is in the loop body.
+            if let Some(position) = command.branch_to() {
+                ensure!(
+                    is_in_body(position) || *position == end,
+                    "Cannot branch to '{position}' outside of the loop ending at '{end}'"
+                );
+            }
+        }
+        // Ensure no command before the loop body branches into the loop body.
+        for command in &self.commands[..start] {
+            if let Some(position) = command.branch_to() {
+                ensure!(!is_in_body(position), "Cannot branch to '{position}' inside the loop ending at '{end}'");
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, Command: CommandTrait<N>> TypeName for FinalizeCore<N, Command> {
@@ -266,4 +377,79 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_add_command_loops() {
+        // A helper to construct a finalize from the given commands.
+        let finalize = |commands: &[&str]| -> Result<Finalize<CurrentNetwork>> {
+            let mut finalize = Finalize::<CurrentNetwork>::new(Identifier::from_str("finalize_core_test")?);
+            commands.iter().try_for_each(|command| finalize.add_command(Command::from_str(command)?))?;
+            finalize.ensure_loops_closed()?;
+            Ok(finalize)
+        };
+
+        // Ensure that a loop with a branch to its end can be added.
+        assert!(finalize(&[
+            "loop r0 bound 4u32 into r1 to end;",
+            "branch.eq r1 0u32 to end;",
+            "set r1 into object[r1];",
+            "position end;"
+        ])
+        .is_ok());
+        // Ensure that nested loops can be added.
+        assert!(finalize(&[
+            "loop r0 bound 4u32 into r1 to outer;",
+            "loop r0 bound 4u32 into r2 to inner;",
+            "position inner;",
+            "position outer;"
+        ])
+        .is_ok());
+        // Ensure that a loop to an earlier position will fail.
+        assert!(finalize(&["position start;", "loop r0 bound 4u32 into r1 to start;", "add r1 r1 into r2;"]).is_err());
+        // Ensure that a loop whose position is never defined will fail.
+        assert!(finalize(&["loop r0 bound 4u32 into r1 to end;", "await r2;"]).is_err());
+        let mut open_loop = Finalize::<CurrentNetwork>::new(Identifier::from_str("finalize_core_test").unwrap());
+        open_loop.add_command(Command::from_str("loop r0 bound 4u32 into r1 to end;").unwrap()).unwrap();
+        assert!(open_loop.ensure_loops_closed().is_err());
+        // Ensure that improperly nested loops will fail.
+        assert!(finalize(&[
+            "loop r0 bound 4u32 into r1 to outer;",
+            "loop r0 bound 4u32 into r2 to inner;",
+            "position outer;",
+            "position inner;"
+        ])
+        .is_err());
+        // Ensure that branching out of a loop body will fail.
+        assert!(finalize(&[
+            "loop r0 bound 4u32 into r1 to end;",
+            "branch.eq r1 0u32 to exit;",
+            "position end;",
+            "position exit;"
+        ])
+        .is_err());
+        // Ensure that branching into a loop body will fail.
+        assert!(finalize(&[
+            "branch.eq r0 0u32 to middle;",
+            "loop r0 bound 4u32 into r1 to end;",
+            "position middle;",
+            "position end;"
+        ])
+        .is_err());
+        // Ensure that awaiting in a loop body will fail.
+        assert!(finalize(&["loop r0 bound 4u32 into r1 to end;", "await r2;", "position end;"]).is_err());
+        // Ensure that the writes in a loop body are counted once per iteration.
+        let max_writes = CurrentNetwork::MAX_WRITES;
+        assert!(finalize(&[
+            &format!("loop r0 bound {}u32 into r1 to end;", max_writes - 1),
+            "remove object[r1];",
+            "position end;"
+        ])
+        .is_ok());
+        assert!(finalize(&[
+            &format!("loop r0 bound {}u32 into r1 to end;", max_writes + 1),
+            "remove object[r1];",
+            "position end;"
+        ])
+        .is_err());
+    }
 }
@@ -47,6 +47,10 @@ impl<N: Network, Command: CommandTrait<N>> Parser for FinalizeCore<N, Command> {
                 eprintln!("{error}");
                 return Err(error);
             }
+            if let Err(error) = finalize.ensure_loops_closed() {
+                eprintln!("{error}");
+                return Err(error);
+            }
             Ok::<_, Error>(finalize)
         })(string)
     }
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Interface<N> {
+    /// Reads the interface from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the interface name.
+        let name = Identifier::<N>::read_le(&mut reader)?;
+        // Read the mapping signatures.
+        let num_mappings = u16::read_le(&mut reader)?;
+        let mappings =
+            (0..num_mappings).map(|_| MappingSignature::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the function signatures.
+        let num_functions = u16::read_le(&mut reader)?;
+        let functions =
+            (0..num_functions).map(|_| FunctionSignature::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the interface.
+        Self::new(name, mappings, functions).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for Interface<N> {
+    /// Writes the interface to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the interface name.
+        self.name.write_le(&mut writer)?;
+        // Write the mapping signatures.
+        u16::try_from(self.mappings.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for mapping in self.mappings.values() {
+            mapping.write_le(&mut writer)?;
+        }
+        // Write the function signatures.
+        u16::try_from(self.functions.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for function in self.functions.values() {
+            function.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_interface_bytes() -> Result<()> {
+        let expected = Interface::<CurrentNetwork>::from_str(
+            r"
+interface fungible:
+    mapping account: address into u64;
+    function transfer_public: address.public u64.public into token.aleo/transfer_public.future;",
+        )?;
+        let candidate = Interface::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le()?)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod signature;
+pub use signature::*;
+
+mod bytes;
+mod parse;
+
+use crate::{CommandTrait, InstructionTrait, ProgramCore};
+use console::{
+    network::prelude::*,
+    program::{EntryType, FinalizeType, Identifier, PlaintextType, RecordType, ValueType},
+};
+
+use indexmap::IndexMap;
+
+/// An interface declares the mapping shapes and function signatures that a conforming program must contain,
+/// and is of the form:
+/// ```text
+/// interface {name}:
+///     mapping {name}: {key_type} into {value_type};
+///     function {name}: {input_types} into {output_types};
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Interface<N: Network> {
+    /// The name of the interface.
+    name: Identifier<N>,
+    /// The mapping shapes in the interface.
+    mappings: IndexMap<Identifier<N>, MappingSignature<N>>,
+    /// The function signatures in the interface.
+    functions: IndexMap<Identifier<N>, FunctionSignature<N>>,
+}
+
+impl<N: Network> Interface<N> {
+    /// Initializes a new interface with the given name, mapping shapes, and function signatures.
+    pub fn new(
+        name: Identifier<N>,
+        mappings: Vec<MappingSignature<N>>,
+        functions: Vec<FunctionSignature<N>>,
+    ) -> Result<Self> {
+        // Ensure the interface is not empty.
+        ensure!(!mappings.is_empty() || !functions.is_empty(), "Interface '{name}' is empty");
+        // Ensure the number of mappings is within the allowed range.
+        ensure!(mappings.len() <= N::MAX_MAPPINGS, "Interface '{name}' exceeds the maximum number of mappings");
+        // Ensure the number of functions is within the allowed range.
+        ensure!(functions.len() <= N::MAX_FUNCTIONS, "Interface '{name}' exceeds the maximum number of functions");
+
+        // Initialize the interface.
+        let mut interface = Self { name, mappings: IndexMap::new(), functions: IndexMap::new() };
+        // Add the mapping shapes.
+        for mapping in mappings {
+            let mapping_name = *mapping.name();
+            if interface.mappings.insert(mapping_name, mapping).is_some() {
+                bail!("Mapping '{mapping_name}' is declared more than once in interface '{name}'")
+            }
+        }
+        // Add the function signatures.
+        for function in functions {
+            let function_name = *function.name();
+            if interface.functions.insert(function_name, function).is_some() {
+                bail!("Function '{function_name}' is declared more than once in interface '{name}'")
+            }
+        }
+        Ok(interface)
+    }
+
+    /// Returns the name of the interface.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the mapping shapes in the interface.
+    pub const fn mappings(&self) -> &IndexMap<Identifier<N>, MappingSignature<N>> {
+        &self.mappings
+    }
+
+    /// Returns the function signatures in the interface.
+    pub const fn functions(&self) -> &IndexMap<Identifier<N>, FunctionSignature<N>> {
+        &self.functions
+    }
+}
+
+impl<N: Network> Interface<N> {
+    /// Ensures the given program conforms to the interface, where `home` is the program that declares the interface.
+    /// A conforming program contains every mapping with the same key and value types,
+    /// and every function with the same input and output types.
+    /// Note: Structs and records conform if they have the same name and conforming members in each program,
+    /// and a future conforms if it is the future of the function with the same name and conforming finalize inputs.
+    pub fn check_conformance<Instruction: InstructionTrait<N>, Command: CommandTrait<N>>(
+        &self,
+        home: &ProgramCore<N, Instruction, Command>,
+        program: &ProgramCore<N, Instruction, Command>,
+    ) -> Result<()> {
+        // Initialize the checker for the types in the interface and the program.
+        let types = Conformance { home, program };
+        // Ensure the program contains each mapping.
+        for (name, signature) in &self.mappings {
+            let Ok(mapping) = program.get_mapping(name) else {
+                bail!("'{}' does not implement '{}': mapping '{name}' is missing", program.id(), self.name)
+            };
+            ensure!(
+                types.plaintext(signature.key_type(), mapping.key().plaintext_type())
+                    && types.plaintext(signature.value_type(), mapping.value().plaintext_type()),
+                "'{}' does not implement '{}': mapping '{name}' has a different shape",
+                program.id(),
+                self.name
+            );
+        }
+        // Ensure the program contains each function.
+        for (name, signature) in &self.functions {
+            let Ok(function) = program.get_function_ref(name) else {
+                bail!("'{}' does not implement '{}': function '{name}' is missing", program.id(), self.name)
+            };
+            ensure!(
+                types.values(signature.input_types(), &function.input_types()),
+                "'{}' does not implement '{}': the inputs of function '{name}' do not match",
+                program.id(),
+                self.name
+            );
+            ensure!(
+                types.values(signature.output_types(), &function.output_types()),
+                "'{}' does not implement '{}': the outputs of function '{name}' do not match",
+                program.id(),
+                self.name
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Compares the types named in an interface, as declared in its `home` program,
+/// with the types named in a `program` that implements the interface.
+struct Conformance<'a, N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
+    /// The program that declares the interface.
+    home: &'a ProgramCore<N, Instruction, Command>,
+    /// The program that implements the interface.
+    program: &'a ProgramCore<N, Instruction, Command>,
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Conformance<'_, N, Instruction, Command> {
+    /// Returns `true` if the given value types of the program conform to the expected value types.
+    fn values(&self, expected: &[ValueType<N>], candidate: &[ValueType<N>]) -> bool {
+        expected.len() == candidate.len()
+            && expected.iter().zip(candidate).all(|(expected, candidate)| self.value(expected, candidate))
+    }
+
+    /// Returns `true` if the given value type of the program conforms to the expected value type.
+    fn value(&self, expected: &ValueType<N>, candidate: &ValueType<N>) -> bool {
+        match (expected, candidate) {
+            (ValueType::Constant(expected), ValueType::Constant(candidate))
+            | (ValueType::Public(expected), ValueType::Public(candidate))
+            | (ValueType::Private(expected), ValueType::Private(candidate)) => self.plaintext(expected, candidate),
+            (ValueType::Record(expected), ValueType::Record(candidate)) => {
+                match (self.home.get_record(expected), self.program.get_record(candidate)) {
+                    (Ok(expected), Ok(candidate)) => self.record(expected, candidate),
+                    _ => false,
+                }
+            }
+            (ValueType::ExternalRecord(expected), ValueType::ExternalRecord(candidate)) => expected == candidate,
+            (ValueType::Future(expected), ValueType::Future(candidate)) => {
+                // Ensure the futures are of the functions with the same name, in the respective programs.
+                if expected.program_id() != self.home.id()
+                    || candidate.program_id() != self.program.id()
+                    || expected.resource() != candidate.resource()
+                {
+                    return false;
+                }
+                // Retrieve the finalize input types of the function in the given program.
+                let finalize_types = |program: &ProgramCore<N, Instruction, Command>| {
+                    let function = program.get_function_ref(expected.resource()).ok()?;
+                    function.finalize_logic().map(|finalize| finalize.input_types())
+                };
+                // Ensure the members of the futures conform.
+                match (finalize_types(self.home), finalize_types(self.program)) {
+                    (Some(expected), Some(candidate)) => {
+                        expected.len() == candidate.len()
+                            && expected.iter().zip(&candidate).all(|(expected, candidate)| {
+                                match (expected, candidate) {
+                                    (FinalizeType::Plaintext(expected), FinalizeType::Plaintext(candidate)) => {
+                                        self.plaintext(expected, candidate)
+                                    }
+                                    (FinalizeType::Future(expected), FinalizeType::Future(candidate)) => {
+                                        expected == candidate
+                                    }
+                                    _ => false,
+                                }
+                            })
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the given plaintext type of the program conforms to the expected plaintext type.
+    fn plaintext(&self, expected: &PlaintextType<N>, candidate: &PlaintextType<N>) -> bool {
+        match (expected, candidate) {
+            (PlaintextType::Literal(expected), PlaintextType::Literal(candidate)) => expected == candidate,
+            (PlaintextType::Struct(expected), PlaintextType::Struct(candidate)) => {
+                match (self.home.get_struct(expected), self.program.get_struct(candidate)) {
+                    (Ok(expected), Ok(candidate)) => {
+                        expected.name() == candidate.name()
+                            && expected.members().len() == candidate.members().len()
+                            && expected.members().iter().zip(candidate.members()).all(
+                                |((expected_name, expected), (candidate_name, candidate))| {
+                                    expected_name == candidate_name && self.plaintext(expected, candidate)
+                                },
+                            )
+                    }
+                    _ => false,
+                }
+            }
+            (PlaintextType::Array(expected), PlaintextType::Array(candidate)) => {
+                expected.length() == candidate.length()
+                    && self.plaintext(expected.next_element_type(), candidate.next_element_type())
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the given record type of the program conforms to the expected record type.
+    fn record(&self, expected: &RecordType<N>, candidate: &RecordType<N>) -> bool {
+        expected.name() == candidate.name()
+            && expected.owner() == candidate.owner()
+            && expected.entries().len() == candidate.entries().len()
+            && expected.entries().iter().zip(candidate.entries()).all(
+                |((expected_name, expected), (candidate_name, candidate))| {
+                    expected_name == candidate_name
+                        && match (expected, candidate) {
+                            (EntryType::Constant(expected), EntryType::Constant(candidate))
+                            | (EntryType::Public(expected), EntryType::Public(candidate))
+                            | (EntryType::Private(expected), EntryType::Private(candidate)) => {
+                                self.plaintext(expected, candidate)
+                            }
+                            _ => false,
+                        }
+                },
+            )
+    }
+}
+
+impl<N: Network> TypeName for Interface<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "interface"
+    }
+}
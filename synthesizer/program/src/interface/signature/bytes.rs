@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for MappingSignature<N> {
+    /// Reads the mapping signature from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let name = Identifier::read_le(&mut reader)?;
+        // Read the key type.
+        let key_type = PlaintextType::read_le(&mut reader)?;
+        // Read the value type.
+        let value_type = PlaintextType::read_le(&mut reader)?;
+        // Return the mapping signature.
+        Ok(Self::new(name, key_type, value_type))
+    }
+}
+
+impl<N: Network> ToBytes for MappingSignature<N> {
+    /// Writes the mapping signature to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.name.write_le(&mut writer)?;
+        // Write the key type.
+        self.key_type.write_le(&mut writer)?;
+        // Write the value type.
+        self.value_type.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for FunctionSignature<N> {
+    /// Reads the function signature from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the function name.
+        let name = Identifier::read_le(&mut reader)?;
+        // Read the input types.
+        let num_inputs = u16::read_le(&mut reader)?;
+        let input_types = (0..num_inputs).map(|_| ValueType::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the output types.
+        let num_outputs = u16::read_le(&mut reader)?;
+        let output_types = (0..num_outputs).map(|_| ValueType::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the function signature.
+        Self::new(name, input_types, output_types).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for FunctionSignature<N> {
+    /// Writes the function signature to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the function name.
+        self.name.write_le(&mut writer)?;
+        // Write the input types.
+        u16::try_from(self.input_types.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for input_type in &self.input_types {
+            input_type.write_le(&mut writer)?;
+        }
+        // Write the output types.
+        u16::try_from(self.output_types.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for output_type in &self.output_types {
+            output_type.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_signature_bytes() -> Result<()> {
+        let expected = MappingSignature::<CurrentNetwork>::from_str("mapping account: address into u64;")?;
+        let candidate = MappingSignature::from_bytes_le(&expected.to_bytes_le()?)?;
+        assert_eq!(expected, candidate);
+
+        let expected = FunctionSignature::<CurrentNetwork>::from_str(
+            "function transfer_public: address.public u64.public into token.aleo/transfer_public.future;",
+        )?;
+        let candidate = FunctionSignature::from_bytes_le(&expected.to_bytes_le()?)?;
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+}
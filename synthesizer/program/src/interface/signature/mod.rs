@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, PlaintextType, ValueType},
+};
+
+/// A mapping signature declares the shape of a mapping in an interface,
+/// and is of the form `mapping {name}: {key_type} into {value_type};`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MappingSignature<N: Network> {
+    /// The name of the mapping.
+    name: Identifier<N>,
+    /// The key plaintext type.
+    key_type: PlaintextType<N>,
+    /// The value plaintext type.
+    value_type: PlaintextType<N>,
+}
+
+impl<N: Network> MappingSignature<N> {
+    /// Initializes a new mapping signature.
+    pub const fn new(name: Identifier<N>, key_type: PlaintextType<N>, value_type: PlaintextType<N>) -> Self {
+        Self { name, key_type, value_type }
+    }
+
+    /// Returns the name of the mapping.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the key plaintext type.
+    pub const fn key_type(&self) -> &PlaintextType<N> {
+        &self.key_type
+    }
+
+    /// Returns the value plaintext type.
+    pub const fn value_type(&self) -> &PlaintextType<N> {
+        &self.value_type
+    }
+}
+
+impl<N: Network> TypeName for MappingSignature<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "mapping"
+    }
+}
+
+/// A function signature declares the input and output types of a function in an interface,
+/// and is of the form `function {name}: {input_types} into {output_types};`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FunctionSignature<N: Network> {
+    /// The name of the function.
+    name: Identifier<N>,
+    /// The input types.
+    input_types: Vec<ValueType<N>>,
+    /// The output types.
+    output_types: Vec<ValueType<N>>,
+}
+
+impl<N: Network> FunctionSignature<N> {
+    /// Initializes a new function signature.
+    pub fn new(name: Identifier<N>, input_types: Vec<ValueType<N>>, output_types: Vec<ValueType<N>>) -> Result<Self> {
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(input_types.len() <= N::MAX_INPUTS, "Function signature '{name}' exceeds maximum number of inputs");
+        // Ensure the number of outputs is within the allowed range.
+        ensure!(output_types.len() <= N::MAX_OUTPUTS, "Function signature '{name}' exceeds maximum number of outputs");
+        // Ensure the inputs do not contain futures.
+        ensure!(
+            !input_types.iter().any(|input_type| matches!(input_type, ValueType::Future(..))),
+            "Function signature '{name}' cannot take a future as input"
+        );
+        Ok(Self { name, input_types, output_types })
+    }
+
+    /// Returns the name of the function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the input types.
+    pub fn input_types(&self) -> &[ValueType<N>] {
+        &self.input_types
+    }
+
+    /// Returns the output types.
+    pub fn output_types(&self) -> &[ValueType<N>] {
+        &self.output_types
+    }
+}
+
+impl<N: Network> TypeName for FunctionSignature<N> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "function"
+    }
+}
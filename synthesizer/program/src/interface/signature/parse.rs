@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for MappingSignature<N> {
+    /// Parses a string into a mapping signature.
+    /// The mapping signature is of the form `mapping {name}: {key_type} into {value_type};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'mapping' keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the mapping name from the string.
+        let (string, name) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the key type from the string.
+        let (string, key_type) = PlaintextType::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the value type from the string.
+        let (string, value_type) = PlaintextType::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon ';' keyword from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the mapping signature.
+        Ok((string, Self::new(name, key_type, value_type)))
+    }
+}
+
+impl<N: Network> Parser for FunctionSignature<N> {
+    /// Parses a string into a function signature.
+    /// The function signature is of the form `function {name}: {input_types} into {output_types};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a value type from the string.
+        fn parse_value_type<N: Network>(string: &str) -> ParserResult<ValueType<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the value type from the string.
+            ValueType::parse(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'function' keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the function name from the string.
+        let (string, name) = Identifier::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+        // Parse the input types from the string.
+        let (string, input_types) = many0(complete(parse_value_type))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Optionally parse the "into" and the output types from the string.
+        let (string, output_types) = match opt(tag("into"))(string)? {
+            // If the "into" was not parsed, return an empty vector of output types.
+            (string, None) => (string, vec![]),
+            // If the "into" was parsed, parse the output types from the string.
+            (string, Some(_)) => many1(complete(parse_value_type))(string)?,
+        };
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon ';' keyword from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the function signature.
+        map_res(take(0usize), move |_| Self::new(name, input_types.clone(), output_types.clone()))(string)
+    }
+}
+
+impl<N: Network> FromStr for MappingSignature<N> {
+    type Err = Error;
+
+    /// Returns a mapping signature from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> FromStr for FunctionSignature<N> {
+    type Err = Error;
+
+    /// Returns a function signature from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for MappingSignature<N> {
+    /// Prints the mapping signature as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Debug for FunctionSignature<N> {
+    /// Prints the function signature as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for MappingSignature<N> {
+    /// Prints the mapping signature as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}: {} into {};", Self::type_name(), self.name, self.key_type, self.value_type)
+    }
+}
+
+impl<N: Network> Display for FunctionSignature<N> {
+    /// Prints the function signature as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the function name.
+        write!(f, "{} {}:", Self::type_name(), self.name)?;
+        // Write the input types.
+        self.input_types.iter().try_for_each(|input_type| write!(f, " {input_type}"))?;
+        // Write the output types, if any.
+        if !self.output_types.is_empty() {
+            write!(f, " into")?;
+            self.output_types.iter().try_for_each(|output_type| write!(f, " {output_type}"))?;
+        }
+        write!(f, ";")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_mapping_signature_parse() {
+        let signature = MappingSignature::<CurrentNetwork>::from_str("mapping account: address into u64;").unwrap();
+        assert_eq!("account", signature.name().to_string());
+        assert_eq!("address", signature.key_type().to_string());
+        assert_eq!("u64", signature.value_type().to_string());
+    }
+
+    #[test]
+    fn test_function_signature_parse() {
+        let signature = FunctionSignature::<CurrentNetwork>::from_str(
+            "function transfer: address.public u64.public into u64.private;",
+        )
+        .unwrap();
+        assert_eq!("transfer", signature.name().to_string());
+        assert_eq!(2, signature.input_types().len());
+        assert_eq!(1, signature.output_types().len());
+
+        let signature = FunctionSignature::<CurrentNetwork>::from_str("function noop:;").unwrap();
+        assert!(signature.input_types().is_empty());
+        assert!(signature.output_types().is_empty());
+
+        // Ensure a future cannot be an input.
+        assert!(FunctionSignature::<CurrentNetwork>::from_str("function foo: token.aleo/bar.future;").is_err());
+    }
+
+    #[test]
+    fn test_signature_display() {
+        for expected in ["mapping account: address into u64;", "mapping pairs: field into [u8; 4u32];"] {
+            assert_eq!(expected, MappingSignature::<CurrentNetwork>::from_str(expected).unwrap().to_string());
+        }
+        for expected in [
+            "function noop:;",
+            "function mint: address.private u64.private into token.record;",
+            "function transfer_public: address.public u64.public into token.aleo/transfer_public.future;",
+        ] {
+            assert_eq!(expected, FunctionSignature::<CurrentNetwork>::from_str(expected).unwrap().to_string());
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub type Program<N> = crate::ProgramCore<N, Instruction<N>, Command<N>>;
 pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
 pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
+pub type Routine<N> = crate::RoutineCore<N, Command<N>>;
 
 mod closure;
 pub use closure::*;
@@ -33,12 +34,18 @@ pub use function::*;
 mod import;
 pub use import::*;
 
+mod interface;
+pub use interface::*;
+
 pub mod logic;
 pub use logic::*;
 
 mod mapping;
 pub use mapping::*;
 
+mod routine;
+pub use routine::*;
+
 pub mod traits;
 pub use traits::*;
 
@@ -51,6 +58,7 @@ use console::{
         alt,
         anyhow,
         bail,
+        complete,
         de,
         ensure,
         error,
@@ -59,6 +67,7 @@ use console::{
         many1,
         map,
         map_res,
+        opt,
         tag,
         take,
         Debug,
@@ -84,7 +93,7 @@ use console::{
         TypeName,
         Write,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{Identifier, Locator, PlaintextType, ProgramID, RecordType, StructType},
 };
 
 use indexmap::IndexMap;
@@ -101,6 +110,10 @@ enum ProgramDefinition {
     Closure,
     /// A program function.
     Function,
+    /// A program interface.
+    Interface,
+    /// A program routine.
+    Routine,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -121,6 +134,12 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     closures: IndexMap<Identifier<N>, ClosureCore<N, Instruction>>,
     /// A map of the declared functions for the program.
     functions: IndexMap<Identifier<N>, FunctionCore<N, Instruction, Command>>,
+    /// A map of the declared interfaces for the program.
+    interfaces: IndexMap<Identifier<N>, Interface<N>>,
+    /// The locators of the interfaces that the program implements.
+    implements: Vec<Locator<N>>,
+    /// A map of the declared routines for the program.
+    routines: IndexMap<Identifier<N>, RoutineCore<N, Command>>,
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -139,6 +158,9 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             records: IndexMap::new(),
             closures: IndexMap::new(),
             functions: IndexMap::new(),
+            interfaces: IndexMap::new(),
+            implements: Vec::new(),
+            routines: IndexMap::new(),
         })
     }
 
@@ -183,6 +205,21 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.functions
     }
 
+    /// Returns the interfaces in the program.
+    pub const fn interfaces(&self) -> &IndexMap<Identifier<N>, Interface<N>> {
+        &self.interfaces
+    }
+
+    /// Returns the locators of the interfaces that the program implements.
+    pub fn implements(&self) -> &[Locator<N>] {
+        &self.implements
+    }
+
+    /// Returns the routines in the program.
+    pub const fn routines(&self) -> &IndexMap<Identifier<N>, RoutineCore<N, Command>> {
+        &self.routines
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
@@ -213,6 +250,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         self.functions.contains_key(name)
     }
 
+    /// Returns `true` if the program contains an interface with the given name.
+    pub fn contains_interface(&self, name: &Identifier<N>) -> bool {
+        self.interfaces.contains_key(name)
+    }
+
+    /// Returns `true` if the program contains a routine with the given name.
+    pub fn contains_routine(&self, name: &Identifier<N>) -> bool {
+        self.routines.contains_key(name)
+    }
+
     /// Returns the mapping with the given name.
     pub fn get_mapping(&self, name: &Identifier<N>) -> Result<Mapping<N>> {
         // Attempt to retrieve the mapping.
@@ -283,6 +330,26 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Return the function.
         Ok(function)
     }
+
+    /// Returns the interface with the given name.
+    pub fn get_interface(&self, name: &Identifier<N>) -> Result<&Interface<N>> {
+        // Attempt to retrieve the interface.
+        let interface = self.interfaces.get(name).ok_or_else(|| anyhow!("Interface '{name}' is not defined."))?;
+        // Ensure the interface name matches.
+        ensure!(interface.name() == name, "Expected interface '{name}', but found interface '{}'", interface.name());
+        // Return the interface.
+        Ok(interface)
+    }
+
+    /// Returns the routine with the given name.
+    pub fn get_routine(&self, name: &Identifier<N>) -> Result<&RoutineCore<N, Command>> {
+        // Attempt to retrieve the routine.
+        let routine = self.routines.get(name).ok_or_else(|| anyhow!("Routine '{name}' is not defined."))?;
+        // Ensure the routine name matches.
+        ensure!(routine.name() == name, "Expected routine '{name}', but found routine '{}'", routine.name());
+        // Return the routine.
+        Ok(routine)
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -539,6 +606,26 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Ensure the number of outputs is within the allowed range.
         ensure!(function.outputs().len() <= N::MAX_OUTPUTS, "Function exceeds maximum number of outputs");
 
+        // If the function has a finalize block, check the routines it invokes.
+        if let Some(finalize) = function.finalize_logic() {
+            // Initialize a counter for the number of writes, including the writes of the invoked routines.
+            let mut num_writes = u64::from(finalize.num_writes());
+            for (command, executions) in finalize.commands().iter().zip(finalize.max_executions()) {
+                if let Some(routine_name) = command.invoked_routine() {
+                    // Ensure the routine is declared before the function.
+                    let routine = self.get_routine(routine_name)?;
+                    // Accumulate the writes of the routine, once per execution of the `invoke` command.
+                    num_writes = num_writes.saturating_add(executions.saturating_mul(u64::from(routine.num_writes())));
+                }
+            }
+            // Ensure the number of writes is within the allowed range.
+            ensure!(
+                num_writes <= u64::from(N::MAX_WRITES),
+                "Function '{function_name}' exceeds the maximum of {} writes, including its routines",
+                N::MAX_WRITES
+            );
+        }
+
         // Add the function name to the identifiers.
         if self.identifiers.insert(function_name, ProgramDefinition::Function).is_some() {
             bail!("'{function_name}' already exists in the program.")
@@ -549,6 +636,102 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Adds a new interface to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the interface was previously added.
+    /// This method will halt if the interface name is already in use in the program.
+    /// This method will halt if the interface name is a reserved opcode or keyword.
+    #[inline]
+    fn add_interface(&mut self, interface: Interface<N>) -> Result<()> {
+        // Retrieve the interface name.
+        let interface_name = *interface.name();
+
+        // Ensure the interface name is new.
+        ensure!(self.is_unique_name(&interface_name), "'{interface_name}' is already in use.");
+        // Ensure the interface name is not a reserved opcode.
+        ensure!(!Self::is_reserved_opcode(&interface_name.to_string()), "'{interface_name}' is a reserved opcode.");
+        // Ensure the interface name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(&interface_name), "'{interface_name}' is a reserved keyword.");
+
+        // Add the interface name to the identifiers.
+        if self.identifiers.insert(interface_name, ProgramDefinition::Interface).is_some() {
+            bail!("'{interface_name}' already exists in the program.")
+        }
+        // Add the interface to the program.
+        if self.interfaces.insert(interface_name, interface).is_some() {
+            bail!("'{interface_name}' already exists in the program.")
+        }
+        Ok(())
+    }
+
+    /// Adds a new routine to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the routine was previously added.
+    /// This method will halt if the routine name is already in use in the program.
+    /// This method will halt if the routine name is a reserved opcode or keyword.
+    /// This method will halt if the routine has no commands.
+    #[inline]
+    fn add_routine(&mut self, routine: RoutineCore<N, Command>) -> Result<()> {
+        // Retrieve the routine name.
+        let routine_name = *routine.name();
+
+        // Ensure the routine name is new.
+        ensure!(self.is_unique_name(&routine_name), "'{routine_name}' is already in use.");
+        // Ensure the routine name is not a reserved opcode.
+        ensure!(!Self::is_reserved_opcode(&routine_name.to_string()), "'{routine_name}' is a reserved opcode.");
+        // Ensure the routine name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(&routine_name), "'{routine_name}' is a reserved keyword.");
+
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(routine.inputs().len() <= N::MAX_INPUTS, "Routine exceeds maximum number of inputs");
+        // Ensure there are commands in the routine.
+        ensure!(!routine.commands().is_empty(), "Cannot evaluate a routine without commands");
+        // Ensure the number of outputs is within the allowed range.
+        ensure!(routine.outputs().len() <= N::MAX_OUTPUTS, "Routine exceeds maximum number of outputs");
+
+        // Add the routine name to the identifiers.
+        if self.identifiers.insert(routine_name, ProgramDefinition::Routine).is_some() {
+            bail!("'{routine_name}' already exists in the program.")
+        }
+        // Add the routine to the program.
+        if self.routines.insert(routine_name, routine).is_some() {
+            bail!("'{routine_name}' already exists in the program.")
+        }
+        Ok(())
+    }
+
+    /// Adds an interface that the program implements.
+    /// Note: This method must be called after the imports and all other components are added.
+    ///
+    /// # Errors
+    /// This method will halt if the interface was previously added.
+    /// This method will halt if the interface is not in the program or its imports.
+    /// This method will halt if the interface is in the program, and the program does not conform to it.
+    #[inline]
+    fn add_implements(&mut self, locator: Locator<N>) -> Result<()> {
+        // Ensure the program has not exceeded the maximum number of interfaces.
+        ensure!(self.implements.len() < N::MAX_INTERFACES, "Program exceeds the maximum number of interfaces");
+        // Ensure the interface is new.
+        ensure!(!self.implements.contains(&locator), "Interface '{locator}' is already implemented.");
+
+        match locator.program_id() == self.id() {
+            // If the interface is in the program, ensure the program conforms to it.
+            true => self.get_interface(locator.resource())?.check_conformance(self, self)?,
+            // Otherwise, ensure the interface is imported.
+            // Note: The conformance to an imported interface is checked when the program is added to a process.
+            false => ensure!(
+                self.contains_import(locator.program_id()),
+                "Interface '{locator}' is not in an imported program."
+            ),
+        }
+
+        // Add the interface to the program.
+        self.implements.push(locator);
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -576,6 +759,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "u128",
         "scalar",
         "string",
+        "decimal",
         // Boolean
         "true",
         "false",
@@ -625,6 +809,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "impl",
         "type",
         "future",
+        "routine",
+        "invoke",
     ];
 
     /// Returns `true` if the given name does not already exist in the program.
@@ -813,4 +999,155 @@ function swap:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_interface() -> Result<()> {
+        // Initialize a new program that implements its own interface.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo implements token.aleo/fungible;
+
+interface fungible:
+    mapping account: address into u64;
+    function transfer_public: address.public u64.public into token.aleo/transfer_public.future;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function transfer_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async transfer_public self.caller r0 r1 into r2;
+    output r2 as token.aleo/transfer_public.future;
+
+finalize transfer_public:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    get.or_use account[r0] 0u64 into r3;
+    sub r3 r2 into r4;
+    set r4 into account[r0];
+    get.or_use account[r1] 0u64 into r5;
+    add r5 r2 into r6;
+    set r6 into account[r1];",
+        )?;
+
+        // Ensure the interface was added.
+        assert!(program.contains_interface(&Identifier::from_str("fungible")?));
+        assert_eq!(program.implements(), &[Locator::from_str("token.aleo/fungible")?]);
+        // Ensure the program round-trips through its string representation.
+        assert_eq!(program, Program::from_str(&program.to_string())?);
+
+        // Ensure a program that does not conform to its interface is rejected.
+        let result = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo implements token.aleo/fungible;
+
+interface fungible:
+    function mint: address.public u64.public;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u128.public;",
+        );
+        assert!(result.is_err());
+
+        // Ensure an interface must be in the program or its imports.
+        let result = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo implements standard.aleo/fungible;
+
+function mint:
+    input r0 as address.public;",
+        );
+        assert!(result.is_err());
+
+        // Ensure 'interface' and 'implements' remain valid identifiers, as they are only keywords in context.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+mapping interface:
+    key as address.public;
+    value as u64.public;
+
+function implements:
+    input r0 as address.public;",
+        )?;
+        assert!(program.contains_mapping(&Identifier::from_str("interface")?));
+        assert!(program.contains_function(&Identifier::from_str("implements")?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_routine() -> Result<()> {
+        // A helper to construct a program that invokes the given routine, with the given loop bound.
+        let program = |routine: &str, bound: u32| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                r"
+program routines.aleo;
+
+mapping fees:
+    key as u8.public;
+    value as u64.public;
+
+{routine}
+
+function deposit:
+    input r0 as u64.public;
+    async deposit r0 into r1;
+    output r1 as routines.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as u64.public;
+    loop 1u32 bound {bound}u32 into r1 to end;
+    invoke charge_fee r0 into r2;
+    position end;"
+            ))
+        };
+        let routine = r"
+routine charge_fee:
+    input r0 as u64.public;
+    div r0 10u64 into r1;
+    set r1 into fees[0u8];
+    set r1 into fees[1u8];
+    output r1 as u64.public;";
+
+        // Initialize a new program.
+        let expected = program(routine, 8)?;
+        // Ensure the routine was added.
+        assert!(expected.contains_routine(&Identifier::from_str("charge_fee")?));
+        assert_eq!(expected.get_routine(&Identifier::from_str("charge_fee")?)?.num_writes(), 2);
+        // Ensure the program round-trips through its string and byte representations.
+        assert_eq!(expected, Program::from_str(&expected.to_string())?);
+        assert_eq!(expected, Program::from_bytes_le(&expected.to_bytes_le()?)?);
+
+        // Ensure the writes of the routine are counted once per iteration of the enclosing loop.
+        assert!(program(routine, 9).is_err());
+
+        // Ensure a routine must be declared before it is invoked.
+        let result = Program::<CurrentNetwork>::from_str(
+            r"
+program routines.aleo;
+
+function deposit:
+    input r0 as u64.public;
+    async deposit r0 into r1;
+    output r1 as routines.aleo/deposit.future;
+
+finalize deposit:
+    input r0 as u64.public;
+    invoke charge_fee r0 into r1;
+
+routine charge_fee:
+    input r0 as u64.public;
+    div r0 10u64 into r1;
+    output r1 as u64.public;",
+        );
+        assert!(result.is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, StackMatches, StackProgram},
+    Event,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, PlaintextType},
+};
+
+/// An emit command, e.g. `emit r0 as transfer;`
+/// Emits the `operand` as an event of the program, where `operand` is an instance of the struct `name`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Emit<N: Network> {
+    /// The operand containing the value of the event.
+    operand: Operand<N>,
+    /// The name of the event, which is the name of its struct.
+    name: Identifier<N>,
+}
+
+impl<N: Network> Emit<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("emit")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.operand.clone()]
+    }
+
+    /// Returns the operand containing the value of the event.
+    #[inline]
+    pub const fn operand(&self) -> &Operand<N> {
+        &self.operand
+    }
+
+    /// Returns the name of the event.
+    #[inline]
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+}
+
+impl<N: Network> Emit<N> {
+    /// Finalizes the command, returning the emitted event.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &impl RegistersLoad<N>,
+    ) -> Result<Event<N>> {
+        // Load the operand as a plaintext.
+        let value = registers.load_plaintext(stack, &self.operand)?;
+        // Ensure the value is an instance of the event struct.
+        stack.matches_plaintext(&value, &PlaintextType::Struct(self.name))?;
+        // Return the event.
+        Ok(Event::new(*stack.program_id(), self.name, value))
+    }
+}
+
+impl<N: Network> Parser for Emit<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the "as" keyword from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the event name from the string.
+        let (string, name) = Identifier::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { operand, name }))
+    }
+}
+
+impl<N: Network> FromStr for Emit<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Emit<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Emit<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} {} as {};", Self::opcode(), self.operand, self.name)
+    }
+}
+
+impl<N: Network> FromBytes for Emit<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Read the event name.
+        let name = Identifier::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { operand, name })
+    }
+}
+
+impl<N: Network> ToBytes for Emit<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the operand.
+        self.operand.write_le(&mut writer)?;
+        // Write the event name.
+        self.name.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, emit) = Emit::<CurrentNetwork>::parse("emit r0 as transfer;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(emit.operand, Operand::Register(Register::Locator(0)), "The operand is incorrect");
+        assert_eq!(emit.name, Identifier::from_str("transfer").unwrap(), "The event name is incorrect");
+        assert_eq!(emit.to_string(), "emit r0 as transfer;");
+
+        Emit::<CurrentNetwork>::parse("emit r0;").unwrap_err();
+        Emit::<CurrentNetwork>::parse("emit r0 as transfer.record;").unwrap_err();
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    MappingLocator,
+    Opcode,
+    Operand,
+    traits::{FinalizeRegistersState, FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, Register, Value},
+};
+
+/// A get-at command, e.g. `get.at accounts[r0] into r1 r2;`.
+/// Gets the (`key`, `value`) entry at the `index` operand in `mapping`, and stores the key in `key_destination`
+/// and the value in `value_destination`. The entries of a mapping are ordered by insertion,
+/// and removing an entry moves the last entry into its position.
+/// The command can only be finalized from `N::MAPPING_POSITIONS_HEIGHT`, at which the entries of every mapping
+/// are repositioned in the order of their key bytes.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GetAt<N: Network> {
+    /// The mapping.
+    mapping: MappingLocator<N>,
+    /// The index of the entry.
+    index: Operand<N>,
+    /// The destination register for the key.
+    key_destination: Register<N>,
+    /// The destination register for the value.
+    value_destination: Register<N>,
+}
+
+impl<N: Network> GetAt<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("get.at")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.index.clone()]
+    }
+
+    /// Returns the mapping.
+    #[inline]
+    pub const fn mapping(&self) -> &MappingLocator<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the index.
+    #[inline]
+    pub const fn index(&self) -> &Operand<N> {
+        &self.index
+    }
+
+    /// Returns the destination register for the key.
+    #[inline]
+    pub const fn key_destination(&self) -> &Register<N> {
+        &self.key_destination
+    }
+
+    /// Returns the destination register for the value.
+    #[inline]
+    pub const fn value_destination(&self) -> &Register<N> {
+        &self.value_destination
+    }
+}
+
+impl<N: Network> GetAt<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N> + FinalizeRegistersState<N>),
+    ) -> Result<()> {
+        // Ensure the positions of the mapping entries are defined at the block height.
+        if registers.state().block_height() < N::MAPPING_POSITIONS_HEIGHT {
+            bail!("Command 'get.at' cannot be finalized before block {}", N::MAPPING_POSITIONS_HEIGHT)
+        }
+
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match self.mapping {
+            MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            MappingLocator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+        };
+
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
+        }
+
+        // Load the operand as a `u32`.
+        let index = match registers.load_literal(stack, &self.index)? {
+            Literal::U32(index) => *index,
+            _ => bail!("The index in 'get.at' must be a 'u32'"),
+        };
+
+        // Retrieve the entry from storage.
+        let (key, value) = match store.get_entry_at_speculative(program_id, mapping_name, index as usize)? {
+            Some((key, Value::Plaintext(value))) => (key, value),
+            Some((_, Value::Record(..))) => bail!("Cannot 'get.at' a 'record'"),
+            Some((_, Value::Future(..))) => bail!("Cannot 'get.at' a 'future'"),
+            // If the index is out of bounds, then bail.
+            None => bail!("Index '{index}' is out of bounds in mapping '{program_id}/{mapping_name}'"),
+        };
+
+        // Assign the key to the key destination register.
+        registers.store(stack, &self.key_destination, Value::Plaintext(key))?;
+        // Assign the value to the value destination register.
+        registers.store(stack, &self.value_destination, Value::Plaintext(value))?;
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for GetAt<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = MappingLocator::parse(string)?;
+        // Parse the "[" from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the index operand from the string.
+        let (string, index) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "]" from the string.
+        let (string, _) = tag("]")(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the key destination register from the string.
+        let (string, key_destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the value destination register from the string.
+        let (string, value_destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, index, key_destination, value_destination }))
+    }
+}
+
+impl<N: Network> FromStr for GetAt<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for GetAt<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for GetAt<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping and index operand.
+        write!(f, "{}[{}] into ", self.mapping, self.index)?;
+        // Print the destination registers.
+        write!(f, "{} {};", self.key_destination, self.value_destination)
+    }
+}
+
+impl<N: Network> FromBytes for GetAt<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping.
+        let mapping = MappingLocator::read_le(&mut reader)?;
+        // Read the index operand.
+        let index = Operand::read_le(&mut reader)?;
+        // Read the key destination register.
+        let key_destination = Register::read_le(&mut reader)?;
+        // Read the value destination register.
+        let value_destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, index, key_destination, value_destination })
+    }
+}
+
+impl<N: Network> ToBytes for GetAt<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping.
+        self.mapping.write_le(&mut writer)?;
+        // Write the index operand.
+        self.index.write_le(&mut writer)?;
+        // Write the key destination register.
+        self.key_destination.write_le(&mut writer)?;
+        // Write the value destination register.
+        self.value_destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, get_at) = GetAt::<CurrentNetwork>::parse("get.at committee[r0] into r1 r2;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get_at.mapping, MappingLocator::from_str("committee").unwrap());
+        assert_eq!(get_at.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(get_at.index, Operand::Register(Register::Locator(0)), "The index operand is incorrect");
+        assert_eq!(get_at.key_destination, Register::Locator(1), "The key destination is incorrect");
+        assert_eq!(get_at.value_destination, Register::Locator(2), "The value destination is incorrect");
+
+        let (string, get_at) =
+            GetAt::<CurrentNetwork>::parse("get.at credits.aleo/committee[0u32] into r1 r2;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get_at.mapping, MappingLocator::from_str("credits.aleo/committee").unwrap());
+        assert_eq!(get_at.index, Operand::from_str("0u32").unwrap(), "The index operand is incorrect");
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Opcode, Operand};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Register},
+};
+
+/// An invoke command, e.g. `invoke charge_fee r0 r1 into r2;`.
+/// Invokes the routine `routine` on the `operands`, and stores the outputs into the `destinations`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Invoke<N: Network> {
+    /// The name of the routine.
+    routine: Identifier<N>,
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> Invoke<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("invoke")
+    }
+
+    /// Returns the name of the routine.
+    #[inline]
+    pub const fn routine(&self) -> &Identifier<N> {
+        &self.routine
+    }
+
+    /// Returns the operands.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> &[Register<N>] {
+        &self.destinations
+    }
+}
+
+impl<N: Network> Parser for Invoke<N> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the name of the routine from the string.
+        let (string, routine) = Identifier::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many0(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the number of operands is within the bounds.
+            match operands.len() <= N::MAX_INPUTS {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'invoke' command: too many operands")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Optionally parse the "into" from the string.
+        let (string, destinations) = match opt(tag("into"))(string)? {
+            // If the "into" was not parsed, return the string and an empty vector of destinations.
+            (string, None) => (string, vec![]),
+            // If the "into" was parsed, parse the destinations from the string.
+            (string, Some(_)) => {
+                // Parse the destinations from the string.
+                let (string, destinations) =
+                    map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+                        // Ensure the number of destinations is within the bounds.
+                        match destinations.len() <= N::MAX_OUTPUTS {
+                            true => Ok(destinations),
+                            false => Err(error("Failed to parse 'invoke' command: too many destinations")),
+                        }
+                    })(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Return the string and the destinations.
+                (string, destinations)
+            }
+        };
+
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { routine, operands, destinations }))
+    }
+}
+
+impl<N: Network> FromStr for Invoke<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Invoke<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Invoke<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_INPUTS {
+            return Err(fmt::Error);
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OUTPUTS {
+            return Err(fmt::Error);
+        }
+        // Print the command.
+        write!(f, "{} {}", Self::opcode(), self.routine)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        if !self.destinations.is_empty() {
+            write!(f, " into")?;
+            self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))?;
+        }
+        write!(f, ";")
+    }
+}
+
+impl<N: Network> FromBytes for Invoke<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the name of the routine.
+        let routine = Identifier::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands > N::MAX_INPUTS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_INPUTS)));
+        }
+        // Read the operands.
+        let mut operands = Vec::with_capacity(num_operands);
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations > N::MAX_OUTPUTS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OUTPUTS)));
+        }
+        // Read the destination registers.
+        let mut destinations = Vec::with_capacity(num_destinations);
+        for _ in 0..num_destinations {
+            destinations.push(Register::read_le(&mut reader)?);
+        }
+
+        // Return the command.
+        Ok(Self { routine, operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for Invoke<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_INPUTS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_INPUTS)));
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.len() > N::MAX_OUTPUTS {
+            return Err(error(format!("The number of destinations must be <= {}", N::MAX_OUTPUTS)));
+        }
+
+        // Write the name of the routine.
+        self.routine.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the number of destination registers.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, invoke) = Invoke::<CurrentNetwork>::parse("invoke charge_fee r0 10u64 into r1 r2;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(invoke.routine, Identifier::from_str("charge_fee").unwrap());
+        assert_eq!(invoke.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(invoke.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(invoke.operands[1], Operand::from_str("10u64").unwrap(), "The second operand is incorrect");
+        assert_eq!(invoke.destinations, vec![Register::Locator(1), Register::Locator(2)]);
+
+        let (string, invoke) = Invoke::<CurrentNetwork>::parse("invoke reset_counter;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert!(invoke.operands.is_empty(), "The number of operands is incorrect");
+        assert!(invoke.destinations.is_empty(), "The number of destinations is incorrect");
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in ["invoke charge_fee r0 10u64 into r1 r2;", "invoke reset_counter;", "invoke check r0.owner;"] {
+            let invoke = Invoke::<CurrentNetwork>::from_str(expected).unwrap();
+            assert_eq!(expected, invoke.to_string());
+        }
+    }
+}
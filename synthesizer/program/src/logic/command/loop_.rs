@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Opcode, Operand};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Register},
+    types::U32,
+};
+
+/// The maximum number of iterations that a `loop` command may declare.
+pub const MAX_LOOP_ITERATIONS: u32 = 256;
+
+/// A loop command, e.g. `loop r0 bound 8u32 into r1 to end;`.
+/// Executes the commands between the `loop` and the `position` named `end`, `count` times.
+/// On each iteration, the iteration index is stored in `destination`, starting from `0u32`.
+/// The `count` must not exceed the `bound`, which is fixed when the program is deployed.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Loop<N: Network> {
+    /// The operand containing the number of iterations.
+    count: Operand<N>,
+    /// The maximum number of iterations.
+    bound: U32<N>,
+    /// The destination register for the iteration index.
+    destination: Register<N>,
+    /// The position that ends the loop body.
+    position: Identifier<N>,
+}
+
+impl<N: Network> Loop<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("loop")
+    }
+
+    /// Returns the operand containing the number of iterations.
+    #[inline]
+    pub const fn count(&self) -> &Operand<N> {
+        &self.count
+    }
+
+    /// Returns the maximum number of iterations.
+    #[inline]
+    pub const fn bound(&self) -> &U32<N> {
+        &self.bound
+    }
+
+    /// Returns the destination register for the iteration index.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+
+    /// Returns the position that ends the loop body.
+    #[inline]
+    pub const fn position(&self) -> &Identifier<N> {
+        &self.position
+    }
+}
+
+impl<N: Network> Parser for Loop<N> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the count operand from the string.
+        let (string, count) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the "bound" keyword from the string.
+        let (string, _) = tag("bound")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the bound from the string.
+        let (string, bound) = U32::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the "to" keyword from the string.
+        let (string, _) = tag("to")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the position from the string.
+        let (string, position) = Identifier::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        // Ensure the bound is within the allowed range.
+        match 0 < *bound && *bound <= MAX_LOOP_ITERATIONS {
+            true => Ok((string, Self { count, bound, destination, position })),
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse 'loop': the bound must be in [1, {MAX_LOOP_ITERATIONS}]")))
+            })(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for Loop<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Loop<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Loop<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(
+            f,
+            "{} {} bound {} into {} to {};",
+            Self::opcode(),
+            self.count,
+            self.bound,
+            self.destination,
+            self.position
+        )
+    }
+}
+
+impl<N: Network> FromBytes for Loop<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the count operand.
+        let count = Operand::read_le(&mut reader)?;
+        // Read the bound.
+        let bound = U32::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the position.
+        let position = Identifier::read_le(&mut reader)?;
+
+        // Ensure the bound is within the allowed range.
+        if *bound == 0 || *bound > MAX_LOOP_ITERATIONS {
+            return Err(error(format!("The loop bound must be in [1, {MAX_LOOP_ITERATIONS}]")));
+        }
+
+        // Return the command.
+        Ok(Self { count, bound, destination, position })
+    }
+}
+
+impl<N: Network> ToBytes for Loop<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the bound is within the allowed range.
+        if *self.bound == 0 || *self.bound > MAX_LOOP_ITERATIONS {
+            return Err(error(format!("The loop bound must be in [1, {MAX_LOOP_ITERATIONS}]")));
+        }
+
+        // Write the count operand.
+        self.count.write_le(&mut writer)?;
+        // Write the bound.
+        self.bound.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the position.
+        self.position.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, loop_) = Loop::<CurrentNetwork>::parse("loop r0 bound 8u32 into r1 to end;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(loop_.count, Operand::Register(Register::Locator(0)), "The count operand is incorrect");
+        assert_eq!(*loop_.bound, 8, "The bound is incorrect");
+        assert_eq!(loop_.destination, Register::Locator(1), "The destination is incorrect");
+        assert_eq!(loop_.position, Identifier::from_str("end").unwrap(), "The position is incorrect");
+
+        // Ensure the bound is enforced.
+        assert!(Loop::<CurrentNetwork>::parse("loop r0 bound 0u32 into r1 to end;").is_err());
+        assert!(Loop::<CurrentNetwork>::parse("loop r0 bound 257u32 into r1 to end;").is_err());
+        assert!(Loop::<CurrentNetwork>::parse("loop r0 bound 8u8 into r1 to end;").is_err());
+    }
+}
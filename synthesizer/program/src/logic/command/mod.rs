@@ -21,12 +21,27 @@ pub use branch::*;
 mod contains;
 pub use contains::*;
 
+mod count;
+pub use count::*;
+
+mod emit;
+pub use emit::*;
+
 mod get;
 pub use get::*;
 
+mod get_at;
+pub use get_at::*;
+
 mod get_or_use;
 pub use get_or_use::*;
 
+mod invoke;
+pub use invoke::*;
+
+mod loop_;
+pub use loop_::*;
+
 mod rand_chacha;
 pub use crate::command::rand_chacha::*;
 
@@ -84,6 +99,16 @@ pub enum Command<N: Network> {
     BranchNeq(BranchNeq<N>),
     /// Indicates a position to which the program can branch to.
     Position(Position<N>),
+    /// Executes the commands up to the `position`, `count` times, storing the iteration index into `destination`.
+    Loop(Loop<N>),
+    /// Counts the number of entries in `mapping`, and stores the result into `destination`.
+    Count(Count<N>),
+    /// Gets the entry at the `index` operand in `mapping`, and stores the key and value into the destinations.
+    GetAt(GetAt<N>),
+    /// Invokes the routine on the operands, and stores the outputs into the destinations.
+    Invoke(Invoke<N>),
+    /// Emits the `operand` as an event of the program.
+    Emit(Emit<N>),
 }
 
 impl<N: Network> CommandTrait<N> for Command<N> {
@@ -96,22 +121,38 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             Command::Get(get) => vec![get.destination().clone()],
             Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
             Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
+            Command::Loop(loop_) => vec![loop_.destination().clone()],
+            Command::Count(count) => vec![count.destination().clone()],
+            Command::GetAt(get_at) => vec![get_at.key_destination().clone(), get_at.value_destination().clone()],
+            Command::Invoke(invoke) => invoke.destinations().to_vec(),
             Command::Await(_)
             | Command::BranchEq(_)
             | Command::BranchNeq(_)
             | Command::Position(_)
             | Command::Remove(_)
-            | Command::Set(_) => vec![],
+            | Command::Set(_)
+            | Command::Emit(_) => vec![],
         }
     }
 
-    /// Returns the branch target, if the command is a branch command.
+    /// Returns the branch target, if the command is a branch or loop command.
     /// Otherwise, returns `None`.
     #[inline]
     fn branch_to(&self) -> Option<&Identifier<N>> {
         match self {
             Command::BranchEq(branch_eq) => Some(branch_eq.position()),
             Command::BranchNeq(branch_neq) => Some(branch_neq.position()),
+            Command::Loop(loop_) => Some(loop_.position()),
+            _ => None,
+        }
+    }
+
+    /// Returns the maximum number of iterations, if the command is a loop command.
+    /// Otherwise, returns `None`.
+    #[inline]
+    fn loop_bound(&self) -> Option<u32> {
+        match self {
+            Command::Loop(loop_) => Some(**loop_.bound()),
             _ => None,
         }
     }
@@ -126,6 +167,22 @@ impl<N: Network> CommandTrait<N> for Command<N> {
         }
     }
 
+    /// Returns the name of the invoked routine, if the command is an invoke command.
+    /// Otherwise, returns `None`.
+    #[inline]
+    fn invoked_routine(&self) -> Option<&Identifier<N>> {
+        match self {
+            Command::Invoke(invoke) => Some(invoke.routine()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the command is an await command.
+    #[inline]
+    fn is_await(&self) -> bool {
+        matches!(self, Command::Await(_))
+    }
+
     /// Returns `true` if the command is a call instruction.
     #[inline]
     fn is_call(&self) -> bool {
@@ -176,6 +233,16 @@ impl<N: Network> Command<N> {
             }
             // Finalize the `position` command, and return no finalize operation.
             Command::Position(position) => position.finalize().map(|_| None),
+            // `loop` commands are processed by the caller of this method.
+            Command::Loop(_) => bail!("`loop` commands cannot be finalized directly."),
+            // Finalize the 'count' command, and return no finalize operation.
+            Command::Count(count) => count.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'get.at' command, and return no finalize operation.
+            Command::GetAt(get_at) => get_at.finalize(stack, store, registers).map(|_| None),
+            // `invoke` commands are processed by the caller of this method.
+            Command::Invoke(_) => bail!("`invoke` commands cannot be finalized directly."),
+            // `emit` commands are processed by the caller of this method.
+            Command::Emit(_) => bail!("`emit` commands cannot be finalized directly."),
         }
     }
 }
@@ -208,8 +275,18 @@ impl<N: Network> FromBytes for Command<N> {
             9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `loop` command.
+            11 => Ok(Self::Loop(Loop::read_le(&mut reader)?)),
+            // Read the `count` command.
+            12 => Ok(Self::Count(Count::read_le(&mut reader)?)),
+            // Read the `get.at` command.
+            13 => Ok(Self::GetAt(GetAt::read_le(&mut reader)?)),
+            // Read the `invoke` command.
+            14 => Ok(Self::Invoke(Invoke::read_le(&mut reader)?)),
+            // Read the `emit` command.
+            15 => Ok(Self::Emit(Emit::read_le(&mut reader)?)),
             // Invalid variant.
-            11.. => Err(error(format!("Invalid command variant: {variant}"))),
+            16.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -284,6 +361,36 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::Loop(loop_) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `loop` command.
+                loop_.write_le(&mut writer)
+            }
+            Self::Count(count) => {
+                // Write the variant.
+                12u8.write_le(&mut writer)?;
+                // Write the `count` command.
+                count.write_le(&mut writer)
+            }
+            Self::GetAt(get_at) => {
+                // Write the variant.
+                13u8.write_le(&mut writer)?;
+                // Write the `get.at` command.
+                get_at.write_le(&mut writer)
+            }
+            Self::Invoke(invoke) => {
+                // Write the variant.
+                14u8.write_le(&mut writer)?;
+                // Write the `invoke` command.
+                invoke.write_le(&mut writer)
+            }
+            Self::Emit(emit) => {
+                // Write the variant.
+                15u8.write_le(&mut writer)?;
+                // Write the `emit` command.
+                emit.write_le(&mut writer)
+            }
         }
     }
 }
@@ -297,6 +404,10 @@ impl<N: Network> Parser for Command<N> {
         alt((
             map(Await::parse, |await_| Self::Await(await_)),
             map(Contains::parse, |contains| Self::Contains(contains)),
+            map(Count::parse, |count| Self::Count(count)),
+            map(Emit::parse, |emit| Self::Emit(emit)),
+            map(GetAt::parse, |get_at| Self::GetAt(get_at)),
+            map(Invoke::parse, |invoke| Self::Invoke(invoke)),
             map(GetOrUse::parse, |get_or_use| Self::GetOrUse(get_or_use)),
             map(Get::parse, |get| Self::Get(get)),
             map(RandChaCha::parse, |rand_chacha| Self::RandChaCha(rand_chacha)),
@@ -305,6 +416,7 @@ impl<N: Network> Parser for Command<N> {
             map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
             map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
             map(Position::parse, |position| Self::Position(position)),
+            map(Loop::parse, |loop_| Self::Loop(loop_)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
         ))(string)
     }
@@ -350,6 +462,11 @@ impl<N: Network> Display for Command<N> {
             Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
             Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
             Self::Position(position) => Display::fmt(position, f),
+            Self::Loop(loop_) => Display::fmt(loop_, f),
+            Self::Count(count) => Display::fmt(count, f),
+            Self::GetAt(get_at) => Display::fmt(get_at, f),
+            Self::Invoke(invoke) => Display::fmt(invoke, f),
+            Self::Emit(emit) => Display::fmt(emit, f),
         }
     }
 }
@@ -442,6 +559,36 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Loop
+        let expected = "loop r0 bound 8u32 into r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Count
+        let expected = "count object into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // GetAt
+        let expected = "get.at object[r0] into r1 r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Invoke
+        let expected = "invoke charge_fee r0 r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Emit
+        let expected = "emit r0 as transfer;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -519,5 +666,35 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Loop
+        let expected = "loop r0 bound 8u32 into r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Loop(Loop::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Count
+        let expected = "count object into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Count(Count::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // GetAt
+        let expected = "get.at object[r0] into r1 r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::GetAt(GetAt::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Invoke
+        let expected = "invoke charge_fee r0 r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Invoke(Invoke::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Emit
+        let expected = "emit r0 as transfer;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Emit(Emit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bits;
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID},
+};
+
+/// An event emitted by the `emit` command in finalize, as (`program ID`, `name`, `value`).
+/// The name of an event is the name of the struct that the value is an instance of.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Event<N: Network> {
+    /// The program ID of the program that emitted the event.
+    program_id: ProgramID<N>,
+    /// The name of the event.
+    name: Identifier<N>,
+    /// The value of the event.
+    value: Plaintext<N>,
+}
+
+impl<N: Network> Event<N> {
+    /// Initializes a new event.
+    pub const fn new(program_id: ProgramID<N>, name: Identifier<N>, value: Plaintext<N>) -> Self {
+        Self { program_id, name, value }
+    }
+
+    /// Returns the program ID of the program that emitted the event.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the event.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the value of the event.
+    pub const fn value(&self) -> &Plaintext<N> {
+        &self.value
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a list of events.
+    pub(crate) fn sample_events() -> Vec<Event<CurrentNetwork>> {
+        vec![
+            Event::new(
+                ProgramID::from_str("token.aleo").unwrap(),
+                Identifier::from_str("transfer").unwrap(),
+                Plaintext::from_str("{ amount: 100u64, memo: 5field }").unwrap(),
+            ),
+            Event::new(
+                ProgramID::from_str("token.aleo").unwrap(),
+                Identifier::from_str("mint").unwrap(),
+                Plaintext::from_str("{ amount: 1u64, recipients: [1u8, 2u8, 3u8] }").unwrap(),
+            ),
+        ]
+    }
+}
@@ -15,29 +15,35 @@
 use console::network::prelude::*;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct FinalizeGlobalState {
+pub struct FinalizeGlobalState<N: Network> {
     /// The block round.
     block_round: u64,
     /// The block height.
     block_height: u32,
+    /// The block timestamp.
+    block_timestamp: i64,
     /// The block-specific random seed.
     random_seed: [u8; 32],
+    /// The ID of the transaction being finalized, if any.
+    transaction_id: Option<N::TransactionID>,
 }
 
-impl FinalizeGlobalState {
+impl<N: Network> FinalizeGlobalState<N> {
     /// Initializes a new genesis global state.
     #[inline]
-    pub fn new_genesis<N: Network>() -> Result<Self> {
+    pub fn new_genesis() -> Result<Self> {
         // Initialize the parameters.
         let block_round = 0;
         let block_height = 0;
+        let block_timestamp = N::GENESIS_TIMESTAMP;
         let block_cumulative_weight = 0;
         let block_cumulative_proof_target = 0;
         let previous_block_hash = N::BlockHash::default();
         // Return the new global state.
-        Self::new::<N>(
+        Self::new(
             block_round,
             block_height,
+            block_timestamp,
             block_cumulative_weight,
             block_cumulative_proof_target,
             previous_block_hash,
@@ -46,9 +52,10 @@ impl FinalizeGlobalState {
 
     /// Initializes a new global state from the given inputs.
     #[inline]
-    pub fn new<N: Network>(
+    pub fn new(
         block_round: u64,
         block_height: u32,
+        block_timestamp: i64,
         block_cumulative_weight: u128,
         block_cumulative_proof_target: u128,
         previous_block_hash: N::BlockHash,
@@ -71,13 +78,19 @@ impl FinalizeGlobalState {
         let mut random_seed = [0u8; 32];
         random_seed.copy_from_slice(&seed[..32]);
 
-        Ok(Self { block_round, block_height, random_seed })
+        Ok(Self { block_round, block_height, block_timestamp, random_seed, transaction_id: None })
     }
 
     /// Initializes a new global state.
     #[inline]
-    pub const fn from(block_round: u64, block_height: u32, random_seed: [u8; 32]) -> Self {
-        Self { block_round, block_height, random_seed }
+    pub const fn from(block_round: u64, block_height: u32, block_timestamp: i64, random_seed: [u8; 32]) -> Self {
+        Self { block_round, block_height, block_timestamp, random_seed, transaction_id: None }
+    }
+
+    /// Returns a copy of the global state for finalizing the given transaction.
+    #[inline]
+    pub fn with_transaction(self, transaction_id: N::TransactionID) -> Self {
+        Self { transaction_id: Some(transaction_id), ..self }
     }
 
     /// Returns the block round.
@@ -92,6 +105,24 @@ impl FinalizeGlobalState {
         self.block_height
     }
 
+    /// Returns the block timestamp.
+    #[inline]
+    pub const fn block_timestamp(&self) -> i64 {
+        self.block_timestamp
+    }
+
+    /// Returns the network ID.
+    #[inline]
+    pub const fn network_id(&self) -> u16 {
+        N::ID
+    }
+
+    /// Returns the ID of the transaction being finalized, if any.
+    #[inline]
+    pub const fn transaction_id(&self) -> Option<N::TransactionID> {
+        self.transaction_id
+    }
+
     /// Returns the random seed.
     #[inline]
     pub const fn random_seed(&self) -> &[u8; 32] {
@@ -35,11 +35,20 @@ pub enum Operand<N: Network> {
     /// Note: This variant is only accessible in the `function` scope.
     Signer,
     /// The operand is the caller address.
-    /// Note: This variant is only accessible in the `function` scope.
+    /// Note: In the `finalize` scope, this variant is only available if the caller is a program.
     Caller,
     /// The operand is the block height.
     /// Note: This variant is only accessible in the `finalize` scope.
     BlockHeight,
+    /// The operand is the block timestamp.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockTimestamp,
+    /// The operand is the network ID.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    NetworkID,
+    /// The operand is the ID of the enclosing transaction.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    TransactionID,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
@@ -14,12 +14,13 @@
 
 use crate::{
     traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Function,
     Opcode,
     Operand,
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Locator, Register, RegisterType, ValueType},
+    program::{Identifier, Locator, PlaintextType, Register, RegisterType, ValueType},
 };
 
 /// The operator references a function name or closure name.
@@ -29,13 +30,36 @@ pub enum CallOperator<N: Network> {
     Locator(Locator<N>),
     /// The reference to a local function or closure.
     Resource(Identifier<N>),
+    /// The reference to a function in the program whose address is in the register,
+    /// which must conform to the signature of the function in the locator.
+    ///
+    /// Note: The locator names a function in an imported program, not an `interface` declaration.
+    /// A dynamic call is restricted to functions that only take and return literals, and that neither
+    /// contain a finalize block nor call other functions. See `Call::check_dynamic_callee`.
+    Dynamic(Register<N>, Locator<N>),
 }
 
 impl<N: Network> Parser for CallOperator<N> {
     /// Parses a string into an operator.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
-        alt((map(Locator::parse, CallOperator::Locator), map(Identifier::parse, CallOperator::Resource)))(string)
+        /// Parses a dynamic operator from the string, i.e. `r0 as token.aleo/transfer`.
+        fn parse_dynamic<N: Network>(string: &str) -> ParserResult<CallOperator<N>> {
+            // Parse the register from the string.
+            let (string, register) = Register::parse(string)?;
+            // Parse the " as " from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            let (string, _) = tag("as")(string)?;
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the interface locator from the string.
+            let (string, locator) = Locator::parse(string)?;
+            // Return the operator.
+            Ok((string, CallOperator::Dynamic(register, locator)))
+        }
+
+        alt((parse_dynamic, map(Locator::parse, CallOperator::Locator), map(Identifier::parse, CallOperator::Resource)))(
+            string,
+        )
     }
 }
 
@@ -70,6 +94,7 @@ impl<N: Network> Display for CallOperator<N> {
         match self {
             CallOperator::Locator(locator) => Display::fmt(locator, f),
             CallOperator::Resource(resource) => Display::fmt(resource, f),
+            CallOperator::Dynamic(register, locator) => write!(f, "{register} as {locator}"),
         }
     }
 }
@@ -83,6 +108,7 @@ impl<N: Network> FromBytes for CallOperator<N> {
         match variant {
             0 => Ok(CallOperator::Locator(Locator::read_le(&mut reader)?)),
             1 => Ok(CallOperator::Resource(Identifier::read_le(&mut reader)?)),
+            2 => Ok(CallOperator::Dynamic(Register::read_le(&mut reader)?, Locator::read_le(&mut reader)?)),
             _ => Err(error("Failed to read CallOperator. Invalid variant.")),
         }
     }
@@ -104,6 +130,14 @@ impl<N: Network> ToBytes for CallOperator<N> {
                 // Write the resource.
                 resource.write_le(&mut writer)
             }
+            CallOperator::Dynamic(register, locator) => {
+                // Write the variant.
+                2u8.write_le(&mut writer)?;
+                // Write the register.
+                register.write_le(&mut writer)?;
+                // Write the interface locator.
+                locator.write_le(&mut writer)
+            }
         }
     }
 }
@@ -152,7 +186,7 @@ impl<N: Network> Call<N> {
     pub fn is_function_call(&self, stack: &impl StackProgram<N>) -> Result<bool> {
         match self.operator() {
             // Check if the locator is for a function.
-            CallOperator::Locator(locator) => {
+            CallOperator::Locator(locator) | CallOperator::Dynamic(_, locator) => {
                 // Retrieve the program.
                 let program = stack.get_external_program(locator.program_id())?;
                 // Check if the resource is a function.
@@ -163,6 +197,58 @@ impl<N: Network> Call<N> {
         }
     }
 
+    /// Ensures the given callee stack contains a function that conforms to the interface of a dynamic call.
+    /// A conforming function has the same name, input types, and output types as the interface function,
+    /// only takes and returns literals, and does not contain a finalize block or any function calls.
+    ///
+    /// Note: Callees that take or return records, or return futures (i.e. contain a finalize block),
+    /// such as a token `transfer`, are not supported. The circuit of the caller hashes the callee program ID
+    /// into record commitments and future IDs as a constant, whose number of bits depends on the program name,
+    /// so the verifying key that the caller synthesizes with the interface program at deployment would only
+    /// verify calls into the interface program. Literals are bound to the callee by its function ID instead,
+    /// which is a public input of the caller.
+    /// Nested calls are not supported, as the number of calls of the caller is fixed at deployment.
+    pub fn check_dynamic_callee(interface: &Function<N>, callee: &impl StackProgram<N>) -> Result<()> {
+        // Retrieve the function name.
+        let function_name = interface.name();
+        // Retrieve the function from the callee.
+        let function = callee.get_function_ref(function_name)?;
+        // Ensure the input and output types only contain literals.
+        for value_type in function.input_types().iter().chain(function.output_types().iter()) {
+            match value_type {
+                ValueType::Constant(PlaintextType::Literal(..))
+                | ValueType::Public(PlaintextType::Literal(..))
+                | ValueType::Private(PlaintextType::Literal(..)) => (),
+                _ => bail!("Dynamic call to '{}/{function_name}' must only use literals", callee.program_id()),
+            }
+        }
+        // Ensure the input types match the interface.
+        ensure!(
+            function.input_types() == interface.input_types(),
+            "The inputs of '{}/{function_name}' do not match the interface",
+            callee.program_id()
+        );
+        // Ensure the output types match the interface.
+        ensure!(
+            function.output_types() == interface.output_types(),
+            "The outputs of '{}/{function_name}' do not match the interface",
+            callee.program_id()
+        );
+        // Ensure the function does not contain a finalize block.
+        ensure!(
+            function.finalize_logic().is_none(),
+            "Dynamic call to '{}/{function_name}' cannot contain a finalize block",
+            callee.program_id()
+        );
+        // Ensure the function does not call other functions.
+        ensure!(
+            callee.get_number_of_calls(function_name)? == 1,
+            "Dynamic call to '{}/{function_name}' cannot call other functions",
+            callee.program_id()
+        );
+        Ok(())
+    }
+
     /// Evaluates the instruction.
     pub fn evaluate(&self, _stack: &impl StackProgram<N>, _registers: &mut impl RegistersLoad<N>) -> Result<()> {
         bail!("Forbidden operation: Evaluate cannot invoke a 'call' directly. Use 'call' in 'Stack' instead.")
@@ -197,7 +283,7 @@ impl<N: Network> Call<N> {
         // Retrieve the program and resource.
         let (is_external, program, resource) = match &self.operator {
             // Retrieve the program and resource from the locator.
-            CallOperator::Locator(locator) => {
+            CallOperator::Locator(locator) | CallOperator::Dynamic(_, locator) => {
                 (true, stack.get_external_program(locator.program_id())?, locator.resource())
             }
             CallOperator::Resource(resource) => {
@@ -463,6 +549,8 @@ mod tests {
         "call foo r0 r1 into r2 r3",
         "call foo r0 r1 r2 into r3 r4",
         "call foo r0 r1 r2 into r3 r4 r5",
+        "call r0 as token.aleo/transfer r1 r2",
+        "call r0 as token.aleo/transfer r1 r2 into r3",
     ];
 
     fn check_parser(
@@ -526,7 +614,17 @@ mod tests {
             vec![Register::Locator(0)],
         );
 
-        check_parser("call noop", CallOperator::from_str("noop").unwrap(), vec![], vec![])
+        check_parser("call noop", CallOperator::from_str("noop").unwrap(), vec![], vec![]);
+
+        check_parser(
+            "call r0 as token.aleo/transfer r1 100u64 into r2",
+            CallOperator::Dynamic(Register::Locator(0), Locator::from_str("token.aleo/transfer").unwrap()),
+            vec![
+                Operand::Register(Register::Locator(1)),
+                Operand::Literal(Literal::U64(U64::from_str("100u64").unwrap())),
+            ],
+            vec![Register::Locator(2)],
+        );
     }
 
     #[test]
@@ -109,6 +109,7 @@ fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) ->
         destination_type,
         PlaintextType::Literal(LiteralType::Boolean)
             | PlaintextType::Literal(LiteralType::String)
+            | PlaintextType::Literal(LiteralType::Decimal)
             | PlaintextType::Struct(..)
             | PlaintextType::Array(..)
     )
@@ -417,11 +418,11 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         let (string, destination_type) = PlaintextType::parse(string)?;
         // Ensure the destination type is allowed.
         match destination_type {
-            PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String) => {
-                map_res(fail, |_: ParserResult<Self>| {
-                    Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
-                })(string)
-            }
+            PlaintextType::Literal(LiteralType::Boolean)
+            | PlaintextType::Literal(LiteralType::String)
+            | PlaintextType::Literal(LiteralType::Decimal) => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
+            })(string),
             _ => Ok((string, Self { operands, destination, destination_type })),
         }
     }
@@ -357,6 +357,7 @@ mod tests {
                 console::program::Literal::Scalar(console::types::Scalar::rand($rng)),
                 console::program::Literal::sample(console::program::LiteralType::Signature, $rng),
                 console::program::Literal::String(console::types::StringType::rand($rng)),
+                console::program::Literal::Decimal(console::types::Decimal::rand($rng)),
             ]
         };
     }
@@ -762,6 +763,13 @@ mod tests {
                                     _ => panic!("Unsupported test enforcement for '{}'", <$operation as $crate::Operation<_, _, _, 2>>::OPCODE),
                                 }
                             };
+                            ("ensure decimal overflows halt") => {
+                                match *<$operation as $crate::Operation<_, _, _, 2>>::OPCODE {
+                                    "div" => should_succeed &= a.checked_div(&b).is_some(),
+                                    "mul" => should_succeed &= a.checked_mul(&b).is_some(),
+                                    _ => panic!("Unsupported test enforcement for '{}'", <$operation as $crate::Operation<_, _, _, 2>>::OPCODE),
+                                }
+                            };
                             ("ensure exponentiation overflows halt") => {
                                 should_succeed &= (*a).checked_pow((*b) as u32).is_some()
                             };
@@ -73,6 +73,7 @@ crate::operation!(
         I32 => I32 ("ensure overflows halt"),
         I64 => I64 ("ensure overflows halt"),
         I128 => I128 ("ensure overflows halt"),
+        Decimal => Decimal ("ensure overflows halt"),
     }
 );
 
@@ -107,6 +108,7 @@ crate::operation!(
         (U64, U64) => U64 ("ensure overflows halt"),
         (U128, U128) => U128 ("ensure overflows halt"),
         (Scalar, Scalar) => Scalar,
+        (Decimal, Decimal) => Decimal ("ensure overflows halt"),
     }
 );
 
@@ -163,6 +165,7 @@ crate::operation!(
         (U32, U32) => U32 ("ensure divide by zero halts"),
         (U64, U64) => U64 ("ensure divide by zero halts"),
         (U128, U128) => U128 ("ensure divide by zero halts"),
+        (Decimal, Decimal) => Decimal ("ensure decimal overflows halt", "ensure divide by zero halts"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -213,6 +216,7 @@ crate::operation!(
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
         (Scalar, Scalar) => Boolean,
+        (Decimal, Decimal) => Boolean,
     }
 );
 
@@ -234,6 +238,7 @@ crate::operation!(
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
         (Scalar, Scalar) => Boolean,
+        (Decimal, Decimal) => Boolean,
     }
 );
 
@@ -264,6 +269,7 @@ crate::operation!(
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
         (Scalar, Scalar) => Boolean,
+        (Decimal, Decimal) => Boolean,
     }
 );
 
@@ -285,6 +291,7 @@ crate::operation!(
         (U64, U64) => Boolean,
         (U128, U128) => Boolean,
         (Scalar, Scalar) => Boolean,
+        (Decimal, Decimal) => Boolean,
     }
 );
 
@@ -319,6 +326,7 @@ crate::operation!(
         (U32, U32) => U32 ("ensure overflows halt"),
         (U64, U64) => U64 ("ensure overflows halt"),
         (U128, U128) => U128 ("ensure overflows halt"),
+        (Decimal, Decimal) => Decimal ("ensure decimal overflows halt"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -362,6 +370,7 @@ crate::operation!(
         I32 => I32 ("ensure overflows halt"),
         I64 => I64 ("ensure overflows halt"),
         I128 => I128 ("ensure overflows halt"),
+        Decimal => Decimal ("ensure overflows halt"),
     }
 );
 
@@ -712,6 +721,7 @@ crate::operation!(
         (U32, U32) => U32 ("ensure overflows halt"),
         (U64, U64) => U64 ("ensure overflows halt"),
         (U128, U128) => U128 ("ensure overflows halt"),
+        (Decimal, Decimal) => Decimal ("ensure overflows halt"),
         // (Scalar, Scalar) => Scalar,
     }
 );
@@ -755,6 +765,7 @@ crate::operation!(
         (Boolean, U128, U128) => U128,
         (Boolean, Scalar, Scalar) => Scalar,
         (Boolean, Signature, Signature) => Signature,
+        (Boolean, Decimal, Decimal) => Decimal,
         // (Boolean, StringType, StringType) => StringType,
     }
 );
@@ -27,6 +27,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
             R(RecordType<N>),
             C(ClosureCore<N, Instruction>),
             F(FunctionCore<N, Instruction, Command>),
+            T(Interface<N>),
+            U(RoutineCore<N, Command>),
+        }
+
+        /// Parses an interface locator from the string.
+        fn parse_interface<N: Network>(string: &str) -> ParserResult<Locator<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the interface locator from the string.
+            Locator::parse(string)
         }
 
         // Parse the imports from the string.
@@ -41,6 +51,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         let (string, id) = ProgramID::parse(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Optionally parse the "implements" and the interfaces from the string.
+        let (string, implements) = match opt(tag("implements"))(string)? {
+            // If the "implements" was not parsed, return an empty vector of interfaces.
+            (string, None) => (string, vec![]),
+            // If the "implements" was parsed, parse the interfaces from the string.
+            (string, Some(_)) => many1(complete(parse_interface))(string)?,
+        };
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
 
@@ -51,6 +70,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
             map(RecordType::parse, |record| P::<N, Instruction, Command>::R(record)),
             map(ClosureCore::parse, |closure| P::<N, Instruction, Command>::C(closure)),
             map(FunctionCore::parse, |function| P::<N, Instruction, Command>::F(function)),
+            map(Interface::parse, |interface| P::<N, Instruction, Command>::T(interface)),
+            map(RoutineCore::parse, |routine| P::<N, Instruction, Command>::U(routine)),
         )))(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
@@ -73,6 +94,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     P::R(record) => program.add_record(record.clone()),
                     P::C(closure) => program.add_closure(closure.clone()),
                     P::F(function) => program.add_function(function.clone()),
+                    P::T(interface) => program.add_interface(interface.clone()),
+                    P::U(routine) => program.add_routine(routine.clone()),
                 };
 
                 match result {
@@ -93,6 +116,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     }
                 }
             }
+            // Lastly, add the implemented interfaces (if any) to the program.
+            for locator in implements.iter() {
+                match program.add_implements(*locator) {
+                    Ok(_) => (),
+                    Err(error) => {
+                        eprintln!("{error}");
+                        return Err(error);
+                    }
+                }
+            }
             // Output the program.
             Ok::<_, Error>(program)
         })(string)
@@ -147,7 +180,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
         }
 
         // Print the program name.
-        program += &format!("{} {};\n\n", Self::type_name(), self.id);
+        program += &format!("{} {}", Self::type_name(), self.id);
+        // Print the implemented interfaces.
+        if !self.implements.is_empty() {
+            program += " implements";
+            for locator in &self.implements {
+                program += &format!(" {locator}");
+            }
+        }
+        program += ";\n\n";
 
         for (identifier, definition) in self.identifiers.iter() {
             match definition {
@@ -171,6 +212,14 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
                     Some(function) => program.push_str(&format!("{function}\n\n")),
                     None => return Err(fmt::Error),
                 },
+                ProgramDefinition::Interface => match self.interfaces.get(identifier) {
+                    Some(interface) => program.push_str(&format!("{interface}\n\n")),
+                    None => return Err(fmt::Error),
+                },
+                ProgramDefinition::Routine => match self.routines.get(identifier) {
+                    Some(routine) => program.push_str(&format!("{routine}\n\n")),
+                    None => return Err(fmt::Error),
+                },
             }
         }
         // Remove the last newline.
@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod output;
+use output::*;
+
+mod bytes;
+mod parse;
+
+use crate::{finalize::input::Input, traits::CommandTrait, FinalizeCore};
+use console::{
+    network::prelude::*,
+    program::{FinalizeType, Identifier},
+};
+
+use indexmap::IndexSet;
+use std::collections::HashMap;
+
+/// A routine is a helper that is invoked from a finalize scope, with the `invoke` command.
+/// A routine takes plaintext inputs, may read and write the mappings of its program, and returns plaintext outputs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RoutineCore<N: Network, Command: CommandTrait<N>> {
+    /// The body of the routine, consisting of the name, input statements, and commands.
+    body: FinalizeCore<N, Command>,
+    /// The output statements, in order of the desired output.
+    outputs: IndexSet<Output<N>>,
+}
+
+impl<N: Network, Command: CommandTrait<N>> RoutineCore<N, Command> {
+    /// Initializes a new routine with the given name.
+    pub fn new(name: Identifier<N>) -> Self {
+        Self { body: FinalizeCore::new(name), outputs: IndexSet::new() }
+    }
+
+    /// Returns the name of the routine.
+    pub const fn name(&self) -> &Identifier<N> {
+        self.body.name()
+    }
+
+    /// Returns the body of the routine, which is executed as finalize logic.
+    pub const fn body(&self) -> &FinalizeCore<N, Command> {
+        &self.body
+    }
+
+    /// Returns the routine inputs.
+    pub const fn inputs(&self) -> &IndexSet<Input<N>> {
+        self.body.inputs()
+    }
+
+    /// Returns the routine input types.
+    pub fn input_types(&self) -> Vec<FinalizeType<N>> {
+        self.body.input_types()
+    }
+
+    /// Returns the routine commands.
+    pub fn commands(&self) -> &[Command] {
+        self.body.commands()
+    }
+
+    /// Returns the number of write commands.
+    pub const fn num_writes(&self) -> u16 {
+        self.body.num_writes()
+    }
+
+    /// Returns the mapping of `Position`s to their index in `commands`.
+    pub const fn positions(&self) -> &HashMap<Identifier<N>, usize> {
+        self.body.positions()
+    }
+
+    /// Returns the routine outputs.
+    pub const fn outputs(&self) -> &IndexSet<Output<N>> {
+        &self.outputs
+    }
+
+    /// Returns the routine output types.
+    pub fn output_types(&self) -> Vec<FinalizeType<N>> {
+        self.outputs.iter().map(|output| output.finalize_type()).cloned().collect()
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> RoutineCore<N, Command> {
+    /// Adds the input statement to the routine.
+    ///
+    /// # Errors
+    /// This method will halt if there are commands or output statements already.
+    /// This method will halt if the input is not a plaintext.
+    /// This method will halt if the maximum number of inputs has been reached.
+    /// This method will halt if the input statement was previously added.
+    #[inline]
+    fn add_input(&mut self, input: Input<N>) -> Result<()> {
+        // Ensure there are no output statements in memory.
+        ensure!(self.outputs.is_empty(), "Cannot add inputs after outputs have been added");
+        // Ensure the input is a plaintext.
+        ensure!(matches!(input.finalize_type(), FinalizeType::Plaintext(..)), "A routine input must be a plaintext");
+        // Insert the input statement.
+        self.body.add_input(input)
+    }
+
+    /// Adds the given command to the routine.
+    ///
+    /// # Errors
+    /// This method will halt if there are output statements already.
+    /// This method will halt if the command is an `await` or `invoke` command.
+    /// This method will halt if the command is not a valid finalize command.
+    #[inline]
+    pub fn add_command(&mut self, command: Command) -> Result<()> {
+        // Ensure there are no output statements in memory.
+        ensure!(self.outputs.is_empty(), "Cannot add commands after outputs have been added");
+        // Ensure the command is not an await command.
+        ensure!(!command.is_await(), "Forbidden operation: A routine cannot 'await'");
+        // Ensure the command does not invoke a routine.
+        ensure!(command.invoked_routine().is_none(), "Forbidden operation: A routine cannot 'invoke' a routine");
+        // Insert the command.
+        self.body.add_command(command)
+    }
+
+    /// Adds the output statement to the routine.
+    ///
+    /// # Errors
+    /// This method will halt if the output is not a plaintext.
+    /// This method will halt if the maximum number of outputs has been reached.
+    #[inline]
+    fn add_output(&mut self, output: Output<N>) -> Result<()> {
+        // Ensure the maximum number of outputs has not been exceeded.
+        ensure!(self.outputs.len() < N::MAX_OUTPUTS, "Cannot add more than {} outputs", N::MAX_OUTPUTS);
+        // Ensure the output is a plaintext.
+        ensure!(matches!(output.finalize_type(), FinalizeType::Plaintext(..)), "A routine output must be a plaintext");
+        // Insert the output statement.
+        self.outputs.insert(output);
+        Ok(())
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> TypeName for RoutineCore<N, Command> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "routine"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Command, Routine};
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_add_input() {
+        // Initialize a new routine instance.
+        let name = Identifier::from_str("routine_core_test").unwrap();
+        let mut routine = Routine::<CurrentNetwork>::new(name);
+
+        // Ensure that a plaintext input can be added.
+        let input = Input::<CurrentNetwork>::from_str("input r0 as field.public;").unwrap();
+        assert!(routine.add_input(input.clone()).is_ok());
+        // Ensure that adding a duplicate input will fail.
+        assert!(routine.add_input(input).is_err());
+        // Ensure that adding a future input will fail.
+        let input = Input::<CurrentNetwork>::from_str("input r1 as token.aleo/mint.future;").unwrap();
+        assert!(routine.add_input(input).is_err());
+    }
+
+    #[test]
+    fn test_add_command() {
+        // Initialize a new routine instance.
+        let name = Identifier::from_str("routine_core_test").unwrap();
+        let mut routine = Routine::<CurrentNetwork>::new(name);
+
+        // Ensure that a command can be added.
+        let command = Command::<CurrentNetwork>::from_str("add r0 r1 into r2;").unwrap();
+        assert!(routine.add_command(command).is_ok());
+        // Ensure that a mapping write can be added.
+        let command = Command::<CurrentNetwork>::from_str("set r2 into object[r0];").unwrap();
+        assert!(routine.add_command(command).is_ok());
+
+        // Ensure that adding an await command will fail.
+        let command = Command::<CurrentNetwork>::from_str("await r0;").unwrap();
+        assert!(routine.add_command(command).is_err());
+        // Ensure that adding an invoke command will fail.
+        let command = Command::<CurrentNetwork>::from_str("invoke routine_core_test r0 r1 into r3;").unwrap();
+        assert!(routine.add_command(command).is_err());
+
+        // Ensure that adding a command after an output will fail.
+        let output = Output::<CurrentNetwork>::from_str("output r2 as field.public;").unwrap();
+        assert!(routine.add_output(output).is_ok());
+        let command = Command::<CurrentNetwork>::from_str("add r0 r1 into r3;").unwrap();
+        assert!(routine.add_command(command).is_err());
+    }
+
+    #[test]
+    fn test_add_output() {
+        // Initialize a new routine instance.
+        let name = Identifier::from_str("routine_core_test").unwrap();
+        let mut routine = Routine::<CurrentNetwork>::new(name);
+
+        // Ensure that a plaintext output can be added.
+        let output = Output::<CurrentNetwork>::from_str("output r0 as field.public;").unwrap();
+        assert!(routine.add_output(output).is_ok());
+        // Ensure that adding a future output will fail.
+        let output = Output::<CurrentNetwork>::from_str("output r1 as token.aleo/mint.future;").unwrap();
+        assert!(routine.add_output(output).is_err());
+
+        // Ensure that adding more than the maximum number of outputs will fail.
+        for i in 1..CurrentNetwork::MAX_OUTPUTS * 2 {
+            let output = Output::<CurrentNetwork>::from_str(&format!("output r{i} as field.public;")).unwrap();
+
+            match routine.outputs.len() < CurrentNetwork::MAX_OUTPUTS {
+                true => assert!(routine.add_output(output).is_ok()),
+                false => assert!(routine.add_output(output).is_err()),
+            }
+        }
+    }
+}
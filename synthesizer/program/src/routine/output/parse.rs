@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Parser for Output<N> {
+    /// Parses a string into an output statement.
+    /// The output statement is of the form `output {operand} as {finalize_type};`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the output keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the finalize type from the string.
+        let (string, finalize_type) = FinalizeType::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the output statement.
+        Ok((string, Self { operand, finalize_type }))
+    }
+}
+
+impl<N: Network> FromStr for Output<N> {
+    type Err = Error;
+
+    /// Parses a string into an output statement.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Output<N> {
+    /// Prints the output as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Output<N> {
+    /// Prints the output statement as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{type_} {operand} as {finalize_type};",
+            type_ = Self::type_name(),
+            operand = self.operand,
+            finalize_type = self.finalize_type
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        network::Testnet3,
+        program::{Literal, Register, U8},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_output_parse() -> Result<()> {
+        // Register
+        let output = Output::<CurrentNetwork>::parse("output r0 as field.public;").unwrap().1;
+        assert_eq!(output.operand(), &Operand::Register(Register::<CurrentNetwork>::Locator(0)));
+        assert_eq!(output.finalize_type(), &FinalizeType::<CurrentNetwork>::from_str("field.public")?);
+
+        // Literal
+        let output = Output::<CurrentNetwork>::parse("output 0u8 as u8.public;").unwrap().1;
+        assert_eq!(output.operand(), &Operand::Literal(Literal::<CurrentNetwork>::U8(U8::new(0))));
+        assert_eq!(output.finalize_type(), &FinalizeType::<CurrentNetwork>::from_str("u8.public")?);
+
+        // Struct
+        let output = Output::<CurrentNetwork>::parse("output r1 as signature.public;").unwrap().1;
+        assert_eq!(output.operand(), &Operand::Register(Register::<CurrentNetwork>::Locator(1)));
+        assert_eq!(output.finalize_type(), &FinalizeType::<CurrentNetwork>::from_str("signature.public")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_display() {
+        for expected in ["output r0 as field.public;", "output 0u8 as u8.public;", "output r1 as signature.public;"] {
+            let output = Output::<CurrentNetwork>::parse(expected).unwrap().1;
+            assert_eq!(format!("{output}"), expected);
+        }
+    }
+}
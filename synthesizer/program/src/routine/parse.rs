@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Command: CommandTrait<N>> Parser for RoutineCore<N, Command> {
+    /// Parses a string into a routine.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'routine' keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the routine name from the string.
+        let (string, name) = Identifier::<N>::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+
+        // Parse the inputs from the string.
+        let (string, inputs) = many0(Input::parse)(string)?;
+        // Parse the commands from the string.
+        let (string, commands) = many1(Command::parse)(string)?;
+        // Parse the outputs from the string.
+        let (string, outputs) = many0(Output::parse)(string)?;
+
+        map_res(take(0usize), move |_| {
+            // Initialize a new routine.
+            let mut routine = Self::new(name);
+            if let Err(error) = inputs.iter().cloned().try_for_each(|input| routine.add_input(input)) {
+                eprintln!("{error}");
+                return Err(error);
+            }
+            if let Err(error) = commands.iter().cloned().try_for_each(|command| routine.add_command(command)) {
+                eprintln!("{error}");
+                return Err(error);
+            }
+            if let Err(error) = outputs.iter().cloned().try_for_each(|output| routine.add_output(output)) {
+                eprintln!("{error}");
+                return Err(error);
+            }
+            Ok::<_, Error>(routine)
+        })(string)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> FromStr for RoutineCore<N, Command> {
+    type Err = Error;
+
+    /// Returns a routine from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> Debug for RoutineCore<N, Command> {
+    /// Prints the routine as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> Display for RoutineCore<N, Command> {
+    /// Prints the routine as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the routine to a string.
+        write!(f, "{} {}:", Self::type_name(), self.name())?;
+        self.inputs().iter().try_for_each(|input| write!(f, "\n    {input}"))?;
+        self.commands().iter().try_for_each(|command| write!(f, "\n    {command}"))?;
+        self.outputs.iter().try_for_each(|output| write!(f, "\n    {output}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Routine;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_routine_parse() {
+        let routine = Routine::<CurrentNetwork>::parse(
+            r"
+routine charge_fee:
+    input r0 as address.public;
+    input r1 as u64.public;
+    div r1 100u64 into r2;
+    get.or_use fees[r0] 0u64 into r3;
+    add r3 r2 into r4;
+    set r4 into fees[r0];
+    sub r1 r2 into r5;
+    output r5 as u64.public;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("charge_fee", routine.name().to_string());
+        assert_eq!(2, routine.inputs().len());
+        assert_eq!(5, routine.commands().len());
+        assert_eq!(1, routine.outputs().len());
+        assert_eq!(1, routine.num_writes());
+
+        // Routine with 0 inputs and 0 outputs.
+        let routine = Routine::<CurrentNetwork>::parse(
+            r"
+routine reset:
+    set 0u64 into counter[0u8];",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("reset", routine.name().to_string());
+        assert_eq!(0, routine.inputs().len());
+        assert_eq!(1, routine.commands().len());
+        assert_eq!(0, routine.outputs().len());
+
+        // Ensure a routine that awaits a future fails to parse.
+        let routine = Routine::<CurrentNetwork>::parse(
+            r"
+routine wait:
+    input r0 as token.aleo/mint.future;
+    await r0;",
+        );
+        assert!(routine.is_err());
+    }
+
+    #[test]
+    fn test_routine_display() {
+        let expected = r"routine charge_fee:
+    input r0 as address.public;
+    input r1 as u64.public;
+    div r1 100u64 into r2;
+    sub r1 r2 into r3;
+    output r2 as u64.public;
+    output r3 as u64.public;";
+        let routine = Routine::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{routine}"));
+    }
+}
@@ -25,6 +25,12 @@ pub trait CommandTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     fn branch_to(&self) -> Option<&Identifier<N>>;
     /// Returns the position name, if the command is a position command.
     fn position(&self) -> Option<&Identifier<N>>;
+    /// Returns the maximum number of iterations, if the command is a loop command.
+    fn loop_bound(&self) -> Option<u32>;
+    /// Returns the name of the invoked routine, if the command is an invoke command.
+    fn invoked_routine(&self) -> Option<&Identifier<N>>;
+    /// Returns `true` if the command is an await command.
+    fn is_await(&self) -> bool;
     /// Returns `true` if the command is a call instruction.
     fn is_call(&self) -> bool;
     /// Returns `true` if the command is a cast to record instruction.
@@ -39,6 +39,19 @@ pub trait FinalizeStoreTrait<N: Network> {
         key: &Plaintext<N>,
     ) -> Result<Option<Value<N>>>;
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize>;
+
+    /// Returns the speculative entry at the given `index` for the given `program ID` and `mapping name`,
+    /// where the entries are ordered by insertion, and removing an entry moves the last entry into its position.
+    /// If the `index` is out of bounds, the method returns `None`.
+    fn get_entry_at_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        index: usize,
+    ) -> Result<Option<(Plaintext<N>, Value<N>)>>;
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -72,6 +72,9 @@ pub trait StackProgram<N: Network> {
     /// Returns the external program for the given program ID.
     fn get_external_program(&self, program_id: &ProgramID<N>) -> Result<&Program<N>>;
 
+    /// Returns the stack of the program with the given address, for a dynamic call.
+    fn get_dynamic_stack(&self, program_address: &Address<N>) -> Result<Arc<Self>>;
+
     /// Returns `true` if the stack contains the external record.
     fn get_external_record(&self, locator: &Locator<N>) -> Result<&RecordType<N>>;
 
@@ -87,7 +90,7 @@ pub trait StackProgram<N: Network> {
 
 pub trait FinalizeRegistersState<N: Network> {
     /// Returns the global state for the finalize scope.
-    fn state(&self) -> &FinalizeGlobalState;
+    fn state(&self) -> &FinalizeGlobalState<N>;
 
     /// Returns the transition ID for the finalize scope.
     fn transition_id(&self) -> &N::TransitionID;
@@ -68,9 +68,10 @@ pub fn sample_finalize_registers(
 ) -> Result<FinalizeRegisters<CurrentNetwork>> {
     // Initialize the registers.
     let mut finalize_registers = FinalizeRegisters::<CurrentNetwork>::new(
-        FinalizeGlobalState::from(1, 1, [0; 32]),
+        FinalizeGlobalState::from(1, 1, 1, [0; 32]),
         <CurrentNetwork as Network>::TransitionID::default(),
         *function_name,
+        None,
         stack.get_finalize_types(function_name)?.clone(),
     );
 
@@ -309,6 +309,7 @@ fn test_hash_ped128_is_consistent() {
                     Literal::U16(console::types::U16::rand(&mut rng)),
                     Literal::U32(console::types::U32::rand(&mut rng)),
                     Literal::U64(console::types::U64::rand(&mut rng)),
+                    Literal::Decimal(console::types::Decimal::rand(&mut rng)),
                 ];
                 for literal in literals.iter() {
                     for mode in modes.iter() {
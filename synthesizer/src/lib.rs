@@ -28,7 +28,7 @@ pub use synthesizer_program as program;
 pub use synthesizer_snark as snark;
 
 #[cfg(feature = "process")]
-pub use crate::process::{Authorization, CallMetrics, Process, Stack, Trace};
+pub use crate::process::{Authorization, AuthorizationBundle, CallMetrics, Process, Stack, Trace};
 #[cfg(feature = "program")]
 pub use crate::program::{Closure, Command, Finalize, Function, Instruction, Program};
 
@@ -0,0 +1,269 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a balance attestation at the latest state root, proving the given unspent `credits.aleo` records,
+    /// owned by the private key, total at least `threshold` microcredits.
+    #[inline]
+    pub fn prove_balance_attestation<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: Vec<Record<N, Plaintext<N>>>,
+        threshold: u64,
+        rng: &mut R,
+    ) -> Result<BalanceAttestation<N>> {
+        let timer = timer!("VM::prove_balance_attestation");
+
+        // Prepare the program ID and record name of `credits.aleo` records.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let record_name = Identifier::from_str("credits")?;
+
+        // Retrieve the state path of each record, ensuring the record is unspent.
+        let state_paths = records
+            .iter()
+            .map(|record| {
+                let commitment = record.to_commitment(&program_id, &record_name)?;
+                let serial_number = Record::<N, Plaintext<N>>::serial_number(*private_key, commitment)?;
+                ensure!(
+                    !self.transition_store().contains_serial_number(&serial_number)?,
+                    "The record with commitment '{commitment}' was already spent"
+                );
+                self.block_store().get_state_path_for_commitment(&commitment)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Retrieve the state paths");
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the records and their state paths.
+                let records = cast_ref!(records as Vec<Record<$network, Plaintext<$network>>>)
+                    .iter()
+                    .cloned()
+                    .zip_eq(cast_ref!(state_paths as Vec<StatePath<$network>>).iter().cloned())
+                    .collect();
+                // Compute the balance attestation.
+                let attestation = $process.prove_balance_attestation::<$aleo, _>(
+                    cast_ref!(&private_key as PrivateKey<$network>),
+                    records,
+                    threshold,
+                    rng,
+                )?;
+                // Prepare the balance attestation.
+                Ok(cast_ref!(attestation as BalanceAttestation<N>).clone())
+            }};
+        }
+
+        // Compute the balance attestation.
+        let result = process!(self, logic);
+        finish!(timer, "Compute the balance attestation");
+        result
+    }
+
+    /// Checks that the given balance attestation proves the address owned unspent records totalling at least
+    /// `threshold` microcredits, at the given state root in this ledger. On failure, returns an error.
+    ///
+    /// A record spent after the state root does not invalidate the attestation, as it was unspent at the state root.
+    pub fn check_balance_attestation(
+        &self,
+        address: &Address<N>,
+        threshold: u64,
+        state_root: N::StateRoot,
+        attestation: &BalanceAttestation<N>,
+    ) -> Result<()> {
+        let timer = timer!("VM::check_balance_attestation");
+
+        // Retrieve the block height of the state root.
+        let Some(root_height) = self.block_store().find_block_height_from_state_root(state_root)? else {
+            bail!("The balance attestation is anchored to an unknown state root '{state_root}'")
+        };
+
+        // Ensure each record was unspent at the state root.
+        for serial_number in attestation.serial_numbers() {
+            // Skip the record if it is unspent.
+            if !self.transition_store().contains_serial_number(serial_number)? {
+                continue;
+            }
+            // Retrieve the block height that the record was spent at.
+            let transition_id = self.transition_store().find_transition_id(serial_number)?;
+            let Some(transaction_id) =
+                self.transaction_store().find_transaction_id_from_transition_id(&transition_id)?
+            else {
+                bail!("Missing the transaction for transition '{transition_id}'")
+            };
+            let Some(block_hash) = self.block_store().find_block_hash(&transaction_id)? else {
+                bail!("Missing the block for transaction '{transaction_id}'")
+            };
+            let Some(spent_height) = self.block_store().get_block_height(&block_hash)? else {
+                bail!("Missing the block height for block '{block_hash}'")
+            };
+            // Ensure the record was spent after the state root.
+            ensure!(
+                spent_height > root_height,
+                "The serial number '{serial_number}' in the balance attestation was spent at block {spent_height}, \
+                 at or before the state root (block {root_height})"
+            );
+        }
+        lap!(timer, "Check the records were unspent");
+
+        // Prepare the state root.
+        let state_root = *state_root;
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Verify the balance attestation.
+                $process.verify_balance_attestation::<$aleo>(
+                    cast_ref!(&address as Address<$network>),
+                    threshold,
+                    (*cast_ref!(state_root as Field<$network>)).into(),
+                    cast_ref!(&attestation as BalanceAttestation<$network>),
+                )
+            }};
+        }
+
+        // Verify the balance attestation.
+        let result = process!(self, logic);
+        finish!(timer, "Verify the balance attestation");
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::ViewKey, network::Testnet3, program::Entry};
+    use ledger_block::Transition;
+    use ledger_store::helpers::memory::ConsensusMemory;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_balance_attestation() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis_root = vm.block_store().current_state_root();
+
+        // Fetch the unspent records.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record.decrypt(&caller_view_key).unwrap())
+            .take(2)
+            .collect::<Vec<_>>();
+        let microcredits = Identifier::from_str("microcredits").unwrap();
+        let balance = records
+            .iter()
+            .map(|record| match record.data().get(&microcredits) {
+                Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => **amount,
+                _ => panic!("Expected a private microcredits entry"),
+            })
+            .sum::<u64>();
+
+        // Prove the balance attestation.
+        let attestation = vm.prove_balance_attestation(&caller_private_key, records.clone(), balance, rng).unwrap();
+        // Ensure the attestation is valid.
+        vm.check_balance_attestation(&address, balance, genesis_root, &attestation).unwrap();
+        // Ensure the attestation is invalid for a higher threshold.
+        assert!(vm.check_balance_attestation(&address, balance + 1, genesis_root, &attestation).is_err());
+        // Ensure the attestation is invalid for an unknown state root.
+        let unknown_root = Field::<CurrentNetwork>::rand(rng).into();
+        assert!(vm.check_balance_attestation(&address, balance, unknown_root, &attestation).is_err());
+
+        // Spend the first record.
+        let inputs = [
+            Value::<CurrentNetwork>::Record(records[0].clone()),
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the attestation remains valid, as the record was unspent at the state root.
+        vm.check_balance_attestation(&address, balance, genesis_root, &attestation).unwrap();
+        // Ensure a spent record cannot be attested to.
+        assert!(vm.prove_balance_attestation(&caller_private_key, records, 1, rng).is_err());
+    }
+
+    #[test]
+    fn test_balance_attestation_with_pruned_blocks() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize a VM that keeps the latest block in full, along with the blocks since the previous block.
+        let storage = ConsensusMemory::<CurrentNetwork>::open_with_pruning(None, 1).unwrap();
+        let vm = VM::from(ConsensusStore::from(storage)).unwrap();
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        vm.add_next_block(&genesis).unwrap();
+        let genesis_root = vm.block_store().current_state_root();
+
+        // Fetch an unspent record.
+        let record = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record.decrypt(&caller_view_key).unwrap())
+            .next()
+            .unwrap();
+        let microcredits = Identifier::from_str("microcredits").unwrap();
+        let balance = match record.data().get(&microcredits) {
+            Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => **amount,
+            _ => panic!("Expected a private microcredits entry"),
+        };
+        // Prove the balance attestation.
+        let attestation = vm.prove_balance_attestation(&caller_private_key, vec![record.clone()], balance, rng).unwrap();
+
+        // Spend the record.
+        let inputs = [
+            Value::<CurrentNetwork>::Record(record),
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_private"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        let spent_root = vm.block_store().current_state_root();
+
+        // Add blocks until the block that spent the record is pruned.
+        for _ in 0..2 {
+            let next_block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[], rng).unwrap();
+            vm.add_next_block(&next_block).unwrap();
+        }
+        assert!(vm.block_store().is_block_pruned(&block.hash()).unwrap());
+
+        // Ensure the attestation remains valid, as the record was unspent at the state root.
+        vm.check_balance_attestation(&address, balance, genesis_root, &attestation).unwrap();
+        // Ensure the attestation is invalid at a state root after the record was spent.
+        let error = vm.check_balance_attestation(&address, balance, spent_root, &attestation).unwrap_err();
+        assert!(error.to_string().contains("was spent at block 1"), "{error}");
+    }
+}
@@ -24,6 +24,38 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
         rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_expiration(private_key, program_id, function_name, inputs, None, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs,
+    /// which expires after the given block height, if any.
+    #[inline]
+    pub fn authorize_with_expiration<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
+        expiration_height: Option<u32>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_memo(private_key, program_id, function_name, inputs, expiration_height, None, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs,
+    /// which expires after the given block height, if any, and carries the given memo to its recipient, if any.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn authorize_with_memo<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
+        expiration_height: Option<u32>,
+        memo: Option<(Plaintext<N>, Address<N>)>,
+        rng: &mut R,
     ) -> Result<Authorization<N>> {
         let timer = timer!("VM::authorize");
 
@@ -44,7 +76,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         lap!(timer, "Prepare inputs");
 
         // Authorize the call.
-        let result = self.authorize_raw(private_key, program_id, function_name, inputs, rng);
+        let result = self.authorize_raw(private_key, program_id, function_name, inputs, expiration_height, memo, rng);
         finish!(timer, "Authorize the call");
         result
     }
@@ -120,22 +152,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Authorizes a call to the program function for the given inputs.
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     fn authorize_raw<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
         program_id: ProgramID<N>,
         function_name: Identifier<N>,
         inputs: Vec<Value<N>>,
+        expiration_height: Option<u32>,
+        memo: Option<(Plaintext<N>, Address<N>)>,
         rng: &mut R,
     ) -> Result<Authorization<N>> {
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the memo.
+                let memo = match &memo {
+                    Some((plaintext, address)) => Some((
+                        cast_ref!(&plaintext as Plaintext<$network>).clone(),
+                        *cast_ref!(&address as Address<$network>),
+                    )),
+                    None => None,
+                };
                 // Compute the authorization.
-                let authorization = $process.authorize::<$aleo, _>(
+                let authorization = $process.authorize_with_memo::<$aleo, _>(
                     cast_ref!(&private_key as PrivateKey<$network>),
                     cast_ref!(program_id as ProgramID<$network>),
                     cast_ref!(function_name as Identifier<$network>),
                     cast_ref!(inputs as Vec<Value<$network>>).iter(),
+                    expiration_height,
+                    memo,
                     rng,
                 )?;
                 // Prepare the authorization.
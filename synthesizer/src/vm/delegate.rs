@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+use synthesizer_program::StackProgram;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Checks that the given authorization bundle is safe to prove on this ledger. On failure, returns an error.
+    ///
+    /// This ensures the bundle has not expired, is anchored to a state root in this ledger,
+    /// contains valid requests, and has not already been proven into the ledger.
+    pub fn check_authorization_bundle(&self, bundle: &AuthorizationBundle<N>) -> Result<()> {
+        // Ensure the bundle has not expired.
+        let latest_height = self.block_store().current_block_height();
+        ensure!(
+            latest_height < bundle.expiration_height(),
+            "The authorization bundle expired at block {} (latest block is {latest_height})",
+            bundle.expiration_height()
+        );
+        // Ensure the state root of the bundle is in the ledger.
+        ensure!(
+            self.block_store().contains_state_root(&bundle.state_root())?,
+            "The authorization bundle is anchored to an unknown state root '{}'",
+            bundle.state_root()
+        );
+
+        // Check the execution and fee authorizations.
+        for authorization in std::iter::once(bundle.authorization()).chain(bundle.fee_authorization()) {
+            // Ensure the requests are valid.
+            for request in authorization.to_vec_deque() {
+                let input_types = self
+                    .process()
+                    .read()
+                    .get_stack(request.program_id())?
+                    .program()
+                    .get_function_ref(request.function_name())?
+                    .input_types();
+                ensure!(
+                    request.verify(&input_types),
+                    "Invalid request for '{}/{}' in the authorization bundle",
+                    request.program_id(),
+                    request.function_name()
+                );
+            }
+            // Ensure the transitions are not already in the ledger, so that the bundle is not replayed.
+            for (transition_id, transition) in authorization.transitions() {
+                ensure!(
+                    !self.transition_store().contains_transition_id(&transition_id)?,
+                    "The transition '{transition_id}' in the authorization bundle already exists in the ledger"
+                );
+                ensure!(
+                    !self.transition_store().contains_tpk(transition.tpk())?,
+                    "The transition public key of '{transition_id}' already exists in the ledger"
+                );
+                for serial_number in transition.serial_numbers() {
+                    ensure!(
+                        !self.transition_store().contains_serial_number(serial_number)?,
+                        "The serial number '{serial_number}' in the authorization bundle was already spent"
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a new execute transaction for the given authorization bundle, after checking the bundle.
+    /// The execution must be proven against the state root that the bundle is anchored to.
+    pub fn execute_authorization_bundle<R: Rng + CryptoRng>(
+        &self,
+        bundle: &AuthorizationBundle<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Check the authorization bundle.
+        self.check_authorization_bundle(bundle)?;
+        // Execute the authorizations.
+        // Note: The authorizations are replicated, as execution consumes their requests.
+        let transaction = self.execute_authorization(
+            bundle.authorization().replicate(),
+            bundle.fee_authorization().map(Authorization::replicate),
+            query,
+            rng,
+        )?;
+        // Ensure the execution was proven against the state root of the bundle.
+        // Note: The execution is proven against the latest state root, so the bundle must be anchored to it.
+        match &transaction {
+            Transaction::Execute(_, execution, _) => ensure!(
+                execution.global_state_root() == bundle.state_root(),
+                "The ledger advanced past the state root of the authorization bundle - the signer must re-anchor it"
+            ),
+            _ => bail!("Expected an execute transaction for the authorization bundle, found '{}'", transaction.id()),
+        }
+        Ok(transaction)
+    }
+
+    /// Checks that the given transaction was proven from the given authorization bundle, and is valid to broadcast.
+    /// On failure, returns an error.
+    pub fn check_delegated_transaction<R: Rng + CryptoRng>(
+        &self,
+        bundle: &AuthorizationBundle<N>,
+        transaction: &Transaction<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Ensure the transaction is an execution.
+        let Transaction::Execute(_, execution, fee) = transaction else {
+            bail!("Expected an execute transaction for the authorization bundle, found '{}'", transaction.id())
+        };
+
+        // Ensure the execution contains exactly the authorized transitions.
+        let transition_ids = execution.transitions().map(|transition| *transition.id()).collect::<Vec<_>>();
+        let expected_ids = bundle.authorization().transitions().into_keys().collect::<Vec<_>>();
+        ensure!(
+            transition_ids == expected_ids,
+            "The transitions in the transaction do not match the authorization bundle"
+        );
+        // Ensure the execution ID matches the bundle.
+        ensure!(
+            execution.to_execution_id()? == bundle.to_execution_id()?,
+            "The execution ID of the transaction does not match the authorization bundle"
+        );
+        // Ensure the execution is anchored to the state root of the bundle.
+        ensure!(
+            execution.global_state_root() == bundle.state_root(),
+            "The global state root of the transaction does not match the authorization bundle"
+        );
+
+        // Ensure the fee is the authorized fee.
+        match (fee, bundle.fee_authorization()) {
+            (Some(fee), Some(fee_authorization)) => ensure!(
+                fee_authorization.transitions().contains_key(fee.transition_id())
+                    && fee_authorization.transitions().len() == 1,
+                "The fee in the transaction does not match the authorization bundle"
+            ),
+            (None, None) => (),
+            (Some(_), None) => bail!("The transaction contains a fee that is not in the authorization bundle"),
+            (None, Some(_)) => bail!("The transaction is missing the fee in the authorization bundle"),
+        }
+
+        // Ensure the bundle has not expired.
+        let latest_height = self.block_store().current_block_height();
+        ensure!(
+            latest_height < bundle.expiration_height(),
+            "The authorization bundle expired at block {}",
+            bundle.expiration_height()
+        );
+
+        // Verify the transaction.
+        self.check_transaction(transaction, None, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use ledger_store::helpers::memory::ConsensusMemory;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a VM with the genesis block, and the private key of the genesis account.
+    fn prepare_vm(
+        rng: &mut TestRng,
+    ) -> (VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>, PrivateKey<CurrentNetwork>) {
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+        (vm, crate::vm::test_helpers::sample_genesis_private_key(rng))
+    }
+
+    /// Returns an authorization bundle for a public transfer, with a public fee.
+    fn sample_bundle(
+        vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        expiration_height: u32,
+        rng: &mut TestRng,
+    ) -> AuthorizationBundle<CurrentNetwork> {
+        let address = Address::try_from(private_key).unwrap();
+        // Authorize the transfer.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let authorization = vm
+            .authorize_with_expiration(private_key, "credits.aleo", "transfer_public", inputs, Some(expiration_height), rng)
+            .unwrap();
+        // Authorize the fee.
+        let execution_id = authorization.to_execution_id().unwrap();
+        let fee_authorization = vm.authorize_fee_public(private_key, 10_000_000, 0, execution_id, rng).unwrap();
+        // Construct the bundle.
+        let state_root = vm.block_store().current_state_root();
+        AuthorizationBundle::new(
+            private_key,
+            authorization,
+            Some(fee_authorization),
+            state_root,
+            expiration_height,
+            rng,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_execute_authorization_bundle() {
+        let rng = &mut TestRng::default();
+
+        // Prepare the VM.
+        let (vm, private_key) = prepare_vm(rng);
+
+        // Ensure an expired bundle is rejected.
+        let bundle = sample_bundle(&vm, &private_key, 0, rng);
+        assert!(vm.check_authorization_bundle(&bundle).is_err());
+
+        // Prove a valid bundle.
+        let bundle = sample_bundle(&vm, &private_key, 10, rng);
+        vm.check_authorization_bundle(&bundle).unwrap();
+        let transaction = vm.execute_authorization_bundle(&bundle, None, rng).unwrap();
+
+        // Ensure the transaction is accepted for the bundle.
+        vm.check_delegated_transaction(&bundle, &transaction, rng).unwrap();
+
+        // Ensure the transaction is rejected for another bundle.
+        let other_bundle = sample_bundle(&vm, &private_key, 10, rng);
+        assert!(vm.check_delegated_transaction(&other_bundle, &transaction, rng).is_err());
+    }
+}
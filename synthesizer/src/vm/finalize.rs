@@ -28,7 +28,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     pub fn speculate<'a>(
         &self,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         coinbase_reward: Option<u64>,
         candidate_ratifications: Vec<Ratify<N>>,
         candidate_solutions: Option<&CoinbaseSolution<N>>,
@@ -70,7 +70,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     pub fn check_speculate(
         &self,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         ratifications: &Ratifications<N>,
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
@@ -112,7 +112,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     pub fn finalize(
         &self,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         ratifications: &Ratifications<N>,
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
@@ -148,7 +148,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///     to the front of the `ratifications` list.
     fn atomic_speculate<'a>(
         &self,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         coinbase_reward: Option<u64>,
         ratifications: Vec<Ratify<N>>,
         solutions: Option<&CoinbaseSolution<N>>,
@@ -192,6 +192,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Retrieve the finalize store.
             let store = self.finalize_store();
 
+            // Reposition the entries of the mappings, at the height from which their positions are read.
+            // Note: On failure, this will abort the entire atomic batch.
+            if state.block_height() == N::MAPPING_POSITIONS_HEIGHT {
+                if let Err(e) = store.reposition_mappings() {
+                    return Err(format!("Failed to reposition the mappings - {e}"));
+                }
+            }
+
             /* Perform the ratifications before finalize. */
 
             match Self::atomic_pre_ratify(store, state, pre_ratifications) {
@@ -243,6 +251,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     }
                 }
 
+                // Construct the finalize state for the transaction.
+                let state = state.with_transaction(transaction.id());
+
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
@@ -310,10 +321,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // The finalize operation here involves calling 'update_key_value',
                     // and update the respective leaves of the finalize tree.
                     Transaction::Execute(_, execution, fee) => {
-                        match process.finalize_execution(state, store, execution, fee.as_ref()) {
+                        match self.editions_at_state_root(execution).and_then(|editions| {
+                            process.at_editions(&editions)?.finalize_execution(state, store, execution, fee.as_ref())
+                        }) {
                             // Construct the accepted execute transaction.
-                            Ok(finalize) => {
-                                ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize)
+                            Ok((finalize, events)) => {
+                                ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize, events)
                                     .map_err(|e| e.to_string())
                             }
                             // Construct the rejected execute transaction.
@@ -443,7 +456,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     fn atomic_finalize(
         &self,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         ratifications: &Ratifications<N>,
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
@@ -469,6 +482,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Retrieve the finalize store.
             let store = self.finalize_store();
 
+            // Start recording the finalize history of the block, if enabled.
+            // Note: On failure, this will abort the entire atomic batch.
+            if let Err(e) = store.start_history(state.block_height()) {
+                return Err(format!("Failed to start the finalize history - {e}"));
+            }
+
+            // Reposition the entries of the mappings, at the height from which their positions are read.
+            // Note: On failure, this will abort the entire atomic batch.
+            if state.block_height() == N::MAPPING_POSITIONS_HEIGHT {
+                if let Err(e) = store.reposition_mappings() {
+                    return Err(format!("Failed to reposition the mappings - {e}"));
+                }
+            }
+
             /* Perform the ratifications before finalize. */
 
             match Self::atomic_pre_ratify(store, state, pre_ratifications) {
@@ -498,6 +525,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Note: This will abort the entire atomic batch.
                     return Err(format!("Mismatch in {} transaction index", transaction.variant()));
                 }
+                // Retrieve the unconfirmed transaction ID.
+                // Note: On failure, this will abort the entire atomic batch.
+                let transaction_id = transaction
+                    .to_unconfirmed_transaction_id()
+                    .map_err(|_| "Failed to retrieve the unconfirmed transaction ID".to_string())?;
+                // Construct the finalize state for the transaction.
+                let state = state.with_transaction(transaction_id);
+
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
@@ -529,7 +564,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         };
                         Ok(())
                     }
-                    ConfirmedTransaction::AcceptedExecute(_, transaction, finalize) => {
+                    ConfirmedTransaction::AcceptedExecute(_, transaction, finalize, events) => {
                         // Extract the execution and fee from the transaction.
                         let (execution, fee) = match transaction {
                             Transaction::Execute(_, execution, fee) => (execution, fee),
@@ -538,15 +573,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         };
                         // The finalize operation here involves calling 'update_key_value',
                         // and update the respective leaves of the finalize tree.
-                        match process.finalize_execution(state, store, execution, fee.as_ref()) {
+                        match self.editions_at_state_root(execution).and_then(|editions| {
+                            process.at_editions(&editions)?.finalize_execution(state, store, execution, fee.as_ref())
+                        }) {
                             // Ensure the finalize operations match the expected.
-                            Ok(finalize_operations) => {
+                            Ok((finalize_operations, emitted_events)) => {
                                 if finalize != &finalize_operations {
                                     // Note: This will abort the entire atomic batch.
                                     return Err(format!(
                                         "Mismatch in finalize operations for an accepted execute - (found: {finalize_operations:?}, expected: {finalize:?})"
                                     ));
                                 }
+                                // Ensure the events match the expected.
+                                if events != &emitted_events {
+                                    // Note: This will abort the entire atomic batch.
+                                    return Err(format!(
+                                        "Mismatch in events for an accepted execute - (found: {emitted_events:?}, expected: {events:?})"
+                                    ));
+                                }
                             }
                             // Note: This will abort the entire atomic batch.
                             Err(error) => {
@@ -663,7 +707,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Commit all of the stacks to the process.
             if !stacks.is_empty() {
-                stacks.into_iter().for_each(|stack| process.add_stack(stack))
+                if let Err(e) = process.add_stacks(stacks) {
+                    // Note: This will abort the entire atomic batch.
+                    return Err(format!("Failed to add the deployed programs - {e}"));
+                }
             }
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
@@ -676,7 +723,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     fn atomic_pre_ratify<'a>(
         store: &FinalizeStore<N, C::FinalizeStorage>,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         pre_ratifications: impl Iterator<Item = &'a Ratify<N>>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         // Construct the program ID.
@@ -776,7 +823,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     fn atomic_post_ratify<'a>(
         store: &FinalizeStore<N, C::FinalizeStorage>,
-        state: FinalizeGlobalState,
+        state: FinalizeGlobalState<N>,
         post_ratifications: impl Iterator<Item = &'a Ratify<N>>,
         solutions: Option<&CoinbaseSolution<N>>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
@@ -1005,6 +1052,7 @@ finalize transfer_public:
             ratifications.to_ratifications_root().unwrap(),
             Field::zero(),
             Field::zero(),
+            transactions.to_events_root().unwrap(),
             metadata,
         )?;
 
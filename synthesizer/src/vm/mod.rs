@@ -15,7 +15,9 @@
 mod helpers;
 pub use helpers::*;
 
+mod attest;
 mod authorize;
+mod delegate;
 mod deploy;
 mod execute;
 mod finalize;
@@ -25,7 +27,7 @@ use crate::{cast_mut_ref, cast_ref, process};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
+    program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, StatePath, Value},
     types::{Field, U64},
 };
 use ledger_block::{
@@ -47,15 +49,17 @@ use ledger_query::Query;
 use ledger_store::{
     atomic_finalize,
     BlockStore,
+    ConsensusSnapshot,
     ConsensusStorage,
     ConsensusStore,
     FinalizeMode,
     FinalizeStore,
+    IntegrityReport,
     TransactionStorage,
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{Authorization, Process, Trace};
+use synthesizer_process::{Authorization, AuthorizationBundle, BalanceAttestation, Process, Trace};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -107,8 +111,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let program = deployment.program();
             let program_id = program.id();
 
-            // Return early if the program is already loaded.
-            if process.contains_program(program_id) {
+            // Return early if the edition of the program is already loaded.
+            if process.contains_program_edition(program_id, deployment.edition()) {
                 return Ok(vec![]);
             }
 
@@ -161,8 +165,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 .collect::<Result<Vec<_>>>()?;
 
             for (program_id, deployment) in deployments.iter().flatten() {
-                // Load the deployment if it does not exist in the process yet.
-                if !process.contains_program(program_id) {
+                // Load the deployment if its edition does not exist in the process yet.
+                if !process.contains_program_edition(program_id, deployment.edition()) {
                     process.load_deployment(deployment)?;
                 }
             }
@@ -209,6 +213,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn transition_store(&self) -> &TransitionStore<N, C::TransitionStorage> {
         self.store.transition_store()
     }
+
+    /// Returns a snapshot of the consensus state at the latest block height.
+    pub fn to_snapshot(&self) -> Result<ConsensusSnapshot<N>> {
+        self.store.to_snapshot()
+    }
+
+    /// Checks the integrity of the consensus storage, and returns the issues that were found.
+    pub fn check_integrity(&self) -> Result<IntegrityReport> {
+        self.store.check_integrity()
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -279,7 +293,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             .collect::<Result<Vec<_>, _>>()?;
 
         // Construct the finalize state.
-        let state = FinalizeGlobalState::new_genesis::<N>()?;
+        let state = FinalizeGlobalState::new_genesis()?;
         // Speculate on the ratifications, solutions, and transactions.
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) =
             self.speculate(state, None, ratifications, solutions.as_ref(), transactions.iter())?;
@@ -315,9 +329,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
         // Construct the finalize state.
-        let state = FinalizeGlobalState::new::<N>(
+        let state = FinalizeGlobalState::new(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -361,8 +376,8 @@ pub(crate) mod test_helpers {
     pub(crate) type CurrentNetwork = Testnet3;
 
     /// Samples a new finalize state.
-    pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState<CurrentNetwork> {
+        FinalizeGlobalState::from(block_height as u64, block_height, block_height as i64, [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
@@ -659,6 +674,7 @@ function compute:
             ratifications.to_ratifications_root().unwrap(),
             Field::zero(),
             Field::zero(),
+            transactions.to_events_root().unwrap(),
             metadata,
         )?;
 
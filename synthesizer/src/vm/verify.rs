@@ -32,6 +32,9 @@ macro_rules! ensure_is_unique {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// The number of blocks after an upgrade, during which executions of the previous edition remain valid.
+    pub const EDITION_GRACE_PERIOD_IN_BLOCKS: u32 = 100;
+
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction<R: CryptoRng + Rng>(
@@ -92,6 +95,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         lap!(timer, "Check for duplicate elements");
 
+        /* Expiration */
+
+        // Ensure none of the transitions have expired.
+        let latest_height = self.block_store().current_block_height();
+        if let Some(transition) = transaction.transitions().find(|transition| transition.is_expired(latest_height)) {
+            bail!(
+                "Transition '{}' expired at block {} (latest block is {latest_height})",
+                transition.id(),
+                transition.expiration_height().unwrap_or_default()
+            )
+        }
+
         // First, verify the fee.
         self.check_fee(transaction, rejected_id)?;
 
@@ -104,13 +119,32 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
-                // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist..
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
+                // Retrieve the program ID.
+                let program_id = deployment.program_id();
+                // Ensure the edition is correct, and an upgrade is authorized by the original owner.
+                match self.transaction_store().deployment_store().get_edition(program_id)? {
+                    // If the program does not exist, ensure this is the initial edition.
+                    None => {
+                        if deployment.edition() != N::EDITION {
+                            bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
+                        }
+                    }
+                    // If the program exists, ensure this is the next edition.
+                    Some(edition) => {
+                        if Some(deployment.edition()) != edition.checked_add(1) {
+                            bail!("Program ID '{program_id}' is already deployed (edition {edition})")
+                        }
+                        // Retrieve the original owner.
+                        let Some(original_owner) =
+                            self.transaction_store().deployment_store().get_owner_at_edition(program_id, N::EDITION)?
+                        else {
+                            bail!("Failed to find the original owner of program '{program_id}'")
+                        };
+                        // Ensure the upgrade is signed by the original owner.
+                        if owner.address() != original_owner.address() {
+                            bail!("Invalid deployment transaction '{id}' - only the owner may upgrade '{program_id}'")
+                        }
+                    }
                 }
                 // Verify the deployment.
                 self.check_deployment_internal(deployment, rng)?;
@@ -167,8 +201,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if let Some(fee) = fee {
                     // If the fee is required, then check that the base fee amount is satisfied.
                     if is_fee_required {
-                        // Compute the execution cost.
-                        let (cost, _) = execution_cost(self, execution)?;
+                        // Compute the minimum cost, as the storage cost plus a single pass over the finalize logic.
+                        // Note: The finalize cost is metered, and an execution that runs out of gas is rejected.
+                        let (cost, _) = execution_minimum_cost(self, execution)?;
                         // Ensure the fee is sufficient to cover the cost.
                         if *fee.base_amount()? < cost {
                             bail!(
@@ -232,8 +267,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     fn check_execution_internal(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("VM::check_execution");
 
-        // Verify the execution.
-        let verification = self.process.read().verify_execution(execution);
+        // Verify the execution, against the editions of its programs at its global state root.
+        let verification = self
+            .editions_at_state_root(execution)
+            .and_then(|editions| self.process.read().at_editions(&editions)?.verify_execution(execution));
         lap!(timer, "Verify the execution");
 
         // Ensure the global state root exists in the block store.
@@ -250,6 +287,74 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 
+    /// Returns the edition of each program in the given execution, at the global state root of the execution.
+    /// As the global state root is a public input of every transition proof, this binds the editions to the execution.
+    /// An execution of a previous edition is only valid for `EDITION_GRACE_PERIOD_IN_BLOCKS` after the upgrade.
+    pub(crate) fn editions_at_state_root(&self, execution: &Execution<N>) -> Result<IndexMap<ProgramID<N>, u16>> {
+        // Retrieve the deployment store.
+        let deployment_store = self.transaction_store().deployment_store();
+        // Returns the block height of the given edition of the program, if it is in a block.
+        let deployment_height = |program_id: &ProgramID<N>, edition: u16| {
+            let Some(transaction_id) = deployment_store.find_transaction_id_at_edition(program_id, edition)? else {
+                return Ok(None);
+            };
+            match self.block_store().find_block_hash(&transaction_id)? {
+                Some(block_hash) => self.block_store().get_block_height(&block_hash),
+                None => Ok(None),
+            }
+        };
+
+        // Initialize the editions.
+        let mut editions = IndexMap::new();
+        // Initialize the block height of the global state root, which is retrieved on first use.
+        let mut state_root_height = None;
+        for program_id in execution.transitions().map(|transition| *transition.program_id()) {
+            // Skip the program, if its edition is already set.
+            if editions.contains_key(&program_id) {
+                continue;
+            }
+            // Retrieve the latest edition of the program, and skip the program if it has not been upgraded.
+            // Note: A program without an edition (i.e. 'credits.aleo') is part of the VM, and is never upgraded.
+            let mut edition = match deployment_store.get_edition(&program_id)? {
+                Some(edition) if edition > N::EDITION => edition,
+                _ => continue,
+            };
+            // Retrieve the block height of the global state root.
+            let height = match state_root_height {
+                Some(height) => height,
+                None => match self.block_store().find_block_height_from_state_root(execution.global_state_root())? {
+                    Some(height) => *state_root_height.insert(height),
+                    None => bail!("The global state root '{}' does not exist", execution.global_state_root()),
+                },
+            };
+            // Find the latest edition that is deployed at the global state root.
+            // Note: An edition that is not in a block yet is not deployed at any global state root.
+            let mut upgrade_height = None;
+            loop {
+                match deployment_height(&program_id, edition)? {
+                    Some(deployment_height) if deployment_height <= height => break,
+                    deployment_height => {
+                        ensure!(
+                            edition > N::EDITION,
+                            "Program '{program_id}' is not deployed at the global state root"
+                        );
+                        upgrade_height = deployment_height;
+                        edition -= 1;
+                    }
+                }
+            }
+            // Ensure an execution of a previous edition is within the grace period of the upgrade.
+            if let Some(upgrade_height) = upgrade_height {
+                let latest_height = self.block_store().current_block_height();
+                if latest_height >= upgrade_height.saturating_add(Self::EDITION_GRACE_PERIOD_IN_BLOCKS) {
+                    bail!("Program '{program_id}' (edition {edition}) was upgraded at block {upgrade_height}")
+                }
+            }
+            editions.insert(program_id, edition);
+        }
+        Ok(editions)
+    }
+
     /// Verifies the given fee. On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
@@ -496,6 +601,7 @@ mod tests {
             ratifications.to_ratifications_root().unwrap(),
             Field::zero(),
             Field::zero(),
+            transactions.to_events_root().unwrap(),
             deployment_metadata,
         )
         .unwrap();
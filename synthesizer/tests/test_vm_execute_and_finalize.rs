@@ -271,7 +271,9 @@ fn run_test(test: &ProgramTest) -> serde_yaml::Mapping {
                     result.insert(
                         serde_yaml::Value::String("speculate".to_string()),
                         serde_yaml::Value::String(match transactions.iter().next().unwrap() {
-                            ConfirmedTransaction::AcceptedExecute(_, _, _) => "the execution was accepted".to_string(),
+                            ConfirmedTransaction::AcceptedExecute(_, _, _, _) => {
+                                "the execution was accepted".to_string()
+                            }
                             ConfirmedTransaction::RejectedExecute(_, _, _, _) => {
                                 "the execution was rejected".to_string()
                             }
@@ -459,6 +461,7 @@ fn construct_next_block<C: ConsensusStorage<CurrentNetwork>, R: Rng + CryptoRng>
         ratifications.to_ratifications_root().unwrap(),
         Field::zero(),
         Field::zero(),
+        transactions.to_events_root().unwrap(),
         metadata,
     )?;
 
@@ -497,7 +500,7 @@ fn split<C: ConsensusStorage<CurrentNetwork>, R: Rng + CryptoRng>(
 // Construct `FinalizeGlobalState` from the current `VM` state.
 fn construct_finalize_global_state<C: ConsensusStorage<CurrentNetwork>>(
     vm: &VM<CurrentNetwork, C>,
-) -> FinalizeGlobalState {
+) -> FinalizeGlobalState<CurrentNetwork> {
     // Retrieve the latest block.
     let block_height = *vm.block_store().heights().max().unwrap().clone();
     let latest_block_hash = vm.block_store().get_block_hash(block_height).unwrap().unwrap();
@@ -513,11 +516,14 @@ fn construct_finalize_global_state<C: ConsensusStorage<CurrentNetwork>>(
     let next_round = latest_round.saturating_add(1);
     // Compute the next height.
     let next_height = latest_height.saturating_add(1);
+    // Compute the next timestamp.
+    let next_timestamp = latest_block.timestamp().saturating_add(CurrentNetwork::BLOCK_TIME as i64);
 
     // Construct the finalize state.
-    FinalizeGlobalState::new::<CurrentNetwork>(
+    FinalizeGlobalState::new(
         next_round,
         next_height,
+        next_timestamp,
         latest_cumulative_weight,
         0u128,
         latest_block.hash(),
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(any(feature = "rocks", feature = "redb"))]
+use crate::cli::commands::Check;
 use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
 
 use anstyle::{AnsiColor, Color, Style};
@@ -40,6 +42,9 @@ pub struct CLI {
 pub enum Command {
     #[clap(name = "build")]
     Build(Build),
+    #[cfg(any(feature = "rocks", feature = "redb"))]
+    #[clap(name = "check")]
+    Check(Check),
     #[clap(name = "clean")]
     Clean(Clean),
     #[clap(name = "execute")]
@@ -57,6 +62,8 @@ impl Command {
     pub fn parse(self) -> Result<String> {
         match self {
             Self::Build(command) => command.parse(),
+            #[cfg(any(feature = "rocks", feature = "redb"))]
+            Self::Check(command) => command.parse(),
             Self::Clean(command) => command.parse(),
             Self::Execute(command) => command.parse(),
             Self::New(command) => command.parse(),
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::ledger::store::{ConsensusStore, IntegrityIssue};
+
+#[cfg(feature = "rocks")]
+type CurrentStorage = crate::ledger::store::helpers::rocksdb::ConsensusDB<CurrentNetwork>;
+#[cfg(all(feature = "redb", not(feature = "rocks")))]
+type CurrentStorage =
+    crate::ledger::store::helpers::kv::ConsensusKV<CurrentNetwork, crate::ledger::store::helpers::kv::RedbEngine>;
+
+/// Checks the integrity of the ledger storage, and rebuilds its inconsistent indexes
+#[derive(Debug, Parser)]
+pub struct Check {
+    /// The development ID of the ledger storage, for a development ledger.
+    #[clap(long)]
+    dev: Option<u16>,
+    /// Rebuilds the inconsistent indexes.
+    #[clap(long)]
+    repair: bool,
+}
+
+impl Check {
+    /// Checks the integrity of the ledger storage.
+    pub fn parse(self) -> Result<String> {
+        // Open the ledger storage.
+        let store = ConsensusStore::<CurrentNetwork, CurrentStorage>::open(self.dev)?;
+
+        // Check the ledger storage, and rebuild the inconsistent indexes if requested.
+        let report = match self.repair {
+            true => store.repair_integrity()?,
+            false => store.check_integrity()?,
+        };
+
+        // Prepare the blocks string.
+        let blocks_string = format!("({} blocks)", report.num_blocks());
+
+        // Log the state that was not checked.
+        if !report.is_complete() {
+            println!("🔎 Unchecked\n");
+            for unchecked in report.unchecked() {
+                println!(" •  {unchecked}");
+            }
+            println!();
+        }
+
+        // If the ledger storage is consistent, return success.
+        if report.is_consistent() {
+            return match report.is_complete() {
+                true => Ok(format!("✅ Checked the ledger storage {}", blocks_string.dimmed())),
+                false => Ok(format!(
+                    "⚠️  Checked the ledger storage, except for the unchecked state {}",
+                    blocks_string.dimmed()
+                )),
+            };
+        }
+
+        // Log the issues.
+        println!("🔎 Issues\n");
+        for issue in report.issues() {
+            println!(" •  {issue}");
+        }
+        println!();
+
+        // Suggest a repair, if any of the issues can be repaired.
+        if !self.repair && report.issues().iter().any(IntegrityIssue::is_repairable) {
+            println!("Run with '--repair' to rebuild the inconsistent indexes\n");
+        }
+
+        anyhow::bail!("Found {} issues in the ledger storage {}", report.issues().len(), blocks_string.dimmed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_check() {
+        let cli = CLI::parse_from(["snarkvm", "check", "--dev", "1", "--repair"]);
+
+        if let Command::Check(check) = cli.command {
+            assert_eq!(check.dev, Some(1));
+            assert!(check.repair);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+}
@@ -15,6 +15,11 @@
 pub mod build;
 pub use build::*;
 
+#[cfg(any(feature = "rocks", feature = "redb"))]
+pub mod check;
+#[cfg(any(feature = "rocks", feature = "redb"))]
+pub use check::*;
+
 pub mod clean;
 pub use clean::*;
 
@@ -61,6 +61,8 @@ impl<N: Network> Package<N> {
                 let (program, resource) = match call.operator() {
                     CallOperator::Locator(locator) => (process.get_program(locator.program_id())?, locator.resource()),
                     CallOperator::Resource(resource) => (program, resource),
+                    // The callee of a dynamic call is only known at runtime.
+                    CallOperator::Dynamic(..) => continue,
                 };
                 // If this is a function call, save its corresponding prover and verifier files.
                 if program.contains_function(resource) {